@@ -0,0 +1,3 @@
+import { b } from './b';
+debugger;
+export const a = b;
@@ -0,0 +1,83 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use cow_utils::CowUtils;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::Baseline;
+
+/// Baseline file, with paths relative to the current working directory.
+///
+/// ```json
+/// {
+///   "files": {
+///     "src/index.js": {
+///       "eslint(no-debugger)": 2
+///     }
+///   }
+/// }
+/// ```
+#[derive(Default, Serialize, Deserialize)]
+struct BaselineFile {
+    files: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+/// Read the baseline at `location`. A missing file is an empty baseline.
+pub fn read_baseline(location: &Path, cwd: &Path) -> Result<Baseline, String> {
+    let bytes = match fs::read(location) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+        Err(err) => return Err(err.to_string()),
+    };
+    let file: BaselineFile = serde_json::from_slice(&bytes).map_err(|err| err.to_string())?;
+    let mut baseline = Baseline::default();
+    for (path, codes) in file.files {
+        let path = cwd.join(path);
+        for (code, count) in codes {
+            baseline.set(path.clone(), code, count);
+        }
+    }
+    Ok(baseline)
+}
+
+/// Write `baseline` to `location`.
+pub fn write_baseline(location: &Path, cwd: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut file = BaselineFile::default();
+    for (path, code, count) in baseline.iter() {
+        let path = path.strip_prefix(cwd).unwrap_or(path).to_string_lossy();
+        file.files
+            .entry(path.cow_replace('\\', "/").into_owned())
+            .or_default()
+            .insert(code.to_string(), count);
+    }
+    let mut json = serde_json::to_string_pretty(&file).map_err(io::Error::other)?;
+    json.push('\n');
+    fs::write(location, json)
+}
+
+/// Remove entries of fixed diagnostics from `baseline`.
+///
+/// Counts of linted files are lowered to the number of `received` diagnostics. Entries of files
+/// which were not linted are kept, unless the file no longer exists.
+pub fn prune_baseline(
+    baseline: &Baseline,
+    received: &Baseline,
+    linted_paths: &FxHashSet<PathBuf>,
+) -> Baseline {
+    let mut pruned = Baseline::default();
+    for (path, code, count) in baseline.iter() {
+        let count = if linted_paths.contains(path) {
+            count.min(received.get(path, code))
+        } else if path.exists() {
+            count
+        } else {
+            0
+        };
+        pruned.set(path.to_path_buf(), code.to_string(), count);
+    }
+    pruned
+}
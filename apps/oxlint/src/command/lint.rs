@@ -35,6 +35,15 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
@@ -83,6 +92,12 @@ pub struct BasicOptions {
     /// Initialize oxlint configuration with default values
     #[bpaf(switch, hide_usage)]
     pub init: bool,
+
+    /// Enable rules which require type information, such as `typescript/no-floating-promises`.
+    /// Type information is provided by `tsserver`, which is looked up in
+    /// `node_modules/.bin` of the current working directory, or else in `PATH`.
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
 }
 
 // This is formatted according to
@@ -194,11 +209,54 @@ pub struct WarningOptions {
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
     /// Use a specific output format. Possible values:
-    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
+    /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `sarif`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint changed files, reusing the results of unchanged files from previous runs.
+    /// Results are invalidated when the file, the configuration, oxlint itself, or (with the
+    /// import plugin) any imported module changes
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file, used with `--cache`. Defaults to `.oxlintcache` in the current
+    /// working directory
+    #[bpaf(argument("./.oxlintcache"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Only report diagnostics which are not in the baseline file. A rule's diagnostics in a file
+    /// are all reported when there are more than in the baseline. Entries of fixed diagnostics
+    /// are removed from the baseline file
+    #[bpaf(argument("./baseline.json"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the current diagnostics of all rules in a baseline file, and do not report them
+    #[bpaf(argument("./baseline.json"), hide_usage)]
+    pub baseline_write: Option<PathBuf>,
+}
+
+/// Stdin
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint code provided on standard input, instead of files. Cannot be combined with paths.
+    /// With `--fix`, the fixed code is written to standard output and diagnostics to standard error
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the code provided on standard input, used to determine its source type,
+    /// configuration and ignore patterns. The file does not need to exist. Defaults to `stdin.js`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
 /// Enable Plugins
 #[expect(clippy::struct_field_names)]
 #[derive(Debug, Default, Clone, Bpaf)]
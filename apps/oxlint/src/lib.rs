@@ -1,8 +1,10 @@
+mod baseline;
 mod command;
 mod lint;
 mod output_formatter;
 mod result;
 mod runner;
+mod stdin;
 mod tester;
 mod walk;
 
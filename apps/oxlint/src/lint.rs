@@ -2,7 +2,7 @@ use std::{
     env,
     ffi::OsStr,
     fs,
-    io::{ErrorKind, Write},
+    io::{self, ErrorKind, Read, Write},
     path::{Path, PathBuf, absolute},
     sync::Arc,
     time::Instant,
@@ -11,17 +11,21 @@ use std::{
 use cow_utils::CowUtils;
 use ignore::{gitignore::Gitignore, overrides::OverrideBuilder};
 use oxc_allocator::AllocatorPool;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
+use oxc_diagnostics::{Baseline, DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
-    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, InvalidFilterKind, LintFilter,
-    LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
+    AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, FixKind, InvalidFilterKind,
+    LINTABLE_EXTENSIONS, LintCache, LintFilter, LintOptions, LintService, LintServiceOptions,
+    Linter, Oxlintrc, TsServer,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
 use crate::{
+    baseline::{prune_baseline, read_baseline, write_baseline},
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, Runner, WarningOptions},
-    output_formatter::{LintCommandInfo, OutputFormatter},
+    command::VERSION,
+    output_formatter::{LintCommandInfo, OutputFormat, OutputFormatter},
+    stdin::StdinFileSystem,
     walk::Walk,
 };
 
@@ -29,13 +33,19 @@ use crate::{
 pub struct LintRunner {
     options: LintCommand,
     cwd: PathBuf,
+    /// Code to lint with `--stdin`, read from standard input when not set.
+    stdin: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options, cwd: env::current_dir().expect("Failed to get current working directory") }
+        Self {
+            options,
+            cwd: env::current_dir().expect("Failed to get current working directory"),
+            stdin: None,
+        }
     }
 
     fn run(self, stdout: &mut dyn Write) -> CliRunResult {
@@ -60,6 +70,9 @@ impl Runner for LintRunner {
             misc_options,
             disable_nested_config,
             inline_config_options,
+            cache_options,
+            baseline_options,
+            stdin_options,
             ..
         } = self.options;
 
@@ -69,6 +82,34 @@ impl Runner for LintRunner {
             basic_options.config.is_none();
 
         let mut paths = paths;
+
+        // Code from standard input is linted as if it were the file at `--stdin-filename`.
+        let stdin = if stdin_options.stdin {
+            if !paths.is_empty() {
+                print_and_flush_stdout(
+                    stdout,
+                    "The `--stdin` option cannot be combined with paths. Use `--stdin-filename` to set the path of the code.\n",
+                );
+                return CliRunResult::InvalidOptionStdin;
+            }
+            let source_text = if let Some(source_text) = self.stdin {
+                source_text
+            } else {
+                let mut source_text = String::new();
+                if let Err(err) = io::stdin().read_to_string(&mut source_text) {
+                    print_and_flush_stdout(stdout, &format!("Failed to read from stdin: {err}\n"));
+                    return CliRunResult::StdinReadFailed;
+                }
+                source_text
+            };
+            paths = vec![stdin_options.stdin_filename.unwrap_or_else(|| "stdin.js".into())];
+            Some(source_text)
+        } else {
+            None
+        };
+        // With `--stdin --fix`, standard output is reserved for the fixed code.
+        let fix_stdin = stdin.is_some() && fix_options.is_enabled();
+
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -157,7 +198,15 @@ impl Runner for LintRunner {
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
                 }) {
-                    print_and_flush_stdout(stdout, &end);
+                    if fix_stdin {
+                        print_and_flush_stdout(&mut io::stderr(), &end);
+                    } else {
+                        print_and_flush_stdout(stdout, &end);
+                    }
+                }
+                // Ignored code is written back unchanged.
+                if let (true, Some(source_text)) = (fix_stdin, &stdin) {
+                    print_and_flush_stdout(stdout, source_text);
                 }
 
                 return CliRunResult::LintNoFilesFound;
@@ -166,8 +215,21 @@ impl Runner for LintRunner {
             paths.push(self.cwd.clone());
         }
 
-        let walker = Walk::new(&paths, &ignore_options, override_builder);
-        let paths = walker.paths();
+        let paths = if stdin.is_some() {
+            // The file does not need to exist, so it is not walked.
+            paths
+                .iter()
+                .filter_map(|path| absolute(self.cwd.join(path)).ok())
+                .filter(|path| {
+                    path.extension()
+                        .and_then(OsStr::to_str)
+                        .is_some_and(|ext| LINTABLE_EXTENSIONS.contains(&ext))
+                })
+                .map(|path| Arc::<OsStr>::from(path.into_os_string()))
+                .collect()
+        } else {
+            Walk::new(&paths, &ignore_options, override_builder).paths()
+        };
         let number_of_files = paths.len();
 
         let handler = GraphicalReportHandler::new();
@@ -250,6 +312,43 @@ impl Runner for LintRunner {
         } else {
             nested_configs.values().any(|config| config.plugins().has_import())
         };
+        // The baseline is written to `--baseline-write`, or pruned in place with `--baseline`.
+        let baseline_location = baseline_options
+            .baseline_write
+            .as_ref()
+            .or(baseline_options.baseline.as_ref())
+            .map(|location| self.cwd.join(location));
+        let baseline = if baseline_options.baseline_write.is_some() {
+            Some(Baseline::all())
+        } else if let Some(location) = &baseline_location {
+            match read_baseline(location, &self.cwd) {
+                Ok(baseline) => Some(baseline),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to read baseline file {:?}: {err}\n",
+                            location.to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                    return CliRunResult::InvalidOptionBaseline;
+                }
+            }
+        } else {
+            None
+        };
+        let linted_paths = if baseline.is_some() {
+            paths.iter().map(PathBuf::from).collect::<FxHashSet<_>>()
+        } else {
+            FxHashSet::default()
+        };
+
+        let stdin_file_system = stdin.as_ref().zip(paths.first()).map(|(source_text, path)| {
+            StdinFileSystem::new(PathBuf::from(path), source_text.clone())
+        });
+        let fixed_stdin = stdin_file_system.as_ref().map(StdinFileSystem::fixed);
+
+        let cwd = self.cwd.clone();
         let mut options =
             LintServiceOptions::new(self.cwd, paths).with_cross_module(use_cross_module);
 
@@ -261,11 +360,37 @@ impl Runner for LintRunner {
             _ => None,
         };
 
-        let linter =
+        // SARIF includes safe fixes for diagnostics, unless they are applied with `--fix`.
+        let report_fixes = format_str == OutputFormat::Sarif && !fix_options.is_enabled();
+        let fix_kind = if report_fixes { FixKind::SafeFix } else { fix_options.fix_kind() };
+        options = options.with_report_fixes(report_fixes);
+
+        let mut linter =
             Linter::new(LintOptions::default(), ConfigStore::new(lint_config, nested_configs))
-                .with_fix(fix_options.fix_kind())
+                .with_fix(fix_kind)
                 .with_report_unused_directives(report_unused_directives);
 
+        if basic_options.type_aware {
+            let local_tsserver = options.cwd().join("node_modules/.bin/tsserver");
+            let command = if local_tsserver.is_file() {
+                local_tsserver.into_os_string()
+            } else {
+                "tsserver".into()
+            };
+            match TsServer::spawn(&command) {
+                Ok(tsserver) => linter = linter.with_type_info_provider(Arc::new(tsserver)),
+                Err(err) => {
+                    print_and_flush_stdout(
+                        stdout,
+                        &format!(
+                            "Failed to start tsserver for type-aware linting: {err}\nInstall `typescript` in the current working directory.\n"
+                        ),
+                    );
+                    return CliRunResult::TypeAwareTsServerNotFound;
+                }
+            }
+        }
+
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if path.is_file() {
@@ -285,8 +410,16 @@ impl Runner for LintRunner {
             }
         }
 
+        if cache_options.cache && stdin.is_none() {
+            let location = cache_options.cache_location.unwrap_or_else(|| ".oxlintcache".into());
+            let location =
+                if location.is_relative() { options.cwd().join(location) } else { location };
+            options = options.with_cache(LintCache::new(location, VERSION));
+        }
+
         let mut diagnostic_service =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options)
+                .with_baseline(baseline.clone());
         let tx_error = diagnostic_service.sender().clone();
 
         let number_of_rules = linter.number_of_rules();
@@ -296,10 +429,38 @@ impl Runner for LintRunner {
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn(move || {
             let mut lint_service = LintService::new(&linter, allocator_pool, options);
+            if let Some(file_system) = stdin_file_system {
+                lint_service = lint_service.with_file_system(Box::new(file_system));
+            }
             lint_service.run(&tx_error);
         });
 
-        let diagnostic_result = diagnostic_service.run(stdout);
+        let mut stderr = io::stderr();
+        let output: &mut dyn Write = if fix_stdin { &mut stderr } else { &mut *stdout };
+
+        let diagnostic_result = diagnostic_service.run(output);
+
+        let mut baseline_write_failed = false;
+        if let (Some(baseline), Some(location)) = (baseline, baseline_location) {
+            let received = diagnostic_service.received_diagnostics();
+            let new_baseline = if baseline_options.baseline_write.is_some() {
+                received.clone()
+            } else {
+                prune_baseline(&baseline, received, &linted_paths)
+            };
+            if new_baseline != baseline || baseline_options.baseline_write.is_some() {
+                if let Err(err) = write_baseline(&location, &cwd, &new_baseline) {
+                    print_and_flush_stdout(
+                        output,
+                        &format!(
+                            "Failed to write baseline file {:?}: {err}\n",
+                            location.to_string_lossy().cow_replace('\\', "/")
+                        ),
+                    );
+                    baseline_write_failed = true;
+                }
+            }
+        }
 
         if let Some(end) = output_formatter.lint_command_info(&LintCommandInfo {
             number_of_files,
@@ -307,10 +468,18 @@ impl Runner for LintRunner {
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
         }) {
-            print_and_flush_stdout(stdout, &end);
+            print_and_flush_stdout(output, &end);
+        }
+
+        if let (true, Some(source_text)) = (fix_stdin, stdin) {
+            let fixed = fixed_stdin.and_then(|fixed| fixed.lock().unwrap().take());
+            print_and_flush_stdout(stdout, &fixed.unwrap_or(source_text));
         }
 
-        if diagnostic_result.errors_count() > 0 {
+        if baseline_write_failed {
+            // Do not let CI pass with a stale baseline.
+            CliRunResult::BaselineWriteFailed
+        } else if diagnostic_result.errors_count() > 0 {
             CliRunResult::LintFoundErrors
         } else if warning_options.deny_warnings && diagnostic_result.warnings_count() > 0 {
             CliRunResult::LintNoWarningsAllowed
@@ -331,6 +500,13 @@ impl LintRunner {
         self
     }
 
+    /// Code to lint with `--stdin`, instead of reading it from standard input.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
     fn get_diagnostic_service(
         reporter: &OutputFormatter,
         warning_options: &WarningOptions,
@@ -857,6 +1033,155 @@ mod test {
         fs::write(file, content_original).unwrap();
     }
 
+    #[test]
+    fn test_cache() {
+        use std::fs;
+        let tester = Tester::new().with_fixture_copy("fixtures/cache");
+        let args = &[
+            "--cache",
+            "--cache-location",
+            ".oxlintcache",
+            "--import-plugin",
+            "-D",
+            "import/no-cycle",
+            ".",
+        ];
+        tester.test(args);
+
+        // Mark the cached diagnostics, so that the second run shows that they are reused.
+        let cache_location = tester.cwd().join(".oxlintcache");
+        let mut cache: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&cache_location).unwrap()).unwrap();
+        for entry in cache["entries"].as_object_mut().unwrap().values_mut() {
+            let Some(diagnostics) = entry.pointer_mut("/result/diagnostics") else { continue };
+            for diagnostic in diagnostics.as_array_mut().unwrap() {
+                let message = format!("{} (cached)", diagnostic["message"].as_str().unwrap());
+                diagnostic["message"] = message.into();
+            }
+        }
+        fs::write(&cache_location, cache.to_string()).unwrap();
+        tester.test_and_snapshot(args);
+
+        // Changing an imported module invalidates the results of the importing module.
+        fs::write(tester.cwd().join("b.js"), "import { a } from './a';\nexport const b = a;\n")
+            .unwrap();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            ".oxlintcache",
+            "--import-plugin",
+            "-D",
+            "import/no-cycle",
+            "a.js",
+        ];
+        tester.test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_cache_with_changed_options() {
+        use std::fs;
+        let cache_location = "fixtures/cache_options/.oxlintcache";
+        let _ = fs::remove_file(cache_location);
+        let args = &["--cache", "--cache-location", cache_location, "fixtures/cache_options"];
+        // Results cached without `--report-unused-disable-directives` are not reused with it.
+        let args_with_option = &[
+            "--cache",
+            "--cache-location",
+            cache_location,
+            "--report-unused-disable-directives",
+            "fixtures/cache_options",
+        ];
+        Tester::new().test_and_snapshot_multiple(&[args, args_with_option]);
+        fs::remove_file(cache_location).unwrap();
+    }
+
+    #[test]
+    fn test_cache_with_sarif_fixes() {
+        use std::fs;
+        let cache_location = "fixtures/cache_options/.oxlintcache_sarif";
+        let _ = fs::remove_file(cache_location);
+        // The second run reports the diagnostics and fixes stored by the first run.
+        let args = &[
+            "--cache",
+            "--cache-location",
+            cache_location,
+            "--format=sarif",
+            "fixtures/cache_options",
+        ];
+        Tester::new().test(args);
+        assert!(fs::exists(cache_location).unwrap());
+        Tester::new().test_and_snapshot(args);
+        fs::remove_file(cache_location).unwrap();
+    }
+
+    #[test]
+    fn test_baseline() {
+        use std::fs;
+        let tester = Tester::new().with_fixture_copy("fixtures/baseline");
+        // `a.js` is within the baseline, `b.js` has more diagnostics than the baseline.
+        let args = &["-D", "no-console", "--baseline", "baseline.json"];
+        tester.test_and_snapshot(args);
+        // Entries of fixed diagnostics and deleted files are removed.
+        assert_eq!(
+            fs::read_to_string(tester.cwd().join("baseline.json")).unwrap(),
+            "{\n  \"files\": {\n    \"a.js\": {\n      \"eslint(no-debugger)\": 1\n    },\n    \"b.js\": {\n      \"eslint(no-debugger)\": 1\n    }\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_baseline_write_failed() {
+        let args = &["--baseline-write", "missing/baseline.json"];
+        Tester::new().with_cwd("fixtures/baseline".into()).test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_stdin() {
+        let source_text = "debugger;\nconsole.log(1 as number);\n";
+        // The source type, overrides, nested configs and ignore patterns depend on the filename.
+        let args_ts = &["--stdin", "--stdin-filename", "a.ts"];
+        let args_js = &["--stdin", "--stdin-filename", "a.js"];
+        let args_nested = &["--stdin", "--stdin-filename", "nested/a.ts"];
+        let args_ignored = &["--stdin", "--stdin-filename", "ignored/a.ts"];
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin(source_text)
+            .test_and_snapshot_multiple(&[args_ts, args_js, args_nested, args_ignored]);
+    }
+
+    #[test]
+    fn test_stdin_with_paths() {
+        let args = &["--stdin", "a.ts"];
+        Tester::new().with_cwd("fixtures/stdin".into()).with_stdin("").test_and_snapshot(args);
+    }
+
+    #[test]
+    fn test_stdin_fix() {
+        // Only the fixed code is written to stdout.
+        let args = &["--stdin", "--stdin-filename", "a.ts", "--fix"];
+        let args_ignored = &["--stdin", "--stdin-filename", "ignored/a.ts", "--fix"];
+        Tester::new()
+            .with_cwd("fixtures/stdin".into())
+            .with_stdin("debugger;\nconsole.log(1 as number);\n")
+            .test_and_snapshot_multiple(&[args, args_ignored]);
+    }
+
+    #[test]
+    fn test_baseline_write() {
+        use std::fs;
+        let baseline = "fixtures/baseline/baseline_write.json";
+        let args = &["--baseline-write", "baseline_write.json"];
+        let args_with_baseline = &["--baseline", "baseline_write.json"];
+        // Neither run reports the recorded diagnostics.
+        Tester::new()
+            .with_cwd("fixtures/baseline".into())
+            .test_and_snapshot_multiple(&[args, args_with_baseline]);
+        assert_eq!(
+            fs::read_to_string(baseline).unwrap(),
+            "{\n  \"files\": {\n    \"a.js\": {\n      \"eslint(no-debugger)\": 1\n    },\n    \"b.js\": {\n      \"eslint(no-debugger)\": 2\n    }\n  }\n}\n"
+        );
+        fs::remove_file(baseline).unwrap();
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
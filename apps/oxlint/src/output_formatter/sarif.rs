@@ -0,0 +1,348 @@
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use oxc_data_structures::rope::Rope;
+use oxc_diagnostics::{
+    Error, OxcDiagnostic, Severity,
+    reporter::{DiagnosticReporter, DiagnosticResult},
+};
+use oxc_linter::{
+    RuleCategory, offset_to_position,
+    rules::{RULES, RuleEnum},
+};
+
+use crate::{command::VERSION, output_formatter::InternalFormatter};
+
+#[derive(Debug, Default)]
+pub struct SarifOutputFormatter;
+
+impl InternalFormatter for SarifOutputFormatter {
+    fn get_diagnostic_reporter(&self) -> Box<dyn DiagnosticReporter> {
+        Box::new(SarifReporter::default())
+    }
+}
+
+/// Renders reports as a SARIF 2.1.0 log, for code scanning tools.
+///
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+///
+/// Note that, due to syntactic restrictions of JSON, this reporter waits until all diagnostics
+/// have been reported before writing them to the output stream.
+#[derive(Default)]
+struct SarifReporter {
+    /// Rules of the reported diagnostics, in order of their first occurrence.
+    rules: Vec<SarifRule>,
+    rule_indices: FxHashMap<String, usize>,
+    results: Vec<SarifResult>,
+    /// Source text of the last reported file. Diagnostics are reported file by file, so this
+    /// avoids reading and indexing the source text for every diagnostic.
+    source: Option<SourceLines>,
+}
+
+struct SourceLines {
+    uri: String,
+    text: String,
+    rope: Rope,
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver<'a> {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: &'a [SarifRule],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifRuleProperties>,
+}
+
+#[derive(Serialize)]
+struct SarifRuleProperties {
+    category: RuleCategory,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Clone, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// Lines and columns are 1-based, columns are counted in UTF-16 code units.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    end_column: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<SarifMessage>,
+    artifact_changes: [SarifArtifactChange; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: [SarifReplacement; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifRegion,
+    inserted_content: SarifMessage,
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self, _: &DiagnosticResult) -> Option<String> {
+        let log = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "oxlint",
+                        version: VERSION,
+                        information_uri: "https://oxc.rs",
+                        rules: &self.rules,
+                    },
+                },
+                results: &self.results,
+            }],
+        };
+        Some(serde_json::to_string_pretty(&log).expect("Failed to serialize"))
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let result = self.build_result(&error);
+        self.results.push(result);
+        None
+    }
+}
+
+impl SarifReporter {
+    #[expect(clippy::cast_possible_truncation)] // source files are limited to 4 GiB
+    fn build_result(&mut self, error: &Error) -> SarifResult {
+        let level = match error.severity() {
+            Some(Severity::Warning) => "warning",
+            Some(Severity::Advice) => "note",
+            Some(Severity::Error) | None => "error",
+        };
+        let rule_id = error.code().map(|code| code.to_string());
+        let rule_index = rule_id.as_deref().map(|rule_id| self.rule_index(rule_id));
+
+        let source = self.read_source(error);
+        let artifact_location = SarifArtifactLocation {
+            uri: source.map(|source| source.uri.clone()).unwrap_or_default(),
+        };
+        let region = source.zip(error.labels().and_then(|mut labels| labels.next())).map(
+            |(source, label)| {
+                let start = label.offset() as u32;
+                source.region(start, start + label.len() as u32)
+            },
+        );
+        let fixes = source
+            .map(|source| {
+                OxcDiagnostic::fixes_of(error)
+                    .iter()
+                    .map(|fix| SarifFix {
+                        description: fix
+                            .message
+                            .as_ref()
+                            .map(|message| SarifMessage { text: message.to_string() }),
+                        artifact_changes: [SarifArtifactChange {
+                            artifact_location: artifact_location.clone(),
+                            replacements: [SarifReplacement {
+                                deleted_region: source.region(fix.start, fix.end),
+                                inserted_content: SarifMessage { text: fix.content.to_string() },
+                            }],
+                        }],
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        SarifResult {
+            rule_id,
+            rule_index,
+            level,
+            message: SarifMessage { text: error.to_string() },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation { artifact_location, region },
+            }],
+            fixes,
+        }
+    }
+
+    /// Index of the rule with the error code `rule_id`, adding its metadata on first use.
+    fn rule_index(&mut self, rule_id: &str) -> usize {
+        if let Some(index) = self.rule_indices.get(rule_id) {
+            return *index;
+        }
+        let rule = RULES.iter().find(|rule| rule.error_code() == rule_id);
+        self.rules.push(SarifRule {
+            id: rule_id.to_string(),
+            help_uri: rule.map(RuleEnum::documentation_url),
+            properties: rule.map(|rule| SarifRuleProperties { category: rule.category() }),
+        });
+        let index = self.rules.len() - 1;
+        self.rule_indices.insert(rule_id.to_string(), index);
+        index
+    }
+
+    /// Read the whole source text of the file the diagnostic was reported in.
+    fn read_source(&mut self, error: &Error) -> Option<&SourceLines> {
+        let source_code = error.source_code()?;
+        // A span at the start of the file, with all lines after it as context.
+        let contents = source_code.read_span(&(0, 0).into(), 0, usize::MAX).ok()?;
+        let uri = contents.name().map(ToString::to_string).unwrap_or_default();
+        let is_cached = self.source.as_ref().is_some_and(|source| source.uri == uri);
+        if !is_cached {
+            let text = String::from_utf8_lossy(contents.data()).into_owned();
+            let rope = Rope::from_str(&text);
+            self.source = Some(SourceLines { uri, text, rope });
+        }
+        self.source.as_ref()
+    }
+}
+
+impl SourceLines {
+    fn region(&self, start: u32, end: u32) -> SarifRegion {
+        let start = offset_to_position(&self.rope, start, &self.text);
+        let end = offset_to_position(&self.rope, end, &self.text);
+        SarifRegion {
+            start_line: start.line + 1,
+            start_column: start.character + 1,
+            end_line: end.line + 1,
+            end_column: end.character + 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{
+        DiagnosticFix, NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticReporter, DiagnosticResult},
+    };
+    use oxc_span::Span;
+
+    use super::SarifReporter;
+
+    #[test]
+    fn reporter() {
+        let mut reporter = SarifReporter::default();
+
+        let error = OxcDiagnostic::warn("error message")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(Span::new(5, 14))
+            .with_fixes([DiagnosticFix {
+                content: "".into(),
+                message: Some("Remove the debugger statement".into()),
+                start: 5,
+                end: 14,
+            }])
+            .with_source_code(NamedSource::new("test.ts", "😀 debugger;"));
+
+        // reporter keeps it in memory
+        assert!(reporter.render_error(error).is_none());
+
+        // reporter gives results when finishing
+        let output = reporter.finish(&DiagnosticResult::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["version"], "2.1.0");
+        let run = &json["runs"][0];
+        let rule = &run["tool"]["driver"]["rules"][0];
+        assert_eq!(rule["id"], "eslint(no-debugger)");
+        assert_eq!(
+            rule["helpUri"],
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+        assert_eq!(rule["properties"]["category"], "correctness");
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "eslint(no-debugger)");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(result["message"]["text"], "error message");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "test.ts");
+        let region = &location["region"];
+        // Columns are counted in UTF-16 code units.
+        assert_eq!(region["startLine"], 1);
+        assert_eq!(region["startColumn"], 4);
+        assert_eq!(region["endLine"], 1);
+        assert_eq!(region["endColumn"], 13);
+
+        let fix = &result["fixes"][0];
+        assert_eq!(fix["description"]["text"], "Remove the debugger statement");
+        let replacement = &fix["artifactChanges"][0]["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 4);
+        assert_eq!(replacement["insertedContent"]["text"], "");
+    }
+}
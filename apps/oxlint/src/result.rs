@@ -5,6 +5,11 @@ pub enum CliRunResult {
     None,
     InvalidOptionConfig,
     InvalidOptionTsConfig,
+    TypeAwareTsServerNotFound,
+    InvalidOptionBaseline,
+    BaselineWriteFailed,
+    InvalidOptionStdin,
+    StdinReadFailed,
     InvalidOptionSeverityWithoutFilter,
     InvalidOptionSeverityWithoutPluginName,
     InvalidOptionSeverityWithoutRuleName,
@@ -33,6 +38,11 @@ impl Termination for CliRunResult {
             | Self::LintMaxWarningsExceeded
             | Self::InvalidOptionConfig
             | Self::InvalidOptionTsConfig
+            | Self::TypeAwareTsServerNotFound
+            | Self::InvalidOptionBaseline
+            | Self::BaselineWriteFailed
+            | Self::InvalidOptionStdin
+            | Self::StdinReadFailed
             | Self::InvalidOptionSeverityWithoutFilter
             | Self::InvalidOptionSeverityWithoutPluginName
             | Self::InvalidOptionSeverityWithoutRuleName => ExitCode::FAILURE,
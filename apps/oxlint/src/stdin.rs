@@ -0,0 +1,50 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{RuntimeFileSystem, read_to_arena_str};
+
+/// File system which serves code read from standard input as the file at `path`.
+///
+/// Fixes of that file are kept in memory instead of being written to disk, see [`Self::fixed`].
+pub struct StdinFileSystem {
+    path: PathBuf,
+    source_text: String,
+    fixed: Arc<Mutex<Option<String>>>,
+}
+
+impl StdinFileSystem {
+    pub fn new(path: PathBuf, source_text: String) -> Self {
+        Self { path, source_text, fixed: Arc::default() }
+    }
+
+    /// The fixed code, which is `None` until fixes have been applied.
+    pub fn fixed(&self) -> Arc<Mutex<Option<String>>> {
+        Arc::clone(&self.fixed)
+    }
+}
+
+impl RuntimeFileSystem for StdinFileSystem {
+    fn read_to_arena_str<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        if path == self.path {
+            return Ok(allocator.alloc_str(&self.source_text));
+        }
+
+        read_to_arena_str(path, allocator)
+    }
+
+    fn write_file(&self, path: &Path, content: String) -> Result<(), std::io::Error> {
+        if path == self.path {
+            *self.fixed.lock().unwrap() = Some(content);
+            return Ok(());
+        }
+
+        std::fs::write(path, content)
+    }
+}
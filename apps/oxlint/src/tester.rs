@@ -7,10 +7,18 @@ use cow_utils::CowUtils;
 #[cfg(test)]
 use lazy_regex::Regex;
 #[cfg(test)]
-use std::{env, path::PathBuf};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+#[cfg(test)]
+use tempfile::TempDir;
 #[cfg(test)]
 pub struct Tester {
     cwd: PathBuf,
+    stdin: Option<String>,
+    /// Temporary directory containing a copy of a fixture directory, with its original path.
+    fixture_copy: Option<(TempDir, PathBuf)>,
 }
 
 #[cfg(test)]
@@ -23,7 +31,7 @@ impl Tester {
         // do not unwrap because we can set it only one time.
         let _ = rayon::ThreadPoolBuilder::new().num_threads(1).build_global();
 
-        Self { cwd }
+        Self { cwd, stdin: None, fixture_copy: None }
     }
 
     pub fn with_cwd(mut self, cwd: PathBuf) -> Self {
@@ -31,13 +39,42 @@ impl Tester {
         self
     }
 
+    /// Run in a copy of the fixture directory `dir` in a temporary directory, for tests which
+    /// write files. Snapshots are named after `dir`.
+    pub fn with_fixture_copy(mut self, dir: &str) -> Self {
+        let temp_dir = TempDir::new().unwrap();
+        copy_dir(&self.cwd.join(dir), temp_dir.path());
+        self.cwd = temp_dir.path().to_path_buf();
+        self.fixture_copy = Some((temp_dir, PathBuf::from(dir)));
+        self
+    }
+
+    /// The working directory to run in.
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    /// Code to lint with `--stdin`.
+    pub fn with_stdin(mut self, source_text: &str) -> Self {
+        self.stdin = Some(source_text.to_string());
+        self
+    }
+
+    fn runner(&self, options: crate::cli::LintCommand) -> LintRunner {
+        let runner = LintRunner::new(options).with_cwd(self.cwd.clone());
+        match &self.stdin {
+            Some(source_text) => runner.with_stdin(source_text.clone()),
+            None => runner,
+        }
+    }
+
     pub fn test(&self, args: &[&str]) {
         let mut new_args = vec!["--silent"];
         new_args.extend(args);
 
         let options = lint_command().run_inner(new_args.as_slice()).unwrap();
         let mut output = Vec::new();
-        let _ = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
+        let _ = self.runner(options).run(&mut output);
     }
 
     pub fn test_and_snapshot(&self, args: &[&str]) {
@@ -47,7 +84,10 @@ impl Tester {
     pub fn test_and_snapshot_multiple(&self, multiple_args: &[&[&str]]) {
         let mut output: Vec<u8> = Vec::new();
         let current_cwd = std::env::current_dir().unwrap();
-        let relative_dir = self.cwd.strip_prefix(&current_cwd).unwrap_or(&self.cwd);
+        let relative_dir = match &self.fixture_copy {
+            Some((_, dir)) => dir.as_path(),
+            None => self.cwd.strip_prefix(&current_cwd).unwrap_or(&self.cwd),
+        };
 
         for args in multiple_args {
             let options = lint_command().run_inner(*args).unwrap();
@@ -59,7 +99,7 @@ impl Tester {
                 format!("working directory: {}\n", relative_dir.to_str().unwrap()).as_bytes(),
             );
             output.extend_from_slice(b"----------\n");
-            let result = LintRunner::new(options).with_cwd(self.cwd.clone()).run(&mut output);
+            let result = self.runner(options).run(&mut output);
 
             output.extend_from_slice(b"----------\n");
             output.extend_from_slice(format!("CLI result: {result:?}\n").as_bytes());
@@ -82,7 +122,11 @@ impl Tester {
         // do not output the current working directory, each machine has a different one
         let cwd_string = current_cwd.to_str().unwrap();
         let cwd_string = cwd_string.cow_replace('\\', "/").to_string(); // for windows
-        let output_string = output_string.cow_replace(&cwd_string, "<cwd>");
+        let mut output_string = output_string.cow_replace(&cwd_string, "<cwd>");
+        if self.fixture_copy.is_some() {
+            let temp_dir = self.cwd.to_str().unwrap().cow_replace('\\', "/").to_string();
+            output_string = output_string.cow_replace(&temp_dir, "<temp>").into_owned().into();
+        }
 
         let full_args_list =
             multiple_args.iter().map(|args| args.join(" ")).collect::<Vec<String>>().join(" ");
@@ -97,3 +141,17 @@ impl Tester {
         });
     }
 }
+
+#[cfg(test)]
+fn copy_dir(from: &Path, to: &Path) {
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let path = to.join(entry.file_name());
+        if entry.file_type().unwrap().is_dir() {
+            fs::create_dir(&path).unwrap();
+            copy_dir(&entry.path(), &path);
+        } else {
+            fs::copy(entry.path(), path).unwrap();
+        }
+    }
+}
@@ -9,6 +9,7 @@ use oxc_mangler::{MangleOptions, Mangler};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser, ParserReturn};
 use oxc_semantic::{Scoping, SemanticBuilder, SemanticBuilderReturn};
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use oxc_transformer::{TransformOptions, Transformer, TransformerReturn};
 use oxc_transformer_plugins::{
@@ -16,23 +17,232 @@ use oxc_transformer_plugins::{
     ReplaceGlobalDefinesConfig,
 };
 
+/// Options of all stages of a [`Compiler`].
+///
+/// Contains no per-file state, so it can be shared across threads,
+/// e.g. cloned into each [`Compiler`] of a rayon thread pool.
+#[derive(Debug, Clone)]
+pub struct CompilerOptions {
+    pub parse: ParseOptions,
+
+    /// Report syntax errors found by semantic analysis.
+    ///
+    /// Default: `true`
+    pub check_semantic_error: bool,
+
+    /// Emit `.d.ts` declarations into [`CompilerReturn::declaration`].
+    pub isolated_declarations: Option<IsolatedDeclarationsOptions>,
+
+    pub transform: Option<TransformOptions>,
+
+    pub define: Option<ReplaceGlobalDefinesConfig>,
+
+    pub inject: Option<InjectGlobalVariablesConfig>,
+
+    pub compress: Option<CompressOptions>,
+
+    pub mangle: Option<MangleOptions>,
+
+    /// Code generation is skipped if `None`.
+    ///
+    /// Default: `Some(CodegenOptions::default())`
+    pub codegen: Option<CodegenOptions>,
+
+    /// Emit source maps into [`CompilerReturn::map`] and [`CompilerReturn::declaration_map`].
+    ///
+    /// Default: `false`
+    pub sourcemap: bool,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            parse: ParseOptions::default(),
+            check_semantic_error: true,
+            isolated_declarations: None,
+            transform: None,
+            define: None,
+            inject: None,
+            compress: None,
+            mangle: None,
+            codegen: Some(CodegenOptions::default()),
+            sourcemap: false,
+        }
+    }
+}
+
+/// Output of [`Compiler::build`].
+#[derive(Debug, Default)]
+pub struct CompilerReturn {
+    /// Generated code. Empty if compilation was aborted.
+    pub code: String,
+
+    /// Source map of [`code`](Self::code), if [`CompilerOptions::sourcemap`] is enabled.
+    pub map: Option<SourceMap>,
+
+    /// `.d.ts` declarations, if [`CompilerOptions::isolated_declarations`] is set.
+    pub declaration: Option<String>,
+
+    /// Source map of [`declaration`](Self::declaration), if [`CompilerOptions::sourcemap`] is enabled.
+    pub declaration_map: Option<SourceMap>,
+
+    /// Errors and warnings of all stages.
+    pub errors: Vec<OxcDiagnostic>,
+}
+
+type AfterParseHook = Box<dyn FnMut(&mut ParserReturn<'_>) -> ControlFlow<()>>;
+type AfterSemanticHook = Box<dyn FnMut(&mut SemanticBuilderReturn<'_>) -> ControlFlow<()>>;
+type AfterTransformHook =
+    Box<dyn FnMut(&mut Program<'_>, &mut TransformerReturn) -> ControlFlow<()>>;
+
+/// Compiler pipeline: parse, semantic analysis, isolated declarations, transform, define,
+/// inject, compress, mangle and codegen.
+///
+/// ```
+/// use std::path::Path;
+///
+/// use oxc::{Compiler, CompilerOptions, span::SourceType};
+///
+/// let options = CompilerOptions { sourcemap: true, ..CompilerOptions::default() };
+/// let ret = Compiler::new(options).build("let a = 1", SourceType::mjs(), Path::new("a.js"));
+/// assert!(ret.errors.is_empty());
+/// assert_eq!(ret.code, "let a = 1;\n");
+/// assert!(ret.map.is_some());
+/// ```
 #[derive(Default)]
 pub struct Compiler {
-    printed: String,
-    errors: Vec<OxcDiagnostic>,
+    options: CompilerOptions,
+
+    after_parse: Option<AfterParseHook>,
+    after_semantic: Option<AfterSemanticHook>,
+    after_transform: Option<AfterTransformHook>,
+
+    output: CompilerReturn,
 }
 
 impl CompilerInterface for Compiler {
     fn handle_errors(&mut self, errors: Vec<OxcDiagnostic>) {
-        self.errors.extend(errors);
+        self.output.errors.extend(errors);
+    }
+
+    fn enable_sourcemap(&self) -> bool {
+        self.options.sourcemap
+    }
+
+    fn parse_options(&self) -> ParseOptions {
+        self.options.parse
+    }
+
+    fn isolated_declaration_options(&self) -> Option<IsolatedDeclarationsOptions> {
+        self.options.isolated_declarations
+    }
+
+    fn transform_options(&self) -> Option<&TransformOptions> {
+        self.options.transform.as_ref()
+    }
+
+    fn define_options(&self) -> Option<ReplaceGlobalDefinesConfig> {
+        self.options.define.clone()
+    }
+
+    fn inject_options(&self) -> Option<InjectGlobalVariablesConfig> {
+        self.options.inject.clone()
+    }
+
+    fn compress_options(&self) -> Option<CompressOptions> {
+        self.options.compress.clone()
+    }
+
+    fn mangle_options(&self) -> Option<MangleOptions> {
+        self.options.mangle.clone()
+    }
+
+    fn codegen_options(&self) -> Option<CodegenOptions> {
+        self.options.codegen.clone()
+    }
+
+    fn check_semantic_error(&self) -> bool {
+        self.options.check_semantic_error
+    }
+
+    fn after_parse(&mut self, parser_return: &mut ParserReturn) -> ControlFlow<()> {
+        self.after_parse.as_mut().map_or(ControlFlow::Continue(()), |hook| hook(parser_return))
+    }
+
+    fn after_semantic(&mut self, semantic_return: &mut SemanticBuilderReturn) -> ControlFlow<()> {
+        self.after_semantic.as_mut().map_or(ControlFlow::Continue(()), |hook| hook(semantic_return))
+    }
+
+    fn after_isolated_declarations(&mut self, ret: CodegenReturn) {
+        self.output.declaration = Some(ret.code);
+        self.output.declaration_map = ret.map;
+    }
+
+    fn after_transform(
+        &mut self,
+        program: &mut Program<'_>,
+        transformer_return: &mut TransformerReturn,
+    ) -> ControlFlow<()> {
+        self.after_transform
+            .as_mut()
+            .map_or(ControlFlow::Continue(()), |hook| hook(program, transformer_return))
     }
 
     fn after_codegen(&mut self, ret: CodegenReturn) {
-        self.printed = ret.code;
+        self.output.code = ret.code;
+        self.output.map = ret.map;
     }
 }
 
 impl Compiler {
+    pub fn new(options: CompilerOptions) -> Self {
+        Self { options, ..Self::default() }
+    }
+
+    /// Run `hook` after parsing. Compilation is aborted if it returns [`ControlFlow::Break`].
+    #[must_use]
+    pub fn with_after_parse(
+        mut self,
+        hook: impl FnMut(&mut ParserReturn<'_>) -> ControlFlow<()> + 'static,
+    ) -> Self {
+        self.after_parse = Some(Box::new(hook));
+        self
+    }
+
+    /// Run `hook` after semantic analysis. Compilation is aborted if it returns [`ControlFlow::Break`].
+    #[must_use]
+    pub fn with_after_semantic(
+        mut self,
+        hook: impl FnMut(&mut SemanticBuilderReturn<'_>) -> ControlFlow<()> + 'static,
+    ) -> Self {
+        self.after_semantic = Some(Box::new(hook));
+        self
+    }
+
+    /// Run `hook` after transformation. Compilation is aborted if it returns [`ControlFlow::Break`].
+    #[must_use]
+    pub fn with_after_transform(
+        mut self,
+        hook: impl FnMut(&mut Program<'_>, &mut TransformerReturn) -> ControlFlow<()> + 'static,
+    ) -> Self {
+        self.after_transform = Some(Box::new(hook));
+        self
+    }
+
+    /// Compile `source_text`, and return the generated code, source maps, declarations and
+    /// diagnostics of all stages.
+    ///
+    /// The compiler can be reused to build other files with the same options and hooks.
+    pub fn build(
+        &mut self,
+        source_text: &str,
+        source_type: SourceType,
+        source_path: &Path,
+    ) -> CompilerReturn {
+        self.compile(source_text, source_type, source_path);
+        mem::take(&mut self.output)
+    }
+
     /// # Errors
     ///
     /// * A list of [OxcDiagnostic].
@@ -42,12 +252,8 @@ impl Compiler {
         source_type: SourceType,
         source_path: &Path,
     ) -> Result<String, Vec<OxcDiagnostic>> {
-        self.compile(source_text, source_type, source_path);
-        if self.errors.is_empty() {
-            Ok(mem::take(&mut self.printed))
-        } else {
-            Err(mem::take(&mut self.errors))
-        }
+        let ret = self.build(source_text, source_type, source_path);
+        if ret.errors.is_empty() { Ok(ret.code) } else { Err(ret.errors) }
     }
 }
 
@@ -58,6 +264,13 @@ pub trait CompilerInterface {
         false
     }
 
+    /// Source map of the source text, e.g. produced by a compiler which generated it.
+    ///
+    /// The output source map is composed with it, see [`CodegenOptions::input_source_map`].
+    fn input_source_map(&self) -> Option<SourceMap> {
+        None
+    }
+
     fn parse_options(&self) -> ParseOptions {
         ParseOptions::default()
     }
@@ -293,7 +506,88 @@ pub trait CompilerInterface {
         let mut options = options;
         if self.enable_sourcemap() {
             options.source_map_path = Some(source_path.to_path_buf());
+            if options.input_source_map.is_none() {
+                options.input_source_map = self.input_source_map();
+            }
         }
         Codegen::new().with_options(options).with_scoping(scoping).build(program)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ops::ControlFlow,
+        path::Path,
+        sync::{Arc, Mutex},
+    };
+
+    use oxc_isolated_declarations::IsolatedDeclarationsOptions;
+    use oxc_mangler::MangleOptions;
+    use oxc_minifier::CompressOptions;
+    use oxc_span::SourceType;
+    use oxc_transformer::TransformOptions;
+
+    use super::{Compiler, CompilerOptions};
+
+    #[test]
+    fn options_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CompilerOptions>();
+    }
+
+    #[test]
+    fn build() {
+        let source_text = "export function add(a: number, b: number): number { return a + b }";
+        let options = CompilerOptions {
+            isolated_declarations: Some(IsolatedDeclarationsOptions::default()),
+            transform: Some(TransformOptions::default()),
+            compress: Some(CompressOptions::smallest()),
+            mangle: Some(MangleOptions::default()),
+            sourcemap: true,
+            ..CompilerOptions::default()
+        };
+        let mut compiler = Compiler::new(options);
+        let ret = compiler.build(source_text, SourceType::ts(), Path::new("add.ts"));
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.code, "export function add(e, t) {\n\treturn e + t;\n}\n");
+        assert_eq!(
+            ret.declaration.as_deref(),
+            Some("export declare function add(a: number, b: number): number;\n")
+        );
+        assert_eq!(ret.map.unwrap().get_sources().collect::<Vec<_>>(), ["add.ts"]);
+        assert!(ret.declaration_map.is_some());
+
+        // The compiler is reusable
+        let ret = compiler.build("let x: = 1", SourceType::ts(), Path::new("error.ts"));
+        assert_eq!(ret.errors.len(), 1);
+        assert!(ret.code.is_empty());
+    }
+
+    #[test]
+    fn hooks() {
+        let stages = Arc::new(Mutex::new(vec![]));
+        let (parse_stages, semantic_stages) = (Arc::clone(&stages), Arc::clone(&stages));
+        let mut compiler = Compiler::default()
+            .with_after_parse(move |ret| {
+                parse_stages.lock().unwrap().push("parse");
+                if ret.program.body.is_empty() {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .with_after_semantic(move |_| {
+                semantic_stages.lock().unwrap().push("semantic");
+                ControlFlow::Continue(())
+            });
+
+        let ret = compiler.build("foo()", SourceType::mjs(), Path::new("foo.js"));
+        assert_eq!(ret.code, "foo();\n");
+        assert_eq!(*stages.lock().unwrap(), ["parse", "semantic"]);
+
+        let ret = compiler.build("", SourceType::mjs(), Path::new("empty.js"));
+        assert!(ret.code.is_empty());
+        assert_eq!(*stages.lock().unwrap(), ["parse", "semantic", "parse"]);
+    }
+}
@@ -2,7 +2,7 @@
 mod compiler;
 
 #[cfg(feature = "full")]
-pub use compiler::{Compiler, CompilerInterface};
+pub use compiler::{Compiler, CompilerInterface, CompilerOptions, CompilerReturn};
 
 pub mod allocator {
     //! Memory arena allocator used by all other submodules.
@@ -114,6 +114,13 @@ pub mod codegen {
     pub use oxc_codegen::*;
 }
 
+#[cfg(feature = "codegen")]
+pub mod sourcemap {
+    //! Source maps, e.g. for [`CodegenOptions::input_source_map`](oxc_codegen::CodegenOptions::input_source_map).
+    #[doc(inline)]
+    pub use oxc_sourcemap::*;
+}
+
 #[cfg(feature = "isolated_declarations")]
 pub mod isolated_declarations {
     //! `.d.ts` emit for Isolated Declarations.
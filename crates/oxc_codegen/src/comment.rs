@@ -128,7 +128,7 @@ impl Codegen<'_> {
         let comment_source = comment.span.source_text(source_text);
         match comment.kind {
             CommentKind::Line => {
-                self.print_str(comment_source);
+                self.print_raw_text(comment_source, false);
             }
             CommentKind::Block => {
                 // Print block comments with our own indentation.
@@ -136,7 +136,7 @@ impl Codegen<'_> {
                     if !line.starts_with("/*") {
                         self.print_indent();
                     }
-                    self.print_str(line.trim_start());
+                    self.print_raw_text(line.trim_start(), false);
                     if !line.ends_with("*/") {
                         self.print_hard_newline();
                     }
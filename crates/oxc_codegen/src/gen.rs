@@ -927,7 +927,7 @@ impl Gen for ImportAttribute<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         match &self.key {
             ImportAttributeKey::Identifier(identifier) => {
-                p.print_str(identifier.name.as_str());
+                p.print_identifier(identifier.name.as_str());
             }
             ImportAttributeKey::StringLiteral(literal) => {
                 p.print_string_literal(literal, false);
@@ -1192,7 +1192,7 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1200,7 +1200,7 @@ impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping(self.span);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1209,7 +1209,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_identifier(name);
     }
 }
 
@@ -1217,7 +1217,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
@@ -1310,7 +1310,7 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(self.regex.pattern.text.as_str());
+        p.print_raw_text(self.regex.pattern.text.as_str(), true);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1575,13 +1575,14 @@ impl Gen for ObjectProperty<'_> {
             }
         }
 
+        // `{ x }` is printed as `{ x: a }` if `x` is mangled.
+        // When minifying, `{ x: x }` is printed as `{ x }`, except for `__proto__` which
+        // sets the prototype only when it is not shorthand.
         let mut shorthand = false;
         if let PropertyKey::StaticIdentifier(key) = &self.key {
-            if key.name == "__proto__" {
-                shorthand = self.shorthand;
-            } else if let Expression::Identifier(ident) = self.value.without_parentheses() {
-                if key.name == p.get_identifier_reference_name(ident) {
-                    shorthand = true;
+            if self.shorthand || (p.options.minify && key.name != "__proto__") {
+                if let Expression::Identifier(ident) = self.value.without_parentheses() {
+                    shorthand = key.name == p.get_identifier_reference_name(ident);
                 }
             }
         }
@@ -1951,10 +1952,10 @@ impl Gen for AssignmentTargetPropertyIdentifier<'_> {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_identifier(self.binding.name.as_str());
             p.print_colon();
             p.print_soft_space();
-            p.print_str(ident_name);
+            p.print_identifier(ident_name);
         }
         if let Some(expr) = &self.init {
             p.print_soft_space();
@@ -2077,22 +2078,31 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_ascii_byte(b'`');
-        let mut expressions = self.expressions.iter();
+        print_template_literal(self, p, /* is_tagged */ false);
+    }
+}
 
-        for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span);
-            p.print_str(quasi.value.raw.as_str());
+fn print_template_literal(template: &TemplateLiteral<'_>, p: &mut Codegen, is_tagged: bool) {
+    p.print_ascii_byte(b'`');
+    let mut expressions = template.expressions.iter();
 
-            if let Some(expr) = expressions.next() {
-                p.print_str("${");
-                p.print_expression(expr);
-                p.print_ascii_byte(b'}');
-            }
+    for quasi in &template.quasis {
+        p.add_source_mapping(quasi.span);
+        // Raw strings are observable in tagged templates, so don't escape them
+        if is_tagged {
+            p.print_str(quasi.value.raw.as_str());
+        } else {
+            p.print_raw_text(quasi.value.raw.as_str(), true);
         }
 
-        p.print_ascii_byte(b'`');
+        if let Some(expr) = expressions.next() {
+            p.print_str("${");
+            p.print_expression(expr);
+            p.print_ascii_byte(b'}');
+        }
     }
+
+    p.print_ascii_byte(b'`');
 }
 
 impl Gen for TaggedTemplateExpression<'_> {
@@ -2102,7 +2112,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        print_template_literal(&self.quasi, p, /* is_tagged */ true);
     }
 }
 
@@ -2412,7 +2422,7 @@ impl Gen for JSXAttributeValue<'_> {
             Self::StringLiteral(lit) => {
                 let quote = if lit.value.contains('"') { b'\'' } else { b'"' };
                 p.print_ascii_byte(quote);
-                p.print_str(&lit.value);
+                p.print_jsx_text(&lit.value);
                 p.print_ascii_byte(quote);
             }
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
@@ -2494,7 +2504,7 @@ impl Gen for JSXClosingFragment {
 impl Gen for JSXText<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping(self.span);
-        p.print_str(self.value.as_str());
+        p.print_jsx_text(self.value.as_str());
     }
 }
 
@@ -2735,7 +2745,7 @@ impl Gen for PrivateIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_identifier(self.name.as_str());
     }
 }
 
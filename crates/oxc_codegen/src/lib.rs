@@ -10,10 +10,11 @@ mod context;
 mod r#gen;
 mod operator;
 mod options;
+mod source_map_remapping;
 mod sourcemap_builder;
 mod str;
 
-use std::borrow::Cow;
+use std::{borrow::Cow, iter};
 
 use oxc_ast::ast::*;
 use oxc_data_structures::{code_buffer::CodeBuffer, stack::Stack};
@@ -27,12 +28,12 @@ use oxc_syntax::{
 
 use crate::{
     binary_expr_visitor::BinaryExpressionVisitor, comment::CommentsMap, operator::Operator,
-    sourcemap_builder::SourcemapBuilder, str::Quote,
+    source_map_remapping::remap_source_map, sourcemap_builder::SourcemapBuilder, str::Quote,
 };
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, LegalComment},
+    options::{CodegenOptions, IndentChar, LegalComment},
 };
 
 /// Output from [`Codegen::build`]
@@ -44,6 +45,7 @@ pub struct CodegenReturn {
     /// The source map from the input source code to the generated source code.
     ///
     /// You must set [`CodegenOptions::source_map_path`] for this to be [`Some`].
+    /// If [`CodegenOptions::input_source_map`] is set, it maps from the original sources instead.
     pub map: Option<oxc_sourcemap::SourceMap>,
 
     /// All the legal comments returned from [LegalComment::Linked] or [LegalComment::External].
@@ -101,6 +103,11 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
+    /// Start of the current line, for [CodegenOptions::max_line_length].
+    line_start: usize,
+    /// End of the output which has been searched for line breaks, for [CodegenOptions::max_line_length].
+    line_scanned_end: usize,
+
     /// Fast path for [CodegenOptions::single_quote]
     quote: Quote,
 
@@ -153,6 +160,8 @@ impl<'a> Codegen<'a> {
             start_of_default_export: 0,
             is_jsx: false,
             indent: 0,
+            line_start: 0,
+            line_scanned_end: 0,
             quote: Quote::Double,
             comments: CommentsMap::default(),
             sourcemap_builder: None,
@@ -198,7 +207,13 @@ impl<'a> Codegen<'a> {
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
         let code = self.code.into_string();
-        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        let map = self.sourcemap_builder.map(|builder| {
+            let map = builder.into_sourcemap();
+            match &self.options.input_source_map {
+                Some(input_map) => remap_source_map(&map, input_map),
+                None => map,
+            }
+        });
         CodegenReturn { code, map, legal_comments }
     }
 
@@ -275,11 +290,39 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_semicolon(&mut self) {
         self.print_ascii_byte(b';');
+        self.print_newline_if_line_too_long();
     }
 
     #[inline]
     fn print_comma(&mut self) {
         self.print_ascii_byte(b',');
+        self.print_newline_if_line_too_long();
+    }
+
+    /// Break the line if it exceeds [CodegenOptions::max_line_length] in minify mode.
+    ///
+    /// Must only be called where a line break cannot change the meaning of the code.
+    #[inline]
+    fn print_newline_if_line_too_long(&mut self) {
+        if let Some(max_line_length) = self.options.max_line_length {
+            if self.options.minify {
+                self.print_newline_if_line_longer_than(max_line_length);
+            }
+        }
+    }
+
+    fn print_newline_if_line_longer_than(&mut self, max_line_length: usize) {
+        let bytes = self.code.as_bytes();
+        // Only search output printed since last time, so each byte is searched at most once
+        if let Some(pos) = bytes[self.line_scanned_end..].iter().rposition(|&b| b == b'\n') {
+            self.line_start = self.line_scanned_end + pos + 1;
+        }
+        self.line_scanned_end = bytes.len();
+        if bytes.len() - self.line_start > max_line_length {
+            self.print_hard_newline();
+            self.line_start = self.code.len();
+            self.line_scanned_end = self.line_start;
+        }
     }
 
     #[inline]
@@ -346,7 +389,11 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        let n = self.indent as usize * self.options.indent_width;
+        match self.options.indent_char {
+            IndentChar::Tab => self.code.print_indent(n),
+            IndentChar::Space => self.code.print_ascii_bytes(iter::repeat_n(b' ', n)),
+        }
     }
 
     #[inline]
@@ -401,6 +448,7 @@ impl<'a> Codegen<'a> {
         self.add_source_mapping(span);
         self.print_ascii_byte(b'{');
         self.print_soft_newline();
+        self.print_newline_if_line_too_long();
         self.indent();
     }
 
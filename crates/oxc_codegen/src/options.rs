@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use oxc_sourcemap::SourceMap;
+
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
@@ -13,6 +15,39 @@ pub struct CodegenOptions {
     /// Default is `false`.
     pub minify: bool,
 
+    /// Character used for indentation.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [`CodegenOptions::indent_char`] printed per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// Escape non-ASCII characters in identifiers, strings, template literals, regular expressions
+    /// and comments, e.g. for scripts served with a Latin-1 charset.
+    /// Non-ASCII characters in JSX text and string attribute values are replaced by HTML
+    /// character references.
+    ///
+    /// Raw strings of tagged templates are printed as is, because escaping them would change their value.
+    ///
+    /// Default is `false`.
+    pub ascii_only: bool,
+
+    /// Escape `</script` as `<\/script` in strings, template literals, regular expressions and comments,
+    /// so the output can be inlined into an HTML `<script>` tag.
+    ///
+    /// Default is `false`.
+    pub escape_script_end_tags: bool,
+
+    /// Break lines which are longer than this number of bytes, if [`CodegenOptions::minify`] is enabled.
+    ///
+    /// Lines are only broken after `,`, `;` and `{`, so they may still exceed this length.
+    ///
+    /// Default is `None` - no limit.
+    pub max_line_length: Option<usize>,
+
     /// Print normal comments?
     ///
     /// At present, only some leading comments are preserved.
@@ -47,6 +82,17 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Source map of the input source text, e.g. from a Vue or Svelte compiler
+    /// or an earlier TypeScript step.
+    ///
+    /// When provided, the returned sourcemap is composed with this map,
+    /// so it maps the generated code back to the original sources,
+    /// including their `sourcesContent` and `names`.
+    /// Has no effect unless [`CodegenOptions::source_map_path`] is set.
+    ///
+    /// Default is `None`.
+    pub input_source_map: Option<SourceMap>,
 }
 
 impl Default for CodegenOptions {
@@ -54,10 +100,16 @@ impl Default for CodegenOptions {
         Self {
             single_quote: false,
             minify: false,
+            indent_char: IndentChar::Tab,
+            indent_width: 1,
+            ascii_only: false,
+            escape_script_end_tags: false,
+            max_line_length: None,
             comments: true,
             annotation_comments: true,
             legal_comments: LegalComment::Inline,
             source_map_path: None,
+            input_source_map: None,
         }
     }
 }
@@ -68,10 +120,16 @@ impl CodegenOptions {
         Self {
             single_quote: false,
             minify: true,
+            indent_char: IndentChar::Tab,
+            indent_width: 1,
+            ascii_only: false,
+            escape_script_end_tags: false,
+            max_line_length: None,
             comments: false,
             annotation_comments: false,
             legal_comments: LegalComment::None,
             source_map_path: None,
+            input_source_map: None,
         }
     }
 
@@ -91,6 +149,16 @@ impl CodegenOptions {
     }
 }
 
+/// Indentation character
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum IndentChar {
+    /// Indent with tabs (default).
+    #[default]
+    Tab,
+    /// Indent with spaces.
+    Space,
+}
+
 /// Legal comment
 ///
 /// <https://esbuild.github.io/api/#legal-comments>
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use oxc_sourcemap::{SourceMap, Token};
+use rustc_hash::FxHashMap;
+
+/// Compose `map`, which maps the input source text to the generated code,
+/// with `input_map`, which maps the original sources to the input source text.
+///
+/// The returned source map maps the original sources to the generated code.
+/// `sources`, `sourcesContent` and `x_google_ignoreList` are taken from `input_map`.
+/// Generated tokens whose position is not covered by `input_map` are dropped.
+///
+/// Code adapted from [@ampproject/remapping](https://github.com/ampproject/remapping/blob/main/src/source-map-tree.ts)
+pub fn remap_source_map(map: &SourceMap, input_map: &SourceMap) -> SourceMap {
+    let lookup_table = input_map.generate_lookup_table();
+
+    let mut names = input_map.get_names().map(Arc::from).collect::<Vec<Arc<str>>>();
+    // Names of `map` which are not in `input_map`, keyed by their id in `map`.
+    let mut name_ids = FxHashMap::default();
+
+    let mut tokens = vec![];
+    for token in map.get_tokens() {
+        let Some(original) =
+            input_map.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        let Some(source_id) = original.get_source_id() else {
+            continue;
+        };
+        // Prefer the name in the original source,
+        // otherwise the name in the input source text is the closest we have.
+        let name_id = original.get_name_id().or_else(|| {
+            let id = token.get_name_id()?;
+            let name = map.get_name(id)?;
+            Some(*name_ids.entry(id).or_insert_with(|| {
+                let new_id = u32::try_from(names.len()).unwrap();
+                names.push(Arc::from(name));
+                new_id
+            }))
+        });
+        tokens.push(Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        ));
+    }
+
+    let mut result = SourceMap::new(
+        map.get_file().map(Arc::from),
+        names,
+        input_map.get_source_root().map(ToString::to_string),
+        input_map.get_sources().map(Arc::from).collect(),
+        input_map.get_source_contents().map(|content| content.map(Arc::from)).collect(),
+        tokens,
+        None,
+    );
+    if let Some(ignore_list) = input_map.get_x_google_ignore_list() {
+        result.set_x_google_ignore_list(ignore_list.to_vec());
+    }
+    result
+}
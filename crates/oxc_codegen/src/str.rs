@@ -42,6 +42,13 @@ impl Codegen<'_> {
             Some(quote)
         };
 
+        let escapes = match (self.options.ascii_only, self.options.escape_script_end_tags) {
+            (false, false) => &ESCAPES.0,
+            (true, false) => &ESCAPES_ASCII_ONLY.0,
+            (false, true) => &ESCAPES_SCRIPT_END_TAGS.0,
+            (true, true) => &ESCAPES_ASCII_ONLY_SCRIPT_END_TAGS.0,
+        };
+
         // Loop through bytes, looking for any which need to be escaped.
         // String is written to buffer in chunks.
         let bytes = s.value.as_bytes().iter();
@@ -56,7 +63,7 @@ impl Codegen<'_> {
         // Loop through bytes.
         while let Some(b) = state.peek() {
             // Look up whether byte needs escaping
-            let escape = ESCAPES.0[b as usize];
+            let escape = escapes[b as usize];
             if escape == Escape::__ {
                 // No escape required.
                 // SAFETY: We just checked there's a byte to consume.
@@ -343,6 +350,8 @@ enum Escape {
     LS = 14, // LS/PS - U+2028 LINE SEPARATOR or U+2029 PARAGRAPH SEPARATOR (first byte)
     NB = 15, // NBSP  - Non-breaking space (first byte)
     LO = 16, // �     - U+FFFD lossy replacement character (first byte)
+    NA = 17, // Non-ASCII character (first byte), with `ascii_only` option
+    LT = 18, // <     - Less than, with `escape_script_end_tags` option
 }
 
 /// Struct which ensures content is aligned on 128.
@@ -354,10 +363,42 @@ struct Aligned128<T>(T);
 /// Aligned on 128, so top half (ASCII chars) occupies a pair of L1 cache lines.
 /// Bottom half (non-ASCII chars) also occupies a pair of L1 cache lines,
 /// but will not be accessed for strings which only contain ASCII (common case).
-static ESCAPES: Aligned128<[Escape; 256]> = {
+static ESCAPES: Aligned128<[Escape; 256]> = Aligned128(BASE_ESCAPES);
+
+/// [`ESCAPES`] for [`CodegenOptions::ascii_only`](crate::CodegenOptions::ascii_only).
+static ESCAPES_ASCII_ONLY: Aligned128<[Escape; 256]> = Aligned128(escapes(true, false));
+
+/// [`ESCAPES`] for [`CodegenOptions::escape_script_end_tags`](crate::CodegenOptions::escape_script_end_tags).
+static ESCAPES_SCRIPT_END_TAGS: Aligned128<[Escape; 256]> = Aligned128(escapes(false, true));
+
+/// [`ESCAPES`] for both [`CodegenOptions::ascii_only`](crate::CodegenOptions::ascii_only)
+/// and [`CodegenOptions::escape_script_end_tags`](crate::CodegenOptions::escape_script_end_tags).
+static ESCAPES_ASCII_ONLY_SCRIPT_END_TAGS: Aligned128<[Escape; 256]> =
+    Aligned128(escapes(true, true));
+
+/// Extend [`BASE_ESCAPES`] with the escapes required by options.
+const fn escapes(ascii_only: bool, script_end_tags: bool) -> [Escape; 256] {
+    let mut escapes = BASE_ESCAPES;
+    if ascii_only {
+        // All first bytes of UTF-8 character sequences which are not already handled
+        let mut b = 0xC0;
+        while b < 256 {
+            if matches!(escapes[b], Escape::__) {
+                escapes[b] = Escape::NA;
+            }
+            b += 1;
+        }
+    }
+    if script_end_tags {
+        escapes[b'<' as usize] = Escape::LT;
+    }
+    escapes
+}
+
+const BASE_ESCAPES: [Escape; 256] = {
     #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
     use Escape::*;
-    Aligned128([
+    [
         //   1   2   3   4   5   6   7   8   9   A   B   C   D   E   F
         NU, __, __, __, __, __, __, BE, BK, __, NL, VT, FF, CR, __, __, // 0
         __, __, __, __, __, __, __, __, __, __, __, ES, __, __, __, __, // 1
@@ -375,7 +416,7 @@ static ESCAPES: Aligned128<[Escape; 256]> = {
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // D
         __, __, LS, __, __, __, __, __, __, __, __, __, __, __, __, LO, // E
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // F
-    ])
+    ]
 };
 
 type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
@@ -385,9 +426,9 @@ type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
 /// Indexed by `escape as usize - 1` (where `escape` is not `Escape::__`).
 /// Must be in same order as discriminants in `Escape`.
 ///
-/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 128 bytes in total.
-/// Aligned on 128, so occupies a pair of L1 cache lines.
-static BYTE_HANDLERS: Aligned128<[ByteHandler; 16]> = Aligned128([
+/// Function pointers are 8 bytes each, so the first 16 handlers, which are not specific to options,
+/// occupy 128 bytes. Aligned on 128, so they occupy a pair of L1 cache lines.
+static BYTE_HANDLERS: Aligned128<[ByteHandler; 18]> = Aligned128([
     print_null,
     print_bell,
     print_backspace,
@@ -404,6 +445,8 @@ static BYTE_HANDLERS: Aligned128<[ByteHandler; 16]> = Aligned128([
     print_ls_or_ps,
     print_non_breaking_space,
     print_lossy_replacement,
+    print_non_ascii,
+    print_less_than,
 ]);
 
 /// Call byte handler for byte which needs escaping.
@@ -593,6 +636,11 @@ unsafe fn print_ls_or_ps(codegen: &mut Codegen, state: &mut PrintStringState) {
     let replacement = match next2 {
         LS_LAST_2_BYTES => "\\u2028",
         PS_LAST_2_BYTES => "\\u2029",
+        _ if codegen.options.ascii_only => {
+            // SAFETY: Caller guarantees the next byte is the start of a non-ASCII character
+            unsafe { print_non_ascii(codegen, state) };
+            return;
+        }
         _ => {
             // Some other character starting with 0xE2. Advance past it.
             // SAFETY: 0xE2 is always the start of a 3-byte Unicode character
@@ -618,6 +666,9 @@ unsafe fn print_non_breaking_space(codegen: &mut Codegen, state: &mut PrintStrin
         // SAFETY: 0xC2 is always the start of a 2-byte Unicode character.
         unsafe { state.flush_and_consume_bytes::<2>(codegen) };
         codegen.print_str("\\xA0");
+    } else if codegen.options.ascii_only {
+        // SAFETY: Caller guarantees the next byte is the start of a non-ASCII character
+        unsafe { print_non_ascii(codegen, state) };
     } else {
         // Some other character starting with 0xC2. Advance past it.
         // SAFETY: 0xC2 is always the start of a 2-byte Unicode character.
@@ -645,6 +696,15 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
             let bytes = &mut state.bytes;
             let hex: [u8; 4] = bytes.as_slice()[3..7].try_into().unwrap();
 
+            if hex == *b"fffd" && codegen.options.ascii_only {
+                state.flush(codegen);
+                // SAFETY: 3 bytes lossy replacement character and 4 hex bytes, as above
+                unsafe { state.consume_bytes_unchecked::<7>() };
+                state.start_chunk();
+                codegen.print_str("\\uFFFD");
+                return;
+            }
+
             if hex == *b"fffd" {
                 // Actual lossy replacement character.
                 // Flush up to and including the lossy replacement character, then skip the 4 hex bytes.
@@ -683,11 +743,173 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
     }
 
     // `lone_surrogates` is `false` or character is some other character starting with 0xEF.
+    if codegen.options.ascii_only {
+        // SAFETY: Caller guarantees the next byte is the start of a non-ASCII character
+        unsafe { print_non_ascii(codegen, state) };
+        return;
+    }
     // Advance past the character.
     // SAFETY: 0xEF is always the start of a 3-byte Unicode character
     unsafe { state.consume_bytes_unchecked::<3>() };
 }
 
+// First byte of a non-ASCII character, with `ascii_only` option
+unsafe fn print_non_ascii(codegen: &mut Codegen, state: &mut PrintStringState) {
+    debug_assert!(state.peek().is_some_and(|b| b >= 0xC0));
+
+    // SAFETY: `bytes` iterator is always positioned on a UTF-8 character boundary,
+    // so the remaining bytes are a valid UTF-8 string
+    let remaining = unsafe { std::str::from_utf8_unchecked(state.bytes.as_slice()) };
+    let ch = remaining.chars().next().unwrap();
+
+    state.flush(codegen);
+    for _ in 0..ch.len_utf8() {
+        // SAFETY: Consuming all bytes of the character leaves `bytes` on a UTF-8 char boundary
+        unsafe { state.consume_byte_unchecked() };
+    }
+    state.start_chunk();
+    codegen.print_unicode_escape(ch);
+}
+
+// `<`, with `escape_script_end_tags` option
+unsafe fn print_less_than(codegen: &mut Codegen, state: &mut PrintStringState) {
+    debug_assert_eq!(state.peek(), Some(b'<'));
+
+    let is_end_tag = is_script_end_tag(state.bytes.as_slice());
+    // SAFETY: Next byte is `<`, which is ASCII
+    unsafe { state.consume_byte_unchecked() };
+    if is_end_tag {
+        // SAFETY: Next byte is `/`, which is ASCII
+        unsafe { state.flush_and_consume_byte(codegen) };
+        codegen.print_str("\\/");
+    }
+}
+
+/// Check if `bytes` starts with `</script`, case-insensitively.
+fn is_script_end_tag(bytes: &[u8]) -> bool {
+    bytes.len() >= 8 && bytes[..2] == *b"</" && bytes[2..8].eq_ignore_ascii_case(b"script")
+}
+
+impl Codegen<'_> {
+    /// Print identifier `name`, escaping non-ASCII characters if
+    /// [`CodegenOptions::ascii_only`](crate::CodegenOptions::ascii_only) is enabled.
+    pub(crate) fn print_identifier(&mut self, name: &str) {
+        if !self.options.ascii_only || name.is_ascii() {
+            self.print_str(name);
+            return;
+        }
+        cold_branch(|| {
+            for ch in name.chars() {
+                if ch.is_ascii() {
+                    self.print_ascii_byte(ch as u8);
+                } else if ch as u32 <= 0xFFFF {
+                    self.print_str("\\u");
+                    self.print_hex(ch as u32, 4);
+                } else {
+                    // Identifiers can't contain surrogate pairs
+                    self.print_str("\\u{");
+                    self.print_hex(ch as u32, 5);
+                    self.print_ascii_byte(b'}');
+                }
+            }
+        });
+    }
+
+    /// Print the source text of a template literal, regular expression or comment,
+    /// escaping it as required by [`CodegenOptions::ascii_only`](crate::CodegenOptions::ascii_only)
+    /// and [`CodegenOptions::escape_script_end_tags`](crate::CodegenOptions::escape_script_end_tags).
+    ///
+    /// If `escapable` is `true`, `text` is interpreted with escape sequences,
+    /// so a non-ASCII character preceded by `\` is replaced by an escape sequence as a whole,
+    /// e.g. `\é` -> `\xE9`.
+    pub(crate) fn print_raw_text(&mut self, text: &str, escapable: bool) {
+        let escape_non_ascii = self.options.ascii_only && !text.is_ascii();
+        let escape_end_tags = self.options.escape_script_end_tags && text.contains("</");
+        if !escape_non_ascii && !escape_end_tags {
+            self.print_str(text);
+            return;
+        }
+        cold_branch(|| {
+            let bytes = text.as_bytes();
+            let mut chunk_start = 0;
+            let mut i = 0;
+            let mut escaped = false;
+            while i < bytes.len() {
+                let b = bytes[i];
+                let is_escaped = escaped;
+                escaped = b == b'\\' && !is_escaped;
+                if escape_end_tags && b == b'<' && is_script_end_tag(&bytes[i..]) {
+                    self.print_str(&text[chunk_start..=i]);
+                    self.print_str("\\/");
+                    // Skip `/`
+                    i += 2;
+                    chunk_start = i;
+                } else if escape_non_ascii && b >= 0x80 {
+                    let ch = text[i..].chars().next().unwrap();
+                    let end = if is_escaped && escapable { i - 1 } else { i };
+                    self.print_str(&text[chunk_start..end]);
+                    self.print_unicode_escape(ch);
+                    i += ch.len_utf8();
+                    chunk_start = i;
+                } else {
+                    i += 1;
+                }
+            }
+            self.print_str(&text[chunk_start..]);
+        });
+    }
+
+    /// Print the text of a JSX text node or string attribute value, replacing non-ASCII characters
+    /// by HTML character references, e.g. `é` -> `&#xE9;`, if
+    /// [`CodegenOptions::ascii_only`](crate::CodegenOptions::ascii_only) is enabled.
+    pub(crate) fn print_jsx_text(&mut self, text: &str) {
+        if !self.options.ascii_only || text.is_ascii() {
+            self.print_str(text);
+            return;
+        }
+        cold_branch(|| {
+            let mut chunk_start = 0;
+            for (i, ch) in text.char_indices() {
+                if !ch.is_ascii() {
+                    self.print_str(&text[chunk_start..i]);
+                    self.print_str("&#x");
+                    self.print_hex(ch as u32, 1);
+                    self.print_ascii_byte(b';');
+                    chunk_start = i + ch.len_utf8();
+                }
+            }
+            self.print_str(&text[chunk_start..]);
+        });
+    }
+
+    /// Print `ch` as an escape sequence which is valid in strings, template literals
+    /// and regular expressions with or without the `u` flag.
+    ///
+    /// Characters outside the BMP are printed as a surrogate pair.
+    fn print_unicode_escape(&mut self, ch: char) {
+        let code = ch as u32;
+        if code <= 0xFF {
+            self.print_str("\\x");
+            self.print_hex(code, 2);
+        } else {
+            let mut units = [0; 2];
+            for unit in ch.encode_utf16(&mut units) {
+                self.print_str("\\u");
+                self.print_hex(u32::from(*unit), 4);
+            }
+        }
+    }
+
+    /// Print `value` as uppercase hex, padded with zeros to at least `min_digits` digits.
+    fn print_hex(&mut self, value: u32, min_digits: u32) {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        let digits = (32 - value.leading_zeros()).div_ceil(4).max(min_digits);
+        for i in (0..digits).rev() {
+            self.print_ascii_byte(HEX_DIGITS[((value >> (i * 4)) & 0xF) as usize]);
+        }
+    }
+}
+
 /// Call a closure while hinting to compiler that this branch is rarely taken.
 ///
 /// "Cold trampoline function", suggested in:
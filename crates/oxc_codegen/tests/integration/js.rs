@@ -1,4 +1,4 @@
-use oxc_codegen::CodegenOptions;
+use oxc_codegen::{CodegenOptions, IndentChar};
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -149,7 +149,10 @@ fn if_stmt() {
 fn shorthand() {
     test("let _ = { x }", "let _ = { x };\n");
     test("let { x } = y", "let { x } = y;\n");
-    test("({ x: (x) })", "({ x });\n");
+    test("({ x: (x) })", "({ x: x });\n");
+    test("let _ = { x: x }", "let _ = { x: x };\n");
+    test_minify("let _ = { x: x }", "let _={x};");
+    test_minify("let _ = { __proto__: __proto__ }", "let _={__proto__:__proto__};");
     test("({ x } = y)", "({x} = y);\n");
     // https://github.com/tc39/test262/blob/05c45a4c430ab6fee3e0c7f0d47d8a30d8876a6d/test/language/expressions/object/__proto__-permitted-dup-shorthand.js
     test("var obj = { __proto__, __proto__, };", "var obj = {\n\t__proto__,\n\t__proto__\n};\n");
@@ -593,3 +596,69 @@ fn v8_intrinsics() {
         parse_opts,
     );
 }
+
+#[test]
+fn indent() {
+    let options = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options(
+        "function foo() { if (x) { bar(); } }",
+        "function foo() {\n  if (x) {\n    bar();\n  }\n}\n",
+        options,
+    );
+    let options = CodegenOptions { indent_width: 2, ..CodegenOptions::default() };
+    test_options("if (x) { bar(); }", "if (x) {\n\t\tbar();\n}\n", options);
+}
+
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options(
+        "x = 'café 你好 😀'",
+        "x = \"caf\\xE9 \\u4F60\\u597D \\uD83D\\uDE00\";\n",
+        options.clone(),
+    );
+    test_options("x = '\\u2028\\xA0'", "x = \"\\u2028\\xA0\";\n", options.clone());
+    test_options("let café = 1", "let caf\\u00E9 = 1;\n", options.clone());
+    test_options("𐊧.#é", "\\u{102A7}.#\\u00E9;\n", options.clone());
+    test_options("x = `é${a}\\é`", "x = `\\xE9${a}\\xE9`;\n", options.clone());
+    test_options("x = tag`é`", "x = tag`é`;\n", options.clone());
+    test_options("/é[\\é]/u", "/\\xE9[\\xE9]/u;\n", options.clone());
+    test_options("// café\nx", "// caf\\xE9\nx;\n", options.clone());
+    test_options(
+        "<a title=\"café\" alt='\"é\"'>café 😀 &amp;</a>",
+        "<a title=\"caf&#xE9;\" alt='\"&#xE9;\"'>caf&#xE9; &#x1F600; &amp;</a>;\n",
+        options.clone(),
+    );
+    test_options("let x = \"\\u{FFFD}\\uD800\";", "let x = \"\\uFFFD\\ud800\";\n", options);
+}
+
+#[test]
+fn escape_script_end_tags() {
+    let options = CodegenOptions { escape_script_end_tags: true, ..CodegenOptions::default() };
+    test_options(
+        "x = '</script></SCRIPT></style>'",
+        "x = \"<\\/script><\\/SCRIPT></style>\";\n",
+        options.clone(),
+    );
+    test_options("x = `</script>${a}`", "x = `<\\/script>${a}`;\n", options.clone());
+    test_options("/[</script]/", "/[<\\/script]/;\n", options.clone());
+    test_options("/* </script> */\nx", "/* <\\/script> */\nx;\n", options);
+    test("x = '</script>'", "x = \"</script>\";\n");
+}
+
+#[test]
+fn max_line_length() {
+    let options = CodegenOptions { max_line_length: Some(10), ..CodegenOptions::minify() };
+    test_options(
+        "foo(aaaa, bbbb, cccc); function f() { return [1, 2] }",
+        "foo(aaaa,bbbb,\ncccc);function f(){return[1,\n2]}",
+        options,
+    );
+    // Only applies to minify
+    let options = CodegenOptions { max_line_length: Some(10), ..CodegenOptions::default() };
+    test_options("foo(aaaa, bbbb, cccc);", "foo(aaaa, bbbb, cccc);\n", options);
+}
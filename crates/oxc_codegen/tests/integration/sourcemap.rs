@@ -1,7 +1,8 @@
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{Expression, Statement};
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
+use oxc_sourcemap::{SourceMapBuilder, Token};
 use oxc_span::{SourceType, Span};
 
 use crate::tester::default_options;
@@ -27,3 +28,43 @@ fn incorrect_ast() {
     let ret = Codegen::new().with_options(default_options()).build(&program);
     assert!(ret.map.is_some(), "sourcemap exists");
 }
+
+#[test]
+fn input_source_map() {
+    let original_text = "<script>\n  let count = 0;\n</script>\n";
+    let source_text = "let count = 0;\n";
+
+    // Source map produced by an upstream compiler, e.g. Svelte.
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.add_source_and_content("App.svelte", original_text);
+    let name_id = builder.add_name("count");
+    builder.add_token(0, 0, 1, 2, Some(source_id), None);
+    builder.add_token(0, 4, 1, 6, Some(source_id), Some(name_id));
+    builder.add_token(0, 12, 1, 14, Some(source_id), None);
+    let input_source_map = builder.into_sourcemap();
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let options = CodegenOptions {
+        source_map_path: Some("App.js".into()),
+        input_source_map: Some(input_source_map),
+        ..CodegenOptions::minify()
+    };
+    let ret = Codegen::new().with_options(options).build(&ret.program);
+    assert_eq!(ret.code, "let count=0;");
+
+    let map = ret.map.unwrap();
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["App.svelte"]);
+    assert_eq!(map.get_source_contents().collect::<Vec<_>>(), [Some(original_text)]);
+
+    let lookup_table = map.generate_lookup_table();
+    let token = |line, col| {
+        let token = map.lookup_token(&lookup_table, line, col).unwrap();
+        let name = token.get_name_id().and_then(|id| map.get_name(id));
+        (token.get_src_line(), token.get_src_col(), token.get_source_id(), name)
+    };
+    assert_eq!(token(0, 0), (1, 2, Some(0), None));
+    assert_eq!(token(0, 4), (1, 6, Some(0), Some("count")));
+    assert_eq!(token(0, 10), (1, 14, Some(0), None));
+    assert!(map.get_tokens().all(|token: &Token| token.get_source_id() == Some(0)));
+}
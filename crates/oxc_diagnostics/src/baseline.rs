@@ -0,0 +1,76 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// Known diagnostics which are not reported by the [`DiagnosticService`], counted by file and
+/// error code (e.g. `eslint(no-debugger)`).
+///
+/// A baseline allows adopting new rules incrementally: existing violations are recorded once,
+/// and only new violations are reported afterwards.
+///
+/// [`DiagnosticService`]: crate::DiagnosticService
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    counts: BTreeMap<PathBuf, BTreeMap<String, usize>>,
+    /// Contains every diagnostic with an error code, regardless of `counts`.
+    all: bool,
+}
+
+impl Baseline {
+    /// A baseline containing every diagnostic with an error code. Used to create a new baseline
+    /// from the diagnostics received by [`DiagnosticService::run`].
+    ///
+    /// [`DiagnosticService::run`]: crate::DiagnosticService::run
+    pub fn all() -> Self {
+        Self { counts: BTreeMap::new(), all: true }
+    }
+
+    /// Number of diagnostics with `code` in the file at `path`.
+    pub fn get(&self, path: &Path, code: &str) -> usize {
+        self.counts.get(path).and_then(|codes| codes.get(code)).copied().unwrap_or(0)
+    }
+
+    /// Set the number of diagnostics with `code` in the file at `path`. A count of `0` removes
+    /// the entry.
+    pub fn set(&mut self, path: PathBuf, code: String, count: usize) {
+        if count == 0 {
+            if let Some(codes) = self.counts.get_mut(&path) {
+                codes.remove(&code);
+                if codes.is_empty() {
+                    self.counts.remove(&path);
+                }
+            }
+        } else {
+            self.counts.entry(path).or_default().insert(code, count);
+        }
+    }
+
+    /// Whether diagnostics with `code` in the file at `path` are in this baseline.
+    pub fn contains(&self, path: &Path, code: &str) -> bool {
+        self.all || self.get(path, code) > 0
+    }
+
+    /// Iterate over all entries as `(path, code, count)`, sorted by path and code.
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &str, usize)> {
+        self.counts.iter().flat_map(|(path, codes)| {
+            codes.iter().map(move |(code, count)| (path.as_path(), code.as_str(), *count))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    pub(crate) fn increment(&mut self, path: &Path, code: &str) -> usize {
+        let count = self.counts.entry(path.to_path_buf()).or_default().entry(code.to_string());
+        let count = count.or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    /// Whether `count` diagnostics with `code` in the file at `path` do not exceed this baseline.
+    pub(crate) fn allows(&self, path: &Path, code: &str, count: usize) -> bool {
+        self.all || count <= self.get(path, code)
+    }
+}
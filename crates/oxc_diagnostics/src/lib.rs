@@ -48,6 +48,7 @@
 //! service.run();
 //! ```
 
+mod baseline;
 mod service;
 
 use std::{
@@ -58,6 +59,7 @@ use std::{
 
 pub mod reporter;
 
+pub use crate::baseline::Baseline;
 pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
 
 pub type Error = miette::Error;
@@ -124,6 +126,20 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    pub fixes: Vec<DiagnosticFix>,
+}
+
+/// A replacement of source code which fixes the problem reported by an [`OxcDiagnostic`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiagnosticFix {
+    /// Replacement for the source code between `start` and `end`.
+    pub content: Cow<'static, str>,
+    /// A brief message describing the fix.
+    pub message: Option<Cow<'static, str>>,
+    /// Start byte offset of the replaced source code.
+    pub start: u32,
+    /// End byte offset of the replaced source code.
+    pub end: u32,
 }
 
 impl Display for OxcDiagnostic {
@@ -180,6 +196,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -194,6 +211,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                fixes: Vec::new(),
             }),
         }
     }
@@ -334,10 +352,110 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add fixes for the problem reported by this diagnostic.
+    ///
+    /// Fixes are not rendered, they are only used by reporters which output machine-readable
+    /// fixes.
+    pub fn with_fixes<T: IntoIterator<Item = DiagnosticFix>>(mut self, fixes: T) -> Self {
+        self.inner.fixes = fixes.into_iter().collect();
+        self
+    }
+
     /// Add source code to this diagnostic and convert it into an [`Error`].
     ///
     /// You should use a [`NamedSource`] if you have a file name as well as the source code.
+    /// Fixes added with [`OxcDiagnostic::with_fixes`] are kept, see [`OxcDiagnostic::fixes_of`].
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        if self.fixes.is_empty() {
+            Error::from(self).with_source_code(code)
+        } else {
+            Error::new(WithFixes { diagnostic: self, source_code: Box::new(code) })
+        }
+    }
+
+    /// Get the fixes of the diagnostic an [`Error`] was created from.
+    pub fn fixes_of(error: &Error) -> &[DiagnosticFix] {
+        error
+            .downcast_ref::<WithFixes>()
+            .map(|error| &error.diagnostic)
+            .or_else(|| error.downcast_ref::<Self>())
+            .map_or(&[], |diagnostic| &diagnostic.fixes)
+    }
+}
+
+/// An [`OxcDiagnostic`] with fixes and source code. Unlike [`Error::with_source_code`], this
+/// allows getting the fixes back with [`OxcDiagnostic::fixes_of`].
+struct WithFixes {
+    diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode + Send + Sync>,
+}
+
+impl fmt::Debug for WithFixes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.diagnostic, f)
+    }
+}
+
+impl Display for WithFixes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithFixes {}
+
+impl Diagnostic for WithFixes {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.code()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticFix, LabeledSpan, NamedSource, OxcDiagnostic};
+
+    #[test]
+    fn with_source_code_keeps_fixes() {
+        let fix = DiagnosticFix { content: "".into(), message: None, start: 0, end: 9 };
+        let error = OxcDiagnostic::warn("message")
+            .with_error_code("eslint", "no-debugger")
+            .with_label(LabeledSpan::new_with_span(None, (0, 9)))
+            .with_help("help")
+            .with_fixes([fix.clone()])
+            .with_source_code(NamedSource::new("test.js", "debugger;"));
+        assert_eq!(OxcDiagnostic::fixes_of(&error), [fix]);
+        assert_eq!(error.to_string(), "message");
+        assert_eq!(error.code().unwrap().to_string(), "eslint(no-debugger)");
+        assert_eq!(error.help().unwrap().to_string(), "help");
+        assert_eq!(error.labels().unwrap().count(), 1);
+        let source = error.source_code().unwrap().read_span(&(0, 9).into(), 0, 0).unwrap();
+        assert_eq!(source.name(), Some("test.js"));
+    }
+
+    #[test]
+    fn with_source_code_without_fixes() {
+        let error = OxcDiagnostic::warn("message").with_source_code("debugger;");
+        assert!(OxcDiagnostic::fixes_of(&error).is_empty());
+        assert!(error.source_code().is_some());
     }
 }
@@ -1,5 +1,6 @@
 use std::{
     borrow::Cow,
+    collections::BTreeMap,
     io::{ErrorKind, Write},
     path::{Path, PathBuf},
     sync::{Arc, mpsc},
@@ -12,7 +13,7 @@ use percent_encoding::AsciiSet;
 use std::fs::canonicalize as strict_canonicalize;
 
 use crate::{
-    Error, NamedSource, OxcDiagnostic, Severity,
+    Baseline, Error, NamedSource, OxcDiagnostic, Severity,
     reporter::{DiagnosticReporter, DiagnosticResult},
 };
 
@@ -65,6 +66,13 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Diagnostics which are not reported, see [`with_baseline`](DiagnosticService::with_baseline)
+    baseline: Option<Baseline>,
+
+    /// Diagnostics with an error code received by [`run`](DiagnosticService::run), only
+    /// recorded when a baseline is set
+    received: Baseline,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -74,7 +82,16 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> Self {
         let (sender, receiver) = mpsc::channel();
-        Self { reporter, quiet: false, silent: false, max_warnings: None, sender, receiver }
+        Self {
+            reporter,
+            quiet: false,
+            silent: false,
+            max_warnings: None,
+            baseline: None,
+            received: Baseline::default(),
+            sender,
+            receiver,
+        }
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -113,6 +130,27 @@ impl DiagnosticService {
         self
     }
 
+    /// Do not report diagnostics which are in the `baseline`, unless there are more diagnostics
+    /// with the same error code in a file than recorded in the baseline. Diagnostics without an
+    /// error code, such as parse errors, are always reported.
+    ///
+    /// Use [`received_diagnostics`](DiagnosticService::received_diagnostics) to update the
+    /// baseline after [`run`](DiagnosticService::run).
+    ///
+    /// Default: [`None`]
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Number of diagnostics received by [`run`](DiagnosticService::run), counted by file and
+    /// error code. Only recorded when a baseline is set with
+    /// [`with_baseline`](DiagnosticService::with_baseline).
+    pub fn received_diagnostics(&self) -> &Baseline {
+        &self.received
+    }
+
     /// Channel for sending [diagnostic messages] to the service.
     ///
     /// The service will only start processing diagnostics after [`run`](DiagnosticService::run)
@@ -155,11 +193,16 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path_display, source_text.to_owned()));
         diagnostics
             .into_iter()
-            .map(|diagnostic| {
+            .map(|mut diagnostic| {
                 if source_start == 0 {
                     return diagnostic.with_source_code(Arc::clone(&source));
                 }
 
+                for fix in &mut diagnostic.fixes {
+                    fix.start += source_start;
+                    fix.end += source_start;
+                }
+
                 match &diagnostic.labels {
                     None => diagnostic.with_source_code(Arc::clone(&source)),
                     Some(labels) => {
@@ -194,52 +237,36 @@ impl DiagnosticService {
         let mut warnings_count: usize = 0;
         let mut errors_count: usize = 0;
 
+        // Diagnostics in the baseline, which are only reported if their number exceeds the baseline.
+        let mut baselined = BTreeMap::<(PathBuf, String), Vec<Error>>::new();
+
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
             for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
-                let is_warning = severity == Some(Severity::Warning);
-                let is_error = severity == Some(Severity::Error) || severity.is_none();
-                if is_warning || is_error {
-                    if is_warning {
-                        warnings_count += 1;
-                    }
-                    if is_error {
-                        errors_count += 1;
-                    }
-                    // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
-                    // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
-                        continue;
+                if let Some(baseline) = &self.baseline {
+                    if let Some(code) = diagnostic.code().map(|code| code.to_string()) {
+                        self.received.increment(&path, &code);
+                        if baseline.contains(&path, &code) {
+                            baselined.entry((path.clone(), code)).or_default().push(diagnostic);
+                            continue;
+                        }
                     }
                 }
-
-                if self.silent {
-                    continue;
+                if !self.report(&path, diagnostic, &mut warnings_count, &mut errors_count, writer) {
+                    break;
                 }
+            }
+        }
 
-                if let Some(err_str) = self.reporter.render_error(diagnostic) {
-                    // Skip large output and print only once.
-                    // Setting to 1200 because graphical output may contain ansi escape codes and other decorations.
-                    if err_str.lines().any(|line| line.len() >= 1200) {
-                        let minified_diagnostic = Error::new(
-                            OxcDiagnostic::warn("File is too long to fit on the screen").with_help(
-                                format!("{} seems like a minified file", path.display()),
-                            ),
-                        );
-
-                        if let Some(err_str) = self.reporter.render_error(minified_diagnostic) {
-                            writer
-                                .write_all(err_str.as_bytes())
-                                .or_else(Self::check_for_writer_error)
-                                .unwrap();
-                        }
-                        break;
-                    }
-
-                    writer
-                        .write_all(err_str.as_bytes())
-                        .or_else(Self::check_for_writer_error)
-                        .unwrap();
+        // Like ESLint's bulk suppressions, all diagnostics of a rule in a file are reported once
+        // there are more than in the baseline, as it is unknown which of them are new.
+        for ((path, code), diagnostics) in baselined {
+            let count = self.received.get(&path, &code);
+            if self.baseline.as_ref().is_some_and(|baseline| baseline.allows(&path, &code, count)) {
+                continue;
+            }
+            for diagnostic in diagnostics {
+                if !self.report(&path, diagnostic, &mut warnings_count, &mut errors_count, writer) {
+                    break;
                 }
             }
         }
@@ -262,6 +289,61 @@ impl DiagnosticService {
         result
     }
 
+    /// Count and render a diagnostic of the file at `path`.
+    ///
+    /// Returns `false` if the remaining diagnostics of the file should be skipped.
+    fn report(
+        &mut self,
+        path: &Path,
+        diagnostic: Error,
+        warnings_count: &mut usize,
+        errors_count: &mut usize,
+        writer: &mut dyn Write,
+    ) -> bool {
+        let severity = diagnostic.severity();
+        let is_warning = severity == Some(Severity::Warning);
+        let is_error = severity == Some(Severity::Error) || severity.is_none();
+        if is_warning || is_error {
+            if is_warning {
+                *warnings_count += 1;
+            }
+            if is_error {
+                *errors_count += 1;
+            }
+            // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
+            // Note that it does not disable ALL diagnostics, only Warning diagnostics
+            else if self.quiet {
+                return true;
+            }
+        }
+
+        if self.silent {
+            return true;
+        }
+
+        if let Some(err_str) = self.reporter.render_error(diagnostic) {
+            // Skip large output and print only once.
+            // Setting to 1200 because graphical output may contain ansi escape codes and other decorations.
+            if err_str.lines().any(|line| line.len() >= 1200) {
+                let minified_diagnostic = Error::new(
+                    OxcDiagnostic::warn("File is too long to fit on the screen")
+                        .with_help(format!("{} seems like a minified file", path.display())),
+                );
+
+                if let Some(err_str) = self.reporter.render_error(minified_diagnostic) {
+                    writer
+                        .write_all(err_str.as_bytes())
+                        .or_else(Self::check_for_writer_error)
+                        .unwrap();
+                }
+                return false;
+            }
+
+            writer.write_all(err_str.as_bytes()).or_else(Self::check_for_writer_error).unwrap();
+        }
+        true
+    }
+
     fn check_for_writer_error(error: std::io::Error) -> Result<(), std::io::Error> {
         // Do not panic when the process is killed (e.g. piping into `less`).
         if matches!(error.kind(), ErrorKind::Interrupted | ErrorKind::BrokenPipe) {
@@ -20,6 +20,8 @@ use super::{Config, categories::OxlintCategories};
 #[must_use = "You dropped your builder without building a Linter! Did you mean to call .build()?"]
 pub struct ConfigStoreBuilder {
     pub(super) rules: FxHashMap<RuleEnum, AllowWarnDeny>,
+    /// JSON configuration of the rules in `rules` which are configured in a config file.
+    rule_configs: FxHashMap<RuleEnum, serde_json::Value>,
     config: LintConfig,
     categories: OxlintCategories,
     overrides: OxlintOverrides,
@@ -49,7 +51,15 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let extended_paths = Vec::new();
 
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self {
+            rules,
+            rule_configs: FxHashMap::default(),
+            config,
+            categories,
+            overrides,
+            cache,
+            extended_paths,
+        }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -63,7 +73,15 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let extended_paths = Vec::new();
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self {
+            rules,
+            rule_configs: FxHashMap::default(),
+            config,
+            categories,
+            overrides,
+            cache,
+            extended_paths,
+        }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -158,6 +176,7 @@ impl ConfigStoreBuilder {
 
         let mut builder = Self {
             rules,
+            rule_configs: FxHashMap::default(),
             config,
             categories,
             overrides: oxlintrc.overrides,
@@ -172,7 +191,9 @@ impl ConfigStoreBuilder {
         {
             let all_rules = builder.cache.borrow();
 
-            oxlintrc.rules.override_rules(&mut builder.rules, all_rules.as_slice());
+            let rule_configs =
+                oxlintrc.rules.override_rules(&mut builder.rules, all_rules.as_slice());
+            builder.rule_configs.extend(rule_configs);
         }
 
         Ok(builder)
@@ -265,6 +286,8 @@ impl ConfigStoreBuilder {
                 LintFilterKind::All => self.rules.clear(),
             },
         }
+        // A rule added again after being disabled has its default configuration.
+        self.rule_configs.retain(|rule, _| self.rules.contains_key(rule));
 
         self
     }
@@ -306,6 +329,7 @@ impl ConfigStoreBuilder {
         };
         rules.sort_unstable_by_key(|(r, _)| r.id());
         Config::new(rules, self.categories, self.config, self.overrides)
+            .with_rule_configs(self.rule_configs)
     }
 
     /// Warn for all correctness rules in the given set of plugins.
@@ -1,9 +1,11 @@
 use std::{
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, OnceLock},
 };
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
+use serde_json::json;
 
 use super::{LintConfig, LintPlugins, categories::OxlintCategories, overrides::OxlintOverrides};
 use crate::{
@@ -42,6 +44,12 @@ pub struct Config {
 
     /// An optional set of overrides to apply to the base state depending on the file being linted.
     pub(crate) overrides: OxlintOverrides,
+
+    /// JSON configuration of the rules in `base_rules` which are configured in a config file.
+    rule_configs: FxHashMap<RuleEnum, serde_json::Value>,
+
+    /// Hash of this configuration, computed on first use by [`Config::hash`].
+    hash: OnceLock<u64>,
 }
 
 impl Config {
@@ -66,9 +74,64 @@ impl Config {
             base_rules: rules,
             categories,
             overrides,
+            rule_configs: FxHashMap::default(),
+            hash: OnceLock::new(),
         }
     }
 
+    /// Set the JSON configuration of the rules, which is part of [`Config::hash`].
+    #[must_use]
+    pub(crate) fn with_rule_configs(
+        mut self,
+        rule_configs: FxHashMap<RuleEnum, serde_json::Value>,
+    ) -> Self {
+        self.rule_configs = rule_configs;
+        self
+    }
+
+    /// Hash of the rules, rule configurations, and settings of this configuration.
+    ///
+    /// Rules are not serializable, so their names, severities and the JSON configuration they
+    /// were created from are hashed along with the rest of the configuration.
+    fn hash(&self) -> u64 {
+        *self.hash.get_or_init(|| {
+            let rules = self
+                .base_rules
+                .iter()
+                .map(|(rule, severity)| {
+                    json!([rule.plugin_name(), rule.name(), severity, self.rule_configs.get(rule)])
+                })
+                .collect::<Vec<_>>();
+            let config = &self.base.config;
+            let serialized = json!({
+                "rules": rules,
+                "categories": self.categories,
+                "overrides": self.overrides,
+                "plugins": config.plugins,
+                "settings": config.settings,
+                "env": config.env,
+                "globals": config.globals,
+                "path": config.path,
+            });
+            let mut hasher = FxHasher::default();
+            serialized.to_string().hash(&mut hasher);
+            hasher.finish()
+        })
+    }
+
+    /// Path of `path` relative to the directory of the config file, which override globs are
+    /// matched against.
+    fn relative_path<'p>(&self, path: &'p Path) -> &'p Path {
+        self.base
+            .config
+            .path
+            .as_ref()
+            .and_then(|config_path| {
+                config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
+            })
+            .unwrap_or(path)
+    }
+
     pub fn plugins(&self) -> LintPlugins {
         self.base.config.plugins
     }
@@ -86,15 +149,7 @@ impl Config {
             return self.base.clone();
         }
 
-        let relative_path = self
-            .base
-            .config
-            .path
-            .as_ref()
-            .and_then(|config_path| {
-                config_path.parent().map(|parent| path.strip_prefix(parent).unwrap_or(path))
-            })
-            .unwrap_or(path);
+        let relative_path = self.relative_path(path);
 
         let overrides_to_apply =
             self.overrides.iter().filter(|config| config.files.is_match(relative_path));
@@ -203,15 +258,32 @@ impl ConfigStore {
     }
 
     pub(crate) fn resolve(&self, path: &Path) -> ResolvedLinterState {
-        let resolved_config = if self.nested_configs.is_empty() {
+        Config::apply_overrides(self.get_config(path), path)
+    }
+
+    /// Hash of the configuration resolved for `path`. Two paths with the same hash are linted
+    /// with the same rules and settings.
+    pub(crate) fn config_hash(&self, path: &Path) -> u64 {
+        let config = self.get_config(path);
+        let mut hasher = FxHasher::default();
+        config.hash().hash(&mut hasher);
+        let relative_path = config.relative_path(path);
+        for (i, override_config) in config.overrides.iter().enumerate() {
+            if override_config.files.is_match(relative_path) {
+                i.hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    fn get_config(&self, path: &Path) -> &Config {
+        if self.nested_configs.is_empty() {
             &self.base
         } else if let Some(config) = self.get_nearest_config(path) {
             config
         } else {
             &self.base
-        };
-
-        Config::apply_overrides(resolved_config, path)
+        }
     }
 
     fn get_nearest_config(&self, path: &Path) -> Option<&Config> {
@@ -234,7 +306,7 @@ mod test {
 
     use super::{ConfigStore, OxlintOverrides};
     use crate::{
-        AllowWarnDeny, LintPlugins, RuleEnum,
+        AllowWarnDeny, ConfigStoreBuilder, LintPlugins, RuleEnum,
         config::{
             LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, categories::OxlintCategories,
             config_store::Config,
@@ -518,4 +590,26 @@ mod test {
         assert!(!app.globals.is_enabled("React"));
         assert!(!app.globals.is_enabled("Secret"));
     }
+
+    #[test]
+    fn test_hash_rule_config() {
+        let hash = |config: serde_json::Value| {
+            let oxlintrc = serde_json::from_value(config).unwrap();
+            let config = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap().build();
+            ConfigStore::new(config, FxHashMap::default()).config_hash("a.js".as_ref())
+        };
+        let warn = hash(
+            serde_json::json!({ "rules": { "no-console": ["error", { "allow": ["warn"] }] } }),
+        );
+        let log =
+            hash(serde_json::json!({ "rules": { "no-console": ["error", { "allow": ["log"] }] } }));
+        assert_eq!(
+            warn,
+            hash(
+                serde_json::json!({ "rules": { "no-console": ["error", { "allow": ["warn"] }] } })
+            )
+        );
+        assert_ne!(warn, log);
+        assert_ne!(warn, hash(serde_json::json!({ "rules": { "no-console": "error" } })));
+    }
 }
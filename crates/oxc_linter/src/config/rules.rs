@@ -58,7 +58,14 @@ pub struct ESLintRule {
 }
 
 impl OxlintRules {
-    pub(crate) fn override_rules(&self, rules_for_override: &mut RuleSet, all_rules: &[RuleEnum]) {
+    /// Configure the rules in `rules_for_override`.
+    ///
+    /// Returns the configured rules with their JSON configuration.
+    pub(crate) fn override_rules(
+        &self,
+        rules_for_override: &mut RuleSet,
+        all_rules: &[RuleEnum],
+    ) -> Vec<(RuleEnum, serde_json::Value)> {
         use itertools::Itertools;
         let mut rules_to_replace = vec![];
 
@@ -86,15 +93,19 @@ impl OxlintRules {
                 });
 
                 if let Some(rule) = rule {
-                    rules_to_replace.push((rule.read_json(config), severity));
+                    rules_to_replace.push((rule.read_json(config.clone()), severity, config));
                 }
             }
         }
 
-        for (rule, severity) in rules_to_replace {
-            let _ = rules_for_override.remove(&rule);
-            rules_for_override.insert(rule, severity);
-        }
+        rules_to_replace
+            .into_iter()
+            .map(|(rule, severity, config)| {
+                let _ = rules_for_override.remove(&rule);
+                rules_for_override.insert(rule.clone(), severity);
+                (rule, config)
+            })
+            .collect()
     }
 }
 
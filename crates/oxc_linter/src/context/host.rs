@@ -13,6 +13,7 @@ use crate::{
     module_record::ModuleRecord,
     options::LintOptions,
     rules::RuleEnum,
+    type_info::TypeInfoProvider,
 };
 
 use super::{LintContext, plugin_name_to_prefix};
@@ -65,6 +66,9 @@ pub struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// Provides type information for type-aware rules. `None` unless type-aware linting is
+    /// enabled, e.g. via the `--type-aware` CLI flag.
+    pub(super) type_info: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 impl<'a> ContextHost<'a> {
@@ -102,6 +106,7 @@ impl<'a> ContextHost<'a> {
             config,
             frameworks: options.framework_hints,
             plugins,
+            type_info: None,
         }
         .sniff_for_frameworks()
     }
@@ -120,6 +125,19 @@ impl<'a> ContextHost<'a> {
         self
     }
 
+    /// Set the provider of type information for this context.
+    #[inline]
+    pub fn with_type_info(mut self, type_info: Option<Arc<dyn TypeInfoProvider>>) -> Self {
+        self.type_info = type_info;
+        self
+    }
+
+    /// Whether type information is available. Type-aware rules should only run when this is `true`.
+    #[inline]
+    pub fn has_type_info(&self) -> bool {
+        self.type_info.is_some()
+    }
+
     /// Shared reference to the [`Semantic`] analysis of the file.
     #[inline]
     pub fn semantic(&self) -> &Semantic<'a> {
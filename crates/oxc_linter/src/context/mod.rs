@@ -4,7 +4,7 @@ use std::{ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::ast::{Expression, IdentifierReference};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
@@ -17,6 +17,8 @@ use crate::{
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
+    rules::RuleEnum,
+    type_info::{ExpressionType, type_of_expression},
 };
 
 mod host;
@@ -69,9 +71,6 @@ impl<'a> Deref for LintContext<'a> {
 }
 
 impl<'a> LintContext<'a> {
-    /// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
-    const WEBSITE_BASE_URL: &'static str = "https://oxc.rs/docs/guide/usage/linter/rules";
-
     /// Set the plugin name for the current rule.
     pub fn with_plugin_name(mut self, plugin: &'static str) -> Self {
         self.current_plugin_name = plugin;
@@ -140,6 +139,25 @@ impl<'a> LintContext<'a> {
         &self.parent.file_path
     }
 
+    /// Get the type of an expression from the type information provider.
+    ///
+    /// Returns `None` if type-aware linting is disabled, or the type of the expression is unknown.
+    /// A failure of the provider is reported as a diagnostic.
+    pub fn type_of(&self, expr: &Expression<'a>) -> Option<ExpressionType> {
+        let provider = self.parent.type_info.as_deref()?;
+        match type_of_expression(provider, self.file_path(), self.source_text(), expr) {
+            Ok(ty) => ty,
+            Err(error) => {
+                self.diagnostic(
+                    OxcDiagnostic::warn(format!("Failed to get type information: {error}"))
+                        .with_help("Type-aware rules do not report problems once type information is unavailable.")
+                        .with_label(expr.span()),
+                );
+                None
+            }
+        }
+    }
+
     /// Plugin settings
     #[inline]
     pub fn settings(&self) -> &OxlintSettings {
@@ -228,12 +246,7 @@ impl<'a> LintContext<'a> {
         message.error = message
             .error
             .with_error_code(self.current_plugin_prefix, self.current_rule_name)
-            .with_url(format!(
-                "{}/{}/{}.html",
-                Self::WEBSITE_BASE_URL,
-                self.current_plugin_name,
-                self.current_rule_name
-            ));
+            .with_url(rule_documentation_url(self.current_plugin_name, self.current_rule_name));
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
@@ -423,6 +436,25 @@ impl<'a> LintContext<'a> {
     }
 }
 
+/// Base URL for the documentation, used to generate rule documentation URLs when a diagnostic is reported.
+const WEBSITE_BASE_URL: &str = "https://oxc.rs/docs/guide/usage/linter/rules";
+
+fn rule_documentation_url(plugin_name: &str, rule_name: &str) -> String {
+    format!("{WEBSITE_BASE_URL}/{plugin_name}/{rule_name}.html")
+}
+
+impl RuleEnum {
+    /// Error code of the diagnostics reported by this rule, e.g. `eslint(no-debugger)`.
+    pub fn error_code(&self) -> String {
+        format!("{}({})", plugin_name_to_prefix(self.plugin_name()), self.name())
+    }
+
+    /// URL of this rule's documentation.
+    pub fn documentation_url(&self) -> String {
+        rule_documentation_url(self.plugin_name(), self.name())
+    }
+}
+
 /// Gets the prefixed plugin name, given the short plugin name.
 ///
 /// Example:
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, OxcDiagnostic};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
@@ -292,6 +292,24 @@ impl<'a> Message<'a> {
     }
 }
 
+impl Message<'_> {
+    /// Convert into an [`OxcDiagnostic`] carrying the fixes of this message, for reporters which
+    /// output fixes instead of applying them.
+    pub fn into_diagnostic_with_fixes(self) -> OxcDiagnostic {
+        let fixes = match self.fixes {
+            PossibleFixes::None => return self.error,
+            PossibleFixes::Single(fix) => vec![fix],
+            PossibleFixes::Multiple(fixes) => fixes,
+        };
+        self.error.with_fixes(fixes.into_iter().map(|fix| DiagnosticFix {
+            content: Cow::Owned(fix.content.into_owned()),
+            message: fix.message.map(|message| Cow::Owned(message.into_owned())),
+            start: fix.span.start,
+            end: fix.span.end,
+        }))
+    }
+}
+
 impl From<Message<'_>> for OxcDiagnostic {
     #[inline]
     fn from(message: Message) -> Self {
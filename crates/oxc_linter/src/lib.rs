@@ -16,15 +16,22 @@ mod module_record;
 mod options;
 mod rule;
 mod service;
+mod type_info;
 mod utils;
 
 pub mod loader;
 pub mod rules;
 pub mod table;
 
-use std::{path::Path, rc::Rc, sync::Arc};
+use std::{
+    hash::{Hash, Hasher},
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 
 use oxc_semantic::{AstNode, Semantic};
+use rustc_hash::FxHasher;
 
 pub use crate::{
     config::{
@@ -39,7 +46,11 @@ pub use crate::{
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
     rule::{RuleCategory, RuleFixMeta, RuleMeta},
-    service::{LintService, LintServiceOptions, RuntimeFileSystem},
+    service::{
+        LintCache, LintService, LintServiceOptions, RuntimeFileSystem,
+        offset_to_position::{SpanPosition, offset_to_position},
+    },
+    type_info::{ExpressionType, TsServer, TypeInfoProvider},
     utils::read_to_arena_str,
     utils::read_to_string,
 };
@@ -68,11 +79,13 @@ pub struct Linter {
     options: LintOptions,
     // config: Arc<LintConfig>,
     config: ConfigStore,
+    /// Provides type information for type-aware rules.
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 impl Linter {
     pub fn new(options: LintOptions, config: ConfigStore) -> Self {
-        Self { options, config }
+        Self { options, config, type_info: None }
     }
 
     /// Set the kind of auto fixes to apply.
@@ -88,10 +101,30 @@ impl Linter {
         self
     }
 
+    /// Set the provider of type information, which enables type-aware rules.
+    #[must_use]
+    pub fn with_type_info_provider(mut self, provider: Arc<dyn TypeInfoProvider>) -> Self {
+        self.type_info = Some(provider);
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    pub(crate) fn has_type_info(&self) -> bool {
+        self.type_info.is_some()
+    }
+
+    /// Hash of the configuration and options used to lint `path`. Two paths with the same hash
+    /// are linted with the same rules, settings and options affecting the diagnostics.
+    pub(crate) fn config_hash(&self, path: &Path) -> u64 {
+        let mut hasher = FxHasher::default();
+        self.config.config_hash(path).hash(&mut hasher);
+        self.options.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Returns the number of rules that will are being used, unless there
     /// nested configurations in use, in which case it returns `None` since the
     /// number of rules depends on which file is being linted.
@@ -107,8 +140,10 @@ impl Linter {
     ) -> Vec<Message<'a>> {
         let ResolvedLinterState { rules, config } = self.config.resolve(path);
 
-        let ctx_host =
-            Rc::new(ContextHost::new(path, semantic, module_record, self.options, config));
+        let ctx_host = Rc::new(
+            ContextHost::new(path, semantic, module_record, self.options, config)
+                .with_type_info(self.type_info.clone()),
+        );
 
         let rules = rules
             .iter()
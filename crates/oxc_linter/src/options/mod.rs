@@ -7,7 +7,7 @@ pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
 use crate::{FrameworkFlags, fixer::FixKind};
 
 /// Subset of options used directly by the linter.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, Hash)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LintOptions {
     pub fix: FixKind,
@@ -188,6 +188,7 @@ mod eslint {
 mod typescript {
     pub mod adjacent_overload_signatures;
     pub mod array_type;
+    pub mod await_thenable;
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
@@ -204,9 +205,11 @@ mod typescript {
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
+    pub mod no_floating_promises;
     pub mod no_import_type_side_effects;
     pub mod no_inferrable_types;
     pub mod no_misused_new;
+    pub mod no_misused_promises;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
     pub mod no_non_null_asserted_optional_chain;
@@ -228,6 +231,7 @@ mod typescript {
     pub mod prefer_literal_enum_member;
     pub mod prefer_namespace_keyword;
     pub mod prefer_ts_expect_error;
+    pub mod restrict_template_expressions;
     pub mod triple_slash_reference;
 }
 
@@ -945,6 +949,7 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_object_as_prop,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
+    typescript::await_thenable,
     typescript::ban_ts_comment,
     typescript::ban_tslint_comment,
     typescript::ban_types,
@@ -962,8 +967,10 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
     typescript::no_extraneous_class,
+    typescript::no_floating_promises,
     typescript::no_import_type_side_effects,
     typescript::no_misused_new,
+    typescript::no_misused_promises,
     typescript::no_namespace,
     typescript::no_non_null_asserted_nullish_coalescing,
     typescript::no_non_null_asserted_optional_chain,
@@ -984,6 +991,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::prefer_literal_enum_member,
     typescript::prefer_namespace_keyword,
     typescript::prefer_ts_expect_error,
+    typescript::restrict_template_expressions,
     typescript::triple_slash_reference,
     unicorn::catch_error_name,
     unicorn::consistent_assert,
@@ -0,0 +1,105 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn await_thenable_diagnostic(span: Span, ty: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `await` of a non-Promise (non-\"Thenable\") value.")
+        .with_help(format!("Remove the `await`, the awaited value has type `{ty}`."))
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AwaitThenable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow awaiting a value that is not a Thenable.
+    ///
+    /// This rule requires type information, and only runs when type-aware linting is enabled
+    /// (`oxlint --type-aware`).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// While it is valid JavaScript to await a non-Promise-like value (it will resolve
+    /// immediately), this pattern is often a programmer error, such as forgetting to add
+    /// parenthesis to call a function that returns a Promise.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// await 'value';
+    ///
+    /// const createValue = () => 'value';
+    /// await createValue();
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// await Promise.resolve('value');
+    ///
+    /// const createValue = async () => 'value';
+    /// await createValue();
+    /// ```
+    AwaitThenable,
+    typescript,
+    suspicious,
+);
+
+impl Rule for AwaitThenable {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        let Some(ty) = ctx.type_of(&await_expr.argument) else {
+            return;
+        };
+        if ty.is_never_thenable() {
+            ctx.diagnostic(await_thenable_diagnostic(await_expr.span, ty.as_str()));
+        }
+    }
+
+    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
+        ctx.source_type().is_typescript() && ctx.has_type_info()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, type_info::StubTypeInfo};
+
+    let type_info = StubTypeInfo::new([
+        ("fetchData", "function fetchData(): Promise<string>"),
+        ("createValue", "const createValue: () => string"),
+        ("promise", "let promise: Promise<number> | undefined"),
+        ("value", "const value: number | null"),
+        ("thenable", "const thenable: CustomThenable"),
+        ("callback", "(parameter) callback: () => void"),
+        ("untyped", "const untyped: any"),
+        ("unknownValue", "const unknownValue: unknown"),
+    ]);
+
+    let pass = vec![
+        "async function f() { await fetchData(); }",
+        "async function f() { await promise; }",
+        "async function f() { await thenable; }",
+        "async function f() { await untyped; }",
+        "async function f() { await unknownValue; }",
+        "async function f() { await unknownFunction(); }",
+    ];
+
+    let fail = vec![
+        "async function f() { await createValue(); }",
+        "async function f() { await value; }",
+        "async function f() { await callback; }",
+        "async function f() { await (value); }",
+    ];
+
+    Tester::new(AwaitThenable::NAME, AwaitThenable::PLUGIN, pass, fail)
+        .with_type_info(type_info)
+        .test_and_snapshot();
+}
@@ -0,0 +1,140 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_floating_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Promises must be awaited.")
+        .with_help("Await the promise, add a rejection handler with `.catch()`, or mark it as intentionally not awaited with the `void` operator.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Promise-like statements to be handled appropriately.
+    ///
+    /// This rule requires type information, and only runs when type-aware linting is enabled
+    /// (`oxlint --type-aware`).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A "floating" Promise is one that is created without any code set up to handle any errors
+    /// it might throw. Floating Promises can cause improperly sequenced operations, ignored
+    /// Promise rejections, and more.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// async function fetchData(): Promise<string> { /* ... */ }
+    ///
+    /// fetchData();
+    /// fetchData().then(() => {});
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// async function fetchData(): Promise<string> { /* ... */ }
+    ///
+    /// await fetchData();
+    /// fetchData().catch(() => {});
+    /// fetchData().then(() => {}, () => {});
+    /// void fetchData();
+    /// ```
+    NoFloatingPromises,
+    typescript,
+    suspicious,
+);
+
+impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        let expr = stmt.expression.get_inner_expression();
+        if let Expression::UnaryExpression(unary) = expr {
+            if unary.operator == UnaryOperator::Void {
+                return;
+            }
+        }
+        if is_handled(expr) {
+            return;
+        }
+        if ctx.type_of(expr).is_some_and(|ty| ty.is_promise_like()) {
+            ctx.diagnostic(no_floating_promises_diagnostic(stmt.span));
+        }
+    }
+
+    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
+        ctx.source_type().is_typescript() && ctx.has_type_info()
+    }
+}
+
+/// `promise.catch(handler)` and `promise.then(onFulfilled, onRejected)` handle rejections.
+fn is_handled(expr: &Expression) -> bool {
+    let Expression::CallExpression(call) = expr else {
+        return false;
+    };
+    let Some(member) = call.callee.get_inner_expression().as_member_expression() else {
+        return false;
+    };
+    match member.static_property_name() {
+        Some("catch") => !call.arguments.is_empty(),
+        Some("then") => call.arguments.len() >= 2,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, type_info::StubTypeInfo};
+
+    let type_info = StubTypeInfo::new([
+        ("fetchData", "function fetchData(): Promise<string>"),
+        ("maybeFetch", "const maybeFetch: () => Promise<void> | undefined"),
+        ("sync", "function sync(): number"),
+        ("promise", "const promise: Promise<number>"),
+        (
+            "then",
+            "(method) Promise<number>.then<void, never>(onfulfilled?: ((value: number) => void) | null | undefined): Promise<void>",
+        ),
+        (
+            "finally",
+            "(method) Promise<number>.finally(onfinally?: (() => void) | null | undefined): Promise<number>",
+        ),
+        ("untyped", "const untyped: any"),
+    ]);
+
+    let pass = vec![
+        "async function f() { await fetchData(); }",
+        "void fetchData();",
+        "fetchData().catch(() => {});",
+        "fetchData().then(() => {}, () => {});",
+        "sync();",
+        "untyped();",
+        "const p = fetchData();",
+        "unknownFunction();",
+    ];
+
+    let fail = vec![
+        "fetchData();",
+        "(fetchData());",
+        "maybeFetch();",
+        "promise;",
+        "promise.then(() => {});",
+        "promise.finally(() => {});",
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, NoFloatingPromises::PLUGIN, pass, fail)
+        .with_type_info(type_info)
+        .test_and_snapshot();
+}
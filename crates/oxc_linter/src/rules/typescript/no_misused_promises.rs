@@ -0,0 +1,125 @@
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, LogicalOperator, UnaryOperator},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn no_misused_promises_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected non-Promise value in a boolean conditional.")
+        .with_help("A Promise is always truthy. Did you forget to `await` it?")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMisusedPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow Promises in places not designed to handle them.
+    ///
+    /// This rule requires type information, and only runs when type-aware linting is enabled
+    /// (`oxlint --type-aware`). Currently, only conditionals are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A Promise object is always truthy, so using one in a conditional is almost always a
+    /// mistake, such as forgetting to `await` an asynchronous function call.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    ///
+    /// if (promise) {}
+    /// const value = promise ? 1 : 2;
+    /// while (!promise) {}
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const promise = Promise.resolve('value');
+    ///
+    /// if (await promise) {}
+    /// const value = (await promise) ? 1 : 2;
+    /// while (!(await promise)) {}
+    /// ```
+    NoMisusedPromises,
+    typescript,
+    suspicious,
+);
+
+impl Rule for NoMisusedPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::IfStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::WhileStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::DoWhileStatement(stmt) => check_conditional(&stmt.test, ctx),
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    check_conditional(test, ctx);
+                }
+            }
+            AstKind::ConditionalExpression(expr) => check_conditional(&expr.test, ctx),
+            AstKind::LogicalExpression(expr) if expr.operator != LogicalOperator::Coalesce => {
+                check_conditional(&expr.left, ctx);
+            }
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                check_conditional(&expr.argument, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
+        ctx.source_type().is_typescript() && ctx.has_type_info()
+    }
+}
+
+fn check_conditional<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) {
+    if ctx.type_of(expr).is_some_and(|ty| ty.is_promise_like()) {
+        ctx.diagnostic(no_misused_promises_diagnostic(expr.span()));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, type_info::StubTypeInfo};
+
+    let type_info = StubTypeInfo::new([
+        ("fetchData", "function fetchData(): Promise<string>"),
+        ("promise", "const promise: Promise<number>"),
+        ("value", "const value: number | undefined"),
+        ("untyped", "const untyped: any"),
+    ]);
+
+    let pass = vec![
+        "async function f() { if (await promise) {} }",
+        "if (value) {}",
+        "if (untyped) {}",
+        "if (fetchData) {}",
+        "const x = value ? 1 : 2;",
+        "while (!value) {}",
+        "const x = promise ?? value;",
+    ];
+
+    let fail = vec![
+        "if (promise) {}",
+        "if (fetchData()) {}",
+        "const x = promise ? 1 : 2;",
+        "while (promise) {}",
+        "do {} while (promise)",
+        "for (; promise; ) {}",
+        "if (!promise) {}",
+        "const x = promise && value;",
+    ];
+
+    Tester::new(NoMisusedPromises::NAME, NoMisusedPromises::PLUGIN, pass, fail)
+        .with_type_info(type_info)
+        .test_and_snapshot();
+}
@@ -0,0 +1,121 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule};
+
+fn restrict_template_expressions_diagnostic(span: Span, ty: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Invalid type `{ty}` of template literal expression."))
+        .with_help("Convert the value to a string explicitly, or only use primitive values in template literals.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RestrictTemplateExpressions;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce template literal expressions to be of a string-convertible type.
+    ///
+    /// This rule requires type information, and only runs when type-aware linting is enabled
+    /// (`oxlint --type-aware`). Objects, arrays, functions, Promises, symbols and values of type
+    /// `never` or `unknown` are reported.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript automatically converts an object to a string in a string context, which
+    /// usually produces an unhelpful result such as `[object Object]`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```ts
+    /// const arg1 = [1, 2];
+    /// const msg1 = `arg1 = ${arg1}`;
+    ///
+    /// const arg2 = { name: 'Foo' };
+    /// const msg2 = `arg2 = ${arg2}`;
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```ts
+    /// const arg = 'foo';
+    /// const msg1 = `arg = ${arg}`;
+    /// const msg2 = `arg = ${arg || 'default'}`;
+    /// ```
+    RestrictTemplateExpressions,
+    typescript,
+    pedantic,
+);
+
+impl Rule for RestrictTemplateExpressions {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TemplateLiteral(template) = node.kind() else {
+            return;
+        };
+        // Tagged templates do not convert expressions to strings.
+        if matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::TaggedTemplateExpression(_)))
+        {
+            return;
+        }
+        for expr in &template.expressions {
+            let Some(ty) = ctx.type_of(expr) else {
+                continue;
+            };
+            if ty.has_non_stringifiable_member() {
+                ctx.diagnostic(restrict_template_expressions_diagnostic(expr.span(), ty.as_str()));
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &crate::rules::ContextHost) -> bool {
+        ctx.source_type().is_typescript() && ctx.has_type_info()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::{tester::Tester, type_info::StubTypeInfo};
+
+    let type_info = StubTypeInfo::new([
+        ("name", "const name: string"),
+        ("count", "let count: number | undefined"),
+        ("flag", "const flag: boolean"),
+        ("kind", "const kind: \"a\" | \"b\""),
+        ("untyped", "const untyped: any"),
+        ("user", "const user: User"),
+        ("items", "const items: string[]"),
+        ("point", "const point: { x: number; y: number; }"),
+        ("callback", "function callback(): void"),
+        ("promise", "const promise: Promise<string>"),
+        ("sym", "const sym: unique symbol"),
+        ("unknownValue", "const unknownValue: unknown"),
+        ("getItems", "function getItems(): string[]"),
+    ]);
+
+    let pass = vec![
+        "`${name}`",
+        "`${count} ${flag} ${kind}`",
+        "`${untyped}`",
+        "`${user}`",
+        "`${items.length}`",
+        "tag`${items}`",
+        "`${undeclared}`",
+    ];
+
+    let fail = vec![
+        "`${items}`",
+        "`${point}`",
+        "`${callback}`",
+        "`${promise}`",
+        "`${sym}`",
+        "`${unknownValue}`",
+        "`${name} ${getItems()}`",
+    ];
+
+    Tester::new(RestrictTemplateExpressions::NAME, RestrictTemplateExpressions::PLUGIN, pass, fail)
+        .with_type_info(type_info)
+        .test_and_snapshot();
+}
@@ -0,0 +1,367 @@
+use std::{
+    borrow::Cow,
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use oxc_diagnostics::{DiagnosticFix, LabeledSpan, OxcDiagnostic, Severity};
+
+use crate::ModuleRecord;
+
+/// Lint results of previous runs, persisted to a file.
+///
+/// Results of a file are reused when
+/// 1. the content of the file is unchanged,
+/// 2. the configuration resolved for the file is unchanged,
+/// 3. the content of all modules it transitively depends on is unchanged. Dependencies are only
+///    recorded when cross-module rules (the import plugin) are enabled, as only these rules read
+///    other modules via [`ModuleRecord::loaded_modules`].
+///
+/// The whole cache is invalidated when the version of the linter changes.
+pub struct LintCache {
+    /// Path of the cache file.
+    location: PathBuf,
+    version: String,
+    entries: Mutex<FxHashMap<PathBuf, CacheEntry>>,
+    /// Paths of modules whose entries are outdated, computed by [`LintCache::invalidate`].
+    /// Paths are removed when their entries are updated.
+    stale: Mutex<FxHashSet<PathBuf>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    entries: FxHashMap<PathBuf, CacheEntry>,
+}
+
+/// A linted file, or a module which a linted file depends on.
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the source text.
+    hash: u64,
+    /// Paths of the modules loaded by this module.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<PathBuf>,
+    /// `None` if this module is only a dependency of linted files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<CachedResult>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedResult {
+    config_hash: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<CachedLabel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    severity: CachedSeverity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<CachedFix>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+    primary: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedFix {
+    content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    start: u32,
+    end: u32,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+impl LintCache {
+    /// Load the cache from `location`. The cache starts empty if the file does not exist, can
+    /// not be parsed, or was written by a different `version`.
+    pub fn new<P: Into<PathBuf>, S: Into<String>>(location: P, version: S) -> Self {
+        let location = location.into();
+        let version = version.into();
+        let entries = fs::read(&location)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|file| file.version == version)
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            location,
+            version,
+            entries: Mutex::new(entries),
+            stale: Mutex::new(FxHashSet::default()),
+        }
+    }
+
+    /// Path of the cache file.
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Write the cache to its location.
+    ///
+    /// # Errors
+    /// If the cache file can not be written.
+    ///
+    /// # Panics
+    /// If the lock is poisoned.
+    pub fn save(&self) -> io::Result<()> {
+        let entries = std::mem::take(&mut *self.entries.lock().unwrap());
+        let file = CacheFile { version: self.version.clone(), entries };
+        let json = serde_json::to_vec(&file).map_err(io::Error::other)?;
+        fs::write(&self.location, json)
+    }
+
+    /// Find modules which changed since the cache was written, and mark them and all modules
+    /// depending on them as stale. Entries of modules which no longer exist are removed.
+    ///
+    /// `hash_file` returns the hash of the current content of a module, or `None` if it can
+    /// not be read.
+    pub(super) fn invalidate(&mut self, hash_file: impl Fn(&Path) -> Option<u64> + Sync) {
+        use rayon::prelude::*;
+
+        let entries = self.entries.get_mut().unwrap();
+        let current_hashes = entries
+            .par_iter()
+            .map(|(path, entry)| (path.clone(), entry.hash, hash_file(path)))
+            .collect::<Vec<_>>();
+
+        let mut changed = vec![];
+        for (path, hash, current_hash) in current_hashes {
+            match current_hash {
+                Some(current_hash) if current_hash == hash => {}
+                Some(_) => changed.push(path),
+                None => {
+                    entries.remove(&path);
+                    changed.push(path);
+                }
+            }
+        }
+
+        // Modules depending on a changed module are stale.
+        let mut dependents = FxHashMap::<&Path, Vec<&Path>>::default();
+        for (path, entry) in entries.iter() {
+            for dependency in &entry.dependencies {
+                dependents.entry(dependency).or_default().push(path);
+            }
+        }
+        let mut stale = FxHashSet::default();
+        let mut queue = changed.iter().map(PathBuf::as_path).collect::<Vec<_>>();
+        while let Some(path) = queue.pop() {
+            if stale.insert(path.to_path_buf()) {
+                if let Some(dependents) = dependents.get(path) {
+                    queue.extend(dependents.iter().copied());
+                }
+            }
+        }
+        *self.stale.get_mut().unwrap() = stale;
+    }
+
+    /// Get the cached diagnostics of `path`, if they can be reused.
+    ///
+    /// Must be called after [`LintCache::invalidate`].
+    pub(super) fn get(&self, path: &Path, config_hash: u64) -> Option<Vec<OxcDiagnostic>> {
+        if self.stale.lock().unwrap().contains(path) {
+            return None;
+        }
+        let entries = self.entries.lock().unwrap();
+        let result = entries.get(path)?.result.as_ref()?;
+        if result.config_hash != config_hash {
+            return None;
+        }
+        Some(result.diagnostics.iter().map(CachedDiagnostic::to_diagnostic).collect())
+    }
+
+    /// Store the diagnostics of a linted file.
+    ///
+    /// `diagnostics` are paired with the offset of the source section they were reported in.
+    pub(super) fn insert(
+        &self,
+        path: &Path,
+        source_text: &str,
+        config_hash: u64,
+        module_records: &[Arc<ModuleRecord>],
+        diagnostics: &[(u32, OxcDiagnostic)],
+        hash_file: impl Fn(&Path) -> Option<u64>,
+    ) {
+        let result = CachedResult {
+            config_hash,
+            diagnostics: diagnostics
+                .iter()
+                .map(|(section_start, diagnostic)| {
+                    CachedDiagnostic::from_diagnostic(diagnostic, *section_start as usize)
+                })
+                .collect(),
+        };
+        let entry = CacheEntry {
+            hash: hash_source(source_text),
+            dependencies: dependencies(module_records.iter().map(AsRef::as_ref)),
+            result: Some(result),
+        };
+
+        // Record all transitive dependencies, so that changes to them invalidate this entry.
+        let mut queue = module_records
+            .iter()
+            .flat_map(|record| {
+                record.loaded_modules.read().unwrap().values().cloned().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut entries = self.entries.lock().unwrap();
+        let mut stale = self.stale.lock().unwrap();
+        entries.insert(path.to_path_buf(), entry);
+        stale.remove(path);
+        while let Some(record) = queue.pop() {
+            let dependency_path = record.resolved_absolute_path.as_path();
+            if entries.contains_key(dependency_path) && !stale.contains(dependency_path) {
+                continue;
+            }
+            let Some(hash) = hash_file(dependency_path) else {
+                continue;
+            };
+            // The lint result of an outdated entry is dropped, it is added back when the module
+            // is linted.
+            let dependencies = dependencies([record.as_ref()]);
+            entries.insert(
+                dependency_path.to_path_buf(),
+                CacheEntry { hash, dependencies, result: None },
+            );
+            stale.remove(dependency_path);
+            queue.extend(record.loaded_modules.read().unwrap().values().cloned());
+        }
+    }
+}
+
+/// Hash of the content of a module.
+pub(super) fn hash_source(source_text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn dependencies<'a>(module_records: impl IntoIterator<Item = &'a ModuleRecord>) -> Vec<PathBuf> {
+    let mut dependencies = module_records
+        .into_iter()
+        .flat_map(|record| {
+            record
+                .loaded_modules
+                .read()
+                .unwrap()
+                .values()
+                .map(|dependency| dependency.resolved_absolute_path.clone())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    dependencies.sort_unstable();
+    dependencies.dedup();
+    dependencies
+}
+
+impl CachedDiagnostic {
+    #[expect(clippy::cast_possible_truncation)] // source files are limited to 4 GiB
+    fn from_diagnostic(diagnostic: &OxcDiagnostic, section_start: usize) -> Self {
+        let labels = diagnostic
+            .labels
+            .iter()
+            .flatten()
+            .map(|label| CachedLabel {
+                label: label.label().map(ToString::to_string),
+                offset: label.offset() + section_start,
+                len: label.len(),
+                primary: label.primary(),
+            })
+            .collect();
+        Self {
+            message: diagnostic.message.to_string(),
+            labels,
+            help: diagnostic.help.as_ref().map(ToString::to_string),
+            severity: match diagnostic.severity {
+                Severity::Error => CachedSeverity::Error,
+                Severity::Warning => CachedSeverity::Warning,
+                Severity::Advice => CachedSeverity::Advice,
+            },
+            scope: diagnostic.code.scope.as_ref().map(ToString::to_string),
+            number: diagnostic.code.number.as_ref().map(ToString::to_string),
+            url: diagnostic.url.as_ref().map(ToString::to_string),
+            fixes: diagnostic
+                .fixes
+                .iter()
+                .map(|fix| CachedFix {
+                    content: fix.content.to_string(),
+                    message: fix.message.as_ref().map(ToString::to_string),
+                    start: fix.start + section_start as u32,
+                    end: fix.end + section_start as u32,
+                })
+                .collect(),
+        }
+    }
+
+    fn to_diagnostic(&self) -> OxcDiagnostic {
+        let mut diagnostic =
+            OxcDiagnostic::error(self.message.clone()).with_severity(match self.severity {
+                CachedSeverity::Error => Severity::Error,
+                CachedSeverity::Warning => Severity::Warning,
+                CachedSeverity::Advice => Severity::Advice,
+            });
+        if !self.labels.is_empty() {
+            diagnostic = diagnostic.with_labels(self.labels.iter().map(|label| {
+                let span = (label.offset, label.len);
+                if label.primary {
+                    LabeledSpan::new_primary_with_span(label.label.clone(), span)
+                } else {
+                    LabeledSpan::new_with_span(label.label.clone(), span)
+                }
+            }));
+        }
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        if let Some(scope) = &self.scope {
+            diagnostic = diagnostic.with_error_code_scope(scope.clone());
+        }
+        if let Some(number) = &self.number {
+            diagnostic = diagnostic.with_error_code_num(number.clone());
+        }
+        if let Some(url) = &self.url {
+            diagnostic = diagnostic.with_url(Cow::Owned(url.clone()));
+        }
+        if !self.fixes.is_empty() {
+            diagnostic = diagnostic.with_fixes(self.fixes.iter().map(|fix| DiagnosticFix {
+                content: Cow::Owned(fix.content.clone()),
+                message: fix.message.clone().map(Cow::Owned),
+                start: fix.start,
+                end: fix.end,
+            }));
+        }
+        diagnostic
+    }
+}
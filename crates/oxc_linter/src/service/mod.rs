@@ -4,15 +4,16 @@ use std::{
     sync::Arc,
 };
 
+pub use cache::LintCache;
 use oxc_diagnostics::DiagnosticSender;
 use runtime::Runtime;
 pub use runtime::RuntimeFileSystem;
 
 use crate::Linter;
 
+mod cache;
 mod runtime;
 
-#[cfg(feature = "language_server")]
 pub mod offset_to_position;
 
 pub struct LintServiceOptions {
@@ -26,6 +27,12 @@ pub struct LintServiceOptions {
     tsconfig: Option<PathBuf>,
 
     cross_module: bool,
+
+    /// Results of previous runs, which are reused for unchanged files
+    cache: Option<LintCache>,
+
+    /// Report fixes with diagnostics instead of applying them
+    report_fixes: bool,
 }
 
 impl LintServiceOptions {
@@ -34,7 +41,14 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            cross_module: false,
+            cache: None,
+            report_fixes: false,
+        }
     }
 
     #[inline]
@@ -59,6 +73,24 @@ impl LintServiceOptions {
         self
     }
 
+    /// Reuse the results of unchanged files from `cache`, and store the results of linted files
+    /// in it. The cache is saved after linting.
+    #[inline]
+    #[must_use]
+    pub fn with_cache(mut self, cache: LintCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Attach the fixes of the linter's [fix kind](crate::LintOptions::fix) to the reported
+    /// diagnostics instead of applying them, for output formats which include fixes.
+    #[inline]
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
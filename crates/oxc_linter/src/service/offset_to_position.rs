@@ -1,6 +1,8 @@
 use oxc_data_structures::rope::{Rope, get_line_column};
+#[cfg(feature = "language_server")]
 use std::borrow::Cow;
 
+#[cfg(feature = "language_server")]
 #[derive(Clone, Debug)]
 pub struct SpanPositionMessage<'a> {
     /// A brief suggestion message describing the fix. Will be shown in
@@ -11,6 +13,7 @@ pub struct SpanPositionMessage<'a> {
     end: SpanPosition,
 }
 
+#[cfg(feature = "language_server")]
 impl<'a> SpanPositionMessage<'a> {
     pub fn new(start: SpanPosition, end: SpanPosition) -> Self {
         Self { start, end, message: None }
@@ -22,7 +22,10 @@ use oxc_resolver::Resolver;
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
-use super::LintServiceOptions;
+use super::{
+    LintServiceOptions,
+    cache::{LintCache, hash_source},
+};
 use crate::{
     Fixer, Linter, Message,
     fixer::PossibleFixes,
@@ -44,6 +47,10 @@ pub struct Runtime<'l> {
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
     allocator_pool: AllocatorPool,
+
+    cache: Option<LintCache>,
+
+    report_fixes: bool,
 }
 
 /// Output of `Runtime::process_path`
@@ -192,6 +199,8 @@ impl<'l> Runtime<'l> {
             linter,
             resolver,
             file_system: Box::new(OsFileSystem),
+            cache: options.cache,
+            report_fixes: options.report_fixes,
         }
     }
 
@@ -494,6 +503,15 @@ impl<'l> Runtime<'l> {
     // we assume that the fix offset will not exceed 2GB in either direction
     #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub(super) fn run(&mut self, tx_error: &DiagnosticSender) {
+        // Applied fixes are not cached, and type information depends on files outside of the
+        // module graph. Reported fixes are cached with the diagnostics.
+        if (self.linter.options().fix.is_some() && !self.report_fixes)
+            || self.linter.has_type_info()
+        {
+            self.cache = None;
+        }
+        self.use_cached_results(tx_error);
+
         rayon::scope(|scope| {
             self.resolve_modules(scope, true, tx_error, |me, mut module_to_lint| {
                 module_to_lint.content.with_dependent_mut(|_owner, dep| {
@@ -508,6 +526,14 @@ impl<'l> Runtime<'l> {
 
                     let path = Path::new(&module_to_lint.path);
 
+                    // Module records and diagnostics to store in the cache.
+                    let module_records = module_to_lint
+                        .section_module_records
+                        .iter()
+                        .filter_map(|record_result| record_result.as_ref().ok().cloned())
+                        .collect::<Vec<_>>();
+                    let mut cached_diagnostics = vec![];
+
                     assert_eq!(
                         module_to_lint.section_module_records.len(),
                         dep.section_contents.len()
@@ -530,7 +556,7 @@ impl<'l> Runtime<'l> {
                         };
 
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
+                        if me.linter.options().fix.is_some() && !me.report_fixes {
                             let fix_result = Fixer::new(source_text, messages).fix();
                             if fix_result.fixed {
                                 // write to file, replacing only the changed part
@@ -549,7 +575,21 @@ impl<'l> Runtime<'l> {
                         }
 
                         if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
+                            let errors: Vec<OxcDiagnostic> = if me.report_fixes {
+                                messages
+                                    .into_iter()
+                                    .map(Message::into_diagnostic_with_fixes)
+                                    .collect()
+                            } else {
+                                messages.into_iter().map(Into::into).collect()
+                            };
+                            if me.cache.is_some() {
+                                cached_diagnostics.extend(
+                                    errors
+                                        .iter()
+                                        .map(|error| (section.source.start, error.clone())),
+                                );
+                            }
                             let diagnostics = DiagnosticService::wrap_diagnostics(
                                 &me.cwd,
                                 path,
@@ -565,9 +605,72 @@ impl<'l> Runtime<'l> {
                     if let Cow::Owned(new_source_text) = new_source_text {
                         me.file_system.write_file(path, new_source_text).unwrap();
                     }
+
+                    if let Some(cache) = &me.cache {
+                        cache.insert(
+                            path,
+                            dep.source_text,
+                            me.linter.config_hash(path),
+                            &module_records,
+                            &cached_diagnostics,
+                            |path| me.hash_file(path),
+                        );
+                    }
                 });
             });
         });
+
+        if let Some(cache) = &self.cache {
+            if let Err(e) = cache.save() {
+                let location = cache.location();
+                let error = Error::new(OxcDiagnostic::error(format!(
+                    "Failed to write cache file {} with error \"{e}\"",
+                    location.display()
+                )));
+                tx_error.send(Some((location.to_path_buf(), vec![error]))).unwrap();
+            }
+        }
+    }
+
+    /// Report the cached diagnostics of unchanged files, and remove them from the paths to lint.
+    fn use_cached_results(&mut self, tx_error: &DiagnosticSender) {
+        let Some(mut cache) = self.cache.take() else {
+            return;
+        };
+        cache.invalidate(|path| self.hash_file(path));
+
+        let cached_paths = self
+            .paths
+            .par_iter()
+            .filter_map(|path| {
+                let path_ref = Path::new(path);
+                let diagnostics = cache.get(path_ref, self.linter.config_hash(path_ref))?;
+                if !diagnostics.is_empty() {
+                    let allocator = self.allocator_pool.get();
+                    let source_text =
+                        self.file_system.read_to_arena_str(path_ref, &allocator).ok()?;
+                    let diagnostics = DiagnosticService::wrap_diagnostics(
+                        &self.cwd,
+                        path_ref,
+                        source_text,
+                        0,
+                        diagnostics,
+                    );
+                    tx_error.send(Some((path_ref.to_path_buf(), diagnostics))).unwrap();
+                }
+                Some(Arc::clone(path))
+            })
+            .collect::<FxHashSet<_>>();
+        self.paths.retain(|path| !cached_paths.contains(path));
+
+        self.cache = Some(cache);
+    }
+
+    /// Hash of the current content of the file at `path`.
+    fn hash_file(&self, path: &Path) -> Option<u64> {
+        let allocator = self.allocator_pool.get();
+        let source_text = self.file_system.read_to_arena_str(path, &allocator).ok()?;
+        Some(hash_source(source_text))
     }
 
     // clippy: the source field is checked and assumed to be less than 4GB, and
@@ -15,7 +15,7 @@ use serde_json::{Value, json};
 
 use crate::{
     AllowWarnDeny, ConfigStore, ConfigStoreBuilder, LintPlugins, LintService, LintServiceOptions,
-    Linter, Oxlintrc, RuleEnum,
+    Linter, Oxlintrc, RuleEnum, TypeInfoProvider,
     fixer::{FixKind, Fixer},
     options::LintOptions,
     rules::RULES,
@@ -233,6 +233,7 @@ pub struct Tester {
     snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     plugins: LintPlugins,
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
 }
 
 impl Tester {
@@ -260,6 +261,7 @@ impl Tester {
             snapshot_suffix: None,
             current_working_directory,
             plugins: LintPlugins::default(),
+            type_info: None,
         }
     }
 
@@ -338,6 +340,12 @@ impl Tester {
         self
     }
 
+    /// Provide type information to type-aware rules.
+    pub fn with_type_info(mut self, provider: impl TypeInfoProvider + 'static) -> Self {
+        self.type_info = Some(Arc::new(provider));
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -517,6 +525,10 @@ impl Tester {
             ),
         )
         .with_fix(fix_kind.into());
+        let linter = match &self.type_info {
+            Some(provider) => linter.with_type_info_provider(Arc::clone(provider)),
+            None => linter,
+        };
 
         let path_to_lint = if self.plugins.has_import() {
             assert!(path.is_none(), "import plugin does not support path");
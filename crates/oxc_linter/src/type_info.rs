@@ -0,0 +1,651 @@
+//! Type information for type-aware lint rules.
+//!
+//! The linter does not implement a type checker. Instead, type information is requested from an
+//! external [`TypeInfoProvider`], such as a `tsserver` process ([`TsServer`]). Rules access it
+//! through [`LintContext::type_of`](crate::LintContext::type_of), which returns `None` when no
+//! provider is configured or the type of an expression is unknown.
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    io::{self, BufRead, BufReader, Write},
+    iter,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::{Child, ChildStdin, Command, Stdio},
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+use rustc_hash::{FxHashMap, FxHasher};
+use serde_json::{Value, json};
+
+use oxc_ast::ast::Expression;
+
+/// Provides type information for the files being linted.
+pub trait TypeInfoProvider: fmt::Debug + Send + Sync {
+    /// Returns the quick info of the symbol at `offset` in the file at `path`, in the format of
+    /// `tsserver`'s `displayString`, e.g. `const x: Promise<number>` or `function foo(): void`.
+    ///
+    /// # Errors
+    /// If the provider failed. A provider should return an error for a failure only once, and
+    /// `Ok(None)` afterwards, as each error is reported as a diagnostic.
+    fn quick_info(&self, path: &Path, source_text: &str, offset: u32)
+    -> io::Result<Option<String>>;
+}
+
+/// A [`TypeInfoProvider`] backed by a pool of `tsserver` processes, which communicate over stdio.
+///
+/// Each file is always sent to the same process, so files are opened once and files on
+/// different processes are queried in parallel.
+pub struct TsServer {
+    /// `None` once the process has failed.
+    servers: Vec<Mutex<Option<TsServerInner>>>,
+    failure_reported: AtomicBool,
+}
+
+struct TsServerInner {
+    process: Child,
+    stdin: ChildStdin,
+    /// Messages read from stdout by a separate thread, so requests can time out.
+    messages: Receiver<io::Result<Value>>,
+    seq: u64,
+    /// Hashes of the source text of opened files.
+    open_files: FxHashMap<PathBuf, u64>,
+}
+
+impl fmt::Debug for TsServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TsServer").finish_non_exhaustive()
+    }
+}
+
+impl TsServer {
+    /// Maximum number of processes. Each process loads the whole project, which takes a lot of
+    /// memory in large projects.
+    const MAX_SERVERS: usize = 4;
+
+    /// Maximum time to wait for a response. Loading a large project on the first request can
+    /// take a while.
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Spawn `tsserver` processes with `command`, e.g. `node_modules/.bin/tsserver`.
+    ///
+    /// # Errors
+    /// If a process cannot be spawned.
+    pub fn spawn<S: AsRef<std::ffi::OsStr>>(command: S) -> io::Result<Self> {
+        let count = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let servers = iter::repeat_with(|| TsServerInner::spawn(command.as_ref()))
+            .take(count.min(Self::MAX_SERVERS))
+            .map(|inner| inner.map(|inner| Mutex::new(Some(inner))))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { servers, failure_reported: AtomicBool::new(false) })
+    }
+}
+
+impl TypeInfoProvider for TsServer {
+    fn quick_info(
+        &self,
+        path: &Path,
+        source_text: &str,
+        offset: u32,
+    ) -> io::Result<Option<String>> {
+        let mut hasher = FxHasher::default();
+        path.hash(&mut hasher);
+        #[expect(clippy::cast_possible_truncation)]
+        let index = hasher.finish() as usize % self.servers.len();
+        let Ok(mut server) = self.servers[index].lock() else { return Ok(None) };
+        let Some(inner) = server.as_mut() else { return Ok(None) };
+
+        let (line, offset) = line_offset(source_text, offset);
+        let response = inner.open(path, source_text).and_then(|()| {
+            inner.request("quickinfo", &json!({ "file": path, "line": line, "offset": offset }))
+        });
+        match response {
+            Ok(response) => Ok(response.and_then(|response| {
+                response.get("body")?.get("displayString")?.as_str().map(ToString::to_string)
+            })),
+            Err(error) => {
+                // Drop the process, as it is no longer in sync with our requests.
+                *server = None;
+                if self.failure_reported.swap(true, Ordering::Relaxed) {
+                    Ok(None)
+                } else {
+                    Err(error)
+                }
+            }
+        }
+    }
+}
+
+impl TsServerInner {
+    fn spawn(command: &std::ffi::OsStr) -> io::Result<Self> {
+        let mut process = Command::new(command)
+            .arg("--disableAutomaticTypingAcquisition")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (process.stdin.take(), process.stdout.take()) else {
+            return Err(io::Error::other("tsserver stdio is not piped"));
+        };
+        let (sender, messages) = mpsc::channel();
+        thread::spawn(move || {
+            let mut stdout = BufReader::new(stdout);
+            loop {
+                let message = read_message(&mut stdout);
+                let failed = message.is_err();
+                if sender.send(message).is_err() || failed {
+                    break;
+                }
+            }
+        });
+        Ok(Self { process, stdin, messages, seq: 0, open_files: FxHashMap::default() })
+    }
+
+    /// Open `path` with `source_text`, or update it if it was opened with a different text.
+    fn open(&mut self, path: &Path, source_text: &str) -> io::Result<()> {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        let hash = hasher.finish();
+        match self.open_files.get(path) {
+            Some(h) if *h == hash => return Ok(()),
+            Some(_) => self.send("close", &json!({ "file": path })).map(|_| ())?,
+            None => {}
+        }
+        self.send("open", &json!({ "file": path, "fileContent": source_text }))?;
+        self.open_files.insert(path.to_path_buf(), hash);
+        Ok(())
+    }
+
+    fn send(&mut self, command: &str, arguments: &Value) -> io::Result<u64> {
+        self.seq += 1;
+        let request = json!({ "seq": self.seq, "type": "request", "command": command, "arguments": arguments });
+        writeln!(self.stdin, "{request}")?;
+        self.stdin.flush()?;
+        Ok(self.seq)
+    }
+
+    /// Send a request and wait for its response. Returns `None` if the request failed.
+    ///
+    /// # Errors
+    /// If the process exited, sent an invalid message or did not respond in time.
+    fn request(&mut self, command: &str, arguments: &Value) -> io::Result<Option<Value>> {
+        let seq = self.send(command, arguments)?;
+        loop {
+            let message = match self.messages.recv_timeout(TsServer::REQUEST_TIMEOUT) {
+                Ok(message) => message?,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("tsserver did not respond to `{command}` in time"),
+                    ));
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "tsserver exited"));
+                }
+            };
+            if message.get("type").and_then(Value::as_str) != Some("response")
+                || message.get("request_seq").and_then(Value::as_u64) != Some(seq)
+            {
+                // Skip events and responses of other requests.
+                continue;
+            }
+            let success = message.get("success").and_then(Value::as_bool) == Some(true);
+            return Ok(success.then_some(message));
+        }
+    }
+}
+
+impl Drop for TsServerInner {
+    fn drop(&mut self) {
+        let _ = self.send("exit", &json!({}));
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+/// Read a message framed by a `Content-Length` header.
+fn read_message(reader: &mut impl BufRead) -> io::Result<Value> {
+    let mut content_length = None;
+    let mut has_headers = false;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "tsserver exited"));
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            if has_headers {
+                break;
+            }
+            // Skip the line break after the previous message.
+            continue;
+        }
+        has_headers = true;
+        if let Some(length) = line.strip_prefix("Content-Length:") {
+            let length = length.trim().parse::<usize>().map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid Content-Length header")
+            })?;
+            content_length = Some(length);
+        }
+    }
+    let Some(content_length) = content_length else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "tsserver message has no Content-Length header",
+        ));
+    };
+    let mut buf = vec![0; content_length];
+    reader.read_exact(&mut buf)?;
+    serde_json::from_slice(&buf).map_err(io::Error::other)
+}
+
+/// Convert a byte offset into a 1-based line and 1-based UTF-16 column, as used by `tsserver`.
+fn line_offset(source_text: &str, offset: u32) -> (usize, usize) {
+    let before = &source_text[..(offset as usize).min(source_text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].encode_utf16().count() + 1;
+    (line, column)
+}
+
+/// A type in the format displayed by `tsserver`, e.g. `Promise<number> | undefined`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionType(String);
+
+impl ExpressionType {
+    pub fn new<S: Into<String>>(ty: S) -> Self {
+        Self(ty.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_any(&self) -> bool {
+        self.0 == "any"
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.0 == "unknown"
+    }
+
+    /// Members of a union type, or the type itself if it is not a union.
+    pub fn union_members(&self) -> Vec<&str> {
+        split_union(&self.0)
+    }
+
+    /// Whether any member of the type is a `Promise` or `PromiseLike`.
+    pub fn is_promise_like(&self) -> bool {
+        self.union_members().into_iter().any(|ty| classify(ty) == TypeKind::Promise)
+    }
+
+    /// Whether the type is known to never be a thenable, e.g. `number | undefined`.
+    ///
+    /// Returns `false` for `any`, `unknown`, and named types which may be thenable.
+    pub fn is_never_thenable(&self) -> bool {
+        self.union_members().into_iter().all(|ty| {
+            matches!(classify(ty), TypeKind::Primitive | TypeKind::Array | TypeKind::Function)
+        })
+    }
+
+    /// Whether the type may be an object which cannot be meaningfully converted to a string,
+    /// such as an object literal type, an array, a function or a `Promise`.
+    pub fn has_non_stringifiable_member(&self) -> bool {
+        self.union_members().into_iter().any(|ty| {
+            matches!(
+                classify(ty),
+                TypeKind::Object | TypeKind::Array | TypeKind::Function | TypeKind::Promise
+            ) || matches!(ty, "symbol" | "unique symbol" | "never" | "unknown")
+        })
+    }
+
+    /// The return type of a function type, e.g. `number` of `(a: string) => number`.
+    pub fn return_type(&self) -> Option<Self> {
+        return_type(&self.0).map(Self::new)
+    }
+}
+
+impl fmt::Display for ExpressionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Primitive,
+    Promise,
+    Array,
+    Function,
+    Object,
+    /// A named type, such as an interface or a type alias, which cannot be classified
+    /// without further type information.
+    Other,
+}
+
+fn classify(ty: &str) -> TypeKind {
+    if is_function_type(ty) {
+        return TypeKind::Function;
+    }
+    if ty.starts_with("Promise<") || ty.starts_with("PromiseLike<") || ty == "Promise" {
+        return TypeKind::Promise;
+    }
+    if ty.ends_with("[]")
+        || ty.starts_with('[')
+        || ty.starts_with("Array<")
+        || ty.starts_with("ReadonlyArray<")
+        || ty.starts_with("readonly ")
+    {
+        return TypeKind::Array;
+    }
+    if ty.starts_with('{') {
+        return TypeKind::Object;
+    }
+    let is_literal = ty.starts_with(['"', '\'', '`', '-'])
+        || ty.starts_with(|c: char| c.is_ascii_digit())
+        || matches!(ty, "true" | "false");
+    if is_literal
+        || matches!(
+            ty,
+            "string"
+                | "number"
+                | "bigint"
+                | "boolean"
+                | "symbol"
+                | "unique symbol"
+                | "null"
+                | "undefined"
+                | "void"
+                | "never"
+        )
+    {
+        return TypeKind::Primitive;
+    }
+    TypeKind::Other
+}
+
+/// Find the index of the bracket closing the one at `open`, skipping string literals and `=>`.
+fn find_closing(s: &str, open: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut i = open;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 1;
+            } else if b == q {
+                quote = None;
+            }
+        } else {
+            match b {
+                b'"' | b'\'' | b'`' => quote = Some(b),
+                b'=' if bytes.get(i + 1) == Some(&b'>') => i += 1,
+                b'(' | b'[' | b'{' | b'<' => depth += 1,
+                b')' | b']' | b'}' | b'>' => {
+                    depth = depth.checked_sub(1)?;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split `s` at `separator`s which are not nested in brackets or string literals.
+fn split_top_level<'s>(s: &'s str, separator: &str) -> Vec<&'s str> {
+    let bytes = s.as_bytes();
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'(' | b'[' | b'{' | b'<' | b'"' | b'\'' | b'`' => {
+                let end = if matches!(bytes[i], b'"' | b'\'' | b'`') {
+                    s[i + 1..].find(bytes[i] as char).map(|j| i + 1 + j)
+                } else {
+                    find_closing(s, i)
+                };
+                let Some(end) = end else { break };
+                i = end + 1;
+                continue;
+            }
+            b'=' if bytes.get(i + 1) == Some(&b'>') => {
+                i += 2;
+                continue;
+            }
+            _ if s[i..].starts_with(separator) => {
+                parts.push(s[start..i].trim());
+                i += separator.len();
+                start = i;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    parts.push(s[start..].trim());
+    parts
+}
+
+fn split_union(ty: &str) -> Vec<&str> {
+    let ty = ty.trim();
+    if is_function_type(ty) {
+        return vec![ty];
+    }
+    split_top_level(ty, " | ")
+}
+
+/// Skip the type parameters `<...>` at the start of `s`.
+fn skip_type_parameters(s: &str) -> Option<&str> {
+    if s.starts_with('<') { find_closing(s, 0).map(|end| &s[end + 1..]) } else { Some(s) }
+}
+
+fn is_function_type(ty: &str) -> bool {
+    return_type(ty).is_some()
+}
+
+/// The return type of a function type `<T>(params) => R`.
+fn return_type(ty: &str) -> Option<&str> {
+    let ty = skip_type_parameters(ty.trim())?;
+    if !ty.starts_with('(') {
+        return None;
+    }
+    let end = find_closing(ty, 0)?;
+    ty[end + 1..].strip_prefix(" => ").map(str::trim)
+}
+
+/// Parse the type of `name` out of a quick info display string.
+///
+/// * `const x: number` → `number`
+/// * `(method) Foo<T>.bar(a: string): void (+1 overload)` → `(a: string) => void`
+fn parse_quick_info(display: &str, name: &str) -> Option<ExpressionType> {
+    // Aliases are displayed as `(alias) const x: number\nimport x`.
+    let mut display = display.lines().next()?.trim();
+    // Strip the symbol kind, e.g. `(parameter) ` or `(method) `.
+    if display.starts_with('(') {
+        display = display[find_closing(display, 0)? + 1..].trim_start();
+    }
+    // Find `name` outside of brackets, followed by a type annotation or a signature.
+    let bytes = display.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if matches!(bytes[i], b'<' | b'(' | b'[' | b'{') {
+            i = find_closing(display, i)? + 1;
+            continue;
+        }
+        let preceded_by_ident = i > 0 && is_identifier_byte(bytes[i - 1]);
+        if !preceded_by_ident && display[i..].starts_with(name) {
+            let rest = &display[i + name.len()..];
+            let rest = rest.strip_prefix('?').unwrap_or(rest);
+            if let Some(ty) = rest.strip_prefix(": ") {
+                return Some(ExpressionType::new(ty.trim()));
+            }
+            if rest.starts_with(['(', '<']) {
+                return parse_signature(rest);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Convert a signature `<T>(params): R (+N overloads)` into a function type `<T>(params) => R`.
+fn parse_signature(signature: &str) -> Option<ExpressionType> {
+    let signature = match signature.rfind(" (+") {
+        Some(i) if signature.ends_with("overload)") || signature.ends_with("overloads)") => {
+            &signature[..i]
+        }
+        _ => signature,
+    };
+    let params = skip_type_parameters(signature)?;
+    let type_parameters = &signature[..signature.len() - params.len()];
+    let end = find_closing(params, 0)?;
+    let return_type = params[end + 1..].strip_prefix(": ")?;
+    Some(ExpressionType::new(format!(
+        "{type_parameters}{} => {}",
+        &params[..=end],
+        return_type.trim()
+    )))
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'$')
+}
+
+/// Get the type of `expr` from `provider`.
+///
+/// Only identifiers, static member expressions and calls of those are supported.
+///
+/// # Errors
+/// If the provider failed.
+pub fn type_of_expression(
+    provider: &dyn TypeInfoProvider,
+    path: &Path,
+    source_text: &str,
+    expr: &Expression,
+) -> io::Result<Option<ExpressionType>> {
+    let quick_info = |name: &str, offset: u32| {
+        let display = provider.quick_info(path, source_text, offset)?;
+        Ok(display.and_then(|display| parse_quick_info(&display, name)))
+    };
+    match expr.get_inner_expression() {
+        Expression::Identifier(ident) => quick_info(&ident.name, ident.span.start),
+        Expression::StaticMemberExpression(member) => {
+            quick_info(&member.property.name, member.property.span.start)
+        }
+        Expression::CallExpression(call) => {
+            let callee = type_of_expression(provider, path, source_text, &call.callee)?;
+            Ok(callee.and_then(|ty| ty.return_type()))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// A [`TypeInfoProvider`] for tests, which resolves identifiers by their name.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct StubTypeInfo {
+    quick_info: FxHashMap<String, String>,
+}
+
+#[cfg(test)]
+impl StubTypeInfo {
+    /// Create a provider from pairs of identifier names and quick info display strings.
+    pub fn new<'s>(quick_info: impl IntoIterator<Item = (&'s str, &'s str)>) -> Self {
+        let quick_info =
+            quick_info.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        Self { quick_info }
+    }
+}
+
+#[cfg(test)]
+impl TypeInfoProvider for StubTypeInfo {
+    fn quick_info(
+        &self,
+        _path: &Path,
+        source_text: &str,
+        offset: u32,
+    ) -> io::Result<Option<String>> {
+        let rest = &source_text[offset as usize..];
+        let end = rest.bytes().position(|b| !is_identifier_byte(b)).unwrap_or(rest.len());
+        Ok(self.quick_info.get(&rest[..end]).cloned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, ErrorKind};
+
+    use super::{ExpressionType, parse_quick_info, read_message};
+
+    #[test]
+    fn quick_info() {
+        let cases = [
+            ("const x: Promise<number>", "x", "Promise<number>"),
+            ("(parameter) x: string | undefined", "x", "string | undefined"),
+            ("(property) Foo.x?: number", "x", "number"),
+            ("function foo(a: number): Promise<void>", "foo", "(a: number) => Promise<void>"),
+            ("function foo<T>(a: T): T (+1 overload)", "foo", "<T>(a: T) => T"),
+            (
+                "(method) Promise<number>.then<TResult1 = number, TResult2 = never>(onfulfilled?: ((value: number) => TResult1) | null): Promise<TResult1 | TResult2>",
+                "then",
+                "<TResult1 = number, TResult2 = never>(onfulfilled?: ((value: number) => TResult1) | null) => Promise<TResult1 | TResult2>",
+            ),
+            ("(alias) const foo: () => void\nimport foo", "foo", "() => void"),
+        ];
+        for (display, name, expected) in cases {
+            assert_eq!(
+                parse_quick_info(display, name),
+                Some(ExpressionType::new(expected)),
+                "{display}"
+            );
+        }
+    }
+
+    #[test]
+    fn classify_types() {
+        let ty = ExpressionType::new("Promise<number> | undefined");
+        assert!(ty.is_promise_like());
+        assert!(!ty.is_never_thenable());
+        assert_eq!(ty.union_members(), vec!["Promise<number>", "undefined"]);
+
+        let ty = ExpressionType::new("() => Promise<void> | undefined");
+        assert!(!ty.is_promise_like());
+        assert!(ty.is_never_thenable());
+        assert_eq!(ty.return_type(), Some(ExpressionType::new("Promise<void> | undefined")));
+
+        let ty = ExpressionType::new("\"a | b\" | 1 | null");
+        assert!(ty.is_never_thenable());
+        assert!(!ty.has_non_stringifiable_member());
+
+        assert!(!ExpressionType::new("Foo").is_never_thenable());
+        assert!(ExpressionType::new("{ a: number; }").has_non_stringifiable_member());
+        assert!(ExpressionType::new("string[]").has_non_stringifiable_member());
+    }
+
+    #[test]
+    fn read_messages() {
+        let mut reader =
+            Cursor::new("Content-Length: 10\r\n\r\n{\"seq\":1}\n\nContent-Length: 2\r\n\r\n{}");
+        assert_eq!(read_message(&mut reader).unwrap()["seq"], 1);
+        assert!(read_message(&mut reader).unwrap().is_object());
+        assert_eq!(read_message(&mut reader).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+        let mut reader = Cursor::new("Content-Type: application/json\r\n\r\n{}");
+        assert_eq!(read_message(&mut reader).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
@@ -3,7 +3,7 @@ use std::iter::{self, repeat_with};
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use keep_names::collect_name_symbols;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use base54::base54;
 use oxc_allocator::{Allocator, Vec};
@@ -12,13 +12,18 @@ use oxc_data_structures::inline_string::InlineString;
 use oxc_index::Idx;
 use oxc_semantic::{AstNodes, Scoping, Semantic, SemanticBuilder, SymbolId};
 use oxc_span::Atom;
+use oxc_syntax::{identifier::is_identifier_name, keyword::is_reserved_keyword};
 
 pub(crate) mod base54;
 mod keep_names;
+mod mangle_props;
+mod name_cache;
 
 pub use keep_names::MangleOptionsKeepNames;
+pub use mangle_props::{MangleOptionsProps, PropertyMangler};
+pub use name_cache::NameCache;
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -33,6 +38,13 @@ pub struct MangleOptions {
     ///
     /// Uses base54 if false.
     pub debug: bool,
+
+    /// Mangle property names. See [`PropertyMangler`].
+    ///
+    /// Properties are renamed in the AST, unlike symbols which are renamed in the returned [`Scoping`].
+    ///
+    /// Default: `None`
+    pub mangle_props: Option<MangleOptionsProps>,
 }
 
 type Slot = usize;
@@ -75,7 +87,7 @@ impl TempAllocator<'_> {
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -175,8 +187,16 @@ impl TempAllocator<'_> {
 /// - slot 1: `top_level_b`, `foo_a`, `bar_a`
 /// - slot 2: `foo`
 /// - slot 3: `bar`
+///
+/// ### Name Cache
+///
+/// With [`Mangler::with_name_cache`], top level symbols which were mangled in a previous build are
+/// renamed to the same names again if possible, so a small change to the program does not rename
+/// most of its top level symbols. These names are excluded from slot assignment,
+/// and are not used for any other symbol.
 pub struct Mangler<'t> {
     options: MangleOptions,
+    name_cache: NameCache,
     /// An allocator meant to be used for temporary allocations during mangling.
     /// It can be cleared after mangling is done, to free up memory for subsequent
     /// files or other operations.
@@ -187,6 +207,7 @@ impl Default for Mangler<'_> {
     fn default() -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Owned(Allocator::default()),
         }
     }
@@ -245,6 +266,7 @@ impl<'t> Mangler<'t> {
     pub fn new_with_temp_allocator(temp_allocator: &'t Allocator) -> Self {
         Self {
             options: MangleOptions::default(),
+            name_cache: NameCache::default(),
             temp_allocator: TempAllocator::Borrowed(temp_allocator),
         }
     }
@@ -255,6 +277,16 @@ impl<'t> Mangler<'t> {
         self
     }
 
+    /// Reuse the names of top level symbols from a previous build.
+    ///
+    /// Only used if [`MangleOptions::top_level`] is enabled.
+    /// Get the updated name cache from [`Mangler::build_with_semantic`].
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
     /// Mangles the program. The resulting SymbolTable contains the mangled symbols - `program` is not modified.
     /// Pass the symbol table to oxc_codegen to generate the mangled code.
     #[must_use]
@@ -265,14 +297,23 @@ impl<'t> Mangler<'t> {
         semantic.into_scoping()
     }
 
+    /// Mangles the program, and renames the symbols in `semantic`.
+    ///
+    /// Returns the name cache, which includes the names of the given name cache, and the names
+    /// assigned to top level symbols in this program.
+    ///
     /// # Panics
     ///
     /// Panics if the child_ids does not exist in scope_tree.
-    pub fn build_with_semantic(self, semantic: &mut Semantic<'_>, program: &Program<'_>) {
+    pub fn build_with_semantic(
+        self,
+        semantic: &mut Semantic<'_>,
+        program: &Program<'_>,
+    ) -> NameCache {
         if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+            self.build_with_semantic_impl(semantic, program, debug_name)
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
+            self.build_with_semantic_impl(semantic, program, base54)
         }
     }
 
@@ -281,14 +322,14 @@ impl<'t> Mangler<'t> {
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
         generate_name: G,
-    ) {
+    ) -> NameCache {
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
 
         assert!(scoping.has_scope_child_ids(), "child_id needs to be generated");
 
         // TODO: implement opt-out of direct-eval in a branch of scopes.
         if scoping.root_scope_flags().contains_direct_eval() {
-            return;
+            return self.name_cache;
         }
 
         let (exported_names, exported_symbols) = if self.options.top_level {
@@ -299,6 +340,44 @@ impl<'t> Mangler<'t> {
         let (keep_name_names, keep_name_symbols) =
             Mangler::collect_keep_name_symbols(self.options.keep_names, scoping, ast_nodes);
 
+        let root_unresolved_references = scoping.root_unresolved_references();
+        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
+
+        // Top level symbols which are mangled, with their original names.
+        let top_level_symbols = if self.options.top_level {
+            root_bindings
+                .iter()
+                .filter(|(name, symbol_id)| {
+                    !exported_symbols.contains(symbol_id)
+                        && !keep_name_symbols.contains(symbol_id)
+                        && !is_special_name(name)
+                })
+                .map(|(name, symbol_id)| (*symbol_id, (*name).to_string()))
+                .collect::<std::vec::Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        // Top level symbols which are renamed to their names in the name cache.
+        let mut cached_symbols = FxHashMap::<SymbolId, &str>::default();
+        let mut cached_names = FxHashSet::<&str>::default();
+        for (symbol_id, name) in &top_level_symbols {
+            let Some(cached) = self.name_cache.vars.get(name.as_str()) else { continue };
+            let n = cached.as_str();
+            // Do not use names which are invalid, or which are used by symbols that are not renamed.
+            let is_invalid = !is_identifier_name(n) || is_reserved_keyword(n) || is_keyword(n);
+            if is_invalid
+                || is_special_name(n)
+                || root_unresolved_references.contains_key(n)
+                || exported_names.contains(n)
+                || keep_name_names.contains(n)
+                || !cached_names.insert(n)
+            {
+                continue;
+            }
+            cached_symbols.insert(*symbol_id, n);
+        }
+
         let temp_allocator = self.temp_allocator.as_ref();
 
         // All symbols with their assigned slots. Keyed by symbol id.
@@ -319,9 +398,9 @@ impl<'t> Mangler<'t> {
 
             // Sort `bindings` in declaration order.
             tmp_bindings.clear();
-            tmp_bindings.extend(
-                bindings.values().copied().filter(|binding| !keep_name_symbols.contains(binding)),
-            );
+            tmp_bindings.extend(bindings.values().copied().filter(|binding| {
+                !keep_name_symbols.contains(binding) && !cached_symbols.contains_key(binding)
+            }));
             tmp_bindings.sort_unstable();
             if tmp_bindings.is_empty() {
                 continue;
@@ -381,13 +460,11 @@ impl<'t> Mangler<'t> {
             scoping,
             &exported_symbols,
             &keep_name_symbols,
+            &cached_symbols,
             total_number_of_slots,
             &slots,
         );
 
-        let root_unresolved_references = scoping.root_unresolved_references();
-        let root_bindings = scoping.get_bindings(scoping.root_scope_id());
-
         let mut reserved_names = Vec::with_capacity_in(total_number_of_slots, temp_allocator);
 
         let mut count = 0;
@@ -404,6 +481,7 @@ impl<'t> Mangler<'t> {
                         && (!self.options.top_level || exported_names.contains(n)))
                         // TODO: only skip the names that are kept in the current scope
                         && !keep_name_names.contains(n)
+                        && !cached_names.contains(n)
                 {
                     break name;
                 }
@@ -460,6 +538,18 @@ impl<'t> Mangler<'t> {
                 }
             }
         }
+
+        for (&symbol_id, name) in &cached_symbols {
+            scoping.set_symbol_name(symbol_id, name);
+        }
+
+        let mut name_cache = self.name_cache;
+        name_cache.vars.extend(
+            top_level_symbols
+                .into_iter()
+                .map(|(symbol_id, name)| (name, scoping.symbol_name(symbol_id).to_string())),
+        );
+        name_cache
     }
 
     fn tally_slot_frequencies<'a>(
@@ -467,6 +557,7 @@ impl<'t> Mangler<'t> {
         scoping: &Scoping,
         exported_symbols: &FxHashSet<SymbolId>,
         keep_name_symbols: &FxHashSet<SymbolId>,
+        cached_symbols: &FxHashMap<SymbolId, &str>,
         total_number_of_slots: usize,
         slots: &[Slot],
     ) -> Vec<'a, SlotFrequency<'a>> {
@@ -487,7 +578,7 @@ impl<'t> Mangler<'t> {
             if is_special_name(scoping.symbol_name(symbol_id)) {
                 continue;
             }
-            if keep_name_symbols.contains(&symbol_id) {
+            if keep_name_symbols.contains(&symbol_id) || cached_symbols.contains_key(&symbol_id) {
                 continue;
             }
             let index = slot;
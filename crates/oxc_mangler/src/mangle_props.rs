@@ -0,0 +1,290 @@
+use std::cmp::Reverse;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::identifier::is_identifier_name;
+
+use crate::{NameCache, base54::base54};
+
+/// Property names which are used implicitly by the language, and are never mangled.
+const IMPLICIT_NAMES: [&str; 16] = [
+    "__proto__",
+    "arguments",
+    "callee",
+    "caller",
+    "configurable",
+    "constructor",
+    "done",
+    "enumerable",
+    "length",
+    "next",
+    "prototype",
+    "then",
+    "toJSON",
+    "toString",
+    "value",
+    "valueOf",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct MangleOptionsProps {
+    /// Only mangle property names matching this regular expression.
+    ///
+    /// If `None`, all property names are mangled, except for reserved ones.
+    /// This is only safe if the program does not access properties of objects it does not create,
+    /// e.g. `console.log` or `document.body`.
+    ///
+    /// Default: `None`
+    pub regex: Option<Regex>,
+
+    /// Property names which are never mangled.
+    pub reserved: Vec<String>,
+
+    /// Do not mangle quoted property names, such as `"foo"` in `{ "foo": 1 }` or `obj["foo"]`.
+    /// Unquoted uses of these names are not mangled either.
+    ///
+    /// Default: `false`
+    pub keep_quoted: bool,
+}
+
+/// # Property Mangler
+///
+/// Renames properties of objects and classes to short names generated by [`base54`].
+///
+/// All uses of a property name are renamed to the same name, so renaming is only safe if every
+/// access to a mangled property is written in the program with the property name, not a computed
+/// value, and no code outside of the program accesses these properties.
+///
+/// Property names are found in:
+///
+/// * Keys of object literals, object patterns and class members: `{ foo: 1 }`, `{ foo } = obj`, `class { foo() {} }`.
+/// * Member expressions: `obj.foo`, `obj["foo"]`.
+/// * `in` expressions: `"foo" in obj`.
+///
+/// Names which are used more often get shorter names. Names in the name cache are mangled to
+/// the same names as before, if possible.
+pub struct PropertyMangler {
+    options: MangleOptionsProps,
+    debug: bool,
+    name_cache: NameCache,
+}
+
+impl PropertyMangler {
+    pub fn new(options: MangleOptionsProps) -> Self {
+        Self { options, debug: false, name_cache: NameCache::default() }
+    }
+
+    /// Use readable names (e.g. `_$foo$_` for `foo`) for debugging.
+    #[must_use]
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Reuse names from a previous build.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
+    }
+
+    /// Rename properties of `program`.
+    ///
+    /// Returns the name cache, which includes the names of the given name cache, and the names
+    /// assigned in this program.
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> NameCache {
+        let ast = AstBuilder::new(allocator);
+
+        // Collect the names to mangle and the names which must be kept.
+        let mut candidates = FxHashMap::<Atom<'a>, PropertyFrequency>::default();
+        let mut unmangleable = FxHashSet::<Atom<'a>>::default();
+        PropertyVisitor::new(ast, |name: &mut Atom<'a>, quoted| {
+            if self.is_mangleable(name, quoted) {
+                let order = candidates.len();
+                candidates.entry(*name).or_insert(PropertyFrequency { order, count: 0 }).count += 1;
+            } else {
+                unmangleable.insert(*name);
+            }
+        })
+        .visit_program(program);
+        candidates.retain(|name, _| !unmangleable.contains(name));
+
+        let mut name_cache = self.name_cache;
+        let kept_names = unmangleable.iter().map(Atom::as_str).collect::<FxHashSet<_>>();
+        let mut used_names = kept_names.clone();
+        used_names.extend(self.options.reserved.iter().map(String::as_str));
+        used_names.extend(name_cache.props.values().map(String::as_str));
+
+        let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+        candidates
+            .sort_unstable_by_key(|(_, frequency)| (Reverse(frequency.count), frequency.order));
+
+        let mut renames = FxHashMap::<Atom<'a>, Atom<'a>>::default();
+        let mut new_names = vec![];
+        let mut count = 0;
+        for (name, _) in candidates {
+            // Reuse the cached name, unless this program uses it as a property name which is kept.
+            if let Some(cached) = name_cache.props.get(name.as_str()) {
+                if !kept_names.contains(cached.as_str()) {
+                    renames.insert(name, ast.atom(cached));
+                    continue;
+                }
+            }
+            let new_name = if self.debug {
+                format!("_${name}$_")
+            } else {
+                loop {
+                    let new_name = base54(count);
+                    count += 1;
+                    if !used_names.contains(new_name.as_str()) {
+                        break new_name.as_str().to_string();
+                    }
+                }
+            };
+            renames.insert(name, ast.atom(&new_name));
+            new_names.push((name.to_string(), new_name));
+        }
+        name_cache.props.extend(new_names);
+
+        PropertyVisitor::new(ast, |name: &mut Atom<'a>, _quoted| {
+            if let Some(new_name) = renames.get(name) {
+                *name = *new_name;
+            }
+        })
+        .visit_program(program);
+
+        name_cache
+    }
+
+    fn is_mangleable(&self, name: &str, quoted: bool) -> bool {
+        if quoted && self.options.keep_quoted {
+            return false;
+        }
+        is_identifier_name(name)
+            && !IMPLICIT_NAMES.contains(&name)
+            && !self.options.reserved.iter().any(|reserved| reserved == name)
+            && self.options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    }
+}
+
+struct PropertyFrequency {
+    /// Order of the first use of the name.
+    order: usize,
+    count: usize,
+}
+
+/// Visitor which calls `on_property` with every property name in the program,
+/// and whether the name is quoted.
+struct PropertyVisitor<'a, F: FnMut(&mut Atom<'a>, bool)> {
+    ast: AstBuilder<'a>,
+    on_property: F,
+}
+
+impl<'a, F: FnMut(&mut Atom<'a>, bool)> PropertyVisitor<'a, F> {
+    fn new(ast: AstBuilder<'a>, on_property: F) -> Self {
+        Self { ast, on_property }
+    }
+
+    fn visit_key(&mut self, key: &mut PropertyKey<'a>, computed: bool) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                (self.on_property)(&mut ident.name, false);
+            }
+            PropertyKey::StringLiteral(lit) => self.visit_quoted(lit),
+            _ => {}
+        }
+    }
+
+    fn visit_quoted(&mut self, lit: &mut StringLiteral<'a>) {
+        let name = lit.value;
+        (self.on_property)(&mut lit.value, true);
+        if lit.value != name {
+            lit.raw = None;
+        }
+    }
+}
+
+impl<'a, F: FnMut(&mut Atom<'a>, bool)> VisitMut<'a> for PropertyVisitor<'a, F> {
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        self.visit_key(&mut it.key, it.computed);
+        walk_mut::walk_object_property(self, it);
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        self.visit_key(&mut it.key, it.computed);
+        walk_mut::walk_binding_property(self, it);
+    }
+
+    fn visit_method_definition(&mut self, it: &mut MethodDefinition<'a>) {
+        self.visit_key(&mut it.key, it.computed);
+        walk_mut::walk_method_definition(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &mut PropertyDefinition<'a>) {
+        self.visit_key(&mut it.key, it.computed);
+        walk_mut::walk_property_definition(self, it);
+    }
+
+    fn visit_accessor_property(&mut self, it: &mut AccessorProperty<'a>) {
+        self.visit_key(&mut it.key, it.computed);
+        walk_mut::walk_accessor_property(self, it);
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        match it {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                let mut name = ident.binding.name;
+                (self.on_property)(&mut name, false);
+                if name != ident.binding.name {
+                    // `({ foo } = obj)` -> `({ a: foo } = obj)`
+                    let ident = ident.take_in(self.ast);
+                    let target =
+                        AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(ident.binding));
+                    let binding = match ident.init {
+                        Some(init) => {
+                            self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                                SPAN, target, init,
+                            )
+                        }
+                        None => AssignmentTargetMaybeDefault::from(target),
+                    };
+                    let key = self.ast.property_key_static_identifier(SPAN, name);
+                    *it = self.ast.assignment_target_property_assignment_target_property_property(
+                        ident.span, key, binding, false,
+                    );
+                }
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                let computed = property.computed;
+                self.visit_key(&mut property.name, computed);
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, it);
+    }
+
+    fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'a>) {
+        (self.on_property)(&mut it.property.name, false);
+        walk_mut::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut it.expression {
+            self.visit_quoted(lit);
+        }
+        walk_mut::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_binary_expression(&mut self, it: &mut BinaryExpression<'a>) {
+        if it.operator == BinaryOperator::In {
+            if let Expression::StringLiteral(lit) = &mut it.left {
+                self.visit_quoted(lit);
+            }
+        }
+        walk_mut::walk_binary_expression(self, it);
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Names assigned by the mangler in a previous build.
+///
+/// Pass the cache returned by one build to the next one, so that names which were mangled before
+/// are mangled to the same names again, which keeps the output stable across builds.
+///
+/// The JSON format is compatible with terser's `nameCache`:
+///
+/// ```json
+/// { "vars": { "props": { "$helper": "t" } }, "props": { "props": { "$_internal": "e" } } }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NameCache {
+    /// Mangled names of top level symbols, keyed by original symbol name.
+    pub vars: BTreeMap<String, String>,
+
+    /// Mangled property names, keyed by original property name.
+    pub props: BTreeMap<String, String>,
+}
+
+impl NameCache {
+    /// Parse a name cache from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not a valid name cache.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let cache: NameCacheJson = serde_json::from_str(json)?;
+        Ok(Self { vars: cache.vars.into_names(), props: cache.props.into_names() })
+    }
+
+    /// Serialize the name cache to JSON.
+    pub fn to_json(&self) -> String {
+        let cache = NameCacheJson {
+            vars: NameMapJson::from_names(&self.vars),
+            props: NameMapJson::from_names(&self.props),
+        };
+        // Maps with string keys always serialize successfully.
+        serde_json::to_string(&cache).unwrap_or_default()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct NameCacheJson {
+    #[serde(default)]
+    vars: NameMapJson,
+    #[serde(default)]
+    props: NameMapJson,
+}
+
+/// Names are prefixed with `$` in terser's format, so that they can't clash with
+/// properties of `Object.prototype` such as `__proto__` when the cache is used as a JS object.
+#[derive(Default, Serialize, Deserialize)]
+struct NameMapJson {
+    #[serde(default)]
+    props: BTreeMap<String, String>,
+}
+
+impl NameMapJson {
+    fn from_names(names: &BTreeMap<String, String>) -> Self {
+        let props = names.iter().map(|(name, mangled)| (format!("${name}"), mangled.clone()));
+        Self { props: props.collect() }
+    }
+
+    fn into_names(self) -> BTreeMap<String, String> {
+        self.props
+            .into_iter()
+            .map(|(name, mangled)| match name.strip_prefix('$') {
+                Some(name) => (name.to_string(), mangled),
+                None => (name, mangled),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NameCache;
+
+    #[test]
+    fn json_round_trip() {
+        let mut cache = NameCache::default();
+        cache.vars.insert("helper".to_string(), "n".to_string());
+        cache.props.insert("_foo".to_string(), "e".to_string());
+        cache.props.insert("__proto__".to_string(), "t".to_string());
+        let json = cache.to_json();
+        assert_eq!(
+            json,
+            r#"{"vars":{"props":{"$helper":"n"}},"props":{"props":{"$__proto__":"t","$_foo":"e"}}}"#
+        );
+        assert_eq!(NameCache::from_json(&json).unwrap(), cache);
+    }
+
+    #[test]
+    fn terser_format() {
+        let json =
+            r#"{ "vars": { "props": { "$foo": "o" } }, "props": { "props": { "$_bar": "n" } } }"#;
+        let cache = NameCache::from_json(json).unwrap();
+        assert_eq!(cache.vars.get("foo").map(String::as_str), Some("o"));
+        assert_eq!(cache.props.get("_bar").map(String::as_str), Some("n"));
+        assert!(NameCache::from_json("{}").unwrap().props.is_empty());
+        assert!(NameCache::from_json(r#"{ "props": [1] }"#).is_err());
+    }
+}
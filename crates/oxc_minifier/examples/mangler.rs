@@ -28,8 +28,9 @@ fn main() -> std::io::Result<()> {
         top_level: source_type.is_module(),
         keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
         debug,
+        ..MangleOptions::default()
     };
-    let printed = mangler(&source_text, source_type, options);
+    let printed = mangler(&source_text, source_type, options.clone());
     println!("{printed}");
 
     if twice {
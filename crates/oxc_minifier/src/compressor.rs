@@ -15,8 +15,8 @@ use crate::{
 };
 
 pub struct Compressor<'a> {
-    allocator: &'a Allocator,
-    options: Rc<CompressOptions>,
+    pub(crate) allocator: &'a Allocator,
+    pub(crate) options: Rc<CompressOptions>,
 }
 
 impl<'a> Compressor<'a> {
@@ -30,6 +30,11 @@ impl<'a> Compressor<'a> {
     }
 
     pub fn build_with_scoping(self, scoping: Scoping, program: &mut Program<'a>) {
+        self.compress(scoping, program);
+    }
+
+    /// Compress `program`, and return its updated [`Scoping`].
+    pub(crate) fn compress(&self, scoping: Scoping, program: &mut Program<'a>) -> Scoping {
         let state = MinifierState::new(Rc::clone(&self.options));
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options =
@@ -38,6 +43,7 @@ impl<'a> Compressor<'a> {
         PeepholeOptimizations::new(self.options.target, self.options.keep_names)
             .run_in_loop(program, &mut ctx);
         LatePeepholeOptimizations::new(self.options.target).build(program, &mut ctx);
+        ctx.into_scoping()
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>) {
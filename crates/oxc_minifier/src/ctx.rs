@@ -113,6 +113,30 @@ pub fn is_exact_int64(num: f64) -> bool {
     num.fract() == 0.0
 }
 
+pub fn value_to_expr<'a>(
+    ast: AstBuilder<'a>,
+    span: Span,
+    value: ConstantValue<'a>,
+) -> Expression<'a> {
+    match value {
+        ConstantValue::Number(n) => {
+            let number_base =
+                if is_exact_int64(n) { NumberBase::Decimal } else { NumberBase::Float };
+            ast.expression_numeric_literal(span, n, None, number_base)
+        }
+        ConstantValue::BigInt(bigint) => {
+            let value = format_atom!(ast.allocator, "{bigint}");
+            ast.expression_big_int_literal(span, value, None, BigintBase::Decimal)
+        }
+        ConstantValue::String(s) => {
+            ast.expression_string_literal(span, ast.atom_from_cow(&s), None)
+        }
+        ConstantValue::Boolean(b) => ast.expression_boolean_literal(span, b),
+        ConstantValue::Undefined => ast.void_0(span),
+        ConstantValue::Null => ast.expression_null_literal(span),
+    }
+}
+
 impl<'a> Ctx<'a, '_> {
     fn scoping(&self) -> &Scoping {
         self.0.scoping()
@@ -140,23 +164,7 @@ impl<'a> Ctx<'a, '_> {
     }
 
     pub fn value_to_expr(&self, span: Span, value: ConstantValue<'a>) -> Expression<'a> {
-        match value {
-            ConstantValue::Number(n) => {
-                let number_base =
-                    if is_exact_int64(n) { NumberBase::Decimal } else { NumberBase::Float };
-                self.ast.expression_numeric_literal(span, n, None, number_base)
-            }
-            ConstantValue::BigInt(bigint) => {
-                let value = format_atom!(self.ast.allocator, "{bigint}");
-                self.ast.expression_big_int_literal(span, value, None, BigintBase::Decimal)
-            }
-            ConstantValue::String(s) => {
-                self.ast.expression_string_literal(span, self.ast.atom_from_cow(&s), None)
-            }
-            ConstantValue::Boolean(b) => self.ast.expression_boolean_literal(span, b),
-            ConstantValue::Undefined => self.ast.void_0(span),
-            ConstantValue::Null => self.ast.expression_null_literal(span),
-        }
+        value_to_expr(self.ast, span, value)
     }
 
     pub fn is_expression_undefined(&self, expr: &Expression) -> bool {
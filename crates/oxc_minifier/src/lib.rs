@@ -5,6 +5,7 @@
 mod compressor;
 mod ctx;
 mod keep_var;
+mod modules;
 mod options;
 mod peephole;
 
@@ -13,12 +14,12 @@ mod tester;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, PropertyMangler};
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, MangleOptionsProps, NameCache};
 
-pub use crate::{compressor::Compressor, options::*};
+pub use crate::{compressor::Compressor, modules::CompressModule, options::*};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
@@ -34,18 +35,42 @@ impl Default for MinifierOptions {
 
 pub struct MinifierReturn {
     pub scoping: Option<Scoping>,
+
+    /// Names assigned by the mangler, to pass to [`Minifier::with_name_cache`] in the next build.
+    ///
+    /// `None` if the program is not mangled.
+    pub name_cache: Option<NameCache>,
 }
 
 pub struct Minifier {
     options: MinifierOptions,
+    name_cache: NameCache,
 }
 
 impl Minifier {
     pub fn new(options: MinifierOptions) -> Self {
-        Self { options }
+        Self { options, name_cache: NameCache::default() }
+    }
+
+    /// Reuse mangled names from a previous build.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = name_cache;
+        self
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
+        // Mangle properties before compressing, which can change how properties are accessed,
+        // e.g. `a["b"]` to `a.b`.
+        let mut name_cache = self.name_cache;
+        if let Some(options) = &self.options.mangle {
+            if let Some(props) = options.mangle_props.clone() {
+                name_cache = PropertyMangler::new(props)
+                    .with_debug(options.debug)
+                    .with_name_cache(name_cache)
+                    .build(allocator, program);
+            }
+        }
         let stats = if let Some(compress) = self.options.compress {
             let semantic = SemanticBuilder::new().build(program).semantic;
             let stats = semantic.stats();
@@ -55,15 +80,18 @@ impl Minifier {
         } else {
             Stats::default()
         };
-        let scoping = self.options.mangle.map(|options| {
-            let mut semantic = SemanticBuilder::new()
-                .with_stats(stats)
-                .with_scope_tree_child_ids(true)
-                .build(program)
-                .semantic;
-            Mangler::default().with_options(options).build_with_semantic(&mut semantic, program);
-            semantic.into_scoping()
-        });
-        MinifierReturn { scoping }
+        let Some(options) = self.options.mangle else {
+            return MinifierReturn { scoping: None, name_cache: None };
+        };
+        let mut semantic = SemanticBuilder::new()
+            .with_stats(stats)
+            .with_scope_tree_child_ids(true)
+            .build(program)
+            .semantic;
+        let name_cache = Mangler::default()
+            .with_options(options)
+            .with_name_cache(name_cache)
+            .build_with_semantic(&mut semantic, program);
+        MinifierReturn { scoping: Some(semantic.into_scoping()), name_cache: Some(name_cache) }
     }
 }
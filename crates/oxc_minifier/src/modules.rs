@@ -0,0 +1,720 @@
+//! Compression of a set of modules, see [`Compressor::build_modules`].
+
+use std::iter;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::{
+    constant_evaluation::{ConstantEvaluation, ConstantEvaluationCtx, ConstantValue},
+    is_global_reference::IsGlobalReference,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference as _, ReferenceId, Scoping, SemanticBuilder, SymbolId};
+use oxc_span::Span;
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ModuleRecord,
+};
+
+use crate::{CompressOptions, Compressor, ctx::value_to_expr};
+
+/// A module compressed by [`Compressor::build_modules`].
+pub struct CompressModule<'a, 'b> {
+    pub program: &'b mut Program<'a>,
+
+    /// Module record of [`program`](Self::program), as returned by the parser.
+    pub module_record: &'b ModuleRecord<'a>,
+
+    /// Index of the module each import request resolves to,
+    /// e.g. `"./feature.js" => 1` if `modules[1]` is imported by `import { X } from "./feature.js"`.
+    ///
+    /// Requests which are not in this map are external modules, which are left untouched.
+    pub resolved_requests: FxHashMap<Atom<'a>, usize>,
+
+    /// Keep all exports, e.g. for an entry point, whose exports are used outside of the compressed modules.
+    ///
+    /// Default `false`
+    pub keep_exports: bool,
+}
+
+impl<'a, 'b> CompressModule<'a, 'b> {
+    pub fn new(program: &'b mut Program<'a>, module_record: &'b ModuleRecord<'a>) -> Self {
+        Self {
+            program,
+            module_record,
+            resolved_requests: FxHashMap::default(),
+            keep_exports: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_resolved_requests(mut self, resolved_requests: FxHashMap<Atom<'a>, usize>) -> Self {
+        self.resolved_requests = resolved_requests;
+        self
+    }
+
+    #[must_use]
+    pub fn with_keep_exports(mut self, yes: bool) -> Self {
+        self.keep_exports = yes;
+        self
+    }
+
+    fn resolve(&self, request: &Atom<'a>) -> Option<usize> {
+        self.resolved_requests.get(request).copied()
+    }
+}
+
+impl<'a> Compressor<'a> {
+    /// Compress a set of modules together.
+    ///
+    /// In addition to compressing each module:
+    ///
+    /// * Exported constants with primitive values, e.g. `export const FEATURE_X = false`, are inlined
+    ///   into the modules which import them, including through re-exports.
+    ///   Import specifiers which are no longer referenced are removed.
+    /// * Exports which are not imported by any module are removed, unless the module has
+    ///   [`CompressModule::keep_exports`].
+    ///   Their declarations are removed if they are not referenced in the module,
+    ///   and have no side effects according to [`CompressOptions::treeshake`].
+    ///
+    /// Modules are compressed in dependency order, so constants propagate through chains of modules.
+    /// Only static imports, re-exports and dynamic imports of string literals are taken into account.
+    /// All exports are kept if a module contains a dynamic import of any other expression.
+    pub fn build_modules(self, modules: &mut [CompressModule<'a, '_>]) {
+        let ast = AstBuilder::new(self.allocator);
+
+        let mut exported_constants: Vec<FxHashMap<Atom<'a>, ConstantValue<'a>>> =
+            iter::repeat_with(FxHashMap::default).take(modules.len()).collect();
+        let mut removed_imports: Vec<FxHashSet<Span>> =
+            iter::repeat_with(FxHashSet::default).take(modules.len()).collect();
+        let mut scopings: Vec<Option<Scoping>> =
+            iter::repeat_with(|| None).take(modules.len()).collect();
+
+        for i in dependency_order(modules) {
+            let module = &mut modules[i];
+
+            let scoping = SemanticBuilder::new().build(module.program).semantic.into_scoping();
+            removed_imports[i] =
+                inline_imported_constants(module, &scoping, &exported_constants, ast);
+
+            let scoping = SemanticBuilder::new().build(module.program).semantic.into_scoping();
+            let scoping = self.compress(scoping, module.program);
+
+            let ctx = ModuleCtx { ast, scoping: &scoping, options: &self.options };
+            exported_constants[i] = collect_exported_constants(module, &ctx, &exported_constants);
+            scopings[i] = Some(scoping);
+        }
+
+        let used_exports = UsedExports::new(modules, &removed_imports);
+        for (i, module) in modules.iter_mut().enumerate() {
+            if used_exports.all[i] {
+                continue;
+            }
+            let Some(scoping) = &scopings[i] else { continue };
+            let ctx = ModuleCtx { ast, scoping, options: &self.options };
+            remove_unused_exports(module.program, &used_exports.names[i], &ctx);
+        }
+    }
+}
+
+/// Module indices in post order of their imports, i.e. dependencies before their importers.
+/// Modules in an import cycle are ordered arbitrarily.
+fn dependency_order(modules: &[CompressModule<'_, '_>]) -> Vec<usize> {
+    let dependencies = modules
+        .iter()
+        .map(|module| {
+            let mut dependencies = module
+                .resolved_requests
+                .values()
+                .copied()
+                .filter(|&index| index < modules.len())
+                .collect::<Vec<_>>();
+            dependencies.sort_unstable();
+            dependencies.dedup();
+            dependencies
+        })
+        .collect::<Vec<_>>();
+
+    let mut visited = vec![false; modules.len()];
+    let mut order = Vec::with_capacity(modules.len());
+    let mut stack = vec![];
+    for root in 0..modules.len() {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        stack.push((root, 0));
+        while let Some((index, next)) = stack.last_mut() {
+            if let Some(&dependency) = dependencies[*index].get(*next) {
+                *next += 1;
+                if !visited[dependency] {
+                    visited[dependency] = true;
+                    stack.push((dependency, 0));
+                }
+            } else {
+                order.push(*index);
+                stack.pop();
+            }
+        }
+    }
+    order
+}
+
+/// Replace references to imported constants with their values.
+///
+/// Returns spans of the local bindings of removed import specifiers.
+fn inline_imported_constants<'a>(
+    module: &mut CompressModule<'a, '_>,
+    scoping: &Scoping,
+    exported_constants: &[FxHashMap<Atom<'a>, ConstantValue<'a>>],
+    ast: AstBuilder<'a>,
+) -> FxHashSet<Span> {
+    let mut values = FxHashMap::default();
+    for stmt in &module.program.body {
+        let Statement::ImportDeclaration(decl) = stmt else { continue };
+        let (Some(specifiers), Some(index)) =
+            (&decl.specifiers, module.resolve(&decl.source.value))
+        else {
+            continue;
+        };
+        if decl.import_kind.is_type() || decl.phase.is_some() {
+            continue;
+        }
+        for specifier in specifiers {
+            let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else {
+                continue;
+            };
+            if specifier.import_kind.is_type() {
+                continue;
+            }
+            let constants = exported_constants.get(index);
+            if let Some(value) = constants.and_then(|c| c.get(&specifier.imported.name())) {
+                values.insert(specifier.local.symbol_id(), value.clone());
+            }
+        }
+    }
+    if values.is_empty() {
+        return FxHashSet::default();
+    }
+
+    let mut inliner = ImportInliner { ast, scoping, values, inlined: FxHashMap::default() };
+    inliner.visit_program(module.program);
+    let ImportInliner { inlined, .. } = inliner;
+
+    // Remove specifiers whose references have all been inlined.
+    let mut removed = FxHashSet::default();
+    for stmt in &mut module.program.body {
+        let Statement::ImportDeclaration(decl) = stmt else { continue };
+        let Some(specifiers) = &mut decl.specifiers else { continue };
+        if specifiers.is_empty() {
+            continue;
+        }
+        specifiers.retain(|specifier| {
+            let local = specifier.local();
+            let symbol_id = local.symbol_id();
+            let is_inlined = inlined
+                .get(&symbol_id)
+                .is_some_and(|&count| count == scoping.get_resolved_reference_ids(symbol_id).len());
+            if is_inlined {
+                removed.insert(local.span);
+            }
+            !is_inlined
+        });
+        // Keep the import for the side effects of the imported module.
+        if specifiers.is_empty() {
+            decl.specifiers = None;
+        }
+    }
+    removed
+}
+
+struct ImportInliner<'a, 'b> {
+    ast: AstBuilder<'a>,
+    scoping: &'b Scoping,
+    values: FxHashMap<SymbolId, ConstantValue<'a>>,
+    /// Number of inlined references of each symbol.
+    inlined: FxHashMap<SymbolId, usize>,
+}
+
+impl<'a> VisitMut<'a> for ImportInliner<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let Some(symbol_id) = self.scoping.get_reference(ident.reference_id()).symbol_id()
+            else {
+                return;
+            };
+            if let Some(value) = self.values.get(&symbol_id) {
+                *self.inlined.entry(symbol_id).or_default() += 1;
+                *expr = value_to_expr(self.ast, ident.span, value.clone());
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        // `{ X }` -> `{ X: false }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+/// Collect the constants exported by a module, including re-exported constants of its dependencies.
+fn collect_exported_constants<'a>(
+    module: &CompressModule<'a, '_>,
+    ctx: &ModuleCtx<'a, '_>,
+    exported_constants: &[FxHashMap<Atom<'a>, ConstantValue<'a>>],
+) -> FxHashMap<Atom<'a>, ConstantValue<'a>> {
+    let resolve_export = |request: &Atom<'a>, name: &Atom<'a>| {
+        module
+            .resolve(request)
+            .and_then(|index| exported_constants.get(index))
+            .and_then(|constants| constants.get(name))
+            .cloned()
+    };
+
+    // Top-level `const` declarations with constant values
+    let mut local_constants = FxHashMap::default();
+    for stmt in &module.program.body {
+        let decl = match stmt {
+            Statement::VariableDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => decl,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if !decl.kind.is_const() {
+            continue;
+        }
+        for declarator in &decl.declarations {
+            let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+                (&declarator.id.kind, &declarator.init)
+            else {
+                continue;
+            };
+            if init.may_have_side_effects(ctx) {
+                continue;
+            }
+            if let Some(value) = init.evaluate_value(ctx) {
+                local_constants.insert(id.name, value);
+            }
+        }
+    }
+
+    let record = module.module_record;
+    let mut constants = FxHashMap::default();
+    for entry in &record.local_export_entries {
+        let (ExportExportName::Name(exported), ExportLocalName::Name(local)) =
+            (&entry.export_name, &entry.local_name)
+        else {
+            continue;
+        };
+        let value = local_constants.get(&local.name).cloned().or_else(|| {
+            // `import { X } from "./a"; export { X }`
+            record
+                .import_entries
+                .iter()
+                .find(|import| import.local_name.name == local.name)
+                .and_then(|import| match &import.import_name {
+                    ImportImportName::Name(name) => {
+                        resolve_export(&import.module_request.name, &name.name)
+                    }
+                    _ => None,
+                })
+        });
+        if let Some(value) = value {
+            constants.insert(exported.name, value);
+        }
+    }
+    // `export { X } from "./a"`
+    for entry in &record.indirect_export_entries {
+        let (Some(request), ExportImportName::Name(imported), ExportExportName::Name(exported)) =
+            (&entry.module_request, &entry.import_name, &entry.export_name)
+        else {
+            continue;
+        };
+        if let Some(value) = resolve_export(&request.name, &imported.name) {
+            constants.insert(exported.name, value);
+        }
+    }
+    // `export * from "./a"`
+    // Names exported by more than one module are ambiguous, and are not exported.
+    let mut star_constants = FxHashMap::default();
+    let mut ambiguous = FxHashSet::default();
+    for entry in &record.star_export_entries {
+        let Some(request) = &entry.module_request else { continue };
+        let Some(star) =
+            module.resolve(&request.name).and_then(|index| exported_constants.get(index))
+        else {
+            continue;
+        };
+        for (name, value) in star {
+            if name == "default" || record.exported_bindings.contains_key(name) {
+                continue;
+            }
+            if star_constants.insert(*name, value.clone()).is_some() {
+                ambiguous.insert(*name);
+            }
+        }
+    }
+    for (name, value) in star_constants {
+        if !ambiguous.contains(&name) {
+            constants.entry(name).or_insert(value);
+        }
+    }
+    constants
+}
+
+/// Exports of each module which are imported by other modules.
+struct UsedExports<'a> {
+    names: Vec<FxHashSet<Atom<'a>>>,
+    /// All exports are used, e.g. by `import * as ns from "./a"`.
+    all: Vec<bool>,
+}
+
+impl<'a> UsedExports<'a> {
+    fn new(modules: &[CompressModule<'a, '_>], removed_imports: &[FxHashSet<Span>]) -> Self {
+        let mut used = Self {
+            names: iter::repeat_with(FxHashSet::default).take(modules.len()).collect(),
+            all: modules.iter().map(|module| module.keep_exports).collect(),
+        };
+
+        for (module, removed_imports) in modules.iter().zip(removed_imports) {
+            let record = module.module_record;
+            for entry in &record.import_entries {
+                if removed_imports.contains(&entry.local_name.span) {
+                    continue;
+                }
+                let Some(index) = module.resolve(&entry.module_request.name) else { continue };
+                match &entry.import_name {
+                    ImportImportName::Name(name) => used.mark(index, name.name),
+                    ImportImportName::Default(_) => used.mark(index, Atom::from("default")),
+                    ImportImportName::NamespaceObject => used.mark_all(index),
+                };
+            }
+            for dynamic_import in &record.dynamic_imports {
+                let request = dynamic_import.module_request.source_text(module.program.source_text);
+                match string_literal_value(request) {
+                    Some(request) => {
+                        if let Some(index) = module.resolve(&Atom::from(request)) {
+                            used.mark_all(index);
+                        }
+                    }
+                    // Any module may be imported.
+                    None => used.all.fill(true),
+                }
+            }
+        }
+
+        // Propagate through re-exports until nothing changes.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (index, module) in modules.iter().enumerate() {
+                let record = module.module_record;
+                for entry in &record.indirect_export_entries {
+                    let Some(dependency) = entry
+                        .module_request
+                        .as_ref()
+                        .and_then(|request| module.resolve(&request.name))
+                    else {
+                        continue;
+                    };
+                    let is_used = match &entry.export_name {
+                        ExportExportName::Name(name) => used.is_used(index, &name.name),
+                        ExportExportName::Default(_) => used.is_used(index, &Atom::from("default")),
+                        ExportExportName::Null => false,
+                    };
+                    if !is_used {
+                        continue;
+                    }
+                    changed |= match &entry.import_name {
+                        ExportImportName::Name(name) => used.mark(dependency, name.name),
+                        ExportImportName::All | ExportImportName::AllButDefault => {
+                            used.mark_all(dependency)
+                        }
+                        ExportImportName::Null => false,
+                    };
+                }
+                for entry in &record.star_export_entries {
+                    let Some(dependency) = entry
+                        .module_request
+                        .as_ref()
+                        .and_then(|request| module.resolve(&request.name))
+                    else {
+                        continue;
+                    };
+                    if used.all[index] {
+                        changed |= used.mark_all(dependency);
+                    } else {
+                        for name in used.names[index].clone() {
+                            changed |= used.mark(dependency, name);
+                        }
+                    }
+                }
+            }
+        }
+        used
+    }
+
+    fn is_used(&self, index: usize, name: &Atom<'a>) -> bool {
+        self.all[index] || self.names[index].contains(name)
+    }
+
+    /// Returns `true` if `name` was not used before.
+    fn mark(&mut self, index: usize, name: Atom<'a>) -> bool {
+        self.names.get_mut(index).is_some_and(|names| names.insert(name))
+    }
+
+    /// Returns `true` if not all exports were used before.
+    fn mark_all(&mut self, index: usize) -> bool {
+        self.all.get_mut(index).is_some_and(|all| !std::mem::replace(all, true))
+    }
+}
+
+/// Value of a string literal in source text, e.g. `"./a"` -> `./a`.
+/// Returns `None` for any other expression, or a string literal containing escapes.
+fn string_literal_value(text: &str) -> Option<&str> {
+    let quote = text.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let value = text.strip_prefix(quote)?.strip_suffix(quote)?;
+    (!value.contains(['\\', quote])).then_some(value)
+}
+
+/// Remove exports which are not in `used`.
+///
+/// Declarations of removed exports are also removed if they are no longer referenced
+/// and have no side effects.
+fn remove_unused_exports<'a>(
+    program: &mut Program<'a>,
+    used: &FxHashSet<Atom<'a>>,
+    ctx: &ModuleCtx<'a, '_>,
+) {
+    let ast = ctx.ast;
+
+    // Symbols which are no longer exported, and references of them in removed `export { X }` specifiers
+    let mut unexported = FxHashSet::default();
+    let mut removed_references = FxHashSet::default();
+
+    for stmt in &mut program.body {
+        match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                let Some(declaration) = &export.declaration else {
+                    remove_unused_specifiers(
+                        stmt,
+                        used,
+                        ctx,
+                        &mut unexported,
+                        &mut removed_references,
+                    );
+                    continue;
+                };
+                if declaration.is_typescript_syntax() {
+                    continue;
+                }
+                let mut ids = vec![];
+                match declaration {
+                    Declaration::VariableDeclaration(decl) => {
+                        for declarator in &decl.declarations {
+                            ids.extend(declarator.id.get_binding_identifiers());
+                        }
+                    }
+                    declaration => ids.extend(declaration.id()),
+                }
+                if ids.iter().any(|id| used.contains(&id.name)) {
+                    continue;
+                }
+                unexported.extend(ids.iter().map(|id| id.symbol_id()));
+                if let Some(declaration) = export.declaration.take() {
+                    *stmt = Statement::from(declaration);
+                }
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                if used.contains("default") {
+                    continue;
+                }
+                let span = export.span;
+                *stmt = match export.declaration.take_in(ast) {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        unexported.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+                        if func.id.is_some() {
+                            Statement::FunctionDeclaration(func)
+                        } else {
+                            ast.statement_empty(span)
+                        }
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                        unexported.extend(class.id.as_ref().map(BindingIdentifier::symbol_id));
+                        if class.id.is_some() {
+                            Statement::ClassDeclaration(class)
+                        } else if class.may_have_side_effects(ctx) {
+                            // `export default class { static { foo() } }` -> `(class { static { foo() } })`
+                            class.r#type = ClassType::ClassExpression;
+                            ast.statement_expression(span, Expression::ClassExpression(class))
+                        } else {
+                            ast.statement_empty(span)
+                        }
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                        export.declaration =
+                            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl);
+                        continue;
+                    }
+                    declaration => {
+                        let expr = declaration.into_expression();
+                        if expr.may_have_side_effects(ctx) {
+                            ast.statement_expression(span, expr)
+                        } else {
+                            ast.statement_empty(span)
+                        }
+                    }
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let is_unused = |symbol_id: SymbolId| {
+        ctx.scoping
+            .get_resolved_reference_ids(symbol_id)
+            .iter()
+            .all(|reference_id| removed_references.contains(reference_id))
+    };
+    let is_removable = |id: Option<&BindingIdentifier>| {
+        id.is_some_and(|id| unexported.contains(&id.symbol_id()) && is_unused(id.symbol_id()))
+    };
+
+    for stmt in &mut program.body {
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                decl.declarations.retain(|declarator| {
+                    let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                        return true;
+                    };
+                    !is_removable(Some(id))
+                        || declarator
+                            .init
+                            .as_ref()
+                            .is_some_and(|init| init.may_have_side_effects(ctx))
+                });
+                if decl.declarations.is_empty() {
+                    *stmt = ast.statement_empty(decl.span);
+                }
+            }
+            Statement::FunctionDeclaration(func) if is_removable(func.id.as_ref()) => {
+                *stmt = ast.statement_empty(func.span);
+            }
+            Statement::ClassDeclaration(class)
+                if is_removable(class.id.as_ref()) && !class.may_have_side_effects(ctx) =>
+            {
+                *stmt = ast.statement_empty(class.span);
+            }
+            // `import { X } from "./a"; export { X }`
+            Statement::ImportDeclaration(decl) => {
+                let Some(specifiers) = &mut decl.specifiers else { continue };
+                if specifiers.is_empty() {
+                    continue;
+                }
+                specifiers.retain(|specifier| {
+                    let symbol_id = specifier.local().symbol_id();
+                    !(unexported.contains(&symbol_id) && is_unused(symbol_id))
+                });
+                // Keep the import for the side effects of the imported module.
+                if specifiers.is_empty() {
+                    decl.specifiers = None;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    program.body.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+}
+
+/// Remove unused specifiers of `export { X }` or `export { X } from "./a"`.
+fn remove_unused_specifiers<'a>(
+    stmt: &mut Statement<'a>,
+    used: &FxHashSet<Atom<'a>>,
+    ctx: &ModuleCtx<'a, '_>,
+    unexported: &mut FxHashSet<SymbolId>,
+    removed_references: &mut FxHashSet<ReferenceId>,
+) {
+    let Statement::ExportNamedDeclaration(export) = stmt else { return };
+    if export.specifiers.is_empty() || export.export_kind.is_type() {
+        return;
+    }
+    export.specifiers.retain(|specifier| {
+        let is_used = used.contains(&specifier.exported.name()) || specifier.export_kind.is_type();
+        if !is_used {
+            if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                let reference_id = ident.reference_id();
+                removed_references.insert(reference_id);
+                unexported.extend(ctx.scoping.get_reference(reference_id).symbol_id());
+            }
+        }
+        is_used
+    });
+    if export.specifiers.is_empty() {
+        *stmt = match export.source.take() {
+            // Keep the import for the side effects of the re-exported module.
+            Some(source) => Statement::ImportDeclaration(ctx.ast.alloc_import_declaration(
+                export.span,
+                None,
+                source,
+                None,
+                export.with_clause.take(),
+                ImportOrExportKind::Value,
+            )),
+            None => ctx.ast.statement_empty(export.span),
+        };
+    }
+}
+
+/// Context for analyzing a module outside of traversal.
+struct ModuleCtx<'a, 'b> {
+    ast: AstBuilder<'a>,
+    scoping: &'b Scoping,
+    options: &'b CompressOptions,
+}
+
+impl<'a> IsGlobalReference<'a> for ModuleCtx<'a, '_> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> Option<bool> {
+        Some(ident.is_global_reference(self.scoping))
+    }
+}
+
+impl<'a> MayHaveSideEffectsContext<'a> for ModuleCtx<'a, '_> {
+    fn annotations(&self) -> bool {
+        self.options.treeshake.annotations
+    }
+
+    fn manual_pure_functions(&self, callee: &Expression) -> bool {
+        if let Expression::Identifier(ident) = callee {
+            return self
+                .options
+                .treeshake
+                .manual_pure_functions
+                .iter()
+                .any(|name| ident.name.as_str() == name);
+        }
+        false
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        self.options.treeshake.property_read_side_effects
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        self.options.treeshake.unknown_global_side_effects
+    }
+}
+
+impl<'a> ConstantEvaluationCtx<'a> for ModuleCtx<'a, '_> {
+    fn ast(&self) -> AstBuilder<'a> {
+        self.ast
+    }
+}
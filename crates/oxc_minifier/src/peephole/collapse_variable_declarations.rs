@@ -116,7 +116,8 @@ mod test {
             // recognize local scope version of x
             test(
                 "var x = 1; function f() { let x = 1; let y = 2; x + y; }",
-                "var x = 1; function f() { let x = 1, y = 2; x + y } ",
+                // The single-use variables are inlined, and the unused result is removed.
+                "var x = 1; function f() {}",
             );
 
             // do not redeclare function parameters
@@ -128,12 +129,14 @@ mod test {
         fn test_arrow_function() {
             test(
                 "(() => { let x = 1; let y = 2; x + y; })()",
-                "(() => { let x = 1, y = 2; x + y; })()",
+                // The single-use variables are inlined, and the empty function call is removed.
+                "",
             );
 
             // do not redeclare function parameters
             // incompatible with strict mode
-            test_same("((x) => { x = 4; let y = 2; x + y; })()");
+            // `y` is inlined instead.
+            test("((x) => { x = 4; let y = 2; x + y; })()", "((x) => { x = 4, x + 2; })()");
         }
 
         #[test]
@@ -0,0 +1,806 @@
+use std::{cell::Cell, ops::ControlFlow};
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{TakeIn, Vec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_ecmascript::{constant_evaluation::IsLiteralValue, side_effects::MayHaveSideEffects};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::reference::ReferenceId;
+
+use crate::ctx::Ctx;
+
+use super::{PeepholeOptimizations, State};
+
+/// Inline single-use variables and functions.
+///
+/// Variables declared at the top level are not inlined, because top level bindings of scripts are
+/// observable by other scripts. Variables of block scopes are inlined, including blocks at the top
+/// level. Functions are only inlined within a function body.
+///
+/// <https://github.com/evanw/esbuild/blob/v0.24.2/internal/js_parser/js_parser.go#L9304>
+/// <https://github.com/terser/terser/blob/v5.39.0/lib/compress/inline.js>
+impl<'a> PeepholeOptimizations {
+    /// Substitute `let` and `const` declarations with a single use into the next statement.
+    ///
+    /// `function f() { const x = foo(); return x.y }` => `function f() { return foo().y }`
+    ///
+    /// The variable must be used exactly once, in the next statement, and the substituted
+    /// expression must be the first thing in the statement with side effects.
+    pub fn inline_single_use_variables(
+        &self,
+        stmt: &mut Statement<'a>,
+        result: &mut Vec<'a, Statement<'a>>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        loop {
+            let Some(Statement::VariableDeclaration(var_decl)) = result.last_mut() else {
+                return;
+            };
+            if !matches!(
+                var_decl.kind,
+                VariableDeclarationKind::Let | VariableDeclarationKind::Const
+            ) {
+                return;
+            }
+            let Some(declarator) = var_decl.declarations.last_mut() else { return };
+            let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+                (&declarator.id.kind, &mut declarator.init)
+            else {
+                return;
+            };
+            let symbol_id = id.symbol_id();
+            let Some(reference_id) = Self::get_single_read_reference(symbol_id, ctx) else {
+                return;
+            };
+            // `const f = function() {}` sets the name of the function.
+            if self.keep_names.function
+                && matches!(
+                    init,
+                    Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+                )
+                || self.keep_names.class && matches!(init, Expression::ClassExpression(_))
+            {
+                return;
+            }
+
+            let mut substitution = SingleUseSubstitution {
+                reference_id,
+                replacement_has_side_effects: init.may_have_side_effects(ctx),
+                replacement: Some(init.take_in(ctx.ast)),
+            };
+            let substituted = substitution.substitute_in_statement(stmt, ctx).is_break()
+                && substitution.replacement.is_none();
+            if !substituted {
+                // Put the initializer back.
+                if let Some(Statement::VariableDeclaration(var_decl)) = result.last_mut() {
+                    if let Some(declarator) = var_decl.declarations.last_mut() {
+                        declarator.init = substitution.replacement;
+                    }
+                }
+                return;
+            }
+
+            ctx.scoping_mut().delete_resolved_reference(symbol_id, reference_id);
+            var_decl.declarations.pop();
+            if var_decl.declarations.is_empty() {
+                result.pop();
+            }
+            state.changed = true;
+        }
+    }
+
+    /// Inline functions declared in a function body, which are called exactly once in the same function.
+    ///
+    /// `function f() { function g(a) { return a + 1 } return g(x) }` => `function f() { return x + 1 }`
+    ///
+    /// The function must be a plain function whose body is a single `return` statement, with an
+    /// expression which does not use `this`, `arguments` or nested functions, and each parameter
+    /// used at most once.
+    pub fn inline_single_use_functions(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.parent().is_function_body() {
+            return;
+        }
+        let scope_id = ctx.current_scope_id();
+        for i in 0..stmts.len() {
+            let Statement::FunctionDeclaration(func) = &stmts[i] else { continue };
+            let Some(candidate) = Self::get_inline_candidate(func, scope_id, ctx) else {
+                continue;
+            };
+            let mut finder = CallSiteFinder {
+                ctx,
+                candidate: &candidate,
+                scope_stack: vec![scope_id],
+                result: None,
+            };
+            for (j, stmt) in stmts.iter().enumerate() {
+                if i != j && finder.result.is_none() {
+                    finder.visit_statement(stmt);
+                }
+            }
+            if finder.result != Some(true) {
+                continue;
+            }
+
+            let Statement::FunctionDeclaration(func) = &mut stmts[i] else { unreachable!() };
+            let body = func.body.as_mut().and_then(|body| match body.statements.first_mut() {
+                Some(Statement::ReturnStatement(ret)) => ret.argument.take(),
+                _ => None,
+            });
+            stmts[i] = ctx.ast.statement_empty(stmts[i].span());
+            let mut inliner =
+                CallInliner { ctx, candidate: &candidate, body, arguments: vec![], inlined: false };
+            for stmt in stmts.iter_mut() {
+                if !inliner.inlined {
+                    inliner.visit_statement(stmt);
+                }
+            }
+            debug_assert!(inliner.inlined);
+            ctx.scoping_mut()
+                .delete_resolved_reference(candidate.symbol_id, candidate.reference_id);
+            state.changed = true;
+        }
+    }
+
+    /// The only reference of `symbol_id`, if it is a read reference.
+    fn get_single_read_reference(symbol_id: SymbolId, ctx: &Ctx<'a, '_>) -> Option<ReferenceId> {
+        let scoping = ctx.scoping();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        if scope_id == scoping.root_scope_id()
+            || scoping.scope_flags(scope_id).contains_direct_eval()
+        {
+            return None;
+        }
+        let [reference_id] = scoping.get_resolved_reference_ids(symbol_id).as_slice() else {
+            return None;
+        };
+        let reference = scoping.get_reference(*reference_id);
+        (reference.is_read() && !reference.is_write()).then_some(*reference_id)
+    }
+
+    fn get_inline_candidate(
+        func: &Function<'a>,
+        scope_id: ScopeId,
+        ctx: &Ctx<'a, '_>,
+    ) -> Option<InlineCandidate<'a>> {
+        if func.r#async || func.generator || func.params.rest.is_some() {
+            return None;
+        }
+        let symbol_id = func.id.as_ref()?.symbol_id();
+        if ctx.scoping().symbol_scope_id(symbol_id) != scope_id
+            || ctx.scoping().scope_flags(func.scope_id()).contains_direct_eval()
+        {
+            return None;
+        }
+        let [reference_id] = ctx.scoping().get_resolved_reference_ids(symbol_id).as_slice() else {
+            return None;
+        };
+        let body = func.body.as_ref()?;
+        if !body.directives.is_empty() {
+            return None;
+        }
+        let expr = match body.statements.as_slice() {
+            [] => None,
+            [Statement::ReturnStatement(ret)] => ret.argument.as_ref(),
+            _ => return None,
+        };
+
+        let mut params = FxHashMap::default();
+        for (index, param) in func.params.items.iter().enumerate() {
+            let BindingPatternKind::BindingIdentifier(id) = &param.pattern.kind else {
+                return None;
+            };
+            let param_symbol_id = id.symbol_id();
+            match ctx.scoping().get_resolved_reference_ids(param_symbol_id).as_slice() {
+                [] => {}
+                [reference_id] if !ctx.scoping().get_reference(*reference_id).is_write() => {}
+                _ => return None,
+            }
+            params.insert(param_symbol_id, index);
+        }
+
+        let mut checker =
+            InlineBodyChecker { ctx, params: &params, free_references: vec![], inlinable: true };
+        if let Some(expr) = expr {
+            checker.visit_expression(expr);
+        }
+        if !checker.inlinable {
+            return None;
+        }
+        Some(InlineCandidate {
+            symbol_id,
+            reference_id: *reference_id,
+            free_references: checker.free_references,
+            params,
+            has_side_effects: expr.is_some_and(|expr| expr.may_have_side_effects(ctx)),
+        })
+    }
+}
+
+/// Substitutes a single use of a variable in evaluation order, stopping at the first expression
+/// which can not be moved across.
+struct SingleUseSubstitution<'a> {
+    reference_id: ReferenceId,
+    replacement: Option<Expression<'a>>,
+    replacement_has_side_effects: bool,
+}
+
+impl<'a> SingleUseSubstitution<'a> {
+    /// Returns [ControlFlow::Break] if the substitution is done or not possible.
+    fn substitute_in_statement(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        ctx: &Ctx<'a, '_>,
+    ) -> ControlFlow<()> {
+        match stmt {
+            Statement::ExpressionStatement(stmt) => self.substitute(&mut stmt.expression, ctx),
+            Statement::ReturnStatement(stmt) => match &mut stmt.argument {
+                Some(argument) => self.substitute(argument, ctx),
+                None => ControlFlow::Break(()),
+            },
+            Statement::ThrowStatement(stmt) => self.substitute(&mut stmt.argument, ctx),
+            Statement::IfStatement(stmt) => self.substitute(&mut stmt.test, ctx),
+            Statement::SwitchStatement(stmt) => self.substitute(&mut stmt.discriminant, ctx),
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &mut decl.declarations {
+                    if !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_)) {
+                        return ControlFlow::Break(());
+                    }
+                    if let Some(init) = &mut declarator.init {
+                        self.substitute(init, ctx)?;
+                    }
+                }
+                ControlFlow::Continue(())
+            }
+            _ => ControlFlow::Break(()),
+        }
+    }
+
+    fn substitute(&mut self, expr: &mut Expression<'a>, ctx: &Ctx<'a, '_>) -> ControlFlow<()> {
+        match expr {
+            Expression::Identifier(ident) => {
+                if ident.reference_id() == self.reference_id {
+                    *expr = self.replacement.take().unwrap();
+                    return ControlFlow::Break(());
+                }
+            }
+            Expression::ParenthesizedExpression(e) => {
+                self.substitute(&mut e.expression, ctx)?;
+            }
+            Expression::UnaryExpression(e) => {
+                if e.operator.is_delete() {
+                    return ControlFlow::Break(());
+                }
+                self.substitute(&mut e.argument, ctx)?;
+            }
+            Expression::AwaitExpression(e) => {
+                self.substitute(&mut e.argument, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::BinaryExpression(e) => {
+                self.substitute(&mut e.left, ctx)?;
+                self.substitute(&mut e.right, ctx)?;
+            }
+            // The right side is evaluated conditionally.
+            Expression::LogicalExpression(e) => {
+                self.substitute(&mut e.left, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::ConditionalExpression(e) => {
+                self.substitute(&mut e.test, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::SequenceExpression(e) => {
+                for expr in &mut e.expressions {
+                    self.substitute(expr, ctx)?;
+                }
+            }
+            Expression::AssignmentExpression(e) => {
+                match &mut e.left {
+                    AssignmentTarget::AssignmentTargetIdentifier(_) => {}
+                    AssignmentTarget::StaticMemberExpression(member) => {
+                        self.substitute(&mut member.object, ctx)?;
+                    }
+                    _ => return ControlFlow::Break(()),
+                }
+                self.substitute(&mut e.right, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::StaticMemberExpression(e) => {
+                self.substitute(&mut e.object, ctx)?;
+            }
+            Expression::ComputedMemberExpression(e) => {
+                self.substitute(&mut e.object, ctx)?;
+                self.substitute(&mut e.expression, ctx)?;
+            }
+            Expression::CallExpression(e) => {
+                if e.optional {
+                    return ControlFlow::Break(());
+                }
+                match &mut e.callee {
+                    // `const f = a.b; f()` => `a.b()` changes `this` of the call.
+                    Expression::Identifier(ident) if ident.reference_id() == self.reference_id => {
+                        if self.replacement.as_ref().is_some_and(Expression::is_member_expression) {
+                            return ControlFlow::Break(());
+                        }
+                        self.substitute(&mut e.callee, ctx)?;
+                    }
+                    // The object of a method call is evaluated before the arguments.
+                    Expression::StaticMemberExpression(member) => {
+                        self.substitute(&mut member.object, ctx)?;
+                    }
+                    Expression::ComputedMemberExpression(member) => {
+                        self.substitute(&mut member.object, ctx)?;
+                        self.substitute(&mut member.expression, ctx)?;
+                    }
+                    callee => self.substitute(callee, ctx)?,
+                }
+                self.substitute_arguments(&mut e.arguments, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::NewExpression(e) => {
+                self.substitute(&mut e.callee, ctx)?;
+                self.substitute_arguments(&mut e.arguments, ctx)?;
+                return ControlFlow::Break(());
+            }
+            Expression::ArrayExpression(e) => {
+                for element in &mut e.elements {
+                    match element {
+                        ArrayExpressionElement::SpreadElement(_) => return ControlFlow::Break(()),
+                        ArrayExpressionElement::Elision(_) => {}
+                        element => self.substitute(element.to_expression_mut(), ctx)?,
+                    }
+                }
+            }
+            Expression::ObjectExpression(e) => {
+                for property in &mut e.properties {
+                    match property {
+                        ObjectPropertyKind::ObjectProperty(property) if !property.computed => {
+                            let shorthand = property.shorthand;
+                            let flow = self.substitute(&mut property.value, ctx);
+                            // `{ x }` => `{ x: foo() }`
+                            if shorthand && !matches!(property.value, Expression::Identifier(_)) {
+                                property.shorthand = false;
+                            }
+                            flow?;
+                        }
+                        _ => return ControlFlow::Break(()),
+                    }
+                }
+            }
+            Expression::TemplateLiteral(e) => {
+                for expr in &mut e.expressions {
+                    self.substitute(expr, ctx)?;
+                }
+            }
+            _ => {}
+        }
+        if self.can_move_across(expr, ctx) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    }
+
+    fn substitute_arguments(
+        &mut self,
+        arguments: &mut Vec<'a, Argument<'a>>,
+        ctx: &Ctx<'a, '_>,
+    ) -> ControlFlow<()> {
+        for argument in arguments {
+            match argument {
+                Argument::SpreadElement(_) => return ControlFlow::Break(()),
+                argument => self.substitute(argument.to_expression_mut(), ctx)?,
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Whether the replacement can be evaluated after `expr` instead of before it.
+    fn can_move_across(&self, expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        if expr.may_have_side_effects(ctx) {
+            return false;
+        }
+        if !self.replacement_has_side_effects {
+            return true;
+        }
+        // The side effects of the replacement must not change the value of `expr`.
+        match expr {
+            Expression::Identifier(ident) => ctx
+                .scoping()
+                .get_reference(ident.reference_id())
+                .symbol_id()
+                .is_some_and(|symbol_id| !ctx.scoping().symbol_is_mutated(symbol_id)),
+            expr => expr.is_literal_value(false),
+        }
+    }
+}
+
+struct InlineCandidate<'a> {
+    symbol_id: SymbolId,
+    /// The only reference of the function, which must be the callee of a call.
+    reference_id: ReferenceId,
+    /// Index of each parameter.
+    params: FxHashMap<SymbolId, usize>,
+    /// References in the body which are not parameters.
+    /// They must resolve to the same symbols at the call site.
+    free_references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+    has_side_effects: bool,
+}
+
+/// Checks that the returned expression of a function can be moved out of the function.
+struct InlineBodyChecker<'a, 'b, 'c> {
+    ctx: &'b Ctx<'a, 'c>,
+    params: &'b FxHashMap<SymbolId, usize>,
+    free_references: std::vec::Vec<(Atom<'a>, Option<SymbolId>)>,
+    inlinable: bool,
+}
+
+impl<'a> Visit<'a> for InlineBodyChecker<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.inlinable {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        if symbol_id.is_some_and(|symbol_id| self.params.contains_key(&symbol_id)) {
+            return;
+        }
+        if symbol_id.is_none() && ident.name == "arguments" {
+            self.inlinable = false;
+        }
+        self.free_references.push((ident.name, symbol_id));
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.inlinable = false;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.inlinable = false;
+    }
+
+    fn visit_meta_property(&mut self, _it: &MetaProperty<'a>) {
+        self.inlinable = false;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {
+        self.inlinable = false;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        self.inlinable = false;
+    }
+
+    fn visit_class(&mut self, _it: &Class<'a>) {
+        self.inlinable = false;
+    }
+}
+
+/// Finds the reference of an [InlineCandidate], and checks whether it is a call which can be inlined.
+///
+/// Nested functions and classes are not visited, so the call must be in the same function as the declaration.
+struct CallSiteFinder<'a, 'b, 'c> {
+    ctx: &'b Ctx<'a, 'c>,
+    candidate: &'b InlineCandidate<'a>,
+    scope_stack: std::vec::Vec<ScopeId>,
+    /// `Some(true)` if the reference is found in a call which can be inlined.
+    result: Option<bool>,
+}
+
+impl<'a> CallSiteFinder<'a, '_, '_> {
+    fn can_inline_call(&self, call: &CallExpression<'a>) -> bool {
+        if call.optional {
+            return false;
+        }
+        let ctx = self.ctx;
+        let scoping = ctx.scoping();
+        let candidate = self.candidate;
+        let is_used = |index: usize| {
+            candidate.params.iter().any(|(symbol_id, i)| {
+                *i == index && !scoping.get_resolved_reference_ids(*symbol_id).is_empty()
+            })
+        };
+        for (index, argument) in call.arguments.iter().enumerate() {
+            let Some(argument) = argument.as_expression() else { return false };
+            // Arguments are evaluated where the parameter is used, after other parts of the body.
+            let can_inline = if !is_used(index) {
+                !argument.may_have_side_effects(ctx)
+            } else if argument.is_literal_value(false) {
+                true
+            } else if let Expression::Identifier(ident) = argument {
+                // Parameters and `var`s are not in TDZ, so reading them later has no side effects.
+                scoping.get_reference(ident.reference_id()).symbol_id().is_some_and(|symbol_id| {
+                    !scoping.symbol_is_mutated(symbol_id)
+                        && (!candidate.has_side_effects
+                            || scoping
+                                .symbol_flags(symbol_id)
+                                .contains(SymbolFlags::FunctionScopedVariable))
+                })
+            } else {
+                false
+            };
+            if !can_inline {
+                return false;
+            }
+        }
+        // References in the body must not be shadowed at the call site.
+        let scope_id = *self.scope_stack.last().unwrap();
+        candidate
+            .free_references
+            .iter()
+            .all(|(name, symbol_id)| scoping.find_binding(scope_id, name) == *symbol_id)
+    }
+}
+
+impl<'a> Visit<'a> for CallSiteFinder<'a, '_, '_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if let Some(scope_id) = scope_id.get() {
+            self.scope_stack.push(scope_id);
+        }
+    }
+
+    fn leave_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    fn visit_call_expression(&mut self, call: &CallExpression<'a>) {
+        if let Expression::Identifier(ident) = &call.callee {
+            if ident.reference_id() == self.candidate.reference_id {
+                self.result = Some(self.can_inline_call(call));
+                return;
+            }
+        }
+        walk::walk_call_expression(self, call);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.reference_id() == self.candidate.reference_id {
+            self.result = Some(false);
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+/// Replaces the call of an [InlineCandidate] with the returned expression of the function.
+struct CallInliner<'a, 'b, 'c> {
+    ctx: &'b Ctx<'a, 'c>,
+    candidate: &'b InlineCandidate<'a>,
+    /// Returned expression of the function, `None` for an empty function.
+    body: Option<Expression<'a>>,
+    arguments: std::vec::Vec<Option<Expression<'a>>>,
+    inlined: bool,
+}
+
+impl<'a> VisitMut<'a> for CallInliner<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.inlined {
+            return;
+        }
+        if let Expression::CallExpression(call) = expr {
+            if matches!(&call.callee, Expression::Identifier(ident) if ident.reference_id() == self.candidate.reference_id)
+            {
+                let span = call.span;
+                let body = self.body.take();
+                self.inlined = true;
+                self.arguments = call
+                    .arguments
+                    .take_in(self.ctx.ast)
+                    .into_iter()
+                    .map(|argument| Some(argument.into_expression()))
+                    .collect();
+                *expr = match body {
+                    Some(mut body) => {
+                        self.visit_expression_params(&mut body);
+                        body
+                    }
+                    // `function f() {} f()` => `void 0`
+                    None => self.ctx.ast.void_0(span),
+                };
+                return;
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &mut Class<'a>) {}
+}
+
+impl<'a> CallInliner<'a, '_, '_> {
+    /// Replace parameters in the returned expression with the arguments of the call.
+    fn visit_expression_params(&mut self, expr: &mut Expression<'a>) {
+        let mut substitution = ParamSubstitution {
+            ctx: self.ctx,
+            params: &self.candidate.params,
+            arguments: &mut self.arguments,
+        };
+        substitution.visit_expression(expr);
+    }
+}
+
+struct ParamSubstitution<'a, 'b, 'c> {
+    ctx: &'b Ctx<'a, 'c>,
+    params: &'b FxHashMap<SymbolId, usize>,
+    arguments: &'b mut std::vec::Vec<Option<Expression<'a>>>,
+}
+
+impl<'a> VisitMut<'a> for ParamSubstitution<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            if let Some(index) = symbol_id.and_then(|symbol_id| self.params.get(&symbol_id)) {
+                let span = ident.span;
+                // `function f(a) { return a } f()` => `void 0`
+                *expr = self
+                    .arguments
+                    .get_mut(*index)
+                    .and_then(Option::take)
+                    .unwrap_or_else(|| self.ctx.ast.void_0(span));
+            }
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_mut::walk_object_property(self, prop);
+        // `{ a }` => `{ a: 1 }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions, CompressOptionsKeepNames,
+        tester::{test, test_same, test_same_options},
+    };
+
+    #[test]
+    fn single_use_variable() {
+        test("function f() { const x = foo(); return x.y }", "function f() { return foo().y }");
+        test("function f(g) { let x = foo(); g(x) }", "function f(g) { g(foo()) }");
+        test("function f() { const x = foo(); if (x) bar() }", "function f() { foo() && bar() }");
+        test(
+            "function f() { const x = foo(); return [x, 1] }",
+            "function f() { return [foo(), 1] }",
+        );
+        test(
+            "function f() { const x = foo(); return { x } }",
+            "function f() { return { x: foo() } }",
+        );
+        test(
+            "function f() { const a = foo(), b = bar(); return a + b }",
+            "function f() { return foo() + bar() }",
+        );
+        test(
+            "function f(y) { const x = foo(); return y + x }",
+            "function f(y) { return y + foo() }",
+        );
+        test("function f() { const x = foo(); return 1 + x }", "function f() { return 1 + foo() }");
+        test("function f(g) { const x = foo(); let y = x; g(y) }", "function f(g) { g(foo()) }");
+        test("function f() { const x = a.b; x.c() }", "function f() { a.b.c() }");
+        // Block scopes at the top level
+        test("{ const x = foo(); x.y() }", "foo().y()");
+        test("if (a) { let x = foo(); x.y() }", "a && foo().y()");
+    }
+
+    #[test]
+    fn single_use_variable_not_inlined() {
+        // Top level variables
+        test_same("const x = foo(); bar(x)");
+        // Used more than once
+        test_same("function f() { let x = foo(); bar(x, x) }");
+        // Not in the next statement
+        test_same("function f() { let x = foo(); return bar(), x }");
+        // Global callees may be reassigned
+        test_same("function f() { let x = foo(); bar(x) }");
+        // `var` is hoisted
+        test_same("function f() { var x = foo(); bar(x) }");
+        // Written to
+        test_same("function f() { let x = foo(); return x = 1 }");
+        // Used in a nested function
+        test_same("function f() { let x = foo(); return () => x }");
+        // Side effects before the use
+        test_same("function f() { let x = foo(); return bar() + x }");
+        test_same("function f(y) { let x = foo(); return y.z + x }");
+        // Conditionally evaluated
+        test_same("function f(y) { let x = foo(); return y || x }");
+        // `this` of the call
+        test_same("function f() { let x = a.b; return x() }");
+        // `delete`
+        test_same("function f() { let x = foo(); return delete x }");
+        // Direct `eval`
+        test_same("function f() { let x = foo(); return eval(x) }");
+        // Function names
+        test_same_options(
+            "function f() { let x = function() {}; return [x] }",
+            &CompressOptions {
+                keep_names: CompressOptionsKeepNames::all_true(),
+                ..CompressOptions::smallest()
+            },
+        );
+    }
+
+    #[test]
+    fn single_use_function() {
+        test(
+            "function f(x) { function g(a, b) { return a + b } return g(x, 1) }",
+            "function f(x) { return x + 1 }",
+        );
+        test(
+            "function f(x) { function g(a) { return a.b } return g(x) }",
+            "function f(x) { return x.b }",
+        );
+        test("function f() { function g() {} return g() }", "function f() {}");
+        test(
+            "function f() { function g(a) { return { a } } return g(1) }",
+            "function f() { return { a: 1 } }",
+        );
+        test(
+            "function f() { function g(a, b) { return foo(a, b) } return g(1) }",
+            "function f() { return foo(1, void 0) }",
+        );
+        // Called before the declaration
+        test(
+            "function f(x) { if (x) return g(x); function g(a) { return a + 1 } }",
+            "function f(x) { if (x) return x + 1 }",
+        );
+        // Called in a nested block
+        test(
+            "function f(x, y) { function g(a) { return bar(a) } if (x) { let z = foo(); z(g(y), z) } }",
+            "function f(x, y) { if (x) { let z = foo(); z(bar(y), z) } }",
+        );
+    }
+
+    #[test]
+    fn single_use_function_not_inlined() {
+        // Top level functions
+        test_same("function g(a) { return a + 1 } foo(g(1))");
+        // Called more than once
+        test_same("function f() { function g(a) { return a + 1 } return g(1) + g(2) }");
+        // Not called
+        test_same("function f() { function g(a) { return a + 1 } return g }");
+        // Called in a nested function
+        test_same("function f() { function g(a) { return a + 1 } return () => g(1) }");
+        // More than one statement
+        test_same("function f() { function g(a) { let b = foo(a); return b + b } return g(1) }");
+        // `this` and `arguments`
+        test_same("function f() { function g() { return this } return g() }");
+        test_same("function f() { function g() { return arguments } return g() }");
+        // Nested functions
+        test_same("function f() { function g() { return () => 1 } return g() }");
+        // Parameter used more than once
+        test_same("function f() { function g(a) { return a + a } return g(1) }");
+        // Arguments with side effects
+        test_same("function f() { function g(a) { return a } return g(foo()) }");
+        test_same("function f() { function g() { return 1 } return g(foo()) }");
+        // Shadowed references
+        test_same(
+            "function f() { let y = 1; function g() { return y } { let y = 2; foo(y, g()) } }",
+        );
+        // async and generator functions
+        test_same("function f() { async function g() { return 1 } return g() }");
+    }
+}
@@ -40,8 +40,9 @@ impl<'a> PeepholeOptimizations {
         let mut is_control_flow_dead = false;
         let mut keep_var = KeepVar::new(ctx.ast);
         let mut new_stmts = stmts.take_in(ctx.ast);
+        self.inline_single_use_functions(&mut new_stmts, state, ctx);
         for i in 0..new_stmts.len() {
-            let stmt = new_stmts[i].take_in(ctx.ast);
+            let mut stmt = new_stmts[i].take_in(ctx.ast);
             if is_control_flow_dead
                 && !stmt.is_module_declaration()
                 && !matches!(stmt.as_declaration(), Some(Declaration::FunctionDeclaration(_)))
@@ -49,6 +50,7 @@ impl<'a> PeepholeOptimizations {
                 keep_var.visit_statement(&stmt);
                 continue;
             }
+            self.inline_single_use_variables(&mut stmt, &mut result, state, ctx);
             if self
                 .minimize_statement(
                     stmt,
@@ -3,6 +3,7 @@
 mod collapse_variable_declarations;
 mod convert_to_dotted_properties;
 mod fold_constants;
+mod inline;
 mod minimize_conditional_expression;
 mod minimize_conditions;
 mod minimize_exit_points;
@@ -1,9 +1,13 @@
 use std::fmt::Write;
 
+use lazy_regex::Regex;
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleOptions, MangleOptionsKeepNames, MangleOptionsProps, Mangler, NameCache, PropertyMangler,
+};
 use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str, options: MangleOptions) -> String {
@@ -15,6 +19,21 @@ fn mangle(source_text: &str, options: MangleOptions) -> String {
     Codegen::new().with_scoping(Some(symbol_table)).build(&program).code
 }
 
+fn mangle_with_name_cache(source_text: &str, name_cache: NameCache) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let program = ret.program;
+    let mut semantic =
+        SemanticBuilder::new().with_scope_tree_child_ids(true).build(&program).semantic;
+    let options = MangleOptions { top_level: true, ..MangleOptions::default() };
+    let name_cache = Mangler::new()
+        .with_options(options)
+        .with_name_cache(name_cache)
+        .build_with_semantic(&mut semantic, &program);
+    let code = Codegen::new().with_scoping(Some(semantic.into_scoping())).build(&program).code;
+    (code, name_cache)
+}
+
 #[test]
 fn direct_eval() {
     let source_text = "function foo() { let NO_MANGLE; eval('') }";
@@ -93,3 +112,117 @@ fn mangler() {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+#[test]
+fn mangler_name_cache() {
+    let (first, name_cache) = mangle_with_name_cache(
+        "const foo = 1, bar = 2; export function f(x) { return foo + bar + bar + x }",
+        NameCache::default(),
+    );
+    assert_eq!(first, "const e = 1, t = 2;\nexport function f(n) {\n\treturn e + t + t + n;\n}\n");
+    assert_eq!(name_cache.vars.get("foo").map(String::as_str), Some("e"));
+    assert_eq!(name_cache.vars.get("bar").map(String::as_str), Some("t"));
+    assert!(!name_cache.vars.contains_key("f"));
+
+    // `foo` and `bar` keep their names, even though `baz` is used more often.
+    let name_cache = NameCache::from_json(&name_cache.to_json()).unwrap();
+    let (second, name_cache) = mangle_with_name_cache(
+        "const baz = 0, foo = 1, bar = 2; export function f(x) { return baz + baz + baz + foo + bar + x }",
+        name_cache,
+    );
+    assert_eq!(
+        second,
+        "const n = 0, e = 1, t = 2;\nexport function f(r) {\n\treturn n + n + n + e + t + r;\n}\n"
+    );
+    assert_eq!(name_cache.vars.len(), 3);
+
+    // Cached names which would change the meaning of the program are not used.
+    let mut name_cache = NameCache::default();
+    name_cache.vars.insert("foo".to_string(), "console".to_string());
+    name_cache.vars.insert("bar".to_string(), "f".to_string());
+    name_cache.vars.insert("baz".to_string(), "if".to_string());
+    let (third, name_cache) = mangle_with_name_cache(
+        "const foo = 1, bar = 2, baz = 3; export function f() { console.log(foo, bar, baz) }",
+        name_cache,
+    );
+    assert_eq!(
+        third,
+        "const e = 1, n = 2, r = 3;\nexport function f() {\n\tconsole.log(e, n, r);\n}\n"
+    );
+    assert_eq!(name_cache.vars.get("foo").map(String::as_str), Some("e"));
+}
+
+fn mangle_props(
+    source_text: &str,
+    options: MangleOptionsProps,
+    name_cache: NameCache,
+) -> (String, NameCache) {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    let mut program = ret.program;
+    let name_cache =
+        PropertyMangler::new(options).with_name_cache(name_cache).build(&allocator, &mut program);
+    (Codegen::new().build(&program).code, name_cache)
+}
+
+fn private_props() -> MangleOptionsProps {
+    MangleOptionsProps {
+        regex: Some(Regex::new(r"^_[\w-]*$").unwrap()),
+        ..MangleOptionsProps::default()
+    }
+}
+
+#[test]
+fn mangle_props_name_cache() {
+    let (first, name_cache) =
+        mangle_props("x._foo = x._bar + x._bar", private_props(), NameCache::default());
+    assert_eq!(first, "x.t = x.e + x.e;\n");
+    assert_eq!(name_cache.props.get("_bar").map(String::as_str), Some("e"));
+
+    // `_foo` keeps its name, even though `_baz` is used more often.
+    let name_cache = NameCache::from_json(&name_cache.to_json()).unwrap();
+    let (second, name_cache) =
+        mangle_props("x._baz = x._baz + x._baz + x._foo", private_props(), name_cache);
+    assert_eq!(second, "x.n = x.n + x.n + x.t;\n");
+    assert_eq!(name_cache.props.len(), 3);
+}
+
+#[test]
+fn property_mangler() {
+    let cases = [
+        "x._foo = x._bar + x._bar",
+        "x.foo = x._foo",
+        "const o = { _a: 1, '_b': 2, ['_c']: 3, [_d]: 4, e: 5 }; o._a; o['_b']; o[_d]",
+        "const { _a, _b: b, _c = 1, ...rest } = o; ({ _a, _b: b, _c = 1 } = o)",
+        "class C { _a = 1; static _b() {} get _c() {} #_d; _e() { return this.#_d + super._e } }",
+        "if ('_a' in o) o?._a?.(o._a)",
+        "x._a = x.e; x.t = x._b", // mangled names skip property names which are kept
+        "x._constructor = x.constructor; x.__proto__ = x._prototype",
+        "x._1 = x['_-']",
+    ];
+    let keep_quoted_cases = ["x._a = x['_a'] + x['_b'] + x._c", "({ '_a': 1, _b: 2 })"];
+    let reserved_cases = ["x._a = x._b"];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let (mangled, _) = mangle_props(case, private_props(), NameCache::default());
+        write!(w, "{case}\n{mangled}\n").unwrap();
+        w
+    });
+    keep_quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProps { keep_quoted: true, ..private_props() };
+        let (mangled, _) = mangle_props(case, options, NameCache::default());
+        write!(w, "{case}\n{mangled}\n").unwrap();
+        w
+    });
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptionsProps { reserved: vec!["_b".to_string()], ..private_props() };
+        let (mangled, _) = mangle_props(case, options, NameCache::default());
+        write!(w, "{case}\n{mangled}\n").unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_props", snapshot);
+    });
+}
@@ -2,6 +2,7 @@
 
 mod ecmascript;
 mod mangler;
+mod modules;
 mod peephole;
 
 use oxc_allocator::Allocator;
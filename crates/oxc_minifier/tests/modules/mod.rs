@@ -0,0 +1,197 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{CompressModule, CompressOptions, Compressor};
+use oxc_parser::Parser;
+use oxc_span::{Atom, SourceType};
+
+/// Compress `modules` together, and compare each module with `expected`.
+///
+/// Modules are named by their index, e.g. `import { X } from "./1"` imports from `modules[1]`.
+/// All exports of `modules[0]` are kept.
+#[track_caller]
+fn test(modules: &[&str], expected: &[&str]) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let mut parsed = modules
+        .iter()
+        .map(|source_text| {
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source_text}");
+            (ret.program, ret.module_record)
+        })
+        .collect::<Vec<_>>();
+
+    let mut compress_modules = parsed
+        .iter_mut()
+        .enumerate()
+        .map(|(i, (program, module_record))| {
+            let resolved_requests = (0..modules.len())
+                .map(|j| (Atom::from(allocator.alloc_str(&format!("./{j}"))), j))
+                .collect::<FxHashMap<_, _>>();
+            CompressModule::new(program, module_record)
+                .with_resolved_requests(resolved_requests)
+                .with_keep_exports(i == 0)
+        })
+        .collect::<Vec<_>>();
+    Compressor::new(&allocator, CompressOptions::default()).build_modules(&mut compress_modules);
+
+    for (i, ((program, _), expected)) in parsed.iter().zip(expected).enumerate() {
+        let code = codegen(&Codegen::new().build(program).code);
+        let expected = codegen(expected);
+        assert_eq!(
+            code, expected,
+            "\nfor module {i}\n{}\nexpect\n{expected}\ngot\n{code}",
+            modules[i]
+        );
+    }
+}
+
+fn codegen(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn inline_exported_constants() {
+    test(
+        &[
+            "import { FEATURE_X, NAME } from './1'; if (FEATURE_X) foo(); console.log(NAME)",
+            "export const FEATURE_X = false; export const NAME = 'a' + 'b';",
+        ],
+        &["import './1'; console.log('ab')", ""],
+    );
+    // Constants of other modules are used in constant evaluation.
+    test(
+        &[
+            "import { A } from './1'; if (A > 1) foo()",
+            "import { B } from './2'; export const A = B + 1",
+            "export const B = 0",
+        ],
+        &["import './1'", "import './2'", ""],
+    );
+    // Imports which are still referenced are kept.
+    test(
+        &["import { A } from './1'; foo(A); export { A }", "export const A = 1"],
+        &["import { A } from './1'; foo(1); export { A }", "export const A = 1"],
+    );
+}
+
+#[test]
+fn inline_exported_constants_through_re_exports() {
+    test(
+        &[
+            "import { A, B, C, D } from './1'; foo(A, B, C, D)",
+            "export { A } from './2'; export { B as C } from './2'; export * from './3'; import { D } from './3'; export { D as B }",
+            "export const A = 1, B = 2",
+            "export const D = 4",
+        ],
+        &[
+            "import './1'; foo(1, 4, 2, 4)",
+            "import './2'; import './2'; export * from './3'; import './3'",
+            "",
+            "export const D = 4",
+        ],
+    );
+    // Ambiguous star exports are not inlined.
+    test(
+        &[
+            "import { A } from './1'; foo(A)",
+            "export * from './2'; export * from './3'",
+            "export const A = 1",
+            "export const A = 2",
+        ],
+        &[
+            "import { A } from './1'; foo(A)",
+            "export * from './2'; export * from './3'",
+            "export const A = 1",
+            "export const A = 2",
+        ],
+    );
+}
+
+#[test]
+fn non_constant_exports_are_not_inlined() {
+    test(
+        &[
+            "import { A, B, C } from './1'; foo(A, B, C)",
+            "export let A = 1; export const B = foo(); export const C = {}",
+        ],
+        &[
+            "import { A, B, C } from './1'; foo(A, B, C)",
+            "export let A = 1; export const B = foo(); export const C = {}",
+        ],
+    );
+}
+
+#[test]
+fn remove_unused_exports() {
+    test(
+        &[
+            "import { used } from './1'; used()",
+            "
+            export function used() {}
+            export function unused() {}
+            export class Unused {}
+            export const a = 1, b = foo();
+            const c = 1;
+            export { c, c as d };
+            export default function() {}
+            ",
+        ],
+        &["import { used } from './1'; used()", "export function used() {} const b = foo();"],
+    );
+    // Declarations referenced in the module are kept.
+    test(
+        &[
+            "import './1'",
+            "export function f() {} export const a = 1; export default class C {} foo(f, a, C)",
+        ],
+        &["import './1'", "function f() {} const a = 1; class C {} foo(f, a, C)"],
+    );
+    // Default exports with side effects are kept as statements.
+    test(&["import './1'", "export default foo()"], &["import './1'", "foo()"]);
+    // Unused re-exports are kept as imports for the side effects of the re-exported module.
+    test(
+        &["import './1'", "export { a } from './2'", "export const a = 1; foo()"],
+        &["import './1'", "import './2'", "foo()"],
+    );
+}
+
+#[test]
+fn keep_used_exports() {
+    // Exports of modules 0 are kept.
+    test(&["export const a = 1; export default 1"], &["export const a = 1; export default 1"]);
+    // Namespace imports use all exports.
+    test(
+        &["import * as ns from './1'; foo(ns)", "export const a = 1; export function f() {}"],
+        &["import * as ns from './1'; foo(ns)", "export const a = 1; export function f() {}"],
+    );
+    // Dynamic imports use all exports.
+    test(
+        &["import('./1')", "export function f() {}"],
+        &["import('./1')", "export function f() {}"],
+    );
+    // Any module may be imported by a dynamic import of another expression.
+    test(&["import(foo)", "export function f() {}"], &["import(foo)", "export function f() {}"]);
+    // Used exports propagate through re-exports.
+    test(
+        &[
+            "import { f, g } from './1'; f(g)",
+            "export { f } from './2'; export * from './3'",
+            "export function f() {} export function h() {}",
+            "export function g() {}",
+        ],
+        &[
+            "import { f, g } from './1'; f(g)",
+            "export { f } from './2'; export * from './3'",
+            "export function f() {}",
+            "export function g() {}",
+        ],
+    );
+}
@@ -47,6 +47,12 @@ bitflags! {
         ///   * ambient variable declaration => `declare var $: any`
         ///   * ambient class declaration => `declare class C { foo(); } , etc..`
         const Ambient = 1 << 6;
+
+        /// Flow: disallow function types without parentheses, i.e. `string => void`.
+        /// Used in arrow function return types, where `=>` starts the function body.
+        ///
+        /// const f = (x): string => x;
+        const NoAnonFunctionType = 1 << 7;
     }
 }
 
@@ -92,6 +98,11 @@ impl Context {
         self.contains(Self::Ambient)
     }
 
+    #[inline]
+    pub(crate) fn has_no_anon_function_type(self) -> bool {
+        self.contains(Self::NoAnonFunctionType)
+    }
+
     #[inline]
     pub(crate) fn union_await_if(self, include: bool) -> Self {
         self.union_if(Self::Await, include)
@@ -9,6 +9,7 @@ use crate::{
     Context, ParserImpl, diagnostics,
     error_handler::FatalError,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
+    tokens::{self, TokenKind},
 };
 
 #[derive(Clone)]
@@ -17,6 +18,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_len: usize,
     fatal_error: Option<FatalError>,
 }
 
@@ -101,6 +103,7 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     fn advance(&mut self, kind: Kind) {
         self.test_escaped_keyword(kind);
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
@@ -108,10 +111,29 @@ impl<'a> ParserImpl<'a> {
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        self.collect_token();
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the current token before moving past it, if `collect_tokens` is enabled.
+    /// The current token is final at this point, i.e. it has already been re-lexed as needed.
+    #[inline]
+    fn collect_token(&mut self) {
+        if self.options.collect_tokens {
+            if let Some(kind) = TokenKind::from_kind(self.token.kind()) {
+                self.tokens.push(tokens::Token { kind, span: self.token.span() });
+            }
+        }
+    }
+
+    /// Change the type of the last collected token, e.g. identifiers inside JSX tags are `JSXIdentifier`s.
+    pub(crate) fn set_last_token_kind(&mut self, kind: TokenKind) {
+        if let Some(token) = self.tokens.last_mut() {
+            token.kind = kind;
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     #[must_use = "Use `bump` instead of `eat` if you are ignoring the return value"]
@@ -210,7 +232,7 @@ impl<'a> ParserImpl<'a> {
 
     /// Tell lexer to continue reading jsx identifier if the lexer character position is at `-` for `<component-name>`
     pub(crate) fn continue_lex_jsx_identifier(&mut self) {
-        if let Some(token) = self.lexer.continue_lex_jsx_identifier() {
+        if let Some(token) = self.lexer.continue_lex_jsx_identifier(self.token.start()) {
             self.token = token;
         }
     }
@@ -261,18 +283,26 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            tokens_len: self.tokens.len(),
             fatal_error: self.fatal_error.take(),
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            tokens_len,
+            fatal_error,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.tokens.truncate(tokens_len);
         self.fatal_error = fatal_error;
     }
 
@@ -32,6 +32,16 @@ pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
 }
 
+#[cold]
+pub fn flow_enum(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Flow enums are not supported").with_label(span)
+}
+
+#[cold]
+pub fn flow_declare_module_exports(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Flow `declare module.exports` is not supported").with_label(span)
+}
+
 #[cold]
 pub fn unexpected_token(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected token").with_label(span)
@@ -769,3 +779,27 @@ pub fn invalid_rest_assignment_target(span: Span) -> OxcDiagnostic {
 pub fn modifiers_cannot_appear_here(span: Span) -> OxcDiagnostic {
     ts_error("1184", "Modifiers cannot appear here.").with_label(span)
 }
+
+#[cold]
+pub fn invalid_template_placeholder(x0: &str) -> OxcDiagnostic {
+    let placeholder = x0.split_whitespace().next().unwrap_or(x0);
+    OxcDiagnostic::error(format!("Invalid template placeholder `{placeholder}`"))
+        .with_help("Placeholders are written as `%%name%%`")
+}
+
+#[cold]
+pub fn missing_template_value(x0: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Missing value for template placeholder `%%{x0}%%`"))
+}
+
+#[cold]
+pub fn unused_template_value(x0: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Unused value for template placeholder `%%{x0}%%`"))
+}
+
+#[cold]
+pub fn invalid_template_value(x0: &str, expected: &str) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Template placeholder `%%{x0}%%` must be replaced with {expected}"
+    ))
+}
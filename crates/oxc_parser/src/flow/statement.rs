@@ -0,0 +1,116 @@
+use oxc_ast::{NONE, ast::*};
+use oxc_span::Span;
+
+use crate::{
+    ParserImpl, diagnostics,
+    lexer::Kind,
+    modifiers::{Modifier, ModifierFlags, ModifierKind, Modifiers},
+};
+
+impl<'a> ParserImpl<'a> {
+    /// Check if the parser is at `opaque type`, `declare opaque type`, `declare export`
+    /// or `declare module.exports`.
+    pub(crate) fn at_start_of_flow_declaration(&mut self) -> bool {
+        self.lookahead(|p| {
+            if p.eat(Kind::Declare) {
+                if p.cur_token().is_on_new_line() {
+                    return false;
+                }
+                if p.eat(Kind::Export) {
+                    return !p.at(Kind::Default);
+                }
+                if p.eat(Kind::Module) {
+                    return p.at(Kind::Dot);
+                }
+            }
+            p.at_flow_opaque_type_worker()
+        })
+    }
+
+    pub(crate) fn at_flow_opaque_type(&mut self) -> bool {
+        self.lookahead(Self::at_flow_opaque_type_worker)
+    }
+
+    fn at_flow_opaque_type_worker(&mut self) -> bool {
+        if !self.at(Kind::Ident) || self.cur_src() != "opaque" {
+            return false;
+        }
+        self.bump_any();
+        self.at(Kind::Type) && !self.cur_token().is_on_new_line()
+    }
+
+    pub(crate) fn parse_flow_declaration_statement(&mut self) -> Statement<'a> {
+        let span = self.start_span();
+        let declare_span = self.start_span();
+        let declare = self.eat(Kind::Declare);
+        if self.at(Kind::Export) {
+            return self.parse_flow_declare_export(span, self.end_span(declare_span));
+        }
+        if self.at(Kind::Module) {
+            // `declare module.exports: T`
+            self.bump_any();
+            self.expect(Kind::Dot);
+            let error = diagnostics::flow_declare_module_exports(self.end_span(span));
+            return self.fatal_error(error);
+        }
+        Statement::from(self.parse_flow_opaque_type_alias(span, declare))
+    }
+
+    /// `declare export function foo(): void;`, parsed as `export declare function foo(): void;`
+    fn parse_flow_declare_export(&mut self, span: u32, declare_span: Span) -> Statement<'a> {
+        self.bump_any(); // bump `export`
+        let decl_span = self.start_span();
+        let modifiers = self.ast.vec1(Modifier::new(declare_span, ModifierKind::Declare));
+        let modifiers = Modifiers::new(Some(modifiers), ModifierFlags::DECLARE);
+        let reserved_ctx = self.ctx;
+        self.ctx = self.ctx.union_ambient_if(true);
+        let declaration = self.parse_declaration(decl_span, &modifiers, self.ast.vec());
+        self.ctx = reserved_ctx;
+        let decl = self.ast.alloc_export_named_declaration(
+            self.end_span(span),
+            Some(declaration),
+            self.ast.vec(),
+            None,
+            ImportOrExportKind::Type,
+            NONE,
+        );
+        Statement::from(ModuleDeclaration::ExportNamedDeclaration(decl))
+    }
+
+    /// `opaque type T: SuperType = Type`
+    ///
+    /// The supertype is only visible to Flow, and is used as the type of `declare opaque type`.
+    pub(crate) fn parse_flow_opaque_type_alias(
+        &mut self,
+        span: u32,
+        declare: bool,
+    ) -> Declaration<'a> {
+        self.bump_any(); // bump `opaque`
+        self.expect(Kind::Type);
+        let id = self.parse_binding_identifier();
+        let type_parameters = self.parse_ts_type_parameters();
+        let super_type = if self.eat(Kind::Colon) { Some(self.parse_ts_type()) } else { None };
+        let ty = if declare && !self.at(Kind::Eq) {
+            super_type.unwrap_or_else(|| {
+                self.ast.ts_type_unknown_keyword(Span::empty(self.prev_token_end))
+            })
+        } else {
+            self.expect(Kind::Eq);
+            self.parse_ts_type()
+        };
+        self.asi();
+        self.ast.declaration_ts_type_alias(self.end_span(span), id, type_parameters, ty, declare)
+    }
+
+    /// `(expression: Type)`, parsed into a [`TSAsExpression`].
+    pub(crate) fn parse_flow_type_cast_or_assignment_expression(&mut self) -> Expression<'a> {
+        let span = self.start_span();
+        let expression = self.parse_assignment_expression_or_higher();
+        if !self.at(Kind::Colon) {
+            return expression;
+        }
+        self.bump_any(); // bump `:`
+        let type_annotation = self.parse_ts_type();
+        self.ast.expression_ts_as(self.end_span(span), expression, type_annotation)
+    }
+}
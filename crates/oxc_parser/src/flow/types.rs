@@ -0,0 +1,282 @@
+use oxc_allocator::Box;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{Atom, GetSpan};
+
+use crate::{Context, ParserImpl, lexer::Kind};
+
+impl<'a> ParserImpl<'a> {
+    /// Variance sigil `+` (covariant) or `-` (contravariant).
+    /// Returns `true` for `+`, which is treated as `readonly`.
+    pub(crate) fn eat_flow_variance(&mut self) -> bool {
+        match self.cur_kind() {
+            Kind::Plus => {
+                self.bump_any();
+                true
+            }
+            Kind::Minus => {
+                self.bump_any();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Predicate function annotation `%checks`, which is only meaningful to Flow.
+    pub(crate) fn eat_flow_predicate(&mut self) {
+        if self.at(Kind::Percent)
+            && self.lookahead(|p| {
+                p.bump_any();
+                p.at(Kind::Ident) && p.cur_src() == "checks"
+            })
+        {
+            self.bump_any();
+            self.bump_any();
+        }
+    }
+
+    /* ------------------- Function type ------------------ */
+
+    /// `(string, b?: number, ...rest: Array<mixed>) => void`, `<T>(T) => T`
+    ///
+    /// Parameter names are optional in Flow,
+    /// unnamed parameters are given the names `arg0`, `arg1`, ...
+    pub(crate) fn parse_flow_function_type(&mut self) -> TSType<'a> {
+        // `(x): (T) => {}` is an arrow function returning `T`, not a function type.
+        if self.ctx.has_no_anon_function_type() && self.lookahead(Self::is_flow_grouped_type) {
+            return self.unexpected();
+        }
+        let span = self.start_span();
+        let type_parameters = self.parse_ts_type_parameters();
+        let (this_param, params) = self.context(
+            Context::empty(),
+            Context::NoAnonFunctionType,
+            Self::parse_flow_function_type_params,
+        );
+        let return_type = self.parse_flow_function_type_return_type();
+        self.ast.ts_type_function_type(
+            self.end_span(span),
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+        )
+    }
+
+    /// `string => void`
+    pub(crate) fn parse_flow_anon_function_type(&mut self, param_type: TSType<'a>) -> TSType<'a> {
+        let span = param_type.span();
+        let pattern = self.flow_unnamed_param(0, param_type);
+        let items = self.ast.vec1(self.ast.formal_parameter(
+            span,
+            self.ast.vec(),
+            pattern,
+            None,
+            false,
+            false,
+        ));
+        let params =
+            self.ast.alloc_formal_parameters(span, FormalParameterKind::Signature, items, NONE);
+        let return_type = self.parse_flow_function_type_return_type();
+        self.ast.ts_type_function_type(self.end_span(span.start), NONE, NONE, params, return_type)
+    }
+
+    fn parse_flow_function_type_return_type(&mut self) -> Box<'a, TSTypeAnnotation<'a>> {
+        let span = self.start_span();
+        if !self.at(Kind::Arrow) {
+            return self.unexpected();
+        }
+        let Some(return_type) = self.parse_return_type(Kind::Arrow, /* is_type */ true) else {
+            return self.unexpected();
+        };
+        self.ast.alloc_ts_type_annotation(self.end_span(span), return_type)
+    }
+
+    fn parse_flow_function_type_params(
+        &mut self,
+    ) -> (Option<Box<'a, TSThisParameter<'a>>>, Box<'a, FormalParameters<'a>>) {
+        let span = self.start_span();
+        self.expect(Kind::LParen);
+        let this_param = if self.at(Kind::This) && self.lookahead(Self::is_next_token_colon) {
+            let this_param = self.parse_ts_this_parameter();
+            if !self.at(Kind::RParen) {
+                self.expect(Kind::Comma);
+            }
+            Some(self.alloc(this_param))
+        } else {
+            None
+        };
+        let mut items = self.ast.vec();
+        let mut rest = None;
+        while !self.at(Kind::RParen) && !self.has_fatal_error() {
+            let param_span = self.start_span();
+            if self.eat(Kind::Dot3) {
+                let pattern = self.parse_flow_function_type_param(items.len());
+                rest =
+                    Some(self.ast.alloc_binding_rest_element(self.end_span(param_span), pattern));
+                self.bump(Kind::Comma);
+                break;
+            }
+            let pattern = self.parse_flow_function_type_param(items.len());
+            items.push(self.ast.formal_parameter(
+                self.end_span(param_span),
+                self.ast.vec(),
+                pattern,
+                None,
+                false,
+                false,
+            ));
+            if !self.at(Kind::RParen) {
+                self.expect(Kind::Comma);
+            }
+        }
+        self.expect(Kind::RParen);
+        let params = self.ast.alloc_formal_parameters(
+            self.end_span(span),
+            FormalParameterKind::Signature,
+            items,
+            rest,
+        );
+        (this_param, params)
+    }
+
+    fn parse_flow_function_type_param(&mut self, index: usize) -> BindingPattern<'a> {
+        if self.cur_kind().is_identifier_name()
+            && self.lookahead(|p| {
+                p.bump_any();
+                p.bump(Kind::Question);
+                p.at(Kind::Colon)
+            })
+        {
+            // `name: Type`, `name?: Type`
+            let name = self.parse_identifier_name();
+            let optional = self.eat(Kind::Question);
+            let type_annotation = self.parse_ts_type_annotation();
+            let kind = self.ast.binding_pattern_kind_binding_identifier(name.span, name.name);
+            return self.ast.binding_pattern(kind, type_annotation, optional);
+        }
+        let ty = self.parse_ts_type();
+        self.flow_unnamed_param(index, ty)
+    }
+
+    fn flow_unnamed_param(&self, index: usize, ty: TSType<'a>) -> BindingPattern<'a> {
+        let span = ty.span();
+        let name = self.ast.atom(&format!("arg{index}"));
+        let kind = self.ast.binding_pattern_kind_binding_identifier(span, name);
+        let type_annotation = self.ast.alloc_ts_type_annotation(span, ty);
+        self.ast.binding_pattern(kind, Some(type_annotation), false)
+    }
+
+    fn is_flow_grouped_type(&mut self) -> bool {
+        if !self.eat(Kind::LParen) || matches!(self.cur_kind(), Kind::RParen | Kind::Dot3) {
+            return false;
+        }
+        if self.cur_kind().is_identifier_name() {
+            self.bump_any();
+            return !matches!(self.cur_kind(), Kind::Colon | Kind::Question);
+        }
+        true
+    }
+
+    fn is_next_token_colon(&mut self) -> bool {
+        self.bump_any();
+        self.at(Kind::Colon)
+    }
+
+    /* ------------------- Object type ------------------ */
+
+    /// `{ a: string, +b?: number, [string]: mixed, ...Other }`, `{| a: string |}`
+    ///
+    /// Exact object types are parsed as type literals.
+    /// Spreads are parsed as an intersection of the spread types and the type literal.
+    pub(crate) fn parse_flow_object_type(&mut self) -> TSType<'a> {
+        self.context(
+            Context::empty(),
+            Context::NoAnonFunctionType,
+            Self::parse_flow_object_type_impl,
+        )
+    }
+
+    fn parse_flow_object_type_impl(&mut self) -> TSType<'a> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly);
+        let close = if self.eat(Kind::Pipe2) {
+            // `{||}`
+            Kind::RCurly
+        } else if self.eat(Kind::Pipe) {
+            Kind::Pipe
+        } else {
+            Kind::RCurly
+        };
+        let mut members = self.ast.vec();
+        let mut spreads = self.ast.vec();
+        while !self.at(close) && !self.at(Kind::Eof) && !self.has_fatal_error() {
+            if self.eat(Kind::Dot3) {
+                // `...` without a type marks an inexact object type.
+                if !matches!(self.cur_kind(), Kind::Comma | Kind::Semicolon | Kind::RCurly)
+                    && !self.at(close)
+                {
+                    spreads.push(self.parse_ts_type());
+                }
+                self.parse_type_member_semicolon();
+            } else {
+                members.push(self.parse_flow_object_type_member());
+            }
+        }
+        if close == Kind::Pipe {
+            self.expect(Kind::Pipe);
+        }
+        self.expect(Kind::RCurly);
+        let span = self.end_span(span);
+        let literal = self.ast.ts_type_type_literal(span, members);
+        if spreads.is_empty() {
+            return literal;
+        }
+        spreads.push(literal);
+        self.ast.ts_type_intersection_type(span, spreads)
+    }
+
+    fn parse_flow_object_type_member(&mut self) -> TSSignature<'a> {
+        let span = self.start_span();
+        let readonly = self.eat_flow_variance();
+        if self.at(Kind::LBrack) {
+            return self.parse_flow_indexer(span, readonly);
+        }
+        let mut member = self.parse_ts_type_signature();
+        if let TSSignature::TSPropertySignature(signature) = &mut member {
+            signature.span = self.end_span(span);
+            signature.readonly |= readonly;
+        }
+        member
+    }
+
+    /// `[string]: number`, `[key: string]: number`
+    ///
+    /// Unnamed keys are given the name `key`.
+    fn parse_flow_indexer(&mut self, span: u32, readonly: bool) -> TSSignature<'a> {
+        self.expect(Kind::LBrack);
+        let key_span = self.start_span();
+        let name =
+            if self.cur_kind().is_identifier_name() && self.lookahead(Self::is_next_token_colon) {
+                let name = self.parse_identifier_name().name;
+                self.bump_any(); // bump `:`
+                name
+            } else {
+                Atom::from("key")
+            };
+        let key_type = self.parse_ts_type();
+        let key_type = self.ast.alloc_ts_type_annotation(key_type.span(), key_type);
+        let parameter = self.ast.ts_index_signature_name(self.end_span(key_span), name, key_type);
+        self.expect(Kind::RBrack);
+        let Some(type_annotation) = self.parse_ts_type_annotation() else {
+            return self.unexpected();
+        };
+        self.parse_type_member_semicolon();
+        self.ast.ts_signature_index_signature(
+            self.end_span(span),
+            self.ast.vec1(parameter),
+            type_annotation,
+            readonly,
+            false,
+        )
+    }
+}
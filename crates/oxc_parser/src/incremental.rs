@@ -0,0 +1,525 @@
+//! Incremental reparsing, see [`Parser::parse_incremental`](crate::Parser::parse_incremental).
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_span::{GetSpan, Span};
+
+use crate::{ParserImpl, ParserReturn, StatementContext, Token};
+
+/// An edit of the source text, used by [`Parser::parse_incremental`](crate::Parser::parse_incremental).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Range of the replaced text, in UTF-8 bytes.
+    pub span: Span,
+    /// Length of the inserted text, in UTF-8 bytes.
+    pub new_len: u32,
+}
+
+impl TextEdit {
+    /// Create an edit which replaces the text in `span` with `new_len` bytes of text.
+    pub fn new(span: Span, new_len: u32) -> Self {
+        Self { span, new_len }
+    }
+}
+
+/// Range of the source text changed by a list of [`TextEdit`]s.
+///
+/// Text before `start` is unchanged, and text after `old_end` in the previous source text
+/// is the same as text after `new_end` in the new source text.
+#[derive(Debug, Clone, Copy)]
+struct ChangedRange {
+    start: u32,
+    old_end: u32,
+    new_end: u32,
+}
+
+impl ChangedRange {
+    /// Returns `None` if there are no edits, or an edit has an invalid span.
+    fn from_edits(edits: &[TextEdit]) -> Option<Self> {
+        let (first, rest) = edits.split_first()?;
+        if first.span.start > first.span.end {
+            return None;
+        }
+        let mut range = Self {
+            start: first.span.start,
+            old_end: first.span.end,
+            new_end: first.span.start.checked_add(first.new_len)?,
+        };
+        // Each edit is relative to the source text produced by the edits before it.
+        for edit in rest {
+            if edit.span.start > edit.span.end {
+                return None;
+            }
+            let end = range.new_end.max(edit.span.end);
+            range.start = range.start.min(edit.span.start);
+            range.old_end = range.old_end.checked_add(end - range.new_end)?;
+            range.new_end = (end - edit.span.size()).checked_add(edit.new_len)?;
+        }
+        Some(range)
+    }
+
+    fn delta(self) -> i64 {
+        i64::from(self.new_end) - i64::from(self.old_end)
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// Reparse the top-level statements around the changed text,
+    /// and reuse all other statements of `previous`.
+    pub(crate) fn parse_incremental(
+        mut self,
+        previous: ParserReturn<'a>,
+        edits: &[TextEdit],
+    ) -> ParserReturn<'a> {
+        let Some(range) = self.changed_range(&previous, edits) else {
+            return self.parse();
+        };
+        let ParserReturn {
+            program: previous_program,
+            module_record: previous_module_record,
+            irregular_whitespaces: previous_irregular_whitespaces,
+            tokens: previous_tokens,
+            ..
+        } = previous;
+        let Program { hashbang, mut directives, body, comments: previous_comments, .. } =
+            previous_program;
+
+        let first_changed = body.partition_point(|stmt| stmt.span().end < range.start);
+        if first_changed == 0 {
+            return self.parse();
+        }
+        let first_unchanged = body.partition_point(|stmt| stmt.span().start < range.old_end);
+        // The end of the statement before the changed text may depend on it, e.g. ASI in
+        // `a \n (b)`, so it is parsed again.
+        let head_len = first_changed - 1;
+        let region_start = body[head_len].span().start;
+        if !self.lexer.seek(region_start) {
+            return self.parse();
+        }
+
+        let delta = range.delta();
+        let shift = |offset: u32| -> i64 { i64::from(offset) + delta };
+
+        // Statements before the changed text
+        let mut statements = self.ast.vec_with_capacity(body.len());
+        let mut previous_statements = body.into_iter();
+        for stmt in previous_statements.by_ref().take(head_len) {
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.module_record_builder.visit_module_declaration(module_decl);
+            }
+            statements.push(stmt);
+        }
+        for dynamic_import in &previous_module_record.dynamic_imports {
+            if dynamic_import.span.end <= region_start {
+                self.module_record_builder.add_dynamic_import(*dynamic_import);
+            }
+        }
+        for span in &previous_module_record.import_metas {
+            if span.end <= region_start {
+                self.module_record_builder.visit_import_meta(*span);
+            }
+        }
+
+        // Parse statements until the parser arrives at the start of an unchanged statement
+        let mut previous_statements =
+            previous_statements.skip(first_unchanged - head_len).peekable();
+        let mut expecting_directives = statements.is_empty();
+        let mut synced = false;
+        self.bump_any();
+        while !self.has_fatal_error() {
+            if !expecting_directives {
+                let start = i64::from(self.start_span());
+                // Skip previous statements which have been parsed again.
+                let is_behind = |stmt: &Statement<'a>| shift(stmt.span().start) < start;
+                while previous_statements.next_if(is_behind).is_some() {}
+                if previous_statements.peek().is_some_and(|stmt| shift(stmt.span().start) == start)
+                {
+                    synced = true;
+                    break;
+                }
+            }
+            let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+            if let Some(module_decl) = stmt.as_module_declaration() {
+                self.module_record_builder.visit_module_declaration(module_decl);
+            }
+            if expecting_directives {
+                if let Some(directive) = self.statement_to_directive(&stmt) {
+                    directives.push(directive);
+                    continue;
+                }
+                expecting_directives = false;
+            }
+            statements.push(stmt);
+        }
+
+        // Statements after the changed text
+        #[expect(clippy::cast_possible_truncation)]
+        let source_len = self.source_text.len() as u32;
+        let (previous_sync_start, sync_start) = match previous_statements.peek() {
+            Some(stmt) if synced => (stmt.span().start, self.start_span()),
+            _ => (u32::MAX, source_len),
+        };
+        if synced {
+            let mut shifter = SpanShifter::new(self.ast.allocator, delta);
+            for mut stmt in previous_statements {
+                if delta != 0 {
+                    shifter.visit_statement(&mut stmt);
+                }
+                if let Some(module_decl) = stmt.as_module_declaration() {
+                    self.module_record_builder.visit_module_declaration(module_decl);
+                }
+                statements.push(stmt);
+            }
+            for dynamic_import in &previous_module_record.dynamic_imports {
+                if dynamic_import.span.start >= previous_sync_start {
+                    let mut dynamic_import = *dynamic_import;
+                    shifter.visit_span(&mut dynamic_import.span);
+                    shifter.visit_span(&mut dynamic_import.module_request);
+                    self.module_record_builder.add_dynamic_import(dynamic_import);
+                }
+            }
+            for span in &previous_module_record.import_metas {
+                if span.start >= previous_sync_start {
+                    let mut span = *span;
+                    shifter.visit_span(&mut span);
+                    self.module_record_builder.visit_import_meta(span);
+                }
+            }
+        }
+
+        // Merge trivia of reused statements with trivia collected by the lexer
+        let shifter = SpanShifter::new(self.ast.allocator, delta);
+        let trivia_builder = &mut self.lexer.trivia_builder;
+        let comments = std::mem::take(&mut trivia_builder.comments);
+        trivia_builder.comments = previous_comments
+            .iter()
+            .copied()
+            .filter(|comment| comment.span.end <= region_start)
+            .chain(comments.into_iter().filter(|comment| comment.span.start < sync_start))
+            .chain(
+                previous_comments
+                    .iter()
+                    .copied()
+                    .filter(|comment| comment.span.start >= previous_sync_start)
+                    .map(|mut comment| {
+                        comment.span = shifter.shift_span(comment.span);
+                        if comment.is_leading() {
+                            comment.attached_to = shifter.shift(comment.attached_to);
+                        }
+                        comment
+                    }),
+            )
+            .collect();
+        let irregular_whitespaces = std::mem::take(&mut trivia_builder.irregular_whitespaces);
+        trivia_builder.irregular_whitespaces = previous_irregular_whitespaces
+            .iter()
+            .copied()
+            .filter(|span| span.end <= region_start)
+            .chain(irregular_whitespaces.into_iter().filter(|span| span.start < sync_start))
+            .chain(
+                previous_irregular_whitespaces
+                    .iter()
+                    .filter(|span| span.start >= previous_sync_start)
+                    .map(|span| shifter.shift_span(*span)),
+            )
+            .collect();
+        if self.options.collect_tokens {
+            let tokens = std::mem::take(&mut self.tokens);
+            self.tokens = previous_tokens
+                .iter()
+                .copied()
+                .filter(|token| token.span.end <= region_start)
+                .chain(tokens.into_iter().filter(|token| token.span.start < sync_start))
+                .chain(
+                    previous_tokens
+                        .iter()
+                        .filter(|token| token.span.start >= previous_sync_start)
+                        .map(|token| Token {
+                            kind: token.kind,
+                            span: shifter.shift_span(token.span),
+                        }),
+                )
+                .collect();
+        }
+
+        let span = Span::new(0, source_len);
+        let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
+        let program = self.ast.program(
+            span,
+            self.source_type,
+            self.source_text,
+            comments,
+            hashbang,
+            directives,
+            statements,
+        );
+        self.finish(program)
+    }
+
+    /// Get the range of source text changed by `edits`.
+    ///
+    /// Returns `None` if `previous` cannot be reused, because it has errors, was parsed without
+    /// collecting tokens, or the text outside of the changed range does not match the current source text.
+    fn changed_range(
+        &self,
+        previous: &ParserReturn<'a>,
+        edits: &[TextEdit],
+    ) -> Option<ChangedRange> {
+        // Errors cannot be moved to the new source text.
+        if previous.panicked || !previous.errors.is_empty() {
+            return None;
+        }
+        if self.options.collect_tokens
+            && previous.tokens.is_empty()
+            && !previous.program.source_text.trim_start().is_empty()
+        {
+            return None;
+        }
+        let range = ChangedRange::from_edits(edits)?;
+        let previous_text = previous.program.source_text;
+        let (start, old_end, new_end) =
+            (range.start as usize, range.old_end as usize, range.new_end as usize);
+        let unchanged = old_end <= previous_text.len()
+            && new_end <= self.source_text.len()
+            && previous_text.len() - old_end == self.source_text.len() - new_end
+            && previous_text.as_bytes()[..start] == self.source_text.as_bytes()[..start]
+            && previous_text.as_bytes()[old_end..] == self.source_text.as_bytes()[new_end..];
+        unchanged.then_some(range)
+    }
+}
+
+/// Moves spans of reused AST nodes after the changed text to their position in the new source text.
+struct SpanShifter<'a> {
+    #[cfg_attr(not(feature = "regular_expression"), expect(dead_code))]
+    allocator: &'a Allocator,
+    delta: i64,
+}
+
+impl<'a> SpanShifter<'a> {
+    fn new(allocator: &'a Allocator, delta: i64) -> Self {
+        Self { allocator, delta }
+    }
+
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift(&self, offset: u32) -> u32 {
+        (i64::from(offset) + self.delta) as u32
+    }
+
+    fn shift_span(&self, span: Span) -> Span {
+        Span::new(self.shift(span.start), self.shift(span.end))
+    }
+}
+
+impl<'a> VisitMut<'a> for SpanShifter<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.shift_span(*span);
+    }
+
+    fn visit_reg_exp_literal(&mut self, it: &mut RegExpLiteral<'a>) {
+        walk_mut::walk_reg_exp_literal(self, it);
+        // Spans in the parsed pattern are not visited, so parse it again at its new position.
+        #[cfg(feature = "regular_expression")]
+        if let (Some(_), Some(raw)) = (&it.regex.pattern.pattern, it.raw) {
+            use oxc_allocator::Box;
+            use oxc_regular_expression::{LiteralParser, Options};
+            let pattern = it.regex.pattern.text.as_str();
+            let pattern_span_offset = it.span.start + 1;
+            #[expect(clippy::cast_possible_truncation)]
+            let flags_span_offset = pattern_span_offset + pattern.len() as u32 + 1;
+            let flags = &raw.as_str()[pattern.len() + 2..];
+            let options = Options { pattern_span_offset, flags_span_offset };
+            let parser = LiteralParser::new(self.allocator, pattern, Some(flags), options);
+            it.regex.pattern.pattern =
+                parser.parse().ok().map(|pattern| Box::new_in(pattern, self.allocator));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::{Allocator, GetAddress};
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_span::{SourceType, Span};
+    use rustc_hash::FxHashSet;
+
+    use super::TextEdit;
+    use crate::{ParseOptions, Parser, ParserReturn};
+
+    /// Apply `edits` (span in the text after the previous edits, replacement) to `source_text`,
+    /// and check that incremental parsing produces the same result as a full parse.
+    /// Returns the number of reused statements.
+    fn check(source_text: &str, edits: &[(Span, &str)]) -> usize {
+        check_with_source_type(source_text, edits, SourceType::mjs())
+    }
+
+    fn check_with_source_type(
+        source_text: &str,
+        edits: &[(Span, &str)],
+        source_type: SourceType,
+    ) -> usize {
+        let options = ParseOptions {
+            parse_regular_expression: true,
+            collect_tokens: true,
+            ..ParseOptions::default()
+        };
+        let mut new_text = source_text.to_string();
+        let mut text_edits = vec![];
+        for (span, text) in edits {
+            new_text.replace_range(span.start as usize..span.end as usize, text);
+            text_edits.push(TextEdit::new(*span, u32::try_from(text.len()).unwrap()));
+        }
+
+        let allocator = Allocator::default();
+        let previous =
+            Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+        let addresses =
+            previous.program.body.iter().map(GetAddress::address).collect::<FxHashSet<_>>();
+        let ret = Parser::new(&allocator, &new_text, source_type)
+            .with_options(options)
+            .parse_incremental(previous, &text_edits);
+
+        let full_allocator = Allocator::default();
+        let expected =
+            Parser::new(&full_allocator, &new_text, source_type).with_options(options).parse();
+        assert_same(&ret, &expected, &new_text);
+
+        ret.program.body.iter().filter(|stmt| addresses.contains(&stmt.address())).count()
+    }
+
+    fn offset(source_text: &str, pat: &str) -> u32 {
+        u32::try_from(source_text.find(pat).unwrap()).unwrap()
+    }
+
+    fn assert_same(ret: &ParserReturn, expected: &ParserReturn, source_text: &str) {
+        assert_eq!(
+            ret.program.to_pretty_estree_ts_json(false),
+            expected.program.to_pretty_estree_ts_json(false),
+            "{source_text}"
+        );
+        assert_eq!(ret.program.comments, expected.program.comments, "{source_text}");
+        assert_eq!(ret.program.source_type, expected.program.source_type, "{source_text}");
+        assert_eq!(ret.irregular_whitespaces, expected.irregular_whitespaces, "{source_text}");
+        assert_eq!(ret.tokens, expected.tokens, "{source_text}");
+        assert_eq!(ret.errors.len(), expected.errors.len(), "{source_text}");
+        assert_eq!(ret.panicked, expected.panicked, "{source_text}");
+        let (record, expected_record) = (&ret.module_record, &expected.module_record);
+        assert_eq!(record.has_module_syntax, expected_record.has_module_syntax);
+        assert_eq!(record.import_entries, expected_record.import_entries);
+        assert_eq!(record.local_export_entries, expected_record.local_export_entries);
+        assert_eq!(record.indirect_export_entries, expected_record.indirect_export_entries);
+        assert_eq!(record.star_export_entries, expected_record.star_export_entries);
+        assert_eq!(
+            format!("{:?}", record.dynamic_imports),
+            format!("{:?}", expected_record.dynamic_imports)
+        );
+        assert_eq!(record.import_metas, expected_record.import_metas);
+    }
+
+    const SOURCE: &str = "\
+#!/usr/bin/env node
+'use strict';
+// leading comment
+import a from 'a';
+let x = 1; /* trailing */
+function foo(y) {
+  return y + x;
+}
+const re = /a(b)c/u;
+export const lazy = () => import('./lazy');
+console.log(import.meta.url, `template ${x}`);
+// final comment
+export { foo };
+";
+
+    #[test]
+    fn replace_in_middle() {
+        // `return y + x;` -> `return y * x;`
+        let start = offset(SOURCE, "y + x") + 2;
+        let reused = check(SOURCE, &[(Span::new(start, start + 1), "*")]);
+        assert_eq!(reused, 5);
+        // `let x = 1;` -> `let x = 1000;`
+        let start = offset(SOURCE, "1;");
+        let reused = check(SOURCE, &[(Span::new(start, start + 1), "1000")]);
+        assert_eq!(reused, 5);
+    }
+
+    #[test]
+    fn insert_and_delete_statements() {
+        let start = offset(SOURCE, "function");
+        check(SOURCE, &[(Span::empty(start), "if (x) { x++ }\n/* inserted */\n")]);
+        let end = offset(SOURCE, "const re");
+        let reused = check(SOURCE, &[(Span::new(start, end), "")]);
+        assert_eq!(reused, 5);
+        // Append at end of file
+        let end = u32::try_from(SOURCE.len()).unwrap();
+        check(SOURCE, &[(Span::empty(end), "export default 1;\n")]);
+    }
+
+    #[test]
+    fn multiple_edits() {
+        let first = offset(SOURCE, "return");
+        let edits = [
+            (Span::new(first, first + 6), "return void"),
+            (Span::empty(first - 10), "  // new comment\n"),
+            (Span::empty(first + 30), "\u{a0}"),
+        ];
+        check(SOURCE, &edits);
+    }
+
+    #[test]
+    fn statement_boundaries_change() {
+        // `foo \n ;[1].map(f)` -> `foo \n [1].map(f)` is a single statement
+        let source = "x;\nfoo\n;[1].map(f);\nbar;\n";
+        let start = offset(source, ";[");
+        check(source, &[(Span::new(start, start + 1), "")]);
+        // Unterminated comment and template
+        let start = offset(SOURCE, "const re");
+        check(SOURCE, &[(Span::empty(start), "/* ")]);
+        check(SOURCE, &[(Span::empty(start), "`${")]);
+        check(SOURCE, &[(Span::empty(start), "{")]);
+    }
+
+    #[test]
+    fn regexp_pattern_is_moved() {
+        let start = offset(SOURCE, "x = 1");
+        let source_type = SourceType::mjs();
+        let options = ParseOptions { parse_regular_expression: true, ..ParseOptions::default() };
+        let mut new_text = SOURCE.to_string();
+        new_text.replace_range(start as usize..start as usize + 5, "x = 12345");
+        let allocator = Allocator::default();
+        let previous = Parser::new(&allocator, SOURCE, source_type).with_options(options).parse();
+        let ret = Parser::new(&allocator, &new_text, source_type)
+            .with_options(options)
+            .parse_incremental(previous, &[TextEdit::new(Span::new(start, start + 5), 9)]);
+        let regexp = ret
+            .program
+            .body
+            .iter()
+            .find_map(|stmt| match stmt {
+                Statement::VariableDeclaration(decl) => match &decl.declarations[0].init {
+                    Some(Expression::RegExpLiteral(regexp)) => Some(regexp),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .unwrap();
+        let pattern = regexp.regex.pattern.pattern.as_ref().unwrap();
+        assert_eq!(pattern.span.source_text(&new_text), "a(b)c");
+    }
+
+    #[test]
+    fn fallback_to_full_parse() {
+        // Change in first statement
+        let start = offset(SOURCE, "import a");
+        assert_eq!(check(SOURCE, &[(Span::new(start + 7, start + 8), "b")]), 0);
+        // Previous parse has errors
+        let source = "let x = 1;\nlet = ;\nlet y = 2;\n";
+        let start = offset(source, "2");
+        assert_eq!(check(source, &[(Span::new(start, start + 1), "3")]), 0);
+        // TypeScript
+        let source = "type A = string;\nenum B { C }\nlet d: A = '';\n";
+        let start = offset(source, "C");
+        check_with_source_type(source, &[(Span::new(start, start + 1), "D = 1")], SourceType::ts());
+    }
+}
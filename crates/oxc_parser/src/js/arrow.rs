@@ -4,7 +4,7 @@ use oxc_span::GetSpan;
 use oxc_syntax::precedence::Precedence;
 
 use super::{FunctionKind, Tristate};
-use crate::{ParserImpl, diagnostics, lexer::Kind};
+use crate::{Context, ParserImpl, diagnostics, lexer::Kind};
 
 struct ArrowFunctionHead<'a> {
     type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
@@ -133,6 +133,8 @@ impl<'a> ParserImpl<'a> {
                         }
 
                         match third {
+                            // In Flow, "(a:" can also be a type cast `(a: T)`.
+                            Kind::Colon if self.is_flow => Tristate::Maybe,
                             // If we have something like "(a:", then we must have a
                             // type-annotated parameter in an arrow function expression.
                             Kind::Colon => Tristate::True,
@@ -280,7 +282,9 @@ impl<'a> ParserImpl<'a> {
         }
 
         let has_return_colon = self.is_ts && self.at(Kind::Colon);
-        let return_type = self.parse_ts_return_type_annotation(Kind::Arrow, false);
+        let return_type = self.context(Context::NoAnonFunctionType, Context::empty(), |p| {
+            p.parse_ts_return_type_annotation(Kind::Arrow, false)
+        });
 
         self.ctx = self.ctx.and_await(has_await);
 
@@ -278,6 +278,11 @@ impl<'a> ParserImpl<'a> {
             );
         }
 
+        if self.is_flow {
+            // `+foo: T`
+            self.eat_flow_variance();
+        }
+
         let kind = self.cur_kind();
         if kind.is_identifier_or_keyword() || kind == Kind::Star || kind == Kind::LBrack {
             let is_ambient = modifiers.contains(ModifierKind::Declare);
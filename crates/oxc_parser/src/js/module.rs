@@ -125,6 +125,15 @@ impl<'a> ParserImpl<'a> {
                     }
                 }
             }
+        } else if self.is_flow && token_after_import.kind() == Kind::Typeof {
+            // `import typeof ...`
+            self.bump_any(); // bump `typeof`
+            import_kind = ImportOrExportKind::Type;
+            if self.cur_kind().is_binding_identifier() {
+                // `import typeof something ...`
+                identifier_after_import = Some(self.parse_binding_identifier());
+                has_default_specifier = true;
+            }
         } else if token_after_import.kind() == Kind::Defer && self.at(Kind::Star) {
             // `import defer * ...`
             phase = Some(ImportPhase::Defer);
@@ -783,6 +792,14 @@ impl<'a> ParserImpl<'a> {
                 check_identifier_token = self.cur_token();
                 name = self.parse_module_export_name();
             }
+        } else if self.is_flow
+            && type_or_name_token_kind == Kind::Typeof
+            && self.can_parse_module_export_name()
+        {
+            // { typeof something ...? }
+            kind = ImportOrExportKind::Type;
+            check_identifier_token = self.cur_token();
+            name = self.parse_module_export_name();
         }
 
         if can_parse_as_keyword && self.eat(Kind::As) {
@@ -49,22 +49,10 @@ impl<'a> ParserImpl<'a> {
                 }
             }
 
-            // Section 11.2.1 Directive Prologue
-            // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
-            // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/v7.26.2/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
             if expecting_directives {
-                if let Statement::ExpressionStatement(expr) = &stmt {
-                    if let Expression::StringLiteral(string) = &expr.expression {
-                        // span start will mismatch if they are parenthesized when `preserve_parens = false`
-                        if expr.span.start == string.span.start {
-                            let src = &self.source_text
-                                [string.span.start as usize + 1..string.span.end as usize - 1];
-                            let directive =
-                                self.ast.directive(expr.span, (*string).clone(), Atom::from(src));
-                            directives.push(directive);
-                            continue;
-                        }
-                    }
+                if let Some(directive) = self.statement_to_directive(&stmt) {
+                    directives.push(directive);
+                    continue;
                 }
                 expecting_directives = false;
             }
@@ -74,6 +62,20 @@ impl<'a> ParserImpl<'a> {
         (directives, statements)
     }
 
+    /// Section 11.2.1 Directive Prologue
+    /// The only way to get a correct directive is to parse the statement first and check if it is a string literal.
+    /// All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/v7.26.2/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
+    pub(crate) fn statement_to_directive(&self, stmt: &Statement<'a>) -> Option<Directive<'a>> {
+        let Statement::ExpressionStatement(expr) = stmt else { return None };
+        let Expression::StringLiteral(string) = &expr.expression else { return None };
+        // span start will mismatch if they are parenthesized when `preserve_parens = false`
+        if expr.span.start != string.span.start {
+            return None;
+        }
+        let src = &self.source_text[string.span.start as usize + 1..string.span.end as usize - 1];
+        Some(self.ast.directive(expr.span, (*string).clone(), Atom::from(src)))
+    }
+
     /// `StatementListItem`[Yield, Await, Return] :
     ///     Statement[?Yield, ?Await, ?Return]
     ///     Declaration[?Yield, ?Await]
@@ -122,6 +124,9 @@ impl<'a> ParserImpl<'a> {
             Kind::Const => self.parse_const_statement(stmt_ctx),
             Kind::Using if self.is_using_declaration() => self.parse_using_statement(),
             Kind::Await if self.is_using_statement() => self.parse_using_statement(),
+            Kind::Ident | Kind::Declare if self.is_flow && self.at_start_of_flow_declaration() => {
+                self.parse_flow_declaration_statement()
+            }
             Kind::Interface
             | Kind::Type
             | Kind::Module
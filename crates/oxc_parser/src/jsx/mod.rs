@@ -4,7 +4,7 @@ use oxc_allocator::{Box, Dummy, Vec};
 use oxc_ast::ast::*;
 use oxc_span::{Atom, GetSpan, Span};
 
-use crate::{Context, ParserImpl, diagnostics, lexer::Kind};
+use crate::{Context, ParserImpl, TokenKind, diagnostics, lexer::Kind};
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_jsx_expression(&mut self) -> Expression<'a> {
@@ -395,6 +395,8 @@ impl<'a> ParserImpl<'a> {
         match self.cur_kind() {
             Kind::Str => {
                 let str_lit = self.parse_literal_string();
+                // Same as espree and typescript-estree
+                self.set_last_token_kind(TokenKind::JSXText);
                 JSXAttributeValue::StringLiteral(self.alloc(str_lit))
             }
             Kind::LCurly => {
@@ -426,6 +428,7 @@ impl<'a> ParserImpl<'a> {
         // Currently at a valid normal Ident or Keyword, keep on lexing for `-` in `<component-name />`
         self.continue_lex_jsx_identifier();
         self.bump_any();
+        self.set_last_token_kind(TokenKind::JSXIdentifier);
         let span = self.end_span(span);
         let name = span.source_text(self.source_text);
         self.ast.jsx_identifier(span, name)
@@ -174,6 +174,16 @@ impl<'a> Lexer<'a> {
         token
     }
 
+    /// Move source cursor to `offset`, to start lexing from the middle of the source text.
+    ///
+    /// Returns `false` if `offset` is out of bounds, or is not on a UTF-8 character boundary.
+    pub fn seek(&mut self, offset: u32) -> bool {
+        let Some(position) = self.source.position_at_offset(offset) else { return false };
+        self.source.set_position(position);
+        self.token = Token::new_on_new_line();
+        true
+    }
+
     /// Advance source cursor to end of file.
     #[inline]
     pub fn advance_to_end(&mut self) {
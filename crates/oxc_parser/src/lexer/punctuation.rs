@@ -58,8 +58,9 @@ impl Lexer<'_> {
         }
     }
 
+    /// Re-lex the current `>` token, which has already been consumed, as `>`, `>>`, `>>>`, `>=`, `>>=` or `>>>=`.
     pub(crate) fn re_lex_right_angle(&mut self) -> Token {
-        self.token.set_start(self.offset());
+        self.token.set_start(self.offset() - 1);
         let kind = self.read_right_angle();
         self.finish_next(kind)
     }
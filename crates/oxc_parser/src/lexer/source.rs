@@ -190,6 +190,19 @@ impl<'a> Source<'a> {
         self.ptr = pos.ptr;
     }
 
+    /// Get [`SourcePosition`] for `offset` bytes from start of source.
+    ///
+    /// Returns `None` if `offset` is out of bounds, or is not on a UTF-8 character boundary.
+    pub(super) fn position_at_offset(&self, offset: u32) -> Option<SourcePosition<'a>> {
+        let offset = offset as usize;
+        if !self.whole().is_char_boundary(offset) {
+            return None;
+        }
+        // SAFETY: `offset` is within bounds of source text (`is_char_boundary` returns `false`
+        // if it's not), and on a UTF-8 character boundary
+        Some(unsafe { SourcePosition::new(self.start.add(offset)) })
+    }
+
     /// Advance `Source`'s cursor to end.
     #[inline]
     pub(super) fn advance_to_end(&mut self) {
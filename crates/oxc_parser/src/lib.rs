@@ -69,10 +69,14 @@
 mod context;
 mod cursor;
 mod error_handler;
+mod incremental;
 mod modifiers;
 mod module_record;
 mod state;
+mod template;
+mod tokens;
 
+mod flow;
 mod js;
 mod jsx;
 mod ts;
@@ -86,10 +90,10 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
+use oxc_allocator::{Allocator, Box as ArenaBox, Dummy, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
-    ast::{Expression, Program},
+    ast::{Expression, Program, Statement, TSType},
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{ModuleKind, SourceType, Span};
@@ -98,10 +102,15 @@ use oxc_syntax::module_record::ModuleRecord;
 use crate::{
     context::{Context, StatementContext},
     error_handler::FatalError,
-    lexer::{Lexer, Token},
+    lexer::{Kind, Lexer},
     module_record::ModuleRecordBuilder,
     state::ParserState,
 };
+pub use crate::{
+    incremental::TextEdit,
+    template::{Template, TemplateValue},
+    tokens::{Token, TokenKind},
+};
 
 /// Maximum length of source which can be parsed (in bytes).
 /// ~4 GiB on 64-bit systems, ~2 GiB on 32-bit systems.
@@ -168,6 +177,13 @@ pub struct ParserReturn<'a> {
     /// Irregular whitespaces for `Oxlint`
     pub irregular_whitespaces: Box<[Span]>,
 
+    /// Tokens of the source text, in source order.
+    ///
+    /// Only collected when [`ParseOptions::collect_tokens`] is enabled, empty otherwise.
+    /// Does not include comments.
+    /// If the parser panicked, only contains the tokens before the fatal error.
+    pub tokens: Vec<Token>,
+
     /// Whether the parser panicked and terminated early.
     ///
     /// This will be `false` if parsing was successful, or if parsing was able to recover from a
@@ -221,6 +237,27 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Parse [Flow](https://flow.org) syntax in JavaScript files.
+    ///
+    /// Flow type annotations, `type` / `opaque type` declarations, `import type` and type casts
+    /// are represented by their TypeScript counterparts (e.g. a type cast `(x: T)` is parsed into
+    /// a [`TSAsExpression`]), so they can be removed by the TypeScript annotation stripping
+    /// in `oxc_transformer`. Has no effect on TypeScript files.
+    ///
+    /// Default: `false`
+    ///
+    /// [`TSAsExpression`]: oxc_ast::ast::TSAsExpression
+    pub flow: bool,
+
+    /// Collect all tokens into [`ParserReturn::tokens`], e.g. for ESTree `tokens` arrays
+    /// consumed by ESLint-based tools.
+    ///
+    /// Template literals are split into their template parts,
+    /// and regular expressions are a single token.
+    ///
+    /// Default: `false`
+    pub collect_tokens: bool,
 }
 
 impl Default for ParseOptions {
@@ -231,6 +268,8 @@ impl Default for ParseOptions {
             allow_return_outside_function: false,
             preserve_parens: true,
             allow_v8_intrinsics: false,
+            flow: false,
+            collect_tokens: false,
         }
     }
 }
@@ -318,6 +357,52 @@ mod parser_parse {
             parser.parse()
         }
 
+        /// Parse the source text after it has been edited, reusing the unchanged top-level
+        /// statements of `previous`, the result of parsing the source text before the edits.
+        ///
+        /// Each of the `edits` is relative to the source text produced by the edits before it.
+        /// The source text passed to [`Parser::new`] must be the result of applying all `edits`,
+        /// and the [`SourceType`] and [`ParseOptions`] must be the same as for `previous`.
+        ///
+        /// Only the top-level statements around the changed text are parsed again. Other statements
+        /// are moved from `previous.program` into the returned program, with the spans of statements
+        /// after the changed text moved to their new position. `previous.program` must not have been
+        /// modified after parsing.
+        ///
+        /// Parses the entire source text if `previous` has errors, or the first statement is changed.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::{Parser, TextEdit};
+        /// use oxc_span::{SourceType, Span};
+        ///
+        /// let allocator = Allocator::new();
+        /// let source_type = SourceType::mjs();
+        /// let previous = Parser::new(&allocator, "let a = 1;\nlet b = 2;\nlet c = 3;", source_type).parse();
+        /// // Replace `2` with `20`
+        /// let edits = [TextEdit::new(Span::new(19, 20), 2)];
+        /// let ret = Parser::new(&allocator, "let a = 1;\nlet b = 20;\nlet c = 3;", source_type)
+        ///     .parse_incremental(previous, &edits);
+        /// assert!(ret.errors.is_empty());
+        /// ```
+        pub fn parse_incremental(
+            self,
+            previous: ParserReturn<'a>,
+            edits: &[TextEdit],
+        ) -> ParserReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_incremental(previous, edits)
+        }
+
         /// Parse a single [`Expression`].
         ///
         /// # Example
@@ -348,6 +433,113 @@ mod parser_parse {
             );
             parser.parse_expression()
         }
+
+        /// Parse a list of [`Statement`]s, e.g. to build AST fragments for splicing into a [`Program`].
+        ///
+        /// Module declarations are parsed as statements and are not recorded in a [`ModuleRecord`].
+        /// Directive prologues are not recognized, i.e. `"use strict"` is parsed as an [`ExpressionStatement`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let statements = Parser::new(&allocator, "let x = 1; x++;", SourceType::mjs())
+        ///     .parse_statements()
+        ///     .unwrap();
+        /// assert_eq!(statements.len(), 2);
+        /// ```
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors.
+        ///
+        /// [`ExpressionStatement`]: oxc_ast::ast::ExpressionStatement
+        pub fn parse_statements(self) -> Result<ArenaVec<'a, Statement<'a>>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_statements()
+        }
+
+        /// Parse a single module item, which is an import or export declaration or a [`Statement`].
+        ///
+        /// The source text is always parsed as module code, regardless of [`SourceType`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::Statement;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let item = Parser::new(&allocator, "import { a } from 'a';", SourceType::default())
+        ///     .parse_module_item()
+        ///     .unwrap();
+        /// assert!(matches!(item, Statement::ImportDeclaration(_)));
+        /// ```
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors, or contains more than one module item.
+        pub fn parse_module_item(self) -> Result<Statement<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                self.source_type.with_module(true),
+                self.options,
+                unique,
+            );
+            parser.parse_module_item()
+        }
+
+        /// Parse a single TypeScript type, e.g. `Array<string> | undefined`.
+        ///
+        /// The source text is parsed as TypeScript regardless of [`SourceType`],
+        /// or as Flow if [`ParseOptions::flow`] is enabled.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::TSType;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let allocator = Allocator::new();
+        /// let ty = Parser::new(&allocator, "string | number", SourceType::ts())
+        ///     .parse_type_annotation()
+        ///     .unwrap();
+        /// assert!(matches!(ty, TSType::TSUnionType(_)));
+        /// ```
+        ///
+        /// # Errors
+        /// If the source code being parsed has syntax errors.
+        pub fn parse_type_annotation(self) -> Result<TSType<'a>, Vec<OxcDiagnostic>> {
+            let unique = UniquePromise::new();
+            let source_type = if self.options.flow {
+                self.source_type
+            } else {
+                self.source_type.with_typescript(true)
+            };
+            let parser = ParserImpl::new(
+                self.allocator,
+                self.source_text,
+                source_type,
+                self.options,
+                unique,
+            );
+            parser.parse_type_annotation()
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -372,7 +564,7 @@ struct ParserImpl<'a> {
     fatal_error: Option<FatalError>,
 
     /// The current parsing token
-    token: Token,
+    token: lexer::Token,
 
     /// The end range of the previous token
     prev_token_end: u32,
@@ -391,6 +583,12 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Parsing Flow syntax. Implies `is_ts`.
+    is_flow: bool,
+
+    /// Consumed tokens, if `options.collect_tokens` is enabled
+    tokens: Vec<Token>,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -406,6 +604,7 @@ impl<'a> ParserImpl<'a> {
         options: ParseOptions,
         unique: UniquePromise,
     ) -> Self {
+        let is_flow = options.flow && source_type.is_javascript();
         Self {
             options,
             lexer: Lexer::new(allocator, source_text, source_type, unique),
@@ -413,13 +612,15 @@ impl<'a> ParserImpl<'a> {
             source_text,
             errors: vec![],
             fatal_error: None,
-            token: Token::default(),
+            token: lexer::Token::default(),
             prev_token_end: 0,
             state: ParserState::new(),
             ctx: Self::default_context(source_type, options),
             ast: AstBuilder::new(allocator),
             module_record_builder: ModuleRecordBuilder::new(allocator),
-            is_ts: source_type.is_typescript(),
+            is_ts: source_type.is_typescript() || is_flow,
+            is_flow,
+            tokens: vec![],
         }
     }
 
@@ -429,7 +630,12 @@ impl<'a> ParserImpl<'a> {
     /// Recoverable errors are stored inside `errors`.
     #[inline]
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let mut program = self.parse_program();
+        let program = self.parse_program();
+        self.finish(program)
+    }
+
+    /// Collect errors and build the module record of a parsed `program`.
+    fn finish(mut self, mut program: Program<'a>) -> ParserReturn<'a> {
         let mut panicked = false;
 
         if let Some(fatal_error) = self.fatal_error.take() {
@@ -457,8 +663,10 @@ impl<'a> ParserImpl<'a> {
 
         let mut is_flow_language = false;
         let mut errors = vec![];
-        // only check for `@flow` if the file failed to parse.
-        if !self.lexer.errors.is_empty() || !self.errors.is_empty() {
+        if self.is_flow {
+            is_flow_language = self.has_flow_pragma();
+        } else if !self.lexer.errors.is_empty() || !self.errors.is_empty() {
+            // only check for `@flow` if the file failed to parse.
             if let Some(error) = self.flow_error() {
                 is_flow_language = true;
                 errors.push(error);
@@ -469,8 +677,8 @@ impl<'a> ParserImpl<'a> {
             errors.reserve(self.lexer.errors.len() + self.errors.len());
             errors.extend(self.lexer.errors);
             errors.extend(self.errors);
-            // Skip checking for exports in TypeScript {
-            if !self.source_type.is_typescript() {
+            // Skip checking for exports in TypeScript and Flow
+            if !self.is_ts {
                 errors.extend(module_record_errors);
             }
         }
@@ -493,6 +701,7 @@ impl<'a> ParserImpl<'a> {
             irregular_whitespaces,
             panicked,
             is_flow_language,
+            tokens: self.tokens,
         }
     }
 
@@ -511,6 +720,47 @@ impl<'a> ParserImpl<'a> {
         Ok(expr)
     }
 
+    pub fn parse_statements(mut self) -> Result<ArenaVec<'a, Statement<'a>>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let mut statements = self.ast.vec();
+        while !self.has_fatal_error() {
+            statements.push(self.parse_statement_list_item(StatementContext::StatementList));
+        }
+        self.finish_fragment(statements)
+    }
+
+    pub fn parse_module_item(mut self) -> Result<Statement<'a>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let stmt = self.parse_statement_list_item(StatementContext::StatementList);
+        self.finish_fragment(stmt)
+    }
+
+    pub fn parse_type_annotation(mut self) -> Result<TSType<'a>, Vec<OxcDiagnostic>> {
+        // initialize cur_token and prev_token by moving onto the first token
+        self.bump_any();
+        let ty = self.parse_ts_type();
+        self.finish_fragment(ty)
+    }
+
+    /// Return a parsed AST fragment, or all errors if any.
+    /// All source text must have been consumed.
+    fn finish_fragment<T>(mut self, node: T) -> Result<T, Vec<OxcDiagnostic>> {
+        if self.fatal_error.is_none() && !self.at(Kind::Eof) {
+            self.set_unexpected();
+        }
+        if let Some(FatalError { error, .. }) = self.fatal_error.take() {
+            return Err(vec![error]);
+        }
+        self.check_unfinished_errors();
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(node)
+    }
+
     #[expect(clippy::cast_possible_truncation)]
     fn parse_program(&mut self) -> Program<'a> {
         // initialize cur_token and prev_token by moving onto the first token
@@ -548,16 +798,20 @@ impl<'a> ParserImpl<'a> {
     /// Check for Flow declaration if the file cannot be parsed.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
     fn flow_error(&mut self) -> Option<OxcDiagnostic> {
-        if !self.source_type.is_javascript() {
+        if !self.source_type.is_javascript() || !self.has_flow_pragma() {
             return None;
         }
+        self.errors.clear();
         let span = self.lexer.trivia_builder.comments.first()?.span;
-        if span.source_text(self.source_text).contains("@flow") {
-            self.errors.clear();
-            Some(diagnostics::flow(span))
-        } else {
-            None
-        }
+        Some(diagnostics::flow(span))
+    }
+
+    fn has_flow_pragma(&self) -> bool {
+        self.lexer
+            .trivia_builder
+            .comments
+            .first()
+            .is_some_and(|comment| comment.span.source_text(self.source_text).contains("@flow"))
     }
 
     fn check_unfinished_errors(&mut self) {
@@ -613,6 +867,147 @@ mod test {
         assert!(matches!(expr, Expression::Identifier(_)));
     }
 
+    #[test]
+    fn parse_statements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "'use strict'; if (a) b(); function c() {}";
+        let stmts = Parser::new(&allocator, source, source_type).parse_statements().unwrap();
+        assert_eq!(stmts.len(), 3);
+        assert!(matches!(stmts[0], Statement::ExpressionStatement(_)));
+        assert!(matches!(stmts[1], Statement::IfStatement(_)));
+        assert!(matches!(stmts[2], Statement::FunctionDeclaration(_)));
+
+        let stmts = Parser::new(&allocator, "", source_type).parse_statements().unwrap();
+        assert!(stmts.is_empty());
+
+        let errors = Parser::new(&allocator, "a +", source_type).parse_statements().unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_module_item() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::cjs();
+        let item = Parser::new(&allocator, "export const a = 1;", source_type)
+            .parse_module_item()
+            .unwrap();
+        assert!(matches!(item, Statement::ExportNamedDeclaration(_)));
+        let item = Parser::new(&allocator, "a;", source_type).parse_module_item().unwrap();
+        assert!(matches!(item, Statement::ExpressionStatement(_)));
+
+        let errors = Parser::new(&allocator, "a; b;", source_type).parse_module_item().unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_type_annotation() {
+        let allocator = Allocator::default();
+        let ty = Parser::new(&allocator, "Array<string> | undefined", SourceType::mjs())
+            .parse_type_annotation()
+            .unwrap();
+        assert!(matches!(ty, TSType::TSUnionType(_)));
+
+        let options = ParseOptions { flow: true, ..ParseOptions::default() };
+        let ty = Parser::new(&allocator, "{| a: string |}", SourceType::mjs())
+            .with_options(options)
+            .parse_type_annotation()
+            .unwrap();
+        assert!(matches!(ty, TSType::TSTypeLiteral(_)));
+
+        let errors = Parser::new(&allocator, "string string", SourceType::ts())
+            .parse_type_annotation()
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn tokens() {
+        fn tokens(source_text: &str, source_type: SourceType) -> Vec<(&'static str, &str)> {
+            let allocator = Allocator::default();
+            let options = ParseOptions { collect_tokens: true, ..ParseOptions::default() };
+            let ret =
+                Parser::new(&allocator, source_text, source_type).with_options(options).parse();
+            assert!(ret.errors.is_empty(), "{source_text}");
+            ret.tokens
+                .iter()
+                .map(|token| (token.kind.as_str(), token.span.source_text(source_text)))
+                .collect()
+        }
+
+        assert_eq!(
+            tokens("#!/usr/bin/env node\nlet a = `x${1}y${/re/g}z` // comment", SourceType::mjs()),
+            [
+                ("Keyword", "let"),
+                ("Identifier", "a"),
+                ("Punctuator", "="),
+                ("Template", "`x${"),
+                ("Numeric", "1"),
+                ("Template", "}y${"),
+                ("RegularExpression", "/re/g"),
+                ("Template", "}z`"),
+            ]
+        );
+        assert_eq!(
+            tokens("async (x) => await x ?? null, this.#y, true", SourceType::mjs()),
+            [
+                ("Identifier", "async"),
+                ("Punctuator", "("),
+                ("Identifier", "x"),
+                ("Punctuator", ")"),
+                ("Punctuator", "=>"),
+                ("Identifier", "await"),
+                ("Identifier", "x"),
+                ("Punctuator", "??"),
+                ("Null", "null"),
+                ("Punctuator", ","),
+                ("Keyword", "this"),
+                ("Punctuator", "."),
+                ("PrivateIdentifier", "#y"),
+                ("Punctuator", ","),
+                ("Boolean", "true"),
+            ]
+        );
+        // Tokens of rewound lookahead are not duplicated
+        assert_eq!(
+            tokens("f<T>(x) as any", SourceType::ts()),
+            [
+                ("Identifier", "f"),
+                ("Punctuator", "<"),
+                ("Identifier", "T"),
+                ("Punctuator", ">"),
+                ("Punctuator", "("),
+                ("Identifier", "x"),
+                ("Punctuator", ")"),
+                ("Identifier", "as"),
+                ("Identifier", "any"),
+            ]
+        );
+        assert_eq!(
+            tokens("<a-b c=\"d\">e{f}</a-b>", SourceType::jsx()),
+            [
+                ("Punctuator", "<"),
+                ("JSXIdentifier", "a-b"),
+                ("JSXIdentifier", "c"),
+                ("Punctuator", "="),
+                ("JSXText", "\"d\""),
+                ("Punctuator", ">"),
+                ("JSXText", "e"),
+                ("Punctuator", "{"),
+                ("Identifier", "f"),
+                ("Punctuator", "}"),
+                ("Punctuator", "<"),
+                ("Punctuator", "/"),
+                ("JSXIdentifier", "a-b"),
+                ("Punctuator", ">"),
+            ]
+        );
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, "a", SourceType::mjs()).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
     #[test]
     fn flow_error() {
         let allocator = Allocator::default();
@@ -635,6 +1030,36 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let options = ParseOptions { flow: true, ..ParseOptions::default() };
+        let sources = [
+            "// @flow\nfunction foo(x: ?string, ...rest: Array<mixed>): void {}",
+            "const f = (x: number): (string => void) => (y) => {};",
+            "const a = (value: any); const b = test ? (c) : d;",
+            "type T = {| +a: string, [string]: number, ...Other |}; opaque type O: string = string;",
+            "import typeof T from 'a'; import { type A, typeof B, C } from 'b';",
+            "declare export function foo(): void; declare opaque type O;",
+            "class A<+T> { +a: T; static -b: number = 1; }",
+            "function is(x: mixed): boolean %checks { return !!x; }",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        }
+
+        let ret = Parser::new(&allocator, "/* @flow */ type A = string;", source_type)
+            .with_options(options)
+            .parse();
+        assert!(ret.is_flow_language);
+        assert!(ret.errors.is_empty());
+
+        let ret = Parser::new(&allocator, "enum E {}", source_type).with_options(options).parse();
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Flow enums are not supported");
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
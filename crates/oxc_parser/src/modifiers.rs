@@ -450,6 +450,8 @@ impl<'a> ParserImpl<'a> {
     fn can_follow_modifier(&self) -> bool {
         match self.cur_kind() {
             Kind::PrivateIdentifier | Kind::LBrack | Kind::LCurly | Kind::Star | Kind::Dot3 => true,
+            // Flow variance, e.g. `static +foo: T`
+            Kind::Plus | Kind::Minus => self.is_flow,
             kind => kind.is_identifier_or_keyword(),
         }
     }
@@ -0,0 +1,375 @@
+//! AST templates, similar to [`@babel/template`](https://babeljs.io/docs/babel-template).
+//!
+//! A template is a source snippet with `%%name%%` placeholders,
+//! which are replaced with [`TemplateValue`]s after parsing.
+
+use oxc_allocator::{Allocator, CloneIn, Vec as ArenaVec};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{Atom, SPAN, SourceType, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{ParseOptions, Parser, diagnostics};
+
+/// Placeholders are replaced with identifiers starting with this prefix before parsing.
+const PLACEHOLDER_PREFIX: &str = "__oxc_template__";
+
+/// A value substituted for a `%%name%%` placeholder in a [`Template`].
+pub enum TemplateValue<'a> {
+    /// Replaces a placeholder in expression position,
+    /// or an expression statement consisting only of the placeholder.
+    Expression(Expression<'a>),
+    /// Replaces an expression statement consisting only of the placeholder.
+    Statement(Statement<'a>),
+    /// Renames a placeholder used as an identifier, e.g. `let %%name%% = 1;` or `a.%%name%%`.
+    Identifier(Atom<'a>),
+}
+
+/// Build AST nodes from a source snippet with `%%name%%` placeholders.
+///
+/// Placeholders are only recognized in identifier, expression and statement positions.
+/// They must not appear in string literals, template literals or comments.
+///
+/// All nodes parsed from the template have the same span, [`SPAN`] by default.
+/// Substituted values are inserted as is.
+///
+/// # Example
+///
+/// ```rust
+/// use oxc_allocator::Allocator;
+/// use oxc_ast::AstBuilder;
+/// use oxc_parser::{Template, TemplateValue};
+/// use oxc_span::{SPAN, SourceType};
+///
+/// let allocator = Allocator::new();
+/// let ast = AstBuilder::new(&allocator);
+/// let value = ast.expression_string_literal(SPAN, "42", None);
+/// let statements = Template::new(&allocator, "const %%name%% = %%value%%;", SourceType::mjs())
+///     .statements([
+///         ("name", TemplateValue::Identifier(ast.atom("answer"))),
+///         ("value", TemplateValue::Expression(value)),
+///     ])
+///     .unwrap();
+/// assert_eq!(statements.len(), 1);
+/// ```
+pub struct Template<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_type: SourceType,
+    options: ParseOptions,
+    span: Span,
+}
+
+impl<'a> Template<'a> {
+    /// Create a template from `source_text`, which is parsed as `source_type`.
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        Self { allocator, source_text, source_type, options: ParseOptions::default(), span: SPAN }
+    }
+
+    /// Set parse options.
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the span of all nodes parsed from the template.
+    #[must_use]
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Build a single [`Expression`].
+    ///
+    /// # Errors
+    /// If the template has syntax errors, or placeholders and values do not match.
+    pub fn expression<I>(self, values: I) -> Result<Expression<'a>, Vec<OxcDiagnostic>>
+    where
+        I: IntoIterator<Item = (&'a str, TemplateValue<'a>)>,
+    {
+        let (parser, mut substitutor) = self.prepare(values)?;
+        let mut expr = parser.parse_expression()?;
+        substitutor.visit_expression(&mut expr);
+        substitutor.finish(expr)
+    }
+
+    /// Build a single [`Statement`], which may be an import or export declaration.
+    ///
+    /// # Errors
+    /// If the template has syntax errors, does not contain exactly one statement,
+    /// or placeholders and values do not match.
+    pub fn statement<I>(self, values: I) -> Result<Statement<'a>, Vec<OxcDiagnostic>>
+    where
+        I: IntoIterator<Item = (&'a str, TemplateValue<'a>)>,
+    {
+        let (parser, mut substitutor) = self.prepare(values)?;
+        let mut stmt = parser.parse_module_item()?;
+        substitutor.visit_statement(&mut stmt);
+        substitutor.finish(stmt)
+    }
+
+    /// Build a list of [`Statement`]s.
+    ///
+    /// # Errors
+    /// If the template has syntax errors, or placeholders and values do not match.
+    pub fn statements<I>(self, values: I) -> Result<ArenaVec<'a, Statement<'a>>, Vec<OxcDiagnostic>>
+    where
+        I: IntoIterator<Item = (&'a str, TemplateValue<'a>)>,
+    {
+        let (parser, mut substitutor) = self.prepare(values)?;
+        let mut stmts = parser.parse_statements()?;
+        substitutor.visit_statements(&mut stmts);
+        substitutor.finish(stmts)
+    }
+
+    fn prepare<I>(self, values: I) -> Result<(Parser<'a>, Substitutor<'a>), Vec<OxcDiagnostic>>
+    where
+        I: IntoIterator<Item = (&'a str, TemplateValue<'a>)>,
+    {
+        let source_text = self.replace_placeholders()?;
+        let parser =
+            Parser::new(self.allocator, source_text, self.source_type).with_options(self.options);
+        let values = values.into_iter().map(|(name, value)| (name, (value, false))).collect();
+        let substitutor = Substitutor {
+            ast: AstBuilder::new(self.allocator),
+            span: self.span,
+            values,
+            errors: vec![],
+        };
+        Ok((parser, substitutor))
+    }
+
+    /// Replace `%%name%%` with `__oxc_template__name`.
+    fn replace_placeholders(&self) -> Result<&'a str, Vec<OxcDiagnostic>> {
+        let mut source_text = String::with_capacity(self.source_text.len());
+        let mut rest = self.source_text;
+        while let Some(start) = rest.find("%%") {
+            source_text.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+            let name_len = after
+                .bytes()
+                .position(|b| !(b.is_ascii_alphanumeric() || b == b'_' || b == b'$'))
+                .unwrap_or(after.len());
+            if name_len == 0 || !after[name_len..].starts_with("%%") {
+                return Err(vec![diagnostics::invalid_template_placeholder(&rest[start..])]);
+            }
+            source_text.push_str(PLACEHOLDER_PREFIX);
+            source_text.push_str(&after[..name_len]);
+            rest = &after[name_len + 2..];
+        }
+        source_text.push_str(rest);
+        Ok(self.allocator.alloc_str(&source_text))
+    }
+}
+
+struct Substitutor<'a> {
+    ast: AstBuilder<'a>,
+    span: Span,
+    /// Values by placeholder name, and whether they have been used.
+    values: FxHashMap<&'a str, (TemplateValue<'a>, bool)>,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Substitutor<'a> {
+    fn finish<T>(mut self, node: T) -> Result<T, Vec<OxcDiagnostic>> {
+        let mut unused = self
+            .values
+            .iter()
+            .filter(|(_, (_, used))| !used)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        unused.sort_unstable();
+        self.errors.extend(unused.into_iter().map(diagnostics::unused_template_value));
+        if self.errors.is_empty() { Ok(node) } else { Err(self.errors) }
+    }
+
+    /// Look up the value for `name` if it is a placeholder.
+    /// Returns `None` if `name` is not a placeholder, or reports an error if it has no value.
+    fn value(&mut self, name: &str) -> Option<&TemplateValue<'a>> {
+        let name = name.strip_prefix(PLACEHOLDER_PREFIX)?;
+        let Some((value, used)) = self.values.get_mut(name) else {
+            self.errors.push(diagnostics::missing_template_value(name));
+            return None;
+        };
+        *used = true;
+        Some(value)
+    }
+
+    fn is_statement_placeholder(&self, name: &str) -> bool {
+        name.strip_prefix(PLACEHOLDER_PREFIX)
+            .and_then(|name| self.values.get(name))
+            .is_some_and(|(value, _)| matches!(value, TemplateValue::Statement(_)))
+    }
+
+    /// Rename an identifier if it is a placeholder for an identifier.
+    fn rename(&mut self, name: &mut Atom<'a>) {
+        match self.value(name) {
+            Some(TemplateValue::Identifier(new_name)) => *name = *new_name,
+            Some(_) => {
+                let error = diagnostics::invalid_template_value(
+                    &name[PLACEHOLDER_PREFIX.len()..],
+                    "an identifier",
+                );
+                self.errors.push(error);
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for Substitutor<'a> {
+    fn visit_span(&mut self, span: &mut Span) {
+        *span = self.span;
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        if let Statement::ExpressionStatement(expr_stmt) = stmt {
+            if let Expression::Identifier(ident) = &expr_stmt.expression {
+                let allocator = self.ast.allocator;
+                // Other values are substituted in `visit_expression`
+                if self.is_statement_placeholder(&ident.name) {
+                    if let Some(TemplateValue::Statement(value)) = self.value(&ident.name) {
+                        *stmt = value.clone_in(allocator);
+                        return;
+                    }
+                }
+            }
+        }
+        walk_mut::walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr {
+            let allocator = self.ast.allocator;
+            match self.value(&ident.name) {
+                Some(TemplateValue::Expression(value)) => {
+                    *expr = value.clone_in(allocator);
+                    return;
+                }
+                Some(TemplateValue::Statement(_)) => {
+                    let error = diagnostics::invalid_template_value(
+                        &ident.name[PLACEHOLDER_PREFIX.len()..],
+                        "an expression or an identifier",
+                    );
+                    self.errors.push(error);
+                    return;
+                }
+                Some(TemplateValue::Identifier(_)) | None => {}
+            }
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        self.rename(&mut ident.name);
+        walk_mut::walk_identifier_reference(self, ident);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        self.rename(&mut ident.name);
+        walk_mut::walk_binding_identifier(self, ident);
+    }
+
+    fn visit_identifier_name(&mut self, ident: &mut IdentifierName<'a>) {
+        self.rename(&mut ident.name);
+        walk_mut::walk_identifier_name(self, ident);
+    }
+
+    fn visit_label_identifier(&mut self, ident: &mut LabelIdentifier<'a>) {
+        self.rename(&mut ident.name);
+        walk_mut::walk_label_identifier(self, ident);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{AstBuilder, ast::*};
+    use oxc_span::{SPAN, SourceType, Span};
+
+    use super::{Template, TemplateValue};
+
+    #[test]
+    fn statements() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let span = Span::new(1, 2);
+        let value = ast.expression_string_literal(Span::new(3, 4), "foo", None);
+        let stmt = ast.statement_empty(SPAN);
+        let stmts = Template::new(
+            &allocator,
+            "const %%name%% = %%value%%; %%stmt%%; %%name%%.%%name%%(%%value%%);",
+            SourceType::mjs(),
+        )
+        .with_span(span)
+        .statements([
+            ("name", TemplateValue::Identifier(ast.atom("x"))),
+            ("value", TemplateValue::Expression(value)),
+            ("stmt", TemplateValue::Statement(stmt)),
+        ])
+        .unwrap();
+        assert_eq!(stmts.len(), 3);
+
+        let Statement::VariableDeclaration(decl) = &stmts[0] else { panic!() };
+        assert_eq!(decl.span, span);
+        let declarator = &decl.declarations[0];
+        assert_eq!(declarator.id.get_identifier_name().unwrap(), "x");
+        let Some(Expression::StringLiteral(lit)) = &declarator.init else { panic!() };
+        assert_eq!(lit.value, "foo");
+        // Substituted values keep their own span
+        assert_eq!(lit.span, Span::new(3, 4));
+
+        assert!(matches!(stmts[1], Statement::EmptyStatement(_)));
+
+        let Statement::ExpressionStatement(expr_stmt) = &stmts[2] else { panic!() };
+        let Expression::CallExpression(call) = &expr_stmt.expression else { panic!() };
+        let Expression::StaticMemberExpression(member) = &call.callee else { panic!() };
+        assert!(matches!(&member.object, Expression::Identifier(ident) if ident.name == "x"));
+        assert_eq!(member.property.name, "x");
+        assert!(matches!(call.arguments[0], Argument::StringLiteral(_)));
+    }
+
+    #[test]
+    fn expression_and_statement() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let expr = Template::new(&allocator, "(%%a%%) => %%a%% + 1", SourceType::ts())
+            .expression([("a", TemplateValue::Identifier(ast.atom("n")))])
+            .unwrap();
+        assert!(matches!(expr, Expression::ArrowFunctionExpression(_)));
+
+        let stmt = Template::new(&allocator, "export { %%a%% as default };", SourceType::mjs())
+            .statement([("a", TemplateValue::Identifier(ast.atom("n")))])
+            .unwrap();
+        assert!(matches!(stmt, Statement::ExportNamedDeclaration(_)));
+    }
+
+    #[test]
+    fn errors() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let template = |source_text| Template::new(&allocator, source_text, SourceType::mjs());
+
+        let errors = template("%%a%% + %%b").expression([]).unwrap_err();
+        assert_eq!(errors[0].to_string(), "Invalid template placeholder `%%b`");
+
+        let errors = template("%%a%% + 1").expression([]).unwrap_err();
+        assert_eq!(errors[0].to_string(), "Missing value for template placeholder `%%a%%`");
+
+        let value = TemplateValue::Identifier(ast.atom("x"));
+        let errors = template("1").expression([("a", value)]).unwrap_err();
+        assert_eq!(errors[0].to_string(), "Unused value for template placeholder `%%a%%`");
+
+        let value = TemplateValue::Statement(ast.statement_empty(SPAN));
+        let errors = template("%%a%% + 1").expression([("a", value)]).unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            "Template placeholder `%%a%%` must be replaced with an expression or an identifier"
+        );
+
+        let errors = template("a +").statements([]).unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+}
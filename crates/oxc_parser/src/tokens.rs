@@ -0,0 +1,95 @@
+//! Tokens collected by the parser, see [`ParseOptions::collect_tokens`](crate::ParseOptions::collect_tokens).
+
+use oxc_span::Span;
+
+use crate::lexer::Kind;
+
+/// A token of the source text.
+///
+/// The value of the token is the source text of its span,
+/// e.g. `"a"` for a string literal, ``"`a${"`` for a template head or `"/a/g"` for a regular expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    /// Token type
+    pub kind: TokenKind,
+    /// Location of the token in the source text
+    pub span: Span,
+}
+
+/// Token types.
+///
+/// Same as the token types in [ESTree](https://github.com/estree/estree) `tokens` arrays of
+/// [espree](https://github.com/eslint/js/tree/main/packages/espree) and
+/// [typescript-estree](https://typescript-eslint.io/packages/typescript-estree).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// `true` or `false`
+    Boolean,
+    /// Identifier, including contextual keywords such as `async` or `of`
+    Identifier,
+    /// Keyword, e.g. `if` or `this`
+    Keyword,
+    /// `null`
+    Null,
+    /// Numeric or BigInt literal
+    Numeric,
+    /// Punctuator, e.g. `(` or `=>`
+    Punctuator,
+    /// Regular expression literal
+    RegularExpression,
+    /// String literal
+    String,
+    /// Part of a template literal, from `` ` `` or `}` to `${` or `` ` ``
+    Template,
+    /// Private identifier, e.g. `#x`
+    PrivateIdentifier,
+    /// Identifier in a JSX element or attribute name
+    JSXIdentifier,
+    /// Text of a JSX child
+    JSXText,
+}
+
+impl TokenKind {
+    /// ESTree token type.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Boolean => "Boolean",
+            Self::Identifier => "Identifier",
+            Self::Keyword => "Keyword",
+            Self::Null => "Null",
+            Self::Numeric => "Numeric",
+            Self::Punctuator => "Punctuator",
+            Self::RegularExpression => "RegularExpression",
+            Self::String => "String",
+            Self::Template => "Template",
+            Self::PrivateIdentifier => "PrivateIdentifier",
+            Self::JSXIdentifier => "JSXIdentifier",
+            Self::JSXText => "JSXText",
+        }
+    }
+
+    /// Token type of a lexer token, or `None` if it is not part of the token list.
+    pub(crate) fn from_kind(kind: Kind) -> Option<Self> {
+        let kind = match kind {
+            Kind::Eof | Kind::Undetermined | Kind::Skip | Kind::HashbangComment => return None,
+            Kind::True | Kind::False => Self::Boolean,
+            Kind::Null => Self::Null,
+            // Same as espree, which treats `await` as an identifier and `let`, `static` and `yield` as keywords
+            Kind::Await => Self::Identifier,
+            Kind::Let | Kind::Static => Self::Keyword,
+            kind if kind.is_reserved_keyword() => Self::Keyword,
+            kind if kind.is_identifier_name() => Self::Identifier,
+            kind if kind.is_number() => Self::Numeric,
+            Kind::Str => Self::String,
+            Kind::RegExp => Self::RegularExpression,
+            Kind::NoSubstitutionTemplate
+            | Kind::TemplateHead
+            | Kind::TemplateMiddle
+            | Kind::TemplateTail => Self::Template,
+            Kind::PrivateIdentifier => Self::PrivateIdentifier,
+            Kind::JSXText => Self::JSXText,
+            _ => Self::Punctuator,
+        };
+        Some(kind)
+    }
+}
@@ -32,6 +32,9 @@ impl<'a> ParserImpl<'a> {
         span: u32,
         modifiers: &Modifiers<'a>,
     ) -> Declaration<'a> {
+        if self.is_flow {
+            return self.fatal_error(diagnostics::flow_enum(self.cur_token().span()));
+        }
         self.bump_any(); // bump `enum`
         let id = self.parse_binding_identifier();
         let body = self.parse_ts_enum_body();
@@ -426,6 +429,9 @@ impl<'a> ParserImpl<'a> {
                 Declaration::TSModuleDeclaration(decl)
             }
             Kind::Type => self.parse_ts_type_alias_declaration(start_span, modifiers),
+            Kind::Ident if self.is_flow && self.at_flow_opaque_type() => {
+                self.parse_flow_opaque_type_alias(start_span, modifiers.contains_declare())
+            }
             Kind::Enum => self.parse_ts_enum_declaration(start_span, modifiers),
             Kind::Interface => {
                 self.bump_any();
@@ -13,7 +13,11 @@ use super::{super::js::FunctionKind, statement::CallOrConstructorSignature};
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> TSType<'a> {
-        if self.is_start_of_function_type_or_constructor_type() {
+        if self.is_flow && matches!(self.cur_kind(), Kind::LParen | Kind::LAngle) {
+            if let Some(ty) = self.try_parse(Self::parse_flow_function_type) {
+                return ty;
+            }
+        } else if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
         let span = self.start_span();
@@ -196,9 +200,18 @@ impl<'a> ParserImpl<'a> {
             ModifierFlags::IN | ModifierFlags::OUT | ModifierFlags::CONST,
             diagnostics::cannot_appear_on_a_type_parameter,
         );
+        if self.is_flow {
+            // `<+T>`
+            self.eat_flow_variance();
+        }
 
         let name = self.parse_binding_identifier();
-        let constraint = self.parse_ts_type_constraint();
+        let constraint = if self.is_flow && self.eat(Kind::Colon) {
+            // `<T: Bound>`
+            Some(self.parse_ts_type())
+        } else {
+            self.parse_ts_type_constraint()
+        };
         let default = self.parse_ts_default_type();
 
         self.ast.ts_type_parameter(
@@ -235,9 +248,10 @@ impl<'a> ParserImpl<'a> {
         let has_leading_operator = self.eat(kind);
         /* hasLeadingOperator && parseFunctionOrConstructorTypeToError(isUnionType) ||*/
         let mut ty = parse_constituent_type(self);
-        if self.at(kind) || has_leading_operator {
+        if self.at_union_or_intersection_operator(kind) || has_leading_operator {
             let mut types = self.ast.vec1(ty);
-            while self.eat(kind) {
+            while self.at_union_or_intersection_operator(kind) {
+                self.bump_any();
                 types.push(
                     /*parseFunctionOrConstructorTypeToError(isUnionType) || */
                     parse_constituent_type(self),
@@ -253,17 +267,35 @@ impl<'a> ParserImpl<'a> {
         ty
     }
 
+    fn at_union_or_intersection_operator(&mut self, kind: Kind) -> bool {
+        // `|}` closes a Flow exact object type.
+        self.at(kind)
+            && !(self.is_flow
+                && kind == Kind::Pipe
+                && self.lookahead(|p| {
+                    p.bump_any();
+                    p.at(Kind::RCurly)
+                }))
+    }
+
     fn parse_type_operator_or_higher(&mut self) -> TSType<'a> {
         match self.cur_kind() {
             Kind::KeyOf => self.parse_type_operator(TSTypeOperatorOperator::Keyof),
             Kind::Unique => self.parse_type_operator(TSTypeOperatorOperator::Unique),
             Kind::Readonly => self.parse_type_operator(TSTypeOperatorOperator::Readonly),
             Kind::Infer => self.parse_infer_type(),
-            _ => self.context(
-                Context::empty(),
-                Context::DisallowConditionalTypes,
-                Self::parse_postfix_type_or_higher,
-            ),
+            _ => {
+                let ty = self.context(
+                    Context::empty(),
+                    Context::DisallowConditionalTypes,
+                    Self::parse_postfix_type_or_higher,
+                );
+                if self.is_flow && !self.ctx.has_no_anon_function_type() && self.at(Kind::Arrow) {
+                    // `string => void`
+                    return self.parse_flow_anon_function_type(ty);
+                }
+                ty
+            }
         }
     }
 
@@ -424,10 +456,18 @@ impl<'a> ParserImpl<'a> {
             Kind::LCurly => {
                 if self.lookahead(Self::is_start_of_mapped_type) {
                     self.parse_mapped_type()
+                } else if self.is_flow {
+                    self.parse_flow_object_type()
                 } else {
                     self.parse_type_literal()
                 }
             }
+            // Flow existential type `*`
+            Kind::Star if self.is_flow => {
+                let span = self.start_span();
+                self.bump_any();
+                self.ast.ts_type_any_keyword(self.end_span(span))
+            }
             Kind::LBrack => self.parse_tuple_type(),
             Kind::LParen => self.parse_parenthesized_type(),
             Kind::Import => TSType::TSImportType(self.parse_ts_import_type()),
@@ -821,8 +861,9 @@ impl<'a> ParserImpl<'a> {
         if self.at(Kind::LAngle) {
             let span = self.start_span();
             self.expect(Kind::LAngle);
-            let (params, _) =
-                self.parse_delimited_list(Kind::RAngle, Kind::Comma, Self::parse_ts_type);
+            let (params, _) = self.context(Context::empty(), Context::NoAnonFunctionType, |p| {
+                p.parse_delimited_list(Kind::RAngle, Kind::Comma, Self::parse_ts_type)
+            });
             self.expect(Kind::RAngle);
             return Some(
                 self.ast.alloc_ts_type_parameter_instantiation(self.end_span(span), params),
@@ -837,8 +878,9 @@ impl<'a> ParserImpl<'a> {
         if !self.cur_token().is_on_new_line() && self.re_lex_l_angle() == Kind::LAngle {
             let span = self.start_span();
             self.expect(Kind::LAngle);
-            let (params, _) =
-                self.parse_delimited_list(Kind::RAngle, Kind::Comma, Self::parse_ts_type);
+            let (params, _) = self.context(Context::empty(), Context::NoAnonFunctionType, |p| {
+                p.parse_delimited_list(Kind::RAngle, Kind::Comma, Self::parse_ts_type)
+            });
             self.expect(Kind::RAngle);
             return Some(
                 self.ast.alloc_ts_type_parameter_instantiation(self.end_span(span), params),
@@ -883,8 +925,9 @@ impl<'a> ParserImpl<'a> {
     fn parse_tuple_type(&mut self) -> TSType<'a> {
         let span = self.start_span();
         self.expect(Kind::LBrack);
-        let (elements, _) =
-            self.parse_delimited_list(Kind::RBrack, Kind::Comma, Self::parse_tuple_element);
+        let (elements, _) = self.context(Context::empty(), Context::NoAnonFunctionType, |p| {
+            p.parse_delimited_list(Kind::RBrack, Kind::Comma, Self::parse_tuple_element)
+        });
         self.expect(Kind::RBrack);
         self.ast.ts_type_tuple_type(self.end_span(span), elements)
     }
@@ -958,7 +1001,7 @@ impl<'a> ParserImpl<'a> {
     fn parse_parenthesized_type(&mut self) -> TSType<'a> {
         let span = self.start_span();
         self.bump_any(); // bump `(`
-        let ty = self.parse_ts_type();
+        let ty = self.context(Context::empty(), Context::NoAnonFunctionType, Self::parse_ts_type);
         self.expect(Kind::RParen);
         if self.options.preserve_parens {
             self.ast.ts_type_parenthesized_type(self.end_span(span), ty)
@@ -1048,11 +1091,20 @@ impl<'a> ParserImpl<'a> {
             return None;
         }
         let span = self.start_span();
-        self.parse_return_type(kind, is_type)
-            .map(|return_type| self.ast.alloc_ts_type_annotation(self.end_span(span), return_type))
+        let return_type = self
+            .parse_return_type(kind, is_type)
+            .map(|return_type| self.ast.alloc_ts_type_annotation(self.end_span(span), return_type));
+        if self.is_flow {
+            self.eat_flow_predicate();
+        }
+        return_type
     }
 
-    fn parse_return_type(&mut self, return_kind: Kind, is_type: bool) -> Option<TSType<'a>> {
+    pub(crate) fn parse_return_type(
+        &mut self,
+        return_kind: Kind,
+        is_type: bool,
+    ) -> Option<TSType<'a>> {
         if self.should_parse_return_type(return_kind, is_type) {
             return Some(self.context(
                 Context::empty(),
@@ -1261,7 +1313,7 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
-    fn parse_type_member_semicolon(&mut self) {
+    pub(crate) fn parse_type_member_semicolon(&mut self) {
         // We allow type members to be separated by commas or (possibly ASI) semicolons.
         // First check if it was a comma.  If so, we're done with the member.
         if self.eat(Kind::Comma) {
@@ -3,10 +3,12 @@ use std::path::Path;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_parser::Parser;
+use oxc_parser::{ParseOptions, Parser};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
-use oxc_transformer::{BabelOptions, EnvOptions, HelperLoaderMode, TransformOptions, Transformer};
+use oxc_transformer::{
+    BabelOptions, EnvOptions, FlowOptions, HelperLoaderMode, TransformOptions, Transformer,
+};
 use pico_args::Arguments;
 
 // Instruction:
@@ -19,6 +21,7 @@ fn main() {
         args.opt_value_from_str("--babel-options").unwrap_or(None);
     let targets: Option<String> = args.opt_value_from_str("--targets").unwrap_or(None);
     let target: Option<String> = args.opt_value_from_str("--target").unwrap_or(None);
+    let flow = args.contains("--flow");
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -27,7 +30,9 @@ fn main() {
     let allocator = Allocator::default();
     let source_type = SourceType::from_path(path).unwrap();
 
-    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let ret = Parser::new(&allocator, &source_text, source_type)
+        .with_options(ParseOptions { flow, ..ParseOptions::default() })
+        .parse();
 
     if !ret.errors.is_empty() {
         println!("Parser Errors:");
@@ -73,6 +78,9 @@ fn main() {
     };
 
     transform_options.helper_loader.mode = HelperLoaderMode::External;
+    if flow {
+        transform_options.flow = Some(FlowOptions::default());
+    }
 
     let ret = Transformer::new(&allocator, path, &transform_options)
         .build_with_scoping(scoping, &mut program);
@@ -167,7 +167,28 @@ pub enum Helper {
     Decorate,
     DecorateParam,
     DecorateMetadata,
+    ApplyDecs2311,
     UsingCtx,
+    ClassCallCheck,
+    CreateClass,
+    Inherits,
+    InheritsLoose,
+    CallSuper,
+    PossibleConstructorReturn,
+    SlicedToArray,
+    ToArray,
+    MaybeArrayLike,
+    Tdz,
+    TaggedTemplateLiteral,
+    TaggedTemplateLiteralLoose,
+    ToConsumableArray,
+    Construct,
+    DefineAccessor,
+    CreateForOfIterator,
+    CreateForOfIteratorLoose,
+    InteropRequireDefault,
+    InteropRequireWildcard,
+    RegeneratorRuntime,
 }
 
 impl Helper {
@@ -200,7 +221,28 @@ impl Helper {
             Self::Decorate => "decorate",
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
+            Self::ApplyDecs2311 => "applyDecs2311",
             Self::UsingCtx => "usingCtx",
+            Self::ClassCallCheck => "classCallCheck",
+            Self::CreateClass => "createClass",
+            Self::Inherits => "inherits",
+            Self::InheritsLoose => "inheritsLoose",
+            Self::CallSuper => "callSuper",
+            Self::PossibleConstructorReturn => "possibleConstructorReturn",
+            Self::SlicedToArray => "slicedToArray",
+            Self::ToArray => "toArray",
+            Self::MaybeArrayLike => "maybeArrayLike",
+            Self::Tdz => "tdz",
+            Self::TaggedTemplateLiteral => "taggedTemplateLiteral",
+            Self::TaggedTemplateLiteralLoose => "taggedTemplateLiteralLoose",
+            Self::ToConsumableArray => "toConsumableArray",
+            Self::Construct => "construct",
+            Self::DefineAccessor => "defineAccessor",
+            Self::CreateForOfIterator => "createForOfIteratorHelper",
+            Self::CreateForOfIteratorLoose => "createForOfIteratorHelperLoose",
+            Self::InteropRequireDefault => "interopRequireDefault",
+            Self::InteropRequireWildcard => "interopRequireWildcard",
+            Self::RegeneratorRuntime => "regeneratorRuntime",
         }
     }
 
@@ -275,6 +317,21 @@ impl<'a> TransformCtx<'a> {
             }
         }
     }
+
+    /// Load the regenerator runtime object and return an expression referencing it.
+    ///
+    /// Unlike other helpers, the runtime is an object rather than a function to call:
+    /// * Runtime mode: `import _regeneratorRuntime from "@oxc-project/runtime/regenerator"`,
+    ///   and the imported binding is the runtime object.
+    /// * External mode: `babelHelpers.regeneratorRuntime()`.
+    pub fn regenerator_runtime(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let helper = Helper::RegeneratorRuntime;
+        let callee = self.helper_load(helper, ctx);
+        match self.helper_loader.mode {
+            HelperLoaderMode::Runtime => callee,
+            _ => ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false),
+        }
+    }
 }
 
 // Internal methods
@@ -315,6 +372,9 @@ impl<'a> HelperLoaderStore<'a> {
 
     // Construct string directly in arena without an intermediate temp allocation
     fn get_runtime_source(&self, helper: Helper, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        if helper == Helper::RegeneratorRuntime {
+            return ctx.ast.atom_from_strs_array([&self.module_name, "/regenerator"]);
+        }
         ctx.ast.atom_from_strs_array([&self.module_name, "/helpers/", helper.name()])
     }
 
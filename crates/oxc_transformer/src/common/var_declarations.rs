@@ -20,6 +20,7 @@ use oxc_allocator::Vec as ArenaVec;
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::SparseStack;
 use oxc_span::SPAN;
+use oxc_syntax::symbol::SymbolFlags;
 use oxc_traverse::{Ancestor, BoundIdentifier, Traverse, ast_operations::GatherNodeParts};
 
 use crate::{
@@ -55,7 +56,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for VarDeclarations<'a, '_> {
         stmts: &mut ArenaVec<'a, Statement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
-        self.ctx.var_declarations.insert_into_statements(stmts, ctx);
+        self.ctx.var_declarations.insert_into_statements(self.ctx, stmts, ctx);
     }
 
     fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -207,15 +208,16 @@ impl<'a> VarDeclarationsStore<'a> {
 
     fn insert_into_statements(
         &self,
+        transform_ctx: &TransformCtx<'a>,
         stmts: &mut ArenaVec<'a, Statement<'a>>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         if matches!(ctx.parent(), Ancestor::ProgramBody(_)) {
             // Handle in `insert_into_program` instead
             return;
         }
 
-        if let Some((var_statement, let_statement)) = self.get_var_statement(ctx) {
+        if let Some((var_statement, let_statement)) = self.get_var_statement(transform_ctx, ctx) {
             let mut new_stmts = ctx.ast.vec_with_capacity(stmts.len() + 2);
             match (var_statement, let_statement) {
                 (Some(var_statement), Some(let_statement)) => {
@@ -233,8 +235,8 @@ impl<'a> VarDeclarationsStore<'a> {
         }
     }
 
-    fn insert_into_program(&self, transform_ctx: &TransformCtx<'a>, ctx: &TraverseCtx<'a>) {
-        if let Some((var_statement, let_statement)) = self.get_var_statement(ctx) {
+    fn insert_into_program(&self, transform_ctx: &TransformCtx<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some((var_statement, let_statement)) = self.get_var_statement(transform_ctx, ctx) {
             // Delegate to `TopLevelStatements`
             transform_ctx
                 .top_level_statements
@@ -250,10 +252,21 @@ impl<'a> VarDeclarationsStore<'a> {
     #[inline]
     fn get_var_statement(
         &self,
-        ctx: &TraverseCtx<'a>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<(Option<Statement<'a>>, Option<Statement<'a>>)> {
         let mut stack = self.stack.borrow_mut();
-        let Declarators { var_declarators, let_declarators } = stack.pop()?;
+        let Declarators { mut var_declarators, mut let_declarators } = stack.pop()?;
+
+        // Block scoping plugin has already visited this statement block,
+        // so `let` declarations inserted by other transforms are declared with `var` here.
+        // TODO: A `let` declared in a loop body is not redeclared for each iteration once it becomes a `var`.
+        if transform_ctx.is_block_scoping_plugin_enabled {
+            for mut declarator in let_declarators.drain(..) {
+                Self::convert_let_to_var(&mut declarator, ctx);
+                var_declarators.push(declarator);
+            }
+        }
 
         let var_statement = (!var_declarators.is_empty())
             .then(|| Self::create_declaration(VariableDeclarationKind::Var, var_declarators, ctx));
@@ -263,6 +276,28 @@ impl<'a> VarDeclarationsStore<'a> {
         Some((var_statement, let_statement))
     }
 
+    /// Convert a `let` declarator to `var`, moving its bindings to the enclosing function scope.
+    fn convert_let_to_var(declarator: &mut VariableDeclarator<'a>, ctx: &mut TraverseCtx<'a>) {
+        declarator.kind = VariableDeclarationKind::Var;
+        for ident in declarator.id.get_binding_identifiers() {
+            let symbol_id = ident.symbol_id();
+            let scoping = ctx.scoping_mut();
+            let flags = scoping.symbol_flags_mut(symbol_id);
+            flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+            flags.insert(SymbolFlags::FunctionScopedVariable);
+
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            let hoist_scope_id = scoping
+                .scope_ancestors(scope_id)
+                .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+                .unwrap();
+            if scope_id != hoist_scope_id {
+                scoping.move_binding(scope_id, hoist_scope_id, &ident.name);
+                scoping.set_symbol_scope_id(symbol_id, hoist_scope_id);
+            }
+        }
+    }
+
     fn create_declaration(
         kind: VariableDeclarationKind,
         declarators: ArenaVec<'a, VariableDeclarator<'a>>,
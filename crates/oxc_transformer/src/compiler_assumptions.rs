@@ -9,7 +9,6 @@ use serde::Deserialize;
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CompilerAssumptions {
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub array_like_is_iterable: bool,
 
     #[serde(default)]
@@ -28,19 +27,15 @@ pub struct CompilerAssumptions {
     pub ignore_function_length: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub ignore_to_primitive_hint: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub iterable_is_array: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub mutable_template_object: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub no_class_calls: bool,
 
     #[serde(default)]
@@ -72,11 +67,9 @@ pub struct CompilerAssumptions {
     pub pure_getters: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_class_methods: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_computed_properties: bool,
 
     /// When using public class fields, assume that they don't shadow any getter in the current class,
@@ -126,10 +119,8 @@ pub struct CompilerAssumptions {
     pub set_spread_properties: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub skip_for_of_iterator_closing: bool,
 
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub super_is_callable_constructor: bool,
 }
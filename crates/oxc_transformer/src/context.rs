@@ -51,6 +51,14 @@ pub struct TransformCtx<'a> {
     // State for multiple plugins interacting
     /// `true` if class properties plugin is enabled
     pub is_class_properties_plugin_enabled: bool,
+    /// `true` if generators plugin is enabled
+    pub is_generators_plugin_enabled: bool,
+    /// `true` if classes plugin is enabled
+    pub is_classes_plugin_enabled: bool,
+    /// `true` if block scoping plugin is enabled
+    pub is_block_scoping_plugin_enabled: bool,
+    /// `true` if shorthand properties plugin is enabled
+    pub is_shorthand_properties_plugin_enabled: bool,
 }
 
 impl TransformCtx<'_> {
@@ -73,6 +81,10 @@ impl TransformCtx<'_> {
             statement_injector: StatementInjectorStore::new(),
             top_level_statements: TopLevelStatementsStore::new(),
             is_class_properties_plugin_enabled: options.env.es2022.class_properties.is_some(),
+            is_generators_plugin_enabled: options.env.es2015.generators,
+            is_classes_plugin_enabled: options.env.es2015.classes.is_some(),
+            is_block_scoping_plugin_enabled: options.env.es2015.block_scoping.is_some(),
+            is_shorthand_properties_plugin_enabled: options.env.es2015.shorthand_properties,
         }
     }
 
@@ -1,5 +1,6 @@
 mod legacy;
 mod options;
+mod standard;
 
 use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
@@ -11,41 +12,60 @@ use crate::{
 
 use legacy::LegacyDecorator;
 pub use options::DecoratorOptions;
+use standard::StandardDecorator;
 
 pub struct Decorator<'a, 'ctx> {
     options: DecoratorOptions,
 
     // Plugins
-    legacy_decorator: LegacyDecorator<'a, 'ctx>,
+    legacy: LegacyDecorator<'a, 'ctx>,
+    standard: StandardDecorator<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> Decorator<'a, 'ctx> {
-    pub fn new(options: DecoratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+    pub fn new(mut options: DecoratorOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        // Legacy decorators take precedence over standard decorators
+        options.standard &= !options.legacy;
         Self {
-            legacy_decorator: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            legacy: LegacyDecorator::new(options.emit_decorator_metadata, ctx),
+            standard: StandardDecorator::new(ctx),
             options,
         }
     }
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
+    #[inline]
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.standard {
+            self.standard.enter_statement(stmt, ctx);
+        }
+    }
+
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.standard {
+            self.standard.enter_expression(expr, ctx);
+        }
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_statement(stmt, ctx);
+            self.legacy.exit_statement(stmt, ctx);
         }
     }
 
     #[inline]
     fn enter_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.enter_class(node, ctx);
+            self.legacy.enter_class(node, ctx);
         }
     }
 
     #[inline]
     fn exit_class(&mut self, node: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
         if self.options.legacy {
-            self.legacy_decorator.exit_class(node, ctx);
+            self.legacy.exit_class(node, ctx);
         }
     }
 
@@ -56,7 +76,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_method_definition(node, ctx);
+            self.legacy.enter_method_definition(node, ctx);
         }
     }
 
@@ -67,7 +87,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_accessor_property(node, ctx);
+            self.legacy.enter_accessor_property(node, ctx);
         }
     }
 
@@ -78,7 +98,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for Decorator<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         if self.options.legacy {
-            self.legacy_decorator.enter_property_definition(node, ctx);
+            self.legacy.enter_property_definition(node, ctx);
         }
     }
 }
@@ -12,6 +12,14 @@ pub struct DecoratorOptions {
     #[serde(skip)]
     pub legacy: bool,
 
+    /// Enables the [2023-11 version](https://github.com/tc39/proposal-decorators) of decorators,
+    /// which is the version implemented by TypeScript 5.
+    ///
+    /// Decorators are applied at runtime by Babel's `applyDecs2311` helper.
+    /// This option is ignored when `legacy` is true.
+    #[serde(skip)]
+    pub standard: bool,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -0,0 +1,1568 @@
+//! 2023-11 Decorators
+//!
+//! This plugin transforms decorators of the [TC39 decorators proposal](https://github.com/tc39/proposal-decorators),
+//! which is the version of decorators implemented by TypeScript 5, by calling the `_applyDecs2311` helper
+//! to apply decorators.
+//!
+//! `accessor` class properties are lowered to a private storage field with a getter and setter,
+//! whether or not they are decorated.
+//!
+//! ## Examples
+//!
+//! Input:
+//! ```js
+//! @dec
+//! class Class {
+//!   @dec
+//!   prop = 0;
+//!
+//!   @dec
+//!   accessor value = 1;
+//!
+//!   @dec
+//!   method() {}
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _initProto, _Class, _init_prop, _init_extra_prop, _init_value, _init_extra_value, _initClass;
+//! let Class = (class Class {
+//!   static {
+//!     ({
+//!       e: [_init_value, _init_extra_value, _init_prop, _init_extra_prop, _initProto],
+//!       c: [_Class, _initClass]
+//!     } = _applyDecs2311(this, [dec], [[dec, 0, "prop"], [dec, 1, "value"], [dec, 2, "method"]]));
+//!   }
+//!   constructor() {
+//!     _init_extra_value(this);
+//!   }
+//!   prop = (_initProto(this), _init_prop(this, 0));
+//!   #A = (_init_extra_prop(this), _init_value(this, 1));
+//!   get value() {
+//!     return this.#A;
+//!   }
+//!   set value(v) {
+//!     this.#A = v;
+//!   }
+//!   method() {}
+//!   static {
+//!     _initClass();
+//!   }
+//! }, _Class);
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-proposal-decorators](https://github.com/babel/babel/blob/v7.26.0/packages/babel-helper-create-class-features-plugin/src/decorators.ts)
+//! with `version: "2023-11"`.
+//!
+//! Decorator expressions and computed keys are evaluated in order before the class, unless they are
+//! plain identifiers. References to the class inside the class body are replaced with the decorated class.
+//!
+//! Unlike Babel, static fields of a class with class decorators are defined on the undecorated class,
+//! instead of on the class returned by the class decorators. The two only differ when a class decorator
+//! replaces the class.
+//!
+//! Private methods which are decorated are converted to function expressions, so `super` is not supported
+//! in their bodies.
+//!
+//! ## References:
+//! * TC39 proposal: <https://github.com/tc39/proposal-decorators>
+//! * TypeScript 5.0 decorators: <https://devblogs.microsoft.com/typescript/announcing-typescript-5-0/#decorators>
+
+use std::{iter, mem};
+
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk_mut};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::{
+    BoundIdentifier, MaybeBoundIdentifier, Traverse, ast_operations::to_identifier,
+};
+
+use crate::{
+    Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::{
+        ast_builder::{create_assignment, create_class_constructor, create_property_access},
+        scope_reparenter::ScopeReparenter,
+    },
+};
+
+/// Kind of a class element, as expected by `applyDecs2311`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Field = 0,
+    Accessor = 1,
+    Method = 2,
+    Getter = 3,
+    Setter = 4,
+}
+
+/// Flag for static class elements.
+const STATIC: u8 = 8;
+
+/// Flag for class elements whose decorators are passed as `[this, decorator]` pairs.
+const DECORATORS_HAVE_THIS: u8 = 16;
+
+/// A decorator expression, with the `this` value to call it with if it is a member expression.
+struct DecoratorEntry<'a> {
+    this: Option<Expression<'a>>,
+    decorator: Expression<'a>,
+}
+
+/// Result of transforming a class.
+struct TransformedClass<'a> {
+    /// Expressions to evaluate before the class, in order.
+    pre_class: Vec<Expression<'a>>,
+    /// Binding for the decorated class. `Some` if the class has class decorators.
+    decorated_class: Option<BoundIdentifier<'a>>,
+}
+
+/// State of a class being transformed.
+struct ClassState<'a> {
+    class_scope_id: ScopeId,
+    /// Scope of the static block which applies the decorators.
+    decorators_scope_id: ScopeId,
+    pre_class: Vec<Expression<'a>>,
+    /// Elements of the `memberDecs` argument of `applyDecs2311`.
+    member_decorators: Vec<Expression<'a>>,
+    /// Bindings for the elements of the array returned by `applyDecs2311`.
+    /// The helper returns them grouped in the order of these fields.
+    static_non_field_bindings: Vec<BoundIdentifier<'a>>,
+    instance_non_field_bindings: Vec<BoundIdentifier<'a>>,
+    static_field_bindings: Vec<BoundIdentifier<'a>>,
+    instance_field_bindings: Vec<BoundIdentifier<'a>>,
+    /// `_initProto`. `Some` if any instance method, getter or setter is decorated.
+    init_proto: Option<BoundIdentifier<'a>>,
+    /// `_initStatic`. `Some` if any static method, getter or setter is decorated.
+    init_static: Option<BoundIdentifier<'a>>,
+    /// Name of a decorated instance private element, used for the brand check function.
+    brand: Option<Atom<'a>>,
+    /// Private names declared in the class, which storage of `accessor`s must not clash with.
+    private_names: FxHashSet<Atom<'a>>,
+    storage_count: u32,
+}
+
+pub struct StandardDecorator<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> StandardDecorator<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for StandardDecorator<'a, '_> {
+    // `#[inline]` because this is a hot path
+    #[inline]
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ClassDeclaration(_)
+            | Statement::ExportNamedDeclaration(_)
+            | Statement::ExportDefaultDeclaration(_) => self.transform_class_statement(stmt, ctx),
+            _ => {}
+        }
+    }
+
+    // `#[inline]` for fast exit for expressions which are not `Class`es
+    #[inline]
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if matches!(expr, Expression::ClassExpression(_)) {
+            self.transform_class_expression(expr, ctx);
+        }
+    }
+}
+
+impl<'a> StandardDecorator<'a, '_> {
+    /// Transforms a statement that is a class declaration, or exports a class declaration.
+    ///
+    /// Input:
+    /// ```js
+    /// @dec
+    /// export class Class {}
+    /// ```
+    ///
+    /// Output:
+    /// ```js
+    /// var _initClass, _Class;
+    /// export let Class = (class Class {
+    ///   static {
+    ///     [_Class, _initClass] = _applyDecs2311(this, [dec], []).c;
+    ///   }
+    ///   static {
+    ///     _initClass();
+    ///   }
+    /// }, _Class);
+    /// ```
+    fn transform_class_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let class = match stmt {
+            Statement::ClassDeclaration(class) => class,
+            Statement::ExportNamedDeclaration(export) => match &mut export.declaration {
+                Some(Declaration::ClassDeclaration(class)) => class,
+                _ => return,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &mut export.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => class,
+                _ => return,
+            },
+            _ => unreachable!(),
+        };
+        if !Self::should_transform(class) {
+            return;
+        }
+
+        let TransformedClass { pre_class, decorated_class } = self.transform_class(class, ctx);
+
+        let Some(decorated_class) = decorated_class else {
+            if !pre_class.is_empty() {
+                let pre_class = ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_iter(pre_class));
+                let pre_class = ctx.ast.statement_expression(SPAN, pre_class);
+                self.ctx.statement_injector.insert_before(stmt, pre_class);
+            }
+            return;
+        };
+
+        // `class Class {}` -> `let Class = (class Class {}, _Class);`
+        // The class binding now refers to the decorated class.
+        let class_scope_id = class.scope_id();
+        let class_binding = class.id.as_mut().map(|ident| {
+            let new_class_binding =
+                ctx.generate_binding(ident.name, class_scope_id, SymbolFlags::Class);
+            let old_class_symbol_id = ident.symbol_id.replace(Some(new_class_binding.symbol_id));
+            let old_class_symbol_id = old_class_symbol_id.expect("class always has a symbol id");
+            *ctx.scoping_mut().symbol_flags_mut(old_class_symbol_id) =
+                SymbolFlags::BlockScopedVariable;
+            // The original symbol and span now belong to the `let` binding
+            let span = ident.span;
+            ident.span = SPAN;
+            (span, BoundIdentifier::new(ident.name, old_class_symbol_id))
+        });
+
+        let span = class.span;
+        class.r#type = ClassType::ClassExpression;
+        let class = Expression::ClassExpression(class.take_in_box(ctx.ast));
+        let init = ctx.ast.expression_sequence(
+            SPAN,
+            ctx.ast.vec_from_iter(
+                pre_class.into_iter().chain([class, decorated_class.create_read_expression(ctx)]),
+            ),
+        );
+
+        let Some((binding_span, class_binding)) = class_binding else {
+            // `export default @dec class {}` -> `export default (class {}, _Class);`
+            let Statement::ExportDefaultDeclaration(export) = stmt else { unreachable!() };
+            export.declaration = ExportDefaultDeclarationKind::from(init);
+            return;
+        };
+
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Let,
+            ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                    binding_span,
+                    class_binding.name,
+                    class_binding.symbol_id,
+                ),
+                NONE,
+                false,
+            ),
+            Some(init),
+            false,
+        );
+        let declaration = ctx.ast.declaration_variable(
+            span,
+            VariableDeclarationKind::Let,
+            ctx.ast.vec1(declarator),
+            false,
+        );
+
+        match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                export.declaration = Some(declaration);
+            }
+            Statement::ExportDefaultDeclaration(_) => {
+                // `export default Class`
+                let export_default_class_reference =
+                    ctx.ast.module_declaration_export_default_declaration(
+                        SPAN,
+                        ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+                        ExportDefaultDeclarationKind::Identifier(
+                            ctx.ast.alloc(class_binding.create_read_reference(ctx)),
+                        ),
+                    );
+                *stmt = Statement::from(declaration);
+                self.ctx
+                    .statement_injector
+                    .insert_after(stmt, Statement::from(export_default_class_reference));
+            }
+            _ => *stmt = Statement::from(declaration),
+        }
+    }
+
+    /// Transforms a class expression.
+    ///
+    /// * With class decorators: `@dec class {}` -> `(class { ... }, _Class)`
+    /// * Decorators or computed keys need evaluating first: `class { @dec() a() {} }` -> `(_dec = dec(), class { ... })`
+    fn transform_class_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ClassExpression(class) = expr else { unreachable!() };
+        if !Self::should_transform(class) {
+            return;
+        }
+
+        let TransformedClass { pre_class, decorated_class } = self.transform_class(class, ctx);
+        if pre_class.is_empty() && decorated_class.is_none() {
+            return;
+        }
+
+        let class = expr.take_in(ctx.ast);
+        let decorated_class = decorated_class.map(|binding| binding.create_read_expression(ctx));
+        *expr = ctx.ast.expression_sequence(
+            SPAN,
+            ctx.ast.vec_from_iter(
+                pre_class.into_iter().chain(iter::once(class)).chain(decorated_class),
+            ),
+        );
+    }
+
+    /// Check if the class has decorators or `accessor` properties which need to be transformed.
+    fn should_transform(class: &Class<'a>) -> bool {
+        if class.declare {
+            return false;
+        }
+        !class.decorators.is_empty()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::MethodDefinition(method) => !method.decorators.is_empty(),
+                ClassElement::PropertyDefinition(prop) => !prop.decorators.is_empty(),
+                ClassElement::AccessorProperty(_) => true,
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => false,
+            })
+    }
+
+    /// Check if the class element only exists in TypeScript, and will be removed by the TypeScript plugin.
+    fn is_typescript_only_element(element: &ClassElement<'a>) -> bool {
+        match element {
+            ClassElement::MethodDefinition(method) => {
+                method.r#type.is_abstract() || method.value.body.is_none()
+            }
+            ClassElement::PropertyDefinition(prop) => prop.r#type.is_abstract() || prop.declare,
+            ClassElement::AccessorProperty(prop) => prop.r#type.is_abstract(),
+            ClassElement::TSIndexSignature(_) => true,
+            ClassElement::StaticBlock(_) => false,
+        }
+    }
+
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> TransformedClass<'a> {
+        let class_scope_id = class.scope_id();
+
+        // Check which of the helper's initializer functions are needed
+        let mut has_element_decorators = false;
+        let mut has_instance_method_decorators = false;
+        let mut has_static_method_decorators = false;
+        let mut private_names = FxHashSet::default();
+        for element in &class.body.body {
+            if let Some(name) = element.property_key().and_then(PropertyKey::private_name) {
+                private_names.insert(name);
+            }
+            if Self::is_typescript_only_element(element) {
+                continue;
+            }
+            match element {
+                ClassElement::MethodDefinition(method) if !method.decorators.is_empty() => {
+                    has_element_decorators = true;
+                    if method.r#static {
+                        has_static_method_decorators = true;
+                    } else {
+                        has_instance_method_decorators = true;
+                    }
+                }
+                ClassElement::PropertyDefinition(prop) if !prop.decorators.is_empty() => {
+                    has_element_decorators = true;
+                }
+                ClassElement::AccessorProperty(prop) if !prop.decorators.is_empty() => {
+                    has_element_decorators = true;
+                }
+                _ => {}
+            }
+        }
+
+        let has_class_decorators = !class.decorators.is_empty();
+        let has_decorators = has_class_decorators || has_element_decorators;
+
+        let decorators_scope_id = if has_decorators {
+            ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock)
+        } else {
+            class_scope_id
+        };
+        let mut state = ClassState {
+            class_scope_id,
+            decorators_scope_id,
+            pre_class: vec![],
+            member_decorators: vec![],
+            static_non_field_bindings: vec![],
+            instance_non_field_bindings: vec![],
+            static_field_bindings: vec![],
+            instance_field_bindings: vec![],
+            init_proto: has_instance_method_decorators
+                .then(|| self.ctx.var_declarations.create_uid_var("initProto", ctx)),
+            init_static: has_static_method_decorators
+                .then(|| self.ctx.var_declarations.create_uid_var("initStatic", ctx)),
+            brand: None,
+            private_names,
+            storage_count: 0,
+        };
+
+        // Class decorators are evaluated first
+        let class_decorators = has_class_decorators.then(|| {
+            let decorators = class.decorators.take_in(ctx.ast);
+            self.memoize_decorators(decorators, false, &mut state, ctx)
+        });
+
+        let decorated_class = has_class_decorators.then(|| {
+            let name = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            let binding = self.ctx.var_declarations.create_uid_var(name, ctx);
+            // References to the class inside the class body refer to the decorated class
+            if let Some(id) = &class.id {
+                ClassReferenceReplacer::new(id.symbol_id(), &binding, ctx)
+                    .visit_class_body(&mut class.body);
+            }
+            binding
+        });
+
+        let pending_instance_init = self.transform_class_elements(class, &mut state, ctx);
+        if let Some(init) = pending_instance_init {
+            Self::insert_instance_init_into_constructor(class, &init, ctx);
+        }
+
+        let pre_class = mem::take(&mut state.pre_class);
+        if !has_decorators {
+            return TransformedClass { pre_class, decorated_class };
+        }
+
+        // Parent class is passed to the helper to inherit metadata.
+        let parent_class = class.super_class.as_mut().map(|super_class| {
+            if let Expression::Identifier(ident) = super_class.without_parentheses() {
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx)
+            } else {
+                // `class C extends (_ref = expr) {}`
+                let binding = self.ctx.var_declarations.create_uid_var("ref", ctx);
+                let expr = super_class.take_in(ctx.ast);
+                *super_class = create_assignment(&binding, expr, ctx);
+                binding.create_read_expression(ctx)
+            }
+        });
+
+        let init_class = has_class_decorators
+            .then(|| self.ctx.var_declarations.create_uid_var("initClass", ctx));
+
+        let static_block = self.create_decorators_static_block(
+            class_decorators,
+            decorated_class.as_ref().zip(init_class.as_ref()),
+            parent_class,
+            state,
+            ctx,
+        );
+        class.body.body.insert(0, static_block);
+
+        if let Some(init_class) = init_class {
+            // `static { _initClass(); }`
+            let call = Self::create_call(&init_class, iter::empty(), ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            let scope_id = ctx.create_child_scope(class_scope_id, ScopeFlags::ClassStaticBlock);
+            let element = ctx.ast.class_element_static_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(stmt),
+                scope_id,
+            );
+            class.body.body.push(element);
+        }
+
+        TransformedClass { pre_class, decorated_class }
+    }
+
+    /// Transform decorated class elements and `accessor` properties.
+    ///
+    /// Returns the initializer which still needs to be called in the constructor, if there are no
+    /// more instance fields to call it from.
+    fn transform_class_elements(
+        &self,
+        class: &mut Class<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        // Initializer to call before the next instance field is initialized.
+        // `_initProto` must be called before any instance fields are initialized.
+        let mut pending_instance_init = state.init_proto.clone();
+
+        let elements = class.body.body.take_in(ctx.ast);
+        let mut new_elements = ctx.ast.vec_with_capacity(elements.len());
+        for mut element in elements {
+            if Self::is_typescript_only_element(&element) {
+                new_elements.push(element);
+                continue;
+            }
+            match &mut element {
+                ClassElement::MethodDefinition(method) => {
+                    if method.decorators.is_empty() {
+                        if method.computed {
+                            self.memoize_computed_key(&mut method.key, state, ctx);
+                        }
+                    } else {
+                        self.transform_decorated_method(method, state, ctx);
+                    }
+                    new_elements.push(element);
+                }
+                ClassElement::PropertyDefinition(prop) => {
+                    let extra_init = self.transform_field(prop, state, ctx);
+                    let is_static = prop.r#static;
+                    let static_extra_init = Self::add_extra_init(
+                        &mut prop.value,
+                        is_static,
+                        extra_init,
+                        &mut pending_instance_init,
+                        state,
+                        ctx,
+                    );
+                    new_elements.push(element);
+                    new_elements.extend(static_extra_init);
+                }
+                ClassElement::AccessorProperty(prop) => {
+                    self.transform_accessor(
+                        prop,
+                        &mut new_elements,
+                        &mut pending_instance_init,
+                        state,
+                        ctx,
+                    );
+                }
+                ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => {
+                    new_elements.push(element);
+                }
+            }
+        }
+        class.body.body = new_elements;
+
+        pending_instance_init
+    }
+
+    /// Transform a decorated method, getter or setter.
+    ///
+    /// Public methods are left in place, and are replaced by the helper.
+    ///
+    /// Private methods are passed to the helper, and replaced with a getter which returns the decorated method:
+    /// * `@dec #m() {}` -> `get #m() { return _call_m; }`
+    /// * `@dec get #g() {}` -> `get #g() { return _call_g(this); }`
+    /// * `@dec set #s(v) {}` -> `set #s(v) { _call_s(this, v); }`
+    fn transform_decorated_method(
+        &self,
+        method: &mut MethodDefinition<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let kind = match method.kind {
+            MethodDefinitionKind::Method => ElementKind::Method,
+            MethodDefinitionKind::Get => ElementKind::Getter,
+            MethodDefinitionKind::Set => ElementKind::Setter,
+            // Decorators on constructor are a syntax error
+            MethodDefinitionKind::Constructor => return,
+        };
+
+        let decorators = method.decorators.take_in(ctx.ast);
+        let decorators = self.memoize_decorators(decorators, true, state, ctx);
+        if method.computed {
+            self.memoize_computed_key(&mut method.key, state, ctx);
+        }
+
+        let is_static = method.r#static;
+        let PropertyKey::PrivateIdentifier(ident) = &method.key else {
+            Self::add_member_decorators(kind, is_static, &method.key, decorators, [], state, ctx);
+            return;
+        };
+        let name = ident.name;
+
+        let call = self.ctx.var_declarations.create_uid_var(&format!("call_{name}"), ctx);
+        let class_scope_id = state.class_scope_id;
+        let (new_kind, new_function) = match kind {
+            ElementKind::Method => {
+                let value = call.create_read_expression(ctx);
+                (
+                    MethodDefinitionKind::Get,
+                    Self::create_getter_function(value, class_scope_id, ctx),
+                )
+            }
+            ElementKind::Getter => {
+                let value = Self::create_call(&call, [ctx.ast.expression_this(SPAN)], ctx);
+                (
+                    MethodDefinitionKind::Get,
+                    Self::create_getter_function(value, class_scope_id, ctx),
+                )
+            }
+            ElementKind::Setter => {
+                let function = Self::create_setter_function(
+                    |v, ctx| Self::create_call(&call, [ctx.ast.expression_this(SPAN), v], ctx),
+                    class_scope_id,
+                    ctx,
+                );
+                (MethodDefinitionKind::Set, function)
+            }
+            ElementKind::Field | ElementKind::Accessor => unreachable!(),
+        };
+        method.kind = new_kind;
+
+        // Original method is passed to the helper as a function expression
+        let function = mem::replace(&mut method.value, new_function);
+        let scope_id = function.scope_id();
+        let scoping = ctx.scoping_mut();
+        scoping.change_scope_parent_id(scope_id, Some(state.decorators_scope_id));
+        scoping.scope_flags_mut(scope_id).remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+        let function = Expression::FunctionExpression(function);
+
+        Self::add_member_decorators(
+            kind,
+            is_static,
+            &method.key,
+            decorators,
+            [function],
+            state,
+            ctx,
+        );
+        state.add_bindings(kind, is_static, [call]);
+        if !is_static {
+            state.brand.get_or_insert(name);
+        }
+    }
+
+    /// Transform a decorated field.
+    ///
+    /// `@dec x = 1` -> `x = _init_x(this, 1)`
+    ///
+    /// Returns the extra initializer to call after the field is initialized.
+    fn transform_field(
+        &self,
+        prop: &mut PropertyDefinition<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<BoundIdentifier<'a>> {
+        let decorators = (!prop.decorators.is_empty()).then(|| {
+            let decorators = prop.decorators.take_in(ctx.ast);
+            self.memoize_decorators(decorators, true, state, ctx)
+        });
+        if prop.computed {
+            self.memoize_computed_key(&mut prop.key, state, ctx);
+        }
+        let decorators = decorators?;
+
+        let is_static = prop.r#static;
+        let private_accessors = match &prop.key {
+            PropertyKey::PrivateIdentifier(ident) => {
+                if !is_static {
+                    state.brand.get_or_insert(ident.name);
+                }
+                Vec::from(Self::create_private_accessor_functions(ident.name, state, ctx))
+            }
+            _ => vec![],
+        };
+        let name = Self::get_element_name(&prop.key);
+        let init = self.ctx.var_declarations.create_uid_var(&format!("init_{name}"), ctx);
+        let extra_init =
+            self.ctx.var_declarations.create_uid_var(&format!("init_extra_{name}"), ctx);
+
+        Self::add_member_decorators(
+            ElementKind::Field,
+            is_static,
+            &prop.key,
+            decorators,
+            private_accessors,
+            state,
+            ctx,
+        );
+        state.add_bindings(ElementKind::Field, is_static, [init.clone(), extra_init.clone()]);
+        prop.value = Some(Self::create_init_call(&init, is_static, prop.value.take(), ctx));
+
+        Some(extra_init)
+    }
+
+    /// Transform an `accessor` property to a private storage field, with a getter and setter.
+    ///
+    /// * `accessor x = 1` -> `#A = 1; get x() { return this.#A; } set x(v) { this.#A = v; }`
+    /// * `@dec accessor x = 1` -> `#A = _init_x(this, 1); get x() { return this.#A; } set x(v) { this.#A = v; }`
+    /// * `@dec accessor #x = 1` -> `#A = _init_x(this, 1); get #x() { return _get_x(this); } set #x(v) { _set_x(this, v); }`
+    fn transform_accessor(
+        &self,
+        prop: &mut AccessorProperty<'a>,
+        new_elements: &mut ArenaVec<'a, ClassElement<'a>>,
+        pending_instance_init: &mut Option<BoundIdentifier<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let decorators = (!prop.decorators.is_empty()).then(|| {
+            let decorators = prop.decorators.take_in(ctx.ast);
+            self.memoize_decorators(decorators, true, state, ctx)
+        });
+        if prop.computed {
+            self.memoize_computed_key(&mut prop.key, state, ctx);
+        }
+
+        let is_static = prop.r#static;
+        let storage = state.create_storage_name(ctx);
+        let mut value = prop.value.take();
+        let mut extra_init = None;
+
+        let class_scope_id = state.class_scope_id;
+        let this_storage = |ctx: &TraverseCtx<'a>| {
+            ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                ctx.ast.expression_this(SPAN),
+                ctx.ast.private_identifier(SPAN, storage),
+                false,
+            )
+        };
+
+        let (getter, setter) = match (decorators, &prop.key) {
+            (Some(decorators), PropertyKey::PrivateIdentifier(ident)) => {
+                let name = ident.name;
+                if !is_static {
+                    state.brand.get_or_insert(name);
+                }
+                let private_accessors =
+                    Self::create_private_accessor_functions(storage, state, ctx);
+                let var_declarations = &self.ctx.var_declarations;
+                let init = var_declarations.create_uid_var(&format!("init_{name}"), ctx);
+                let get = var_declarations.create_uid_var(&format!("get_{name}"), ctx);
+                let set = var_declarations.create_uid_var(&format!("set_{name}"), ctx);
+                let extra = var_declarations.create_uid_var(&format!("init_extra_{name}"), ctx);
+                Self::add_member_decorators(
+                    ElementKind::Accessor,
+                    is_static,
+                    &prop.key,
+                    decorators,
+                    private_accessors,
+                    state,
+                    ctx,
+                );
+                state.add_bindings(
+                    ElementKind::Accessor,
+                    is_static,
+                    [init.clone(), get.clone(), set.clone(), extra.clone()],
+                );
+                value = Some(Self::create_init_call(&init, is_static, value, ctx));
+                extra_init = Some(extra);
+
+                // `_get_x(this)` / `_get_x()`
+                let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+                let getter_value = Self::create_call(&get, this, ctx);
+                let getter = Self::create_getter_function(getter_value, class_scope_id, ctx);
+                // `_set_x(this, v)` / `_set_x(v)`
+                let setter = Self::create_setter_function(
+                    |v, ctx| {
+                        let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+                        Self::create_call(&set, this.into_iter().chain(iter::once(v)), ctx)
+                    },
+                    class_scope_id,
+                    ctx,
+                );
+                (getter, setter)
+            }
+            (decorators, key) => {
+                if let Some(decorators) = decorators {
+                    let name = Self::get_element_name(key);
+                    let var_declarations = &self.ctx.var_declarations;
+                    let init = var_declarations.create_uid_var(&format!("init_{name}"), ctx);
+                    let extra = var_declarations.create_uid_var(&format!("init_extra_{name}"), ctx);
+                    Self::add_member_decorators(
+                        ElementKind::Accessor,
+                        is_static,
+                        key,
+                        decorators,
+                        [],
+                        state,
+                        ctx,
+                    );
+                    state.add_bindings(
+                        ElementKind::Accessor,
+                        is_static,
+                        [init.clone(), extra.clone()],
+                    );
+                    value = Some(Self::create_init_call(&init, is_static, value, ctx));
+                    extra_init = Some(extra);
+                }
+
+                // `this.#A`
+                let getter_value = Expression::from(this_storage(ctx));
+                let getter = Self::create_getter_function(getter_value, class_scope_id, ctx);
+                // `this.#A = v`
+                let setter = Self::create_setter_function(
+                    |v, ctx| {
+                        let target = AssignmentTarget::from(this_storage(ctx));
+                        ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, v)
+                    },
+                    class_scope_id,
+                    ctx,
+                );
+                (getter, setter)
+            }
+        };
+
+        let static_extra_init = Self::add_extra_init(
+            &mut value,
+            is_static,
+            extra_init,
+            pending_instance_init,
+            state,
+            ctx,
+        );
+
+        // `#A = value`
+        new_elements.push(ctx.ast.class_element_property_definition(
+            prop.span,
+            PropertyDefinitionType::PropertyDefinition,
+            ctx.ast.vec(),
+            ctx.ast.property_key_private_identifier(SPAN, storage),
+            NONE,
+            value,
+            false,
+            is_static,
+            false,
+            false,
+            false,
+            false,
+            false,
+            None,
+        ));
+        let getter_key = Self::clone_key(&prop.key, ctx);
+        let setter_key = prop.key.take_in(ctx.ast);
+        for (kind, key, function) in [
+            (MethodDefinitionKind::Get, getter_key, getter),
+            (MethodDefinitionKind::Set, setter_key, setter),
+        ] {
+            new_elements.push(ctx.ast.class_element_method_definition(
+                SPAN,
+                MethodDefinitionType::MethodDefinition,
+                ctx.ast.vec(),
+                key,
+                function,
+                kind,
+                prop.computed,
+                is_static,
+                false,
+                false,
+                None,
+            ));
+        }
+        new_elements.extend(static_extra_init);
+    }
+
+    /// Insert calls to the extra initializer of a field (and `_initProto`) so that they run
+    /// immediately after the field is initialized.
+    ///
+    /// * Instance fields: The initializer is called before the next instance field is initialized.
+    ///   `y = 2` -> `y = (_init_extra_x(this), 2)`
+    /// * Static fields: Returns a static block which calls the initializer.
+    ///   `static { _init_extra_x(); }`
+    fn add_extra_init(
+        value: &mut Option<Expression<'a>>,
+        is_static: bool,
+        extra_init: Option<BoundIdentifier<'a>>,
+        pending_instance_init: &mut Option<BoundIdentifier<'a>>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ClassElement<'a>> {
+        if is_static {
+            let extra_init = extra_init?;
+            let call = Self::create_call(&extra_init, iter::empty(), ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            let scope_id =
+                ctx.create_child_scope(state.class_scope_id, ScopeFlags::ClassStaticBlock);
+            return Some(ctx.ast.class_element_static_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec1(stmt),
+                scope_id,
+            ));
+        }
+
+        if let Some(init) = pending_instance_init.take() {
+            let call = Self::create_call(&init, [ctx.ast.expression_this(SPAN)], ctx);
+            let old_value = value.take().unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            *value =
+                Some(ctx.ast.expression_sequence(SPAN, ctx.ast.vec_from_array([call, old_value])));
+        }
+        *pending_instance_init = extra_init;
+        None
+    }
+
+    /// Call initializer which wasn't called by any instance field in the constructor.
+    ///
+    /// * Base class: `constructor() { _init_extra_x(this); }`
+    /// * Derived class: `super()` -> `_init_extra_x(super())`
+    fn insert_instance_init_into_constructor(
+        class: &mut Class<'a>,
+        init: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let has_super_class = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method)
+                if method.kind.is_constructor() && method.value.body.is_some() =>
+            {
+                method.value.body.as_mut()
+            }
+            _ => None,
+        });
+
+        if let Some(body) = constructor {
+            if has_super_class {
+                SuperCallWrapper::new(init, ctx).visit_function_body(body);
+            } else {
+                let call = Self::create_call(init, [ctx.ast.expression_this(SPAN)], ctx);
+                body.statements.insert(0, ctx.ast.statement_expression(SPAN, call));
+            }
+        } else {
+            let scope_id = ctx.create_child_scope(
+                class.scope_id(),
+                ScopeFlags::Function | ScopeFlags::Constructor,
+            );
+            let call = Self::create_call(init, [ctx.ast.expression_this(SPAN)], ctx);
+            let stmt = ctx.ast.statement_expression(SPAN, call);
+            let constructor =
+                create_class_constructor(iter::once(stmt), has_super_class, scope_id, ctx);
+            class.body.body.insert(0, constructor);
+        }
+    }
+
+    /// Create the static block which applies decorators.
+    ///
+    /// * Without class decorators: `static { [_init_x, _init_extra_x] = _applyDecs2311(this, [], [[dec, 0, "x"]]).e; }`
+    /// * With class decorators: `static { ({ e: [...], c: [_Class, _initClass] } = _applyDecs2311(this, [dec], [...])); }`
+    ///
+    /// If there are decorated static methods, `_initStatic(this)` is called in the block too.
+    fn create_decorators_static_block(
+        &self,
+        class_decorators: Option<Vec<DecoratorEntry<'a>>>,
+        class_bindings: Option<(&BoundIdentifier<'a>, &BoundIdentifier<'a>)>,
+        parent_class: Option<Expression<'a>>,
+        state: ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ClassElement<'a> {
+        let ClassState {
+            decorators_scope_id,
+            member_decorators,
+            static_non_field_bindings,
+            instance_non_field_bindings,
+            static_field_bindings,
+            instance_field_bindings,
+            init_proto,
+            init_static,
+            brand,
+            ..
+        } = state;
+
+        let (class_decorators, class_decorators_have_this) = match class_decorators {
+            Some(decorators) => Self::create_decorators_expression(decorators, true, ctx),
+            None => (ctx.ast.expression_array(SPAN, ctx.ast.vec()), false),
+        };
+        let member_decorators = ctx.ast.expression_array(
+            SPAN,
+            ctx.ast.vec_from_iter(member_decorators.into_iter().map(ArrayExpressionElement::from)),
+        );
+        let brand =
+            brand.map(|name| Self::create_brand_check_function(name, decorators_scope_id, ctx));
+
+        // `_applyDecs2311(this, classDecs, memberDecs, classDecsHaveThis, instanceBrand, parentClass)`
+        let mut arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_this(SPAN)),
+            Argument::from(class_decorators),
+            Argument::from(member_decorators),
+        ]);
+        if class_decorators_have_this || brand.is_some() || parent_class.is_some() {
+            let value = if class_decorators_have_this { 1.0 } else { 0.0 };
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                value,
+                None,
+                NumberBase::Decimal,
+            )));
+        }
+        if brand.is_some() || parent_class.is_some() {
+            let brand = brand.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+            arguments.push(Argument::from(brand));
+        }
+        if let Some(parent_class) = parent_class {
+            arguments.push(Argument::from(parent_class));
+        }
+        let call = self.ctx.helper_call_expr(Helper::ApplyDecs2311, SPAN, arguments, ctx);
+
+        let element_bindings = static_non_field_bindings
+            .iter()
+            .chain(&instance_non_field_bindings)
+            .chain(&static_field_bindings)
+            .chain(&instance_field_bindings)
+            .chain(&init_proto)
+            .chain(&init_static)
+            .collect::<Vec<_>>();
+
+        let assignment = match class_bindings {
+            None => {
+                // `[_init_x, _init_extra_x] = _applyDecs2311(...).e`
+                let target = Self::create_array_assignment_target(element_bindings, ctx);
+                let value = create_property_access(SPAN, call, "e", ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            Some(class_bindings) if element_bindings.is_empty() => {
+                // `[_Class, _initClass] = _applyDecs2311(...).c`
+                let target =
+                    Self::create_array_assignment_target(<[_; 2]>::from(class_bindings), ctx);
+                let value = create_property_access(SPAN, call, "c", ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            Some(class_bindings) => {
+                // `({ e: [_init_x, _init_extra_x], c: [_Class, _initClass] } = _applyDecs2311(...))`
+                let e = Self::create_array_assignment_target(element_bindings, ctx);
+                let c = Self::create_array_assignment_target(<[_; 2]>::from(class_bindings), ctx);
+                let properties =
+                    ctx.ast.vec_from_iter([("e", e), ("c", c)].map(|(name, target)| {
+                        ctx.ast.assignment_target_property_assignment_target_property_property(
+                            SPAN,
+                            ctx.ast.property_key_static_identifier(SPAN, name),
+                            AssignmentTargetMaybeDefault::from(target),
+                            false,
+                        )
+                    }));
+                let target = AssignmentTarget::from(
+                    ctx.ast
+                        .assignment_target_pattern_object_assignment_target(SPAN, properties, None),
+                );
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, call)
+            }
+        };
+
+        let mut stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment));
+        if let Some(init_static) = &init_static {
+            // `_initStatic(this)`
+            let call = Self::create_call(init_static, [ctx.ast.expression_this(SPAN)], ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        ctx.ast.class_element_static_block_with_scope_id(SPAN, stmts, decorators_scope_id)
+    }
+
+    /// Memoize decorator expressions, so they are evaluated before the class, in order.
+    ///
+    /// * `@dec` -> `dec`
+    /// * `@dec()` -> `_dec = dec()` before the class, and `_dec`
+    /// * `@obj.dec` -> `obj.dec`, called with `obj` as `this`
+    /// * `@obj.prop.dec` -> `_obj$prop = obj.prop` before the class, and `_obj$prop.dec` called with `_obj$prop` as `this`
+    ///
+    /// `reparent` is `true` if the decorators are inside the class scope.
+    fn memoize_decorators(
+        &self,
+        decorators: ArenaVec<'a, Decorator<'a>>,
+        reparent: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<DecoratorEntry<'a>> {
+        decorators
+            .into_iter()
+            .map(|decorator| {
+                let mut expr = decorator.expression;
+                while let Expression::ParenthesizedExpression(paren) = expr {
+                    expr = paren.unbox().expression;
+                }
+
+                let is_member = expr.as_member_expression().is_some_and(|m| !m.object().is_super());
+                match &mut expr {
+                    Expression::Identifier(_) => DecoratorEntry { this: None, decorator: expr },
+                    _ if is_member => {
+                        let member = expr.as_member_expression_mut().unwrap();
+                        let this = if let Expression::Identifier(ident) = member.object() {
+                            MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                                .create_read_expression(ctx)
+                        } else {
+                            let object = member.object_mut().take_in(ctx.ast);
+                            let binding = self.memoize(object, None, reparent, state, ctx);
+                            *member.object_mut() = binding.create_read_expression(ctx);
+                            binding.create_read_expression(ctx)
+                        };
+                        if let MemberExpression::ComputedMemberExpression(member) = member {
+                            if !member.expression.is_literal() {
+                                let property = member.expression.take_in(ctx.ast);
+                                let binding = self.memoize(property, None, reparent, state, ctx);
+                                member.expression = binding.create_read_expression(ctx);
+                            }
+                        }
+                        DecoratorEntry { this: Some(this), decorator: expr }
+                    }
+                    _ => {
+                        let binding = self.memoize(expr, Some("dec"), reparent, state, ctx);
+                        DecoratorEntry {
+                            this: None,
+                            decorator: binding.create_read_expression(ctx),
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Memoize a computed key of a class element, so it is evaluated before the class, in order.
+    ///
+    /// `[key]() {}` -> `_computedKey = _toPropertyKey(key)` before the class, and `[_computedKey]() {}`
+    fn memoize_computed_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if matches!(key, PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_)) {
+            return;
+        }
+        let Some(expr) = key.as_expression_mut() else { return };
+        let expr = expr.take_in(ctx.ast);
+        let expr = self.ctx.helper_call_expr(
+            Helper::ToPropertyKey,
+            SPAN,
+            ctx.ast.vec1(Argument::from(expr)),
+            ctx,
+        );
+        let binding = self.memoize(expr, Some("computedKey"), true, state, ctx);
+        *key = PropertyKey::from(binding.create_read_expression(ctx));
+    }
+
+    /// Assign `expr` to a new variable before the class.
+    ///
+    /// If `reparent` is `true`, scopes in `expr` are moved out of the class scope.
+    fn memoize(
+        &self,
+        expr: Expression<'a>,
+        name: Option<&str>,
+        reparent: bool,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        if reparent {
+            let scope_id = ctx.current_scope_id();
+            ScopeReparenter::new(ctx.scoping_mut(), scope_id).visit_expression(&expr);
+        }
+        let binding = match name {
+            Some(name) => self.ctx.var_declarations.create_uid_var(name, ctx),
+            None => self.ctx.var_declarations.create_uid_var_based_on_node(&expr, ctx),
+        };
+        state.pre_class.push(create_assignment(&binding, expr, ctx));
+        binding
+    }
+
+    /// Create the decorators of an element, or of the class.
+    ///
+    /// * Single decorator: `dec`
+    /// * Multiple decorators or `always_array` is `true`: `[dec1, dec2]`
+    /// * Any decorator has a `this` value: `[obj, obj.dec1, void 0, dec2]`
+    ///
+    /// Returns the decorators, and whether the decorators have a `this` value.
+    fn create_decorators_expression(
+        decorators: Vec<DecoratorEntry<'a>>,
+        always_array: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> (Expression<'a>, bool) {
+        let has_this = decorators.iter().any(|decorator| decorator.this.is_some());
+        if has_this {
+            let elements = decorators.into_iter().flat_map(|DecoratorEntry { this, decorator }| {
+                [this.unwrap_or_else(|| ctx.ast.void_0(SPAN)), decorator]
+            });
+            let elements = ctx.ast.vec_from_iter(elements.map(ArrayExpressionElement::from));
+            return (ctx.ast.expression_array(SPAN, elements), true);
+        }
+
+        if !always_array && decorators.len() == 1 {
+            let decorator = decorators.into_iter().next().unwrap().decorator;
+            return (decorator, false);
+        }
+
+        let elements = decorators
+            .into_iter()
+            .map(|DecoratorEntry { decorator, .. }| ArrayExpressionElement::from(decorator));
+        (ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(elements)), false)
+    }
+
+    /// Add an element to the `memberDecs` argument of the helper.
+    ///
+    /// `[decorators, flags, name, ...privateAccessors]`
+    fn add_member_decorators(
+        kind: ElementKind,
+        is_static: bool,
+        key: &PropertyKey<'a>,
+        decorators: Vec<DecoratorEntry<'a>>,
+        private_accessors: impl IntoIterator<Item = Expression<'a>>,
+        state: &mut ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (decorators, has_this) = Self::create_decorators_expression(decorators, false, ctx);
+        let mut flags = kind as u8;
+        if is_static {
+            flags |= STATIC;
+        }
+        if has_this {
+            flags |= DECORATORS_HAVE_THIS;
+        }
+        let flags =
+            ctx.ast.expression_numeric_literal(SPAN, f64::from(flags), None, NumberBase::Decimal);
+
+        let name = match key {
+            PropertyKey::PrivateIdentifier(ident) => {
+                ctx.ast.expression_string_literal(SPAN, ident.name, None)
+            }
+            PropertyKey::Identifier(ident) => {
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx)
+            }
+            _ => {
+                let name = key.static_name().unwrap_or_default();
+                ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(&name), None)
+            }
+        };
+
+        let elements = [decorators, flags, name].into_iter().chain(private_accessors);
+        let elements = ctx.ast.vec_from_iter(elements.map(ArrayExpressionElement::from));
+        state.member_decorators.push(ctx.ast.expression_array(SPAN, elements));
+    }
+
+    /// Get name of a class element for naming the initializer bindings.
+    fn get_element_name(key: &PropertyKey<'a>) -> String {
+        match key {
+            PropertyKey::PrivateIdentifier(ident) => ident.name.to_string(),
+            // Memoized computed key
+            PropertyKey::Identifier(_) => "computedKey".to_string(),
+            _ => to_identifier(key.static_name().unwrap_or_default().into_owned()),
+        }
+    }
+
+    /// Clone a property key of a class element, which has been memoized if computed.
+    fn clone_key(key: &PropertyKey<'a>, ctx: &mut TraverseCtx<'a>) -> PropertyKey<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => PropertyKey::from(
+                MaybeBoundIdentifier::from_identifier_reference(ident, ctx)
+                    .create_read_expression(ctx),
+            ),
+            _ => key.clone_in(ctx.ast.allocator),
+        }
+    }
+
+    /// * Instance field: `_init_x(this, value)`
+    /// * Static field: `_init_x(value)`
+    fn create_init_call(
+        init: &BoundIdentifier<'a>,
+        is_static: bool,
+        value: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let this = (!is_static).then(|| ctx.ast.expression_this(SPAN));
+        Self::create_call(init, this.into_iter().chain(value), ctx)
+    }
+
+    /// `callee(...arguments)`
+    fn create_call(
+        callee: &BoundIdentifier<'a>,
+        arguments: impl IntoIterator<Item = Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let callee = callee.create_read_expression(ctx);
+        let arguments = ctx.ast.vec_from_iter(arguments.into_iter().map(Argument::from));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// `[a, b, c]` as an assignment target
+    fn create_array_assignment_target<'b>(
+        bindings: impl IntoIterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a>
+    where
+        'a: 'b,
+    {
+        let elements = bindings
+            .into_iter()
+            .map(|binding| {
+                Some(AssignmentTargetMaybeDefault::from(binding.create_write_target(ctx)))
+            })
+            .collect::<Vec<_>>();
+        AssignmentTarget::from(ctx.ast.assignment_target_pattern_array_assignment_target(
+            SPAN,
+            ctx.ast.vec_from_iter(elements),
+            None,
+        ))
+    }
+
+    /// `get key() { return value; }`
+    fn create_getter_function(
+        value: Expression<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::GetAccessor);
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec(),
+            NONE,
+        );
+        let stmts = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+        Self::create_function(params, stmts, scope_id, ctx)
+    }
+
+    /// `set key(v) { body; }`
+    fn create_setter_function(
+        body: impl FnOnce(Expression<'a>, &mut TraverseCtx<'a>) -> Expression<'a>,
+        class_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let scope_id =
+            ctx.create_child_scope(class_scope_id, ScopeFlags::Function | ScopeFlags::SetAccessor);
+        let binding =
+            ctx.generate_binding(Atom::from("v"), scope_id, SymbolFlags::FunctionScopedVariable);
+        let param = ctx.ast.formal_parameter(
+            SPAN,
+            ctx.ast.vec(),
+            binding.create_binding_pattern(ctx),
+            None,
+            false,
+            false,
+        );
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ctx.ast.vec1(param),
+            NONE,
+        );
+        let v = binding.create_read_expression(ctx);
+        let stmts = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body(v, ctx)));
+        Self::create_function(params, stmts, scope_id, ctx)
+    }
+
+    fn create_function(
+        params: ArenaBox<'a, FormalParameters<'a>>,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        ctx.ast.alloc_function_with_scope_id(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts)),
+            scope_id,
+        )
+    }
+
+    /// Create functions to access a private field, passed to the helper for a private field or accessor.
+    ///
+    /// `o => o.#x`, `(o, v) => o.#x = v`
+    fn create_private_accessor_functions(
+        name: Atom<'a>,
+        state: &ClassState<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> [Expression<'a>; 2] {
+        let private_field = |o: &BoundIdentifier<'a>, ctx: &mut TraverseCtx<'a>| {
+            ctx.ast.member_expression_private_field_expression(
+                SPAN,
+                o.create_read_expression(ctx),
+                ctx.ast.private_identifier(SPAN, name),
+                false,
+            )
+        };
+        let getter = Self::create_arrow_function(
+            &["o"],
+            |params, ctx| Expression::from(private_field(&params[0], ctx)),
+            state.decorators_scope_id,
+            ctx,
+        );
+        let setter = Self::create_arrow_function(
+            &["o", "v"],
+            |params, ctx| {
+                let target = AssignmentTarget::from(private_field(&params[0], ctx));
+                let value = params[1].create_read_expression(ctx);
+                ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+            },
+            state.decorators_scope_id,
+            ctx,
+        );
+        [getter, setter]
+    }
+
+    /// `_ => #x in _`
+    fn create_brand_check_function(
+        name: Atom<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        Self::create_arrow_function(
+            &["_"],
+            |params, ctx| {
+                let object = params[0].create_read_expression(ctx);
+                ctx.ast.expression_private_in(SPAN, ctx.ast.private_identifier(SPAN, name), object)
+            },
+            parent_scope_id,
+            ctx,
+        )
+    }
+
+    /// `(a, b) => body`
+    fn create_arrow_function(
+        param_names: &[&'static str],
+        body: impl FnOnce(&[BoundIdentifier<'a>], &mut TraverseCtx<'a>) -> Expression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id =
+            ctx.create_child_scope(parent_scope_id, ScopeFlags::Arrow | ScopeFlags::Function);
+        let bindings = param_names
+            .iter()
+            .map(|&name| {
+                ctx.generate_binding(
+                    Atom::from(name),
+                    scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                )
+            })
+            .collect::<Vec<_>>();
+        let params = ctx.ast.vec_from_iter(bindings.iter().map(|binding| {
+            ctx.ast.formal_parameter(
+                SPAN,
+                ctx.ast.vec(),
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+                false,
+            )
+        }));
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            params,
+            NONE,
+        );
+        let body = body(&bindings, ctx);
+        let body = ctx.ast.alloc_function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, body)),
+        );
+        ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+        )
+    }
+}
+
+impl<'a> ClassState<'a> {
+    /// Add bindings for the elements returned by the helper for a class element.
+    fn add_bindings(
+        &mut self,
+        kind: ElementKind,
+        is_static: bool,
+        bindings: impl IntoIterator<Item = BoundIdentifier<'a>>,
+    ) {
+        let list = match (kind == ElementKind::Field, is_static) {
+            (false, true) => &mut self.static_non_field_bindings,
+            (false, false) => &mut self.instance_non_field_bindings,
+            (true, true) => &mut self.static_field_bindings,
+            (true, false) => &mut self.instance_field_bindings,
+        };
+        list.extend(bindings);
+    }
+
+    /// Create a name for the private storage of an `accessor` property: `A`, `B`, ..., `Z`, `AA`, ...
+    fn create_storage_name(&mut self, ctx: &TraverseCtx<'a>) -> Atom<'a> {
+        loop {
+            let mut index = self.storage_count;
+            self.storage_count += 1;
+            let mut name = String::new();
+            loop {
+                name.insert(0, char::from(b'A' + u8::try_from(index % 26).unwrap()));
+                if index < 26 {
+                    break;
+                }
+                index = index / 26 - 1;
+            }
+            let name = ctx.ast.atom(&name);
+            if self.private_names.insert(name) {
+                return name;
+            }
+        }
+    }
+}
+
+/// Visitor to replace references to the class inside the class body with the decorated class.
+struct ClassReferenceReplacer<'a, 'b, 'c> {
+    symbol_id: SymbolId,
+    binding: &'b BoundIdentifier<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b, 'c> ClassReferenceReplacer<'a, 'b, 'c> {
+    fn new(
+        symbol_id: SymbolId,
+        binding: &'b BoundIdentifier<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        Self { symbol_id, binding, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassReferenceReplacer<'a, '_, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let reference = self.ctx.scoping().get_reference(reference_id);
+        if reference.symbol_id() != Some(self.symbol_id) {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.scoping_mut().delete_resolved_reference(self.symbol_id, reference_id);
+        *ident = self.binding.create_reference(flags, self.ctx);
+    }
+}
+
+/// Visitor to wrap `super()` calls in a constructor with a call to an initializer.
+///
+/// `super()` -> `_init(super())`
+struct SuperCallWrapper<'a, 'b, 'c> {
+    init: &'b BoundIdentifier<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+}
+
+impl<'a, 'b, 'c> SuperCallWrapper<'a, 'b, 'c> {
+    fn new(init: &'b BoundIdentifier<'a>, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { init, ctx }
+    }
+}
+
+impl<'a> VisitMut<'a> for SuperCallWrapper<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_mut::walk_expression(self, expr);
+        if matches!(expr, Expression::CallExpression(call) if call.callee.is_super()) {
+            let super_call = expr.take_in(self.ctx.ast);
+            *expr = StandardDecorator::create_call(self.init, [super_call], self.ctx);
+        }
+    }
+
+    // `super()` in nested functions or classes doesn't refer to this class
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
@@ -0,0 +1,1095 @@
+//! ES2015: Block Scoping
+//!
+//! This plugin transforms `let` and `const` declarations into `var` declarations.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! let a = 1;
+//! {
+//!   let a = 2;
+//!   const b = a;
+//! }
+//! for (let i = 0; i < 3; i++) {
+//!   fns.push(() => i);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var a = 1;
+//! {
+//!   var _a = 2;
+//!   var b = _a;
+//! }
+//! var _loop = function (i) {
+//!   fns.push(() => i);
+//! };
+//! for (var i = 0; i < 3; i++) {
+//!   _loop(i);
+//! }
+//! ```
+//!
+//! Bindings are hoisted to the enclosing function, and renamed if they would otherwise clash with,
+//! or shadow, another binding of the same name.
+//!
+//! If a binding declared in a loop is captured by a closure, the loop body is moved into a function
+//! which is called on each iteration, so each closure sees its own copy of the binding.
+//! `break`, `continue` and `return` in the loop body are passed back via the function's return value,
+//! and `var` declarations in the loop body stay in the enclosing function.
+//! Loop bodies which use `this`, `arguments`, `super`, `new.target`, `yield` or `await`, jump to
+//! an outer label, or assign to a binding declared in the loop head, are not moved.
+//!
+//! With the `tdz` option, reading a binding before its declaration throws a `ReferenceError`,
+//! via the `tdz` helper. Only reads which definitely occur in the temporal dead zone are checked.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-block-scoping](https://babel.dev/docs/babel-plugin-transform-block-scoping).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-block-scoping>
+//! * Let and Const Declarations: <https://tc39.es/ecma262/#sec-let-and-const-declarations>
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BlockScopingOptions {
+    /// Throw a `ReferenceError` when a binding is read before its declaration.
+    pub tdz: bool,
+
+    /// Report an error instead of moving a loop body into a function.
+    pub throw_if_closure_required: bool,
+}
+
+pub struct BlockScoping<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    options: BlockScopingOptions,
+
+    /// Original scopes of `let` / `const` bindings which have been hoisted.
+    hoisted_symbols: FxHashMap<SymbolId, ScopeId>,
+
+    /// New names of bindings which were renamed when hoisted.
+    renamed_symbols: FxHashMap<SymbolId, Atom<'a>>,
+
+    /// Hoisted bindings which are referenced from a closure within their original scope.
+    captured_symbols: FxHashSet<SymbolId>,
+
+    /// Loops which are currently being traversed.
+    loops: Vec<LoopFrame>,
+
+    /// Loop which has just been exited, to be transformed in `exit_statement`.
+    exited_loop: Option<LoopFrame>,
+
+    /// `var _loop = function() {}` for a labeled loop, and the function's scope.
+    /// Inserted when exiting the outermost label.
+    pending_loop_declaration: Option<(Statement<'a>, ScopeId)>,
+}
+
+/// Bindings hoisted out of a loop.
+struct LoopFrame {
+    /// Scope which bindings in the loop are hoisted to.
+    hoist_scope_id: ScopeId,
+    /// Scope of the loop head. `None` for `while` and `do-while` loops.
+    scope_id: Option<ScopeId>,
+    /// Bindings declared in the loop head.
+    head_symbols: Vec<SymbolId>,
+    /// Bindings declared in the loop body.
+    body_symbols: Vec<SymbolId>,
+}
+
+impl<'a, 'ctx> BlockScoping<'a, 'ctx> {
+    pub fn new(options: BlockScopingOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            options,
+            hoisted_symbols: FxHashMap::default(),
+            renamed_symbols: FxHashMap::default(),
+            captured_symbols: FxHashSet::default(),
+            loops: vec![],
+            exited_loop: None,
+            pending_loop_declaration: None,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for BlockScoping<'a, '_> {
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.hoist_scope_bindings(block.scope_id(), ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoist_scope_bindings(stmt.scope_id(), ctx);
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(Some(stmt.scope_id()), ctx);
+    }
+
+    fn enter_while_statement(&mut self, _stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.enter_loop(None, ctx);
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        _stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.enter_loop(None, ctx);
+    }
+
+    fn exit_for_statement(&mut self, _stmt: &mut ForStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.exit_loop();
+    }
+
+    fn exit_for_in_statement(
+        &mut self,
+        _stmt: &mut ForInStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn exit_for_of_statement(
+        &mut self,
+        _stmt: &mut ForOfStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn exit_while_statement(&mut self, _stmt: &mut WhileStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.exit_loop();
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        _stmt: &mut DoWhileStatement<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.exit_loop();
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(frame) = self.exited_loop.take() {
+            self.transform_loop(stmt, frame, ctx);
+        } else if matches!(stmt, Statement::LabeledStatement(_))
+            && !matches!(ctx.parent(), Ancestor::LabeledStatementBody(_))
+        {
+            if let Some((declaration, scope_id)) = self.pending_loop_declaration.take() {
+                self.insert_loop_declaration(stmt, declaration, scope_id, ctx);
+            }
+        }
+    }
+
+    // `let a = 1` -> `var a = 1`
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !matches!(decl.kind, VariableDeclarationKind::Let | VariableDeclarationKind::Const) {
+            return;
+        }
+
+        // Bindings declared in a function's top level scope, or added by other transforms
+        // after their scope was entered, have not been hoisted yet
+        let mut symbol_ids = vec![];
+        decl.bound_names(&mut |ident| symbol_ids.push(ident.symbol_id()));
+        for symbol_id in symbol_ids {
+            if is_lexical_binding(ctx.scoping().symbol_flags(symbol_id)) {
+                let scope_id = ctx.scoping().symbol_scope_id(symbol_id);
+                self.hoist_binding(symbol_id, scope_id, Self::hoist_scope_id(scope_id, ctx), ctx);
+            }
+        }
+
+        // `let` bindings in a loop are reset on each iteration, but a `var` is not.
+        // `for (;;) { let a; }` -> `for (;;) { var a = void 0; }`
+        let reset = decl.kind == VariableDeclarationKind::Let && Self::is_in_loop_body(ctx);
+        decl.kind = VariableDeclarationKind::Var;
+        for declarator in &mut decl.declarations {
+            declarator.kind = VariableDeclarationKind::Var;
+            if reset && declarator.init.is_none() {
+                declarator.init = Some(ctx.ast.void_0(SPAN));
+            }
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(&name) = self.renamed_symbols.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if let Some(&name) = self.renamed_symbols.get(&symbol_id) {
+            ident.name = name;
+        }
+        if self.loops.is_empty() {
+            return;
+        }
+        if let Some(&scope_id) = self.hoisted_symbols.get(&symbol_id) {
+            if Self::is_in_closure(scope_id, ctx) {
+                self.captured_symbols.insert(symbol_id);
+            }
+        }
+    }
+
+    // `a; let a;` -> `babelHelpers.tdz("a"); var a;`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !self.options.tdz {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { return };
+        if ident.span.is_unspanned() {
+            return;
+        }
+        let scoping = ctx.scoping();
+        let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let scope_id = match self.hoisted_symbols.get(&symbol_id) {
+            Some(&scope_id) => scope_id,
+            None if is_lexical_binding(scoping.symbol_flags(symbol_id)) => {
+                scoping.symbol_scope_id(symbol_id)
+            }
+            None => return,
+        };
+        // A read from a closure may happen after the binding is initialized
+        if ident.span.end > scoping.symbol_span(symbol_id).start
+            || Self::is_in_closure(scope_id, ctx)
+        {
+            return;
+        }
+
+        let span = ident.span;
+        let name = ctx.ast.expression_string_literal(SPAN, ident.name, None);
+        ctx.delete_reference_for_identifier(ident);
+        *expr =
+            self.ctx.helper_call_expr(Helper::Tdz, span, ctx.ast.vec1(Argument::from(name)), ctx);
+    }
+}
+
+impl<'a> BlockScoping<'a, '_> {
+    /// Hoist all `let` / `const` bindings in a block scope.
+    ///
+    /// Done on entering the scope, so all references to them are renamed if required.
+    fn hoist_scope_bindings(&mut self, scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let scoping = ctx.scoping();
+        let symbol_ids = scoping
+            .iter_bindings_in(scope_id)
+            .filter(|&symbol_id| {
+                let flags = scoping.symbol_flags(symbol_id);
+                // Classes are transformed to `var` declarations by the classes plugin
+                is_lexical_binding(flags)
+                    || (self.ctx.is_classes_plugin_enabled && flags.contains(SymbolFlags::Class))
+            })
+            .collect::<Vec<_>>();
+        if symbol_ids.is_empty() {
+            return;
+        }
+        let hoist_scope_id = Self::hoist_scope_id(scope_id, ctx);
+        for symbol_id in symbol_ids {
+            self.hoist_binding(symbol_id, scope_id, hoist_scope_id, ctx);
+        }
+    }
+
+    /// Move a `let` / `const` binding from `scope_id` to `hoist_scope_id`, renaming it if required.
+    fn hoist_binding(
+        &mut self,
+        symbol_id: SymbolId,
+        scope_id: ScopeId,
+        hoist_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.hoisted_symbols.insert(symbol_id, scope_id);
+
+        let flags = ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+
+        if scope_id != hoist_scope_id {
+            let mut name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            // Rename if hoisting would clash with a binding in the function, or shadow a binding
+            // or global which is referenced by that name elsewhere in the function.
+            let scoping = ctx.scoping();
+            let parent_scope_id = scoping.scope_parent_id(scope_id).unwrap();
+            if scoping.find_binding(parent_scope_id, &name).is_some()
+                || scoping.root_unresolved_references().contains_key(name.as_str())
+            {
+                let new_name = ctx.generate_uid_name(&name);
+                ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+                self.renamed_symbols.insert(symbol_id, new_name);
+                name = new_name;
+            }
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(scope_id, hoist_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, hoist_scope_id);
+        }
+
+        if let Some(frame) = self.loops.last_mut() {
+            if frame.hoist_scope_id == hoist_scope_id {
+                if frame.scope_id == Some(scope_id) {
+                    frame.head_symbols.push(symbol_id);
+                } else {
+                    frame.body_symbols.push(symbol_id);
+                }
+            }
+        }
+    }
+
+    fn enter_loop(&mut self, scope_id: Option<ScopeId>, ctx: &mut TraverseCtx<'a>) {
+        self.loops.push(LoopFrame {
+            hoist_scope_id: ctx.current_hoist_scope_id(),
+            scope_id,
+            head_symbols: vec![],
+            body_symbols: vec![],
+        });
+        if let Some(scope_id) = scope_id {
+            self.hoist_scope_bindings(scope_id, ctx);
+        }
+    }
+
+    fn exit_loop(&mut self) {
+        self.exited_loop = self.loops.pop();
+    }
+
+    /// Move the loop body into a function, if any binding declared in the loop is captured by a closure.
+    ///
+    /// ```js
+    /// for (let i = 0; i < 3; i++) {
+    ///   if (i === 1) continue;
+    ///   fns.push(() => i);
+    /// }
+    /// ```
+    /// ->
+    /// ```js
+    /// var _loop = function (i) {
+    ///   if (i === 1) return;
+    ///   fns.push(() => i);
+    /// };
+    /// for (var i = 0; i < 3; i++) {
+    ///   _loop(i);
+    /// }
+    /// ```
+    fn transform_loop(
+        &mut self,
+        stmt: &mut Statement<'a>,
+        frame: LoopFrame,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let captured = frame
+            .head_symbols
+            .iter()
+            .chain(&frame.body_symbols)
+            .any(|symbol_id| self.captured_symbols.contains(symbol_id));
+        let Some(body) = loop_body_mut(stmt).filter(|_| captured) else {
+            self.exit_loop_frame(frame);
+            return;
+        };
+
+        if self.options.throw_if_closure_required {
+            self.ctx.error(closure_required(body.span()));
+            self.exit_loop_frame(frame);
+            return;
+        }
+
+        let labels = ctx
+            .ancestors()
+            .map_while(|ancestor| match ancestor {
+                Ancestor::LabeledStatementBody(labeled) => Some(labeled.label().name),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut visitor =
+            LoopBodyVisitor::new(&labels, &frame.head_symbols, &frame.body_symbols, ctx);
+        visitor.visit_statement(body);
+        if visitor.unsupported {
+            self.exit_loop_frame(frame);
+            return;
+        }
+        let LoopBodyVisitor { has_break, has_return, used_head_symbols, .. } = visitor;
+
+        let hoist_scope_id = frame.hoist_scope_id;
+        let current_scope_id = ctx.current_scope_id();
+        let body_parent_scope_id = frame.scope_id.unwrap_or(current_scope_id);
+
+        // Reuse scope of the loop body block as the function's scope
+        let (function_scope_id, mut statements) = match body.take_in(ctx.ast) {
+            Statement::BlockStatement(block) => {
+                let scope_id = block.scope_id();
+                let scoping = ctx.scoping_mut();
+                let flags = scoping.scope_flags(scope_id) & ScopeFlags::StrictMode;
+                *scoping.scope_flags_mut(scope_id) = ScopeFlags::Function | flags;
+                scoping.change_scope_parent_id(scope_id, Some(current_scope_id));
+                (scope_id, block.unbox().body)
+            }
+            body => {
+                let scope_id = ctx.insert_scope_below_statement_from_scope_id(
+                    &body,
+                    body_parent_scope_id,
+                    ScopeFlags::Function,
+                );
+                ctx.scoping_mut().change_scope_parent_id(scope_id, Some(current_scope_id));
+                (scope_id, ctx.ast.vec1(body))
+            }
+        };
+
+        // Bindings declared in the body move into the function
+        for &symbol_id in &frame.body_symbols {
+            let name = ctx.ast.atom(ctx.scoping().symbol_name(symbol_id));
+            let scoping = ctx.scoping_mut();
+            scoping.move_binding(hoist_scope_id, function_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, function_scope_id);
+        }
+
+        // Bindings declared in the head are passed to the function as parameters
+        let mut params = ctx.ast.vec_with_capacity(used_head_symbols.len());
+        let mut arguments = ctx.ast.vec_with_capacity(used_head_symbols.len());
+        let mut replacements = Vec::with_capacity(used_head_symbols.len());
+        for symbol_id in used_head_symbols {
+            let binding =
+                BoundIdentifier::new(ctx.ast.atom(ctx.scoping().symbol_name(symbol_id)), symbol_id);
+            let param = ctx.generate_binding(
+                binding.name,
+                function_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            params.push(ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx)));
+            arguments.push(Argument::from(binding.create_read_expression(ctx)));
+            replacements.push((symbol_id, param.symbol_id));
+        }
+
+        let mut visitor =
+            LoopBodyVisitor::new(&labels, &frame.head_symbols, &frame.body_symbols, ctx);
+        visitor.rewrite = true;
+        visitor.replacements = replacements;
+        visitor.visit_statements(&mut statements);
+        for (span, binding) in visitor.hoisted_vars {
+            let ident = ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                span,
+                binding.name,
+                binding.symbol_id,
+            );
+            let pattern = ctx.ast.binding_pattern(ident, NONE, false);
+            self.ctx.var_declarations.insert_var_binding_pattern(pattern, None, ctx);
+        }
+
+        // `var _loop = function (i) { ... }`
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        let function_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
+        let function = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(function_body),
+            function_scope_id,
+            false,
+        );
+        let loop_binding =
+            ctx.generate_uid("loop", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+        let declaration = Self::create_var_declaration(&loop_binding, function, ctx);
+
+        // `_loop(i)`
+        let call = ctx.ast.expression_call(
+            SPAN,
+            loop_binding.create_read_expression(ctx),
+            NONE,
+            arguments,
+            false,
+        );
+        let mut new_symbols = vec![loop_binding.symbol_id];
+        let new_body = if has_return {
+            // `var _ret = _loop(i); if (_ret === "break") break; if (_ret) return _ret.v;`
+            let ret_binding =
+                ctx.generate_uid("ret", hoist_scope_id, SymbolFlags::FunctionScopedVariable);
+            new_symbols.push(ret_binding.symbol_id);
+            let mut stmts = ctx.ast.vec_with_capacity(3);
+            stmts.push(Self::create_var_declaration(&ret_binding, call, ctx));
+            if has_break {
+                stmts.push(Self::create_break_if_requested(
+                    ret_binding.create_read_expression(ctx),
+                    ctx,
+                ));
+            }
+            let value = Expression::from(ctx.ast.member_expression_static(
+                SPAN,
+                ret_binding.create_read_expression(ctx),
+                ctx.ast.identifier_name(SPAN, "v"),
+                false,
+            ));
+            stmts.push(ctx.ast.statement_if(
+                SPAN,
+                ret_binding.create_read_expression(ctx),
+                ctx.ast.statement_return(SPAN, Some(value)),
+                None,
+            ));
+            stmts
+        } else if has_break {
+            // `if (_loop(i) === "break") break;`
+            ctx.ast.vec1(Self::create_break_if_requested(call, ctx))
+        } else {
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call))
+        };
+        let new_body_scope_id = ctx.create_child_scope(body_parent_scope_id, ScopeFlags::empty());
+        *loop_body_mut(stmt).unwrap() =
+            ctx.ast.statement_block_with_scope_id(SPAN, new_body, new_body_scope_id);
+
+        if labels.is_empty() {
+            self.insert_loop_declaration(stmt, declaration, function_scope_id, ctx);
+        } else {
+            self.pending_loop_declaration = Some((declaration, function_scope_id));
+        }
+
+        // Bindings in the head, and `_loop` and `_ret`, are still declared in the enclosing scope
+        new_symbols.extend(frame.head_symbols);
+        self.add_symbols_to_parent_loop(hoist_scope_id, new_symbols);
+    }
+
+    /// Exit a loop without transforming it.
+    fn exit_loop_frame(&mut self, frame: LoopFrame) {
+        let LoopFrame { hoist_scope_id, head_symbols, mut body_symbols, .. } = frame;
+        body_symbols.extend(head_symbols);
+        self.add_symbols_to_parent_loop(hoist_scope_id, body_symbols);
+    }
+
+    /// Record bindings hoisted out of an exited loop as declared in the body of the enclosing loop.
+    fn add_symbols_to_parent_loop(&mut self, hoist_scope_id: ScopeId, symbol_ids: Vec<SymbolId>) {
+        if let Some(parent) = self.loops.last_mut() {
+            if parent.hoist_scope_id == hoist_scope_id {
+                parent.body_symbols.extend(symbol_ids);
+            }
+        }
+    }
+
+    /// Insert `var _loop = function() {}` before the loop.
+    fn insert_loop_declaration(
+        &self,
+        stmt: &mut Statement<'a>,
+        declaration: Statement<'a>,
+        function_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if Self::is_multiple_statements_allowed(ctx) {
+            self.ctx.statement_injector.insert_before(stmt, declaration);
+        } else {
+            // `if (x) for (;;) {}` -> `if (x) { var _loop = function () {}; for (;;) {} }`
+            let scope_id = ctx.insert_scope_below_statement(stmt, ScopeFlags::empty());
+            ctx.scoping_mut().change_scope_parent_id(function_scope_id, Some(scope_id));
+            let stmts = ctx.ast.vec_from_array([declaration, stmt.take_in(ctx.ast)]);
+            *stmt = ctx.ast.statement_block_with_scope_id(SPAN, stmts, scope_id);
+        }
+    }
+
+    /// `var binding = init`
+    fn create_var_declaration(
+        binding: &BoundIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            binding.create_binding_pattern(ctx),
+            Some(init),
+            false,
+        );
+        Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            ctx.ast.vec1(declarator),
+            false,
+        ))
+    }
+
+    /// `if (value === "break") break;`
+    fn create_break_if_requested(value: Expression<'a>, ctx: &TraverseCtx<'a>) -> Statement<'a> {
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            value,
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "break", None),
+        );
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_break(SPAN, None), None)
+    }
+
+    /// Get the closest function, static block or program scope to `scope_id`.
+    fn hoist_scope_id(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> ScopeId {
+        let scoping = ctx.scoping();
+        scoping
+            .scope_ancestors(scope_id)
+            .find(|&scope_id| scoping.scope_flags(scope_id).is_var())
+            .unwrap()
+    }
+
+    /// Check if current position is inside a function nested within `scope_id`.
+    fn is_in_closure(scope_id: ScopeId, ctx: &TraverseCtx<'a>) -> bool {
+        let scoping = ctx.scoping();
+        ctx.ancestor_scopes()
+            .take_while(|&ancestor_id| ancestor_id != scope_id)
+            .any(|ancestor_id| scoping.scope_flags(ancestor_id).is_function())
+    }
+
+    /// Check if current variable declaration is run once per iteration of a loop.
+    fn is_in_loop_body(ctx: &TraverseCtx<'a>) -> bool {
+        if matches!(ctx.parent(), Ancestor::ForInStatementLeft(_) | Ancestor::ForOfStatementLeft(_))
+        {
+            return false;
+        }
+        ctx.ancestors()
+            .take_while(|ancestor| {
+                !matches!(
+                    ancestor,
+                    Ancestor::FunctionBodyStatements(_) | Ancestor::StaticBlockBody(_)
+                )
+            })
+            .any(|ancestor| {
+                matches!(
+                    ancestor,
+                    Ancestor::ForStatementBody(_)
+                        | Ancestor::ForInStatementBody(_)
+                        | Ancestor::ForOfStatementBody(_)
+                        | Ancestor::WhileStatementBody(_)
+                        | Ancestor::DoWhileStatementBody(_)
+                )
+            })
+    }
+
+    /// Check the parent node to see if multiple statements are allowed.
+    fn is_multiple_statements_allowed(ctx: &TraverseCtx<'a>) -> bool {
+        matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        )
+    }
+}
+
+fn is_lexical_binding(flags: SymbolFlags) -> bool {
+    flags.contains(SymbolFlags::BlockScopedVariable) && !flags.contains(SymbolFlags::CatchVariable)
+}
+
+fn loop_body_mut<'s, 'a>(stmt: &'s mut Statement<'a>) -> Option<&'s mut Statement<'a>> {
+    match stmt {
+        Statement::ForStatement(stmt) => Some(&mut stmt.body),
+        Statement::ForInStatement(stmt) => Some(&mut stmt.body),
+        Statement::ForOfStatement(stmt) => Some(&mut stmt.body),
+        Statement::WhileStatement(stmt) => Some(&mut stmt.body),
+        Statement::DoWhileStatement(stmt) => Some(&mut stmt.body),
+        _ => None,
+    }
+}
+
+fn closure_required(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Compiling let/const in this block would add a closure (throwIfClosureRequired).",
+    )
+    .with_label(span)
+}
+
+/// Where a `break` or `continue` in a loop body jumps to.
+enum Jump {
+    /// Loop or switch within the body.
+    Inner,
+    /// Out of the loop.
+    Break,
+    /// Next iteration of the loop.
+    Continue,
+    /// Loop outside the loop being transformed.
+    Outer,
+}
+
+/// Visitor for a loop body which is moved into a function.
+///
+/// Run first to check the body can be moved, and then with `rewrite: true` to:
+/// * Replace `break`, `continue` and `return` with returns from the function.
+/// * Point references to bindings in the loop head to the function's parameters.
+/// * Replace `var` declarations with assignments, and collect the bindings,
+///   to be declared outside the function.
+struct LoopBodyVisitor<'a, 'v> {
+    ctx: &'v mut TraverseCtx<'a>,
+    rewrite: bool,
+    /// Labels of the loop
+    labels: &'v [Atom<'a>],
+    /// Bindings declared in the loop head
+    head_symbols: &'v [SymbolId],
+    /// Bindings declared in the loop body
+    body_symbols: &'v [SymbolId],
+    /// Bindings declared in the loop head, and the parameters which replace them
+    replacements: Vec<(SymbolId, SymbolId)>,
+    /// Labels of statements within the body
+    inner_labels: Vec<Atom<'a>>,
+    loop_depth: u32,
+    switch_depth: u32,
+    function_depth: u32,
+    arrow_depth: u32,
+    unsupported: bool,
+    has_break: bool,
+    has_return: bool,
+    used_head_symbols: Vec<SymbolId>,
+    /// Outer `var` bindings with their original spans, to be declared outside the function.
+    hoisted_vars: Vec<(Span, BoundIdentifier<'a>)>,
+}
+
+impl<'a, 'v> LoopBodyVisitor<'a, 'v> {
+    fn new(
+        labels: &'v [Atom<'a>],
+        head_symbols: &'v [SymbolId],
+        body_symbols: &'v [SymbolId],
+        ctx: &'v mut TraverseCtx<'a>,
+    ) -> Self {
+        Self {
+            ctx,
+            rewrite: false,
+            labels,
+            head_symbols,
+            body_symbols,
+            replacements: vec![],
+            inner_labels: vec![],
+            loop_depth: 0,
+            switch_depth: 0,
+            function_depth: 0,
+            arrow_depth: 0,
+            unsupported: false,
+            has_break: false,
+            has_return: false,
+            used_head_symbols: vec![],
+            hoisted_vars: vec![],
+        }
+    }
+
+    fn is_in_loop_function(&self) -> bool {
+        self.function_depth == 0 && self.arrow_depth == 0
+    }
+
+    fn classify_jump(&self, label: Option<&LabelIdentifier<'a>>, is_break: bool) -> Jump {
+        match label {
+            Some(label) if self.inner_labels.contains(&label.name) => Jump::Inner,
+            Some(label) if !self.labels.contains(&label.name) => Jump::Outer,
+            None if self.loop_depth > 0 || (is_break && self.switch_depth > 0) => Jump::Inner,
+            _ if is_break => Jump::Break,
+            _ => Jump::Continue,
+        }
+    }
+
+    /// `break` -> `return "break"`, `continue` -> `return`, `return x` -> `return { v: x }`
+    fn transform_jump(&mut self, stmt: &mut Statement<'a>) {
+        let jump = match stmt {
+            Statement::BreakStatement(stmt) => self.classify_jump(stmt.label.as_ref(), true),
+            Statement::ContinueStatement(stmt) => self.classify_jump(stmt.label.as_ref(), false),
+            Statement::ReturnStatement(ret) => {
+                self.has_return = true;
+                if self.rewrite {
+                    let ast = self.ctx.ast;
+                    let value = ret.argument.take().unwrap_or_else(|| ast.void_0(SPAN));
+                    let property = ast.object_property_kind_object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        ast.property_key_static_identifier(SPAN, "v"),
+                        value,
+                        false,
+                        false,
+                        false,
+                    );
+                    let object = ast.expression_object(SPAN, ast.vec1(property));
+                    *stmt = ast.statement_return(ret.span, Some(object));
+                }
+                return;
+            }
+            _ => return,
+        };
+        let ast = self.ctx.ast;
+        match jump {
+            Jump::Inner => {}
+            Jump::Outer => self.unsupported = true,
+            Jump::Break => {
+                self.has_break = true;
+                if self.rewrite {
+                    let value = ast.expression_string_literal(SPAN, "break", None);
+                    *stmt = ast.statement_return(stmt.span(), Some(value));
+                }
+            }
+            Jump::Continue => {
+                if self.rewrite {
+                    *stmt = ast.statement_return(stmt.span(), None);
+                }
+            }
+        }
+    }
+
+    /// Check if a `var` declaration declares bindings other than those hoisted from the loop body.
+    fn is_outer_var(&self, decl: &VariableDeclaration<'a>) -> bool {
+        decl.kind == VariableDeclarationKind::Var
+            && self.is_in_loop_function()
+            && decl.declarations.first().is_some_and(|declarator| {
+                let mut is_outer = false;
+                declarator.id.bound_names(&mut |ident| {
+                    is_outer |= !self.body_symbols.contains(&ident.symbol_id());
+                });
+                is_outer
+            })
+    }
+
+    /// `var a = 1, b` -> `a = 1`
+    fn hoist_var_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in &mut decl.declarations {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                unreachable!()
+            };
+            let span = ident.span;
+            let binding = BoundIdentifier::from_binding_ident(ident);
+            if let Some(init) = declarator.init.take() {
+                let target = binding.create_write_target(self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+            self.hoisted_vars.push((span, binding));
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(SPAN, expressions)),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for LoopBodyVisitor<'a, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        walk_mut::walk_statements(self, stmts);
+        if self.rewrite {
+            // Remove `var` declarations without initializers
+            stmts.retain(
+                |stmt| !matches!(stmt, Statement::EmptyStatement(empty) if empty.span.is_unspanned()),
+            );
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let is_loop = loop_body_mut(stmt).is_some();
+        let is_switch = matches!(stmt, Statement::SwitchStatement(_));
+        self.loop_depth += u32::from(is_loop);
+        self.switch_depth += u32::from(is_switch);
+        walk_mut::walk_statement(self, stmt);
+        self.loop_depth -= u32::from(is_loop);
+        self.switch_depth -= u32::from(is_switch);
+
+        if !self.is_in_loop_function() {
+            return;
+        }
+        self.transform_jump(stmt);
+
+        if self.rewrite {
+            if let Statement::VariableDeclaration(decl) = stmt {
+                if self.is_outer_var(decl) {
+                    *stmt = match self.hoist_var_declaration(decl) {
+                        Some(expr) => self.ctx.ast.statement_expression(SPAN, expr),
+                        None => self.ctx.ast.statement_empty(SPAN),
+                    };
+                }
+            }
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if !self.rewrite
+            && self.is_outer_var(decl)
+            && decl
+                .declarations
+                .iter()
+                .any(|declarator| !declarator.id.kind.is_binding_identifier())
+        {
+            self.unsupported = true;
+        }
+        walk_mut::walk_variable_declaration(self, decl);
+    }
+
+    // `for (var i = 0;;)` -> `for (i = 0;;)`
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        walk_mut::walk_for_statement_init(self, init);
+        if self.rewrite {
+            if let ForStatementInit::VariableDeclaration(decl) = init {
+                if self.is_outer_var(decl) {
+                    let expr = self
+                        .hoist_var_declaration(decl)
+                        .unwrap_or_else(|| self.ctx.ast.void_0(SPAN));
+                    *init = ForStatementInit::from(expr);
+                }
+            }
+        }
+    }
+
+    // `for (var k in obj)` -> `for (k in obj)`
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        walk_mut::walk_for_statement_left(self, left);
+        if self.rewrite {
+            if let ForStatementLeft::VariableDeclaration(decl) = left {
+                if self.is_outer_var(decl) {
+                    let BindingPatternKind::BindingIdentifier(ident) =
+                        &decl.declarations[0].id.kind
+                    else {
+                        unreachable!()
+                    };
+                    let span = ident.span;
+                    let binding = BoundIdentifier::from_binding_ident(ident);
+                    *left = ForStatementLeft::from(binding.create_write_target(self.ctx));
+                    self.hoisted_vars.push((span, binding));
+                }
+            }
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        if !self.rewrite && stmt.r#await && self.is_in_loop_function() {
+            self.unsupported = true;
+        }
+        walk_mut::walk_for_of_statement(self, stmt);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        self.inner_labels.push(stmt.label.name);
+        walk_mut::walk_labeled_statement(self, stmt);
+        self.inner_labels.pop();
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: ScopeFlags) {
+        // Functions have their own `this`, `arguments` etc, so only references to bindings
+        // in the loop head need collecting and updating
+        self.function_depth += 1;
+        walk_mut::walk_function(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        self.function_depth += 1;
+        walk_mut::walk_class_body(self, body);
+        self.function_depth -= 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        let reference = scoping.get_reference(reference_id);
+        if self.rewrite {
+            let Some(symbol_id) = reference.symbol_id() else { return };
+            if let Some(&(_, param_symbol_id)) =
+                self.replacements.iter().find(|(head_symbol_id, _)| *head_symbol_id == symbol_id)
+            {
+                scoping.get_reference_mut(reference_id).set_symbol_id(param_symbol_id);
+                scoping.delete_resolved_reference(symbol_id, reference_id);
+                scoping.add_resolved_reference(param_symbol_id, reference_id);
+            }
+        } else if ident.name == "arguments" && reference.symbol_id().is_none() {
+            self.unsupported |= self.function_depth == 0;
+        } else if let Some(symbol_id) = reference.symbol_id() {
+            if self.head_symbols.contains(&symbol_id) {
+                // Changes would not be visible to the loop head
+                if reference.is_write() {
+                    self.unsupported = true;
+                }
+                if !self.used_head_symbols.contains(&symbol_id) {
+                    self.used_head_symbols.push(symbol_id);
+                }
+            }
+        }
+    }
+
+    fn visit_this_expression(&mut self, _expr: &mut ThisExpression) {
+        self.unsupported |= !self.rewrite && self.function_depth == 0;
+    }
+
+    fn visit_super(&mut self, _expr: &mut Super) {
+        self.unsupported |= !self.rewrite && self.function_depth == 0;
+    }
+
+    fn visit_meta_property(&mut self, meta: &mut MetaProperty<'a>) {
+        self.unsupported |= !self.rewrite && self.function_depth == 0 && meta.meta.name == "new";
+    }
+
+    fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
+        self.unsupported |= !self.rewrite && self.function_depth == 0;
+        walk_mut::walk_yield_expression(self, expr);
+    }
+
+    fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
+        self.unsupported |= !self.rewrite && self.is_in_loop_function();
+        walk_mut::walk_await_expression(self, expr);
+    }
+}
@@ -0,0 +1,1343 @@
+//! ES2015: Classes
+//!
+//! This plugin transforms class declarations and class expressions to constructor functions
+//! with methods defined on the prototype.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! class Foo extends Bar {
+//!   constructor(x) {
+//!     super(x);
+//!     this.x = x;
+//!   }
+//!   method() {
+//!     return super.method() + new.target;
+//!   }
+//!   get prop() {
+//!     return this.x;
+//!   }
+//!   static create() {
+//!     return new Foo(1);
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! let Foo = /*#__PURE__*/function (_Bar) {
+//!   function Foo(x) {
+//!     var _this;
+//!     babelHelpers.classCallCheck(this, Foo);
+//!     _this = babelHelpers.callSuper(this, Foo, [x]);
+//!     _this.x = x;
+//!     return _this;
+//!   }
+//!   babelHelpers.inherits(Foo, _Bar);
+//!   return babelHelpers.createClass(Foo, [{
+//!     key: "method",
+//!     value: function method() {
+//!       return babelHelpers.superPropGet(Foo, "method", this, 3)([]) + void 0;
+//!     }
+//!   }, {
+//!     key: "prop",
+//!     get: function () {
+//!       return this.x;
+//!     }
+//!   }], [{
+//!     key: "create",
+//!     value: function create() {
+//!       return new Foo(1);
+//!     }
+//!   }]);
+//! }(Bar);
+//! ```
+//!
+//! ## Options
+//!
+//! ### `loose`
+//!
+//! `boolean`, defaults to `false`.
+//!
+//! Shorthand for enabling the `setClassMethods`, `superIsCallableConstructor` and `noClassCalls`
+//! assumptions.
+//!
+//! ## Assumptions
+//!
+//! * `setClassMethods`: Methods are assigned to the prototype (`_proto.method = function method() {}`)
+//!   instead of being defined with `createClass`. Accessors are still defined with `createClass`.
+//! * `superIsCallableConstructor`: `super(x)` is compiled to `_Bar.call(this, x) || this`
+//!   and `inheritsLoose` is used instead of `inherits`.
+//! * `noClassCalls`: `classCallCheck` is not inserted into the constructor.
+//!
+//! ## Implementation
+//!
+//! The transform runs on exit of the class, after other plugins (notably class properties) have
+//! already transformed the class body. The class's own scope is reused as the scope of the wrapper
+//! IIFE, so methods keep their existing scopes.
+//!
+//! ## Missing features
+//!
+//! Implementation is incomplete at present. Still TODO:
+//!
+//! * Classes which still contain properties, accessor properties, static blocks, private elements
+//!   or decorators after other plugins have run. These are left as is, and an error is reported.
+//! * Update expressions (`super.prop++`) and logical assignments (`super.prop ||= value`)
+//!   on `super` properties. An error is reported.
+//! * `super` in object literal methods (`ES2015ObjectSuper`). The shorthand properties plugin
+//!   reports an error for these.
+//! * `super` in arrow functions which have already been transformed to function expressions.
+//! * Class expressions wrapped by the legacy decorators transform.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/tree/v7.26.2/packages/babel-plugin-transform-classes>
+//! * Class definitions specification: <https://tc39.es/ecma262/#sec-class-definitions>
+
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{GetSpan, SPAN, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword, number::NumberBase,
+};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_member_callee, create_prototype_member},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ClassesOptions {
+    /// Enables `setClassMethods`, `superIsCallableConstructor` and `noClassCalls` assumptions.
+    pub loose: bool,
+}
+
+pub struct Classes<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    set_class_methods: bool,
+    super_is_callable_constructor: bool,
+    no_class_calls: bool,
+}
+
+impl<'a, 'ctx> Classes<'a, 'ctx> {
+    pub fn new(options: ClassesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            set_class_methods: options.loose || ctx.assumptions.set_class_methods,
+            super_is_callable_constructor: options.loose
+                || ctx.assumptions.super_is_callable_constructor,
+            no_class_calls: options.loose || ctx.assumptions.no_class_calls,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Classes<'a, '_> {
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            // `class C {}` -> `let C = function () { ... }();`
+            Statement::ClassDeclaration(class) => {
+                if let Some(decl) = self.transform_class_declaration(class, ctx) {
+                    let new_stmt = Statement::VariableDeclaration(decl);
+                    self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                    *stmt = new_stmt;
+                }
+            }
+            // `export class C {}` -> `export let C = function () { ... }();`
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::ClassDeclaration(class)) = &mut export.declaration {
+                    if let Some(decl) = self.transform_class_declaration(class, ctx) {
+                        export.declaration = Some(Declaration::VariableDeclaration(decl));
+                    }
+                }
+            }
+            // `export default class C {}` -> `let C = function () { ... }(); export { C as default };`
+            // `export default class {}` -> `export default function () { ... }();`
+            Statement::ExportDefaultDeclaration(export) => {
+                let ExportDefaultDeclarationKind::ClassDeclaration(class) = &mut export.declaration
+                else {
+                    return;
+                };
+                if class.id.is_none() {
+                    if let Some(expr) = self.transform_class(class, ctx) {
+                        export.declaration = ExportDefaultDeclarationKind::from(expr);
+                    }
+                    return;
+                }
+                let Some(decl) = self.transform_class_declaration(class, ctx) else { return };
+                let local = decl.declarations[0].id.get_binding_identifier().unwrap();
+                let local = BoundIdentifier::from_binding_ident(local);
+                let specifier = ctx.ast.export_specifier(
+                    SPAN,
+                    ModuleExportName::IdentifierReference(local.create_read_reference(ctx)),
+                    ctx.ast.module_export_name_identifier_name(SPAN, "default"),
+                    ImportOrExportKind::Value,
+                );
+                let export_stmt = ctx.ast.plain_export_named_declaration(
+                    export.span,
+                    ctx.ast.vec1(specifier),
+                    None,
+                );
+                let new_stmt = Statement::VariableDeclaration(decl);
+                self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+                self.ctx
+                    .statement_injector
+                    .insert_after(&new_stmt, Statement::ExportNamedDeclaration(export_stmt));
+                *stmt = new_stmt;
+            }
+            _ => {}
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(class_expr) = Self::find_class_expression(expr) {
+            let Expression::ClassExpression(class) = class_expr else { unreachable!() };
+            if let Some(new_expr) = self.transform_class(class, ctx) {
+                *class_expr = new_expr;
+            }
+        }
+    }
+}
+
+impl<'a> Classes<'a, '_> {
+    /// Find the class expression to transform.
+    ///
+    /// Class properties transform wraps class expressions which need statements inserted before
+    /// or after them in a sequence expression (`(_Class = class {}, _Class.x = 1, _Class)`),
+    /// so look inside that too.
+    fn find_class_expression<'e>(expr: &'e mut Expression<'a>) -> Option<&'e mut Expression<'a>> {
+        match expr {
+            Expression::ClassExpression(_) => Some(expr),
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.iter_mut().find_map(|expr| match expr {
+                    Expression::ClassExpression(_) => Some(expr),
+                    Expression::AssignmentExpression(assign)
+                        if matches!(assign.right, Expression::ClassExpression(_)) =>
+                    {
+                        Some(&mut assign.right)
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Create an error for the first class decorator or element which prevents the class being
+    /// transformed.
+    ///
+    /// Only classes which contain nothing but methods (after other transforms have run) are supported.
+    fn unsupported_element_error(class: &Class<'a>) -> Option<OxcDiagnostic> {
+        if let Some(decorator) = class.decorators.first() {
+            return Some(
+                OxcDiagnostic::error("Class decorators are not supported yet.")
+                    .with_label(decorator.span),
+            );
+        }
+        class.body.body.iter().find_map(|element| {
+            let message = match element {
+                ClassElement::MethodDefinition(method) => {
+                    if let Some(decorator) = method.decorators.first() {
+                        return Some(
+                            OxcDiagnostic::error("Class decorators are not supported yet.")
+                                .with_label(decorator.span),
+                        );
+                    }
+                    if method.key.is_private_identifier() {
+                        "Private methods are not supported yet."
+                    } else if method.value.body.is_none() {
+                        "Class methods without a body are not supported yet."
+                    } else {
+                        return None;
+                    }
+                }
+                ClassElement::PropertyDefinition(_) => "Class properties are not supported yet.",
+                ClassElement::AccessorProperty(_) => {
+                    "Class accessor properties are not supported yet."
+                }
+                ClassElement::StaticBlock(_) => "Class static blocks are not supported yet.",
+                ClassElement::TSIndexSignature(_) => "Index signatures are not supported yet.",
+            };
+            Some(OxcDiagnostic::error(message).with_label(element.span()))
+        })
+    }
+
+    /// Transform class declaration to `let C = function () { ... }();`.
+    ///
+    /// The class is declared with `var` if block scoping plugin is enabled, which hoisted the class
+    /// binding when its scope was entered.
+    fn transform_class_declaration(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<ArenaBox<'a, VariableDeclaration<'a>>> {
+        let span = class.span;
+        let id = class.id.clone()?;
+        let init = self.transform_class(class, ctx)?;
+
+        let (kind, flags) = if self.ctx.is_block_scoping_plugin_enabled {
+            (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
+        } else {
+            (VariableDeclarationKind::Let, SymbolFlags::BlockScopedVariable)
+        };
+        *ctx.scoping_mut().symbol_flags_mut(id.symbol_id()) = flags;
+
+        let declarator = ctx.ast.variable_declarator(
+            SPAN,
+            kind,
+            ctx.ast.binding_pattern(
+                BindingPatternKind::BindingIdentifier(ctx.ast.alloc(id)),
+                NONE,
+                false,
+            ),
+            Some(init),
+            false,
+        );
+        Some(ctx.ast.alloc_variable_declaration(span, kind, ctx.ast.vec1(declarator), false))
+    }
+
+    /// Transform a class to an IIFE which returns the constructor function.
+    ///
+    /// Returns `None` if the class cannot be transformed.
+    fn transform_class(
+        &self,
+        class: &mut Class<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if class.declare {
+            return None;
+        }
+        if let Some(error) = Self::unsupported_element_error(class) {
+            self.ctx.error(error);
+            return None;
+        }
+
+        // Reuse class scope as the scope of the wrapper function
+        let wrapper_scope_id = class.scope_id();
+        let is_strict_mode = ctx.current_scope_flags().is_strict_mode();
+        *ctx.scoping_mut().scope_flags_mut(wrapper_scope_id) =
+            ScopeFlags::Function | ScopeFlags::StrictMode;
+
+        let class_binding = Self::create_class_binding(class, wrapper_scope_id, ctx);
+
+        // `class C extends Bar {}` -> `function (_Bar) { ... }(Bar)`
+        let super_class = class.super_class.take().map(|super_class| {
+            let binding = ctx.generate_uid_based_on_node(
+                &super_class,
+                wrapper_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            (binding, super_class)
+        });
+        let super_binding = super_class.as_ref().map(|(binding, _)| binding);
+
+        let mut elements = class.body.body.take_in(ctx.ast);
+        let constructor = elements
+            .iter()
+            .position(|element| {
+                matches!(element, ClassElement::MethodDefinition(method) if method.kind.is_constructor())
+            })
+            .map(|index| {
+                let ClassElement::MethodDefinition(method) = elements.remove(index) else {
+                    unreachable!()
+                };
+                method.unbox().value
+            });
+
+        let mut stmts = ctx.ast.vec();
+
+        // `function C() { ... }`
+        let constructor =
+            self.transform_constructor(constructor, &class_binding, super_binding, ctx);
+        stmts.push(Statement::FunctionDeclaration(constructor));
+
+        // `babelHelpers.inherits(C, _Bar);`
+        if let Some(super_binding) = super_binding {
+            let helper = if self.super_is_callable_constructor {
+                Helper::InheritsLoose
+            } else {
+                Helper::Inherits
+            };
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(class_binding.create_read_expression(ctx)),
+                Argument::from(super_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+            stmts.push(ctx.ast.statement_expression(SPAN, call));
+        }
+
+        // Methods
+        let return_value = self.transform_methods(elements, &class_binding, &mut stmts, ctx);
+        stmts.push(ctx.ast.statement_return(SPAN, Some(return_value)));
+
+        // `/*#__PURE__*/ function (_Bar) { ... }(Bar)`
+        let (params, arguments) = match super_class {
+            Some((binding, super_class)) => (
+                ctx.ast.vec1(
+                    ctx.ast.plain_formal_parameter(SPAN, binding.create_binding_pattern(ctx)),
+                ),
+                ctx.ast.vec1(Argument::from(super_class)),
+            ),
+            None => (ctx.ast.vec(), ctx.ast.vec()),
+        };
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            params,
+            NONE,
+        );
+        // Class body is always strict mode code.
+        // Improve on Babel: If outer scope is sloppy mode, add `"use strict"` directive to the wrapper.
+        let directives = if is_strict_mode {
+            ctx.ast.vec()
+        } else {
+            ctx.ast.vec1(ctx.ast.use_strict_directive())
+        };
+        let body = ctx.ast.alloc_function_body(SPAN, directives, stmts);
+        let wrapper = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+            wrapper_scope_id,
+            false,
+        );
+        Some(ctx.ast.expression_call_with_pure(class.span, wrapper, NONE, arguments, false, true))
+    }
+
+    /// Create binding for the constructor function, inside the wrapper function.
+    ///
+    /// * Class expression with a name: reuse the existing binding in class scope.
+    /// * Class declaration: create a new binding in class scope, and point references to the class
+    ///   from inside the class body to it.
+    /// * Anonymous class: create a new `_Class` binding.
+    fn create_class_binding(
+        class: &Class<'a>,
+        wrapper_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        let Some(id) = &class.id else {
+            return ctx.generate_uid("Class", wrapper_scope_id, SymbolFlags::Function);
+        };
+
+        let outer_symbol_id = id.symbol_id();
+        if ctx.scoping().symbol_scope_id(outer_symbol_id) == wrapper_scope_id {
+            *ctx.scoping_mut().symbol_flags_mut(outer_symbol_id) = SymbolFlags::Function;
+            return BoundIdentifier::from_binding_ident(id);
+        }
+
+        let binding = ctx.generate_binding(id.name, wrapper_scope_id, SymbolFlags::Function);
+        ClassReferenceRetargeter { from: outer_symbol_id, to: binding.symbol_id, ctx }
+            .visit_class_body(&class.body);
+        binding
+    }
+
+    /// Transform class constructor to a function declaration.
+    ///
+    /// Creates a default constructor if class does not have one.
+    fn transform_constructor(
+        &self,
+        constructor: Option<ArenaBox<'a, Function<'a>>>,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: Option<&BoundIdentifier<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaBox<'a, Function<'a>> {
+        let is_derived = super_binding.is_some();
+        let mut func = if let Some(func) = constructor {
+            func
+        } else {
+            let scope_id = ctx.create_child_scope(
+                class_binding_scope_id(class_binding, ctx),
+                ScopeFlags::Function,
+            );
+            let params = ctx.ast.alloc_formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                ctx.ast.vec(),
+                NONE,
+            );
+            let mut stmts = ctx.ast.vec();
+            if is_derived {
+                // `return babelHelpers.callSuper(this, C, arguments);`
+                let arguments = Self::create_arguments_ident(scope_id, ctx);
+                let call = self.create_super_call(
+                    class_binding,
+                    super_binding,
+                    SuperCallArguments::Arguments(arguments),
+                    ctx,
+                );
+                stmts.push(ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), stmts);
+            ctx.ast.alloc_function_with_scope_id(
+                SPAN,
+                FunctionType::FunctionDeclaration,
+                None,
+                false,
+                false,
+                false,
+                NONE,
+                NONE,
+                params,
+                NONE,
+                Some(body),
+                scope_id,
+            )
+        };
+
+        func.r#type = FunctionType::FunctionDeclaration;
+        let symbol_id = class_binding.symbol_id;
+        let span = ctx.scoping().symbol_span(symbol_id);
+        func.id =
+            Some(ctx.ast.binding_identifier_with_symbol_id(span, class_binding.name, symbol_id));
+        let scope_id = func.scope_id();
+        Self::reset_method_scope_flags(scope_id, ctx);
+
+        let this_binding = (is_derived && func.span != SPAN)
+            .then(|| ctx.generate_uid("this", scope_id, SymbolFlags::FunctionScopedVariable));
+
+        let mut visitor = ClassMethodVisitor {
+            classes: self,
+            class_binding,
+            super_binding,
+            kind: MethodKind::Constructor,
+            this_binding: this_binding.as_ref(),
+            arrow_depth: 0,
+            scope_id,
+            temp_bindings: vec![],
+            ctx,
+        };
+        visitor.visit_formal_parameters(&mut func.params);
+        let body = func.body.as_mut().unwrap();
+        visitor.visit_function_body(body);
+        let temp_bindings = visitor.temp_bindings;
+
+        let mut prepend = ctx.ast.vec();
+        // `var _this, _key;`
+        if let Some(decl) =
+            Self::create_var_declaration(this_binding.iter().chain(&temp_bindings), ctx)
+        {
+            prepend.push(decl);
+        }
+        if !self.no_class_calls {
+            // `babelHelpers.classCallCheck(this, C);`
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_this(SPAN)),
+                Argument::from(class_binding.create_read_expression(ctx)),
+            ]);
+            let call = self.ctx.helper_call_expr(Helper::ClassCallCheck, SPAN, arguments, ctx);
+            prepend.push(ctx.ast.statement_expression(SPAN, call));
+        }
+        body.statements.splice(0..0, prepend);
+
+        if let Some(this_binding) = &this_binding {
+            // `return _this;`
+            if !matches!(body.statements.last(), Some(Statement::ReturnStatement(_))) {
+                let this = this_binding.create_read_expression(ctx);
+                body.statements.push(ctx.ast.statement_return(SPAN, Some(this)));
+            }
+        }
+
+        func
+    }
+
+    /// Transform methods, and push the statements defining them to `stmts`.
+    ///
+    /// Returns the expression the wrapper function should return:
+    /// `babelHelpers.createClass(C, [...], [...])`, or `C` if methods have all been assigned.
+    fn transform_methods(
+        &self,
+        elements: ArenaVec<'a, ClassElement<'a>>,
+        class_binding: &BoundIdentifier<'a>,
+        stmts: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut instance_props = ClassPropertyDescriptors::new(ctx);
+        let mut static_props = ClassPropertyDescriptors::new(ctx);
+        let mut proto_binding: Option<BoundIdentifier<'a>> = None;
+
+        for element in elements {
+            let ClassElement::MethodDefinition(method) = element else { unreachable!() };
+            let MethodDefinition { key, value: mut func, kind, computed, r#static, .. } =
+                method.unbox();
+
+            let method_kind = MethodKind::Method { is_static: r#static };
+            self.transform_method_function(&mut func, &key, kind, class_binding, method_kind, ctx);
+
+            let func = Expression::FunctionExpression(func);
+            if self.set_class_methods && kind == MethodDefinitionKind::Method {
+                // `_proto.method = function method() {}` / `C.method = function method() {}`
+                let object = if r#static {
+                    class_binding.create_read_expression(ctx)
+                } else {
+                    let proto_binding = proto_binding.get_or_insert_with(|| {
+                        // `var _proto = C.prototype;`
+                        let binding = ctx.generate_uid(
+                            "proto",
+                            class_binding_scope_id(class_binding, ctx),
+                            SymbolFlags::FunctionScopedVariable,
+                        );
+                        let init =
+                            create_prototype_member(class_binding.create_read_expression(ctx), ctx);
+                        let declarator = ctx.ast.variable_declarator(
+                            SPAN,
+                            VariableDeclarationKind::Var,
+                            binding.create_binding_pattern(ctx),
+                            Some(init),
+                            false,
+                        );
+                        stmts.push(Statement::VariableDeclaration(
+                            ctx.ast.alloc_variable_declaration(
+                                SPAN,
+                                VariableDeclarationKind::Var,
+                                ctx.ast.vec1(declarator),
+                                false,
+                            ),
+                        ));
+                        binding
+                    });
+                    proto_binding.create_read_expression(ctx)
+                };
+                let target = Self::create_member_target(object, key, computed, ctx);
+                let assign =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, func);
+                stmts.push(ctx.ast.statement_expression(SPAN, assign));
+                continue;
+            }
+
+            let props = if r#static { &mut static_props } else { &mut instance_props };
+            props.add(key, computed, kind, func, ctx);
+        }
+
+        if instance_props.is_empty() && static_props.is_empty() && self.set_class_methods {
+            return class_binding.create_read_expression(ctx);
+        }
+
+        // `babelHelpers.createClass(C, [...], [...])`
+        let mut arguments = ctx.ast.vec1(Argument::from(class_binding.create_read_expression(ctx)));
+        match (instance_props.into_array(ctx), static_props.into_array(ctx)) {
+            (None, None) => {}
+            (Some(instance_props), None) => arguments.push(Argument::from(instance_props)),
+            (instance_props, Some(static_props)) => {
+                let instance_props =
+                    instance_props.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN));
+                arguments.push(Argument::from(instance_props));
+                arguments.push(Argument::from(static_props));
+            }
+        }
+        self.ctx.helper_call_expr(Helper::CreateClass, SPAN, arguments, ctx)
+    }
+
+    /// Transform method function to a function expression, and transform `super` and `new.target`
+    /// inside it.
+    fn transform_method_function(
+        &self,
+        func: &mut ArenaBox<'a, Function<'a>>,
+        key: &PropertyKey<'a>,
+        kind: MethodDefinitionKind,
+        class_binding: &BoundIdentifier<'a>,
+        method_kind: MethodKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        func.r#type = FunctionType::FunctionExpression;
+        let scope_id = func.scope_id();
+        Self::reset_method_scope_flags(scope_id, ctx);
+
+        let mut visitor = ClassMethodVisitor {
+            classes: self,
+            class_binding,
+            super_binding: None,
+            kind: method_kind,
+            this_binding: None,
+            arrow_depth: 0,
+            scope_id,
+            temp_bindings: vec![],
+            ctx,
+        };
+        visitor.visit_formal_parameters(&mut func.params);
+        let body = func.body.as_mut().unwrap();
+        visitor.visit_function_body(body);
+        let temp_bindings = visitor.temp_bindings;
+
+        // `var _key;`
+        if let Some(decl) = Self::create_var_declaration(temp_bindings.iter(), ctx) {
+            body.statements.insert(0, decl);
+        }
+
+        // Name the function after the method: `value: function method() {}`
+        if kind == MethodDefinitionKind::Method {
+            if let Some(name) = key.static_name() {
+                let name = name.as_ref();
+                if is_identifier_name(name)
+                    && !is_reserved_keyword(name)
+                    && !matches!(name, "arguments" | "eval")
+                    && !IdentifierReferenceFinder::contains(name, func)
+                {
+                    let name = ctx.ast.atom(name);
+                    let binding = ctx.generate_binding(name, scope_id, SymbolFlags::Function);
+                    func.id = Some(binding.create_binding_identifier(ctx));
+                }
+            }
+        }
+    }
+
+    /// `var a, b;`
+    fn create_var_declaration<'b>(
+        bindings: impl Iterator<Item = &'b BoundIdentifier<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Statement<'a>>
+    where
+        'a: 'b,
+    {
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_iter(bindings.map(|binding| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                None,
+                false,
+            )
+        }));
+        if declarations.is_empty() {
+            return None;
+        }
+        Some(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarations,
+            false,
+        )))
+    }
+
+    /// Method function scopes are no longer class methods, so remove method-specific flags.
+    fn reset_method_scope_flags(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        ctx.scoping_mut()
+            .scope_flags_mut(scope_id)
+            .remove(ScopeFlags::Constructor | ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+    }
+
+    /// `object.key` / `object[key]`.
+    fn create_member_target(
+        object: Expression<'a>,
+        key: PropertyKey<'a>,
+        computed: bool,
+        ctx: &TraverseCtx<'a>,
+    ) -> AssignmentTarget<'a> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) if !computed => {
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                AssignmentTarget::from(
+                    ctx.ast.member_expression_static(SPAN, object, property, false),
+                )
+            }
+            key => {
+                let key = key.into_expression();
+                AssignmentTarget::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+            }
+        }
+    }
+
+    /// Create `super(...)` call replacement.
+    ///
+    /// * `babelHelpers.callSuper(this, C, [a, b])`
+    /// * `_Bar.call(this, a, b) || this` (`superIsCallableConstructor`)
+    fn create_super_call(
+        &self,
+        class_binding: &BoundIdentifier<'a>,
+        super_binding: Option<&BoundIdentifier<'a>>,
+        arguments: SuperCallArguments<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let this = Argument::from(ctx.ast.expression_this(SPAN));
+        if self.super_is_callable_constructor {
+            let super_binding = super_binding.unwrap();
+            let callee = super_binding.create_read_expression(ctx);
+            let call = match arguments {
+                // `_Bar.apply(this, arguments)`
+                SuperCallArguments::Arguments(arguments) => {
+                    let callee = create_member_callee(callee, "apply", ctx);
+                    let arguments = ctx.ast.vec_from_array([this, Argument::from(arguments)]);
+                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+                }
+                SuperCallArguments::List(list) => {
+                    if list.iter().any(Argument::is_spread) {
+                        // `_Bar.apply(this, [a, ...b])`
+                        let callee = create_member_callee(callee, "apply", ctx);
+                        let array = Self::create_arguments_array(list, ctx);
+                        let arguments = ctx.ast.vec_from_array([this, Argument::from(array)]);
+                        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+                    } else {
+                        // `_Bar.call(this, a, b)`
+                        let callee = create_member_callee(callee, "call", ctx);
+                        let mut arguments = ctx.ast.vec_with_capacity(list.len() + 1);
+                        arguments.push(this);
+                        arguments.extend(list);
+                        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+                    }
+                }
+            };
+            return ctx.ast.expression_logical(
+                SPAN,
+                call,
+                LogicalOperator::Or,
+                ctx.ast.expression_this(SPAN),
+            );
+        }
+
+        let mut helper_arguments = ctx
+            .ast
+            .vec_from_array([this, Argument::from(class_binding.create_read_expression(ctx))]);
+        match arguments {
+            SuperCallArguments::Arguments(arguments) => {
+                helper_arguments.push(Argument::from(arguments));
+            }
+            SuperCallArguments::List(list) => {
+                if let [Argument::SpreadElement(spread)] = list.as_slice() {
+                    if spread.argument.is_specific_id("arguments") {
+                        // `super(...arguments)` -> `callSuper(this, C, arguments)`
+                        let Some(Argument::SpreadElement(spread)) = list.into_iter().next() else {
+                            unreachable!()
+                        };
+                        helper_arguments.push(Argument::from(spread.unbox().argument));
+                        return self.ctx.helper_call_expr(
+                            Helper::CallSuper,
+                            SPAN,
+                            helper_arguments,
+                            ctx,
+                        );
+                    }
+                }
+                if !list.is_empty() {
+                    let array = Self::create_arguments_array(list, ctx);
+                    helper_arguments.push(Argument::from(array));
+                }
+            }
+        }
+        self.ctx.helper_call_expr(Helper::CallSuper, SPAN, helper_arguments, ctx)
+    }
+
+    /// `(a, ...b)` -> `[a, ...b]`
+    fn create_arguments_array(
+        arguments: ArenaVec<'a, Argument<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements =
+            ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => ArrayExpressionElement::from(argument.into_expression()),
+            }));
+        ctx.ast.expression_array(SPAN, elements)
+    }
+
+    /// `arguments` reference.
+    fn create_arguments_ident(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scoping().find_binding(scope_id, "arguments");
+        ctx.create_ident_expr(SPAN, Atom::from("arguments"), symbol_id, ReferenceFlags::Read)
+    }
+}
+
+/// Get scope ID of the wrapper function, which is where class binding is declared.
+fn class_binding_scope_id(class_binding: &BoundIdentifier<'_>, ctx: &TraverseCtx<'_>) -> ScopeId {
+    ctx.scoping().symbol_scope_id(class_binding.symbol_id)
+}
+
+enum SuperCallArguments<'a> {
+    /// `arguments` in default constructor
+    Arguments(Expression<'a>),
+    /// Arguments of `super(...)` call
+    List(ArenaVec<'a, Argument<'a>>),
+}
+
+/// Property descriptors passed to `createClass`:
+/// `[{ key: "method", value: function method() {} }, { key: "prop", get: function () {} }]`
+struct ClassPropertyDescriptors<'a> {
+    descriptors: ArenaVec<'a, ArenaVec<'a, ObjectPropertyKind<'a>>>,
+    /// Index of descriptor for accessors with static keys, so getter and setter can be merged
+    accessors: FxHashMap<Atom<'a>, usize>,
+}
+
+impl<'a> ClassPropertyDescriptors<'a> {
+    fn new(ctx: &TraverseCtx<'a>) -> Self {
+        Self { descriptors: ctx.ast.vec(), accessors: FxHashMap::default() }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.descriptors.is_empty()
+    }
+
+    fn add(
+        &mut self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        kind: MethodDefinitionKind,
+        func: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let value_key = match kind {
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+            _ => "value",
+        };
+        let value = Self::create_property(value_key, func, ctx);
+
+        let static_key =
+            if computed { None } else { key.static_name().map(|name| ctx.ast.atom(&name)) };
+        if kind.is_accessor() {
+            if let Some(index) = static_key.and_then(|name| self.accessors.get(&name)) {
+                self.descriptors[*index].push(value);
+                return;
+            }
+        } else if let Some(name) = static_key {
+            // A method replaces an accessor with the same name
+            self.accessors.remove(&name);
+        }
+
+        let key_expr = match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ctx.ast.expression_string_literal(ident.span, ident.name, None)
+            }
+            key => key.into_expression(),
+        };
+        let key = Self::create_property("key", key_expr, ctx);
+        if kind.is_accessor() {
+            if let Some(name) = static_key {
+                self.accessors.insert(name, self.descriptors.len());
+            }
+        }
+        self.descriptors.push(ctx.ast.vec_from_array([key, value]));
+    }
+
+    fn create_property(
+        name: &'static str,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, name),
+            value,
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn into_array(self, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        if self.descriptors.is_empty() {
+            return None;
+        }
+        let elements = ctx.ast.vec_from_iter(self.descriptors.into_iter().map(|properties| {
+            ArrayExpressionElement::from(ctx.ast.expression_object(SPAN, properties))
+        }));
+        Some(ctx.ast.expression_array(SPAN, elements))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MethodKind {
+    Constructor,
+    Method { is_static: bool },
+}
+
+/// Visitor for transforming `super`, `this` and `new.target` in class constructor and methods.
+///
+/// * `super(a, b)` -> `_this = babelHelpers.callSuper(this, C, [a, b])` (derived class constructor)
+/// * `this` -> `_this` (derived class constructor)
+/// * `super.prop` -> `babelHelpers.superPropGet(C, "prop", this, 1)`
+/// * `super.method(a)` -> `babelHelpers.superPropGet(C, "method", this, 3)([a])`
+/// * `super.prop = value` -> `babelHelpers.superPropSet(C, "prop", value, this, 1, 1)`
+/// * `new.target` -> `this.constructor` (constructor) or `void 0` (method)
+/// * `return;` -> `return _this;` (derived class constructor)
+/// * `return value;` -> `return babelHelpers.possibleConstructorReturn(_this, value);` (derived class constructor)
+struct ClassMethodVisitor<'a, 'v, 'c> {
+    classes: &'v Classes<'a, 'c>,
+    class_binding: &'v BoundIdentifier<'a>,
+    super_binding: Option<&'v BoundIdentifier<'a>>,
+    kind: MethodKind,
+    /// `_this` binding in derived class constructor
+    this_binding: Option<&'v BoundIdentifier<'a>>,
+    /// Depth of arrow functions. `return` in arrow functions should not be transformed.
+    arrow_depth: u32,
+    /// Scope of the constructor or method function
+    scope_id: ScopeId,
+    /// Temporary variables to be declared at top of the function
+    temp_bindings: Vec<BoundIdentifier<'a>>,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> VisitMut<'a> for ClassMethodVisitor<'a, '_, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) => {
+                if let Some(this_binding) = self.this_binding {
+                    *expr = this_binding.create_spanned_read_expression(this.span, self.ctx);
+                }
+                return;
+            }
+            Expression::MetaProperty(meta) => {
+                if meta.meta.name == "new" && meta.property.name == "target" {
+                    let span = meta.span;
+                    *expr = match self.kind {
+                        // `this.constructor`
+                        MethodKind::Constructor => {
+                            let this = self.ctx.ast.expression_this(SPAN);
+                            let property = self.ctx.ast.identifier_name(SPAN, "constructor");
+                            Expression::from(
+                                self.ctx.ast.member_expression_static(span, this, property, false),
+                            )
+                        }
+                        MethodKind::Method { .. } => self.ctx.ast.void_0(span),
+                    };
+                }
+                return;
+            }
+            Expression::CallExpression(call) => {
+                if matches!(call.callee, Expression::Super(_)) {
+                    self.visit_arguments(&mut call.arguments);
+                    *expr = self.transform_super_call(call);
+                    return;
+                }
+                if is_super_member(&call.callee) {
+                    self.visit_arguments(&mut call.arguments);
+                    *expr = self.transform_super_method_call(call);
+                    return;
+                }
+            }
+            Expression::StaticMemberExpression(member) if member.object.is_super() => {
+                let span = member.span;
+                let key = self.create_static_key(&member.property);
+                *expr = self.create_super_prop_get(span, key, false);
+                return;
+            }
+            Expression::ComputedMemberExpression(member) if member.object.is_super() => {
+                self.visit_expression(&mut member.expression);
+                let span = member.span;
+                let key = member.expression.take_in(self.ctx.ast);
+                *expr = self.create_super_prop_get(span, key, false);
+                return;
+            }
+            Expression::AssignmentExpression(assign)
+                if is_super_assignment_target(&assign.left) =>
+            {
+                self.visit_expression(&mut assign.right);
+                if let Some(new_expr) = self.transform_super_assignment(assign) {
+                    *expr = new_expr;
+                }
+                return;
+            }
+            Expression::UpdateExpression(update)
+                if matches!(
+                    &update.argument,
+                    SimpleAssignmentTarget::StaticMemberExpression(member) if member.object.is_super()
+                ) || matches!(
+                    &update.argument,
+                    SimpleAssignmentTarget::ComputedMemberExpression(member) if member.object.is_super()
+                ) =>
+            {
+                self.classes.ctx.error(
+                    OxcDiagnostic::error("Updating `super` properties is not supported yet.")
+                        .with_label(update.span),
+                );
+                return;
+            }
+            _ => {}
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        walk_mut::walk_return_statement(self, stmt);
+        if self.arrow_depth > 0 {
+            return;
+        }
+        let Some(this_binding) = self.this_binding else { return };
+        stmt.argument = Some(match stmt.argument.take() {
+            // `return;` -> `return _this;`
+            None => this_binding.create_read_expression(self.ctx),
+            // `return value;` -> `return babelHelpers.possibleConstructorReturn(_this, value);`
+            Some(argument) => {
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(this_binding.create_read_expression(self.ctx)),
+                    Argument::from(argument),
+                ]);
+                self.classes.ctx.helper_call_expr(
+                    Helper::PossibleConstructorReturn,
+                    SPAN,
+                    arguments,
+                    self.ctx,
+                )
+            }
+        });
+    }
+
+    fn visit_arrow_function_expression(&mut self, arrow: &mut ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_mut::walk_arrow_function_expression(self, arrow);
+        self.arrow_depth -= 1;
+    }
+
+    // `this`, `super` and `new.target` in nested functions refer to that function
+    #[inline]
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    /// Only visit parts of nested classes which are evaluated in the scope of the current method.
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.visit_decorators(&mut class.decorators);
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.computed => {
+                    self.visit_property_key(&mut method.key);
+                }
+                ClassElement::PropertyDefinition(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                ClassElement::AccessorProperty(prop) if prop.computed => {
+                    self.visit_property_key(&mut prop.key);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'a> ClassMethodVisitor<'a, '_, '_> {
+    /// Receiver for `super` property access. `_this` in derived class constructor, otherwise `this`.
+    fn create_receiver(&mut self) -> Expression<'a> {
+        match self.this_binding {
+            Some(this_binding) => this_binding.create_read_expression(self.ctx),
+            None => self.ctx.ast.expression_this(SPAN),
+        }
+    }
+
+    /// Whether `super` refers to the prototype of the super class (instance methods and constructor),
+    /// rather than the super class itself (static methods).
+    fn is_prototype(&self) -> bool {
+        !matches!(self.kind, MethodKind::Method { is_static: true })
+    }
+
+    fn create_static_key(&self, property: &IdentifierName<'a>) -> Expression<'a> {
+        self.ctx.ast.expression_string_literal(property.span, property.name, None)
+    }
+
+    fn create_number(&self, value: u32) -> Expression<'a> {
+        self.ctx.ast.expression_numeric_literal(SPAN, f64::from(value), None, NumberBase::Decimal)
+    }
+
+    /// `babelHelpers.superPropGet(C, key, this, flags)`
+    ///
+    /// `flags`: `1` = prototype, `2` = call.
+    fn create_super_prop_get(
+        &mut self,
+        span: Span,
+        key: Expression<'a>,
+        call: bool,
+    ) -> Expression<'a> {
+        let flags = u32::from(self.is_prototype()) | if call { 2 } else { 0 };
+        let arguments = self.ctx.ast.vec_from_array([
+            Argument::from(self.class_binding.create_read_expression(self.ctx)),
+            Argument::from(key),
+            Argument::from(self.create_receiver()),
+            Argument::from(self.create_number(flags)),
+        ]);
+        self.classes.ctx.helper_call_expr(Helper::SuperPropGet, span, arguments, self.ctx)
+    }
+
+    /// `super(a, b)` -> `_this = babelHelpers.callSuper(this, C, [a, b])`
+    fn transform_super_call(&mut self, call: &mut CallExpression<'a>) -> Expression<'a> {
+        let arguments = call.arguments.take_in(self.ctx.ast);
+        let call = self.classes.create_super_call(
+            self.class_binding,
+            self.super_binding,
+            SuperCallArguments::List(arguments),
+            self.ctx,
+        );
+        match self.this_binding {
+            Some(this_binding) => create_assignment(this_binding, call, self.ctx),
+            None => call,
+        }
+    }
+
+    /// `super.method(a, b)` -> `babelHelpers.superPropGet(C, "method", this, 3)([a, b])`
+    fn transform_super_method_call(&mut self, call: &mut CallExpression<'a>) -> Expression<'a> {
+        let span = call.span;
+        let key = match call.callee.take_in(self.ctx.ast) {
+            Expression::StaticMemberExpression(member) => self.create_static_key(&member.property),
+            Expression::ComputedMemberExpression(member) => {
+                let mut key = member.unbox().expression;
+                self.visit_expression(&mut key);
+                key
+            }
+            _ => unreachable!(),
+        };
+        let callee = self.create_super_prop_get(SPAN, key, true);
+        let arguments = call.arguments.take_in(self.ctx.ast);
+        let array = Classes::create_arguments_array(arguments, self.ctx);
+        let arguments = self.ctx.ast.vec1(Argument::from(array));
+        self.ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// `super.prop = value` -> `babelHelpers.superPropSet(C, "prop", value, this, 1, 1)`
+    /// `super.prop += value` -> `babelHelpers.superPropSet(C, "prop", babelHelpers.superPropGet(C, "prop", this, 1) + value, this, 1, 1)`
+    ///
+    /// Reports an error for logical assignments, which are not supported yet.
+    fn transform_super_assignment(
+        &mut self,
+        assign: &mut AssignmentExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        let span = assign.span;
+        let mut key = match &mut assign.left {
+            AssignmentTarget::StaticMemberExpression(member) => {
+                self.create_static_key(&member.property)
+            }
+            AssignmentTarget::ComputedMemberExpression(member) => {
+                self.visit_expression(&mut member.expression);
+                member.expression.take_in(self.ctx.ast)
+            }
+            _ => unreachable!(),
+        };
+
+        let mut value = assign.right.take_in(self.ctx.ast);
+        if assign.operator != AssignmentOperator::Assign {
+            let Some(operator) = assign.operator.to_binary_operator() else {
+                self.classes.ctx.error(
+                    OxcDiagnostic::error(
+                        "Logical assignment to `super` properties is not supported yet.",
+                    )
+                    .with_label(span),
+                );
+                return None;
+            };
+            // `super[key] += value` -> `babelHelpers.superPropSet(C, _key = key, babelHelpers.superPropGet(C, _key, this, 1) + value, this, 1, 1)`
+            let current_key = if let Some(current_key) = Self::duplicate_key(&key, self.ctx) {
+                current_key
+            } else {
+                let binding = self.ctx.generate_uid_based_on_node(
+                    &key,
+                    self.scope_id,
+                    SymbolFlags::FunctionScopedVariable,
+                );
+                key = create_assignment(&binding, key, self.ctx);
+                let current_key = binding.create_read_expression(self.ctx);
+                self.temp_bindings.push(binding);
+                current_key
+            };
+            let current = self.create_super_prop_get(SPAN, current_key, false);
+            value = self.ctx.ast.expression_binary(SPAN, current, operator, value);
+        }
+
+        let arguments = self.ctx.ast.vec_from_array([
+            Argument::from(self.class_binding.create_read_expression(self.ctx)),
+            Argument::from(key),
+            Argument::from(value),
+            Argument::from(self.create_receiver()),
+            Argument::from(self.create_number(1)),
+            Argument::from(self.create_number(u32::from(self.is_prototype()))),
+        ]);
+        Some(self.classes.ctx.helper_call_expr(Helper::SuperPropSet, span, arguments, self.ctx))
+    }
+
+    /// Duplicate a `super` property key, if it can be evaluated twice without side effects.
+    fn duplicate_key(key: &Expression<'a>, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+        match key {
+            Expression::StringLiteral(lit) => {
+                Some(ctx.ast.expression_string_literal(lit.span, lit.value, None))
+            }
+            Expression::NumericLiteral(lit) => {
+                Some(ctx.ast.expression_numeric_literal(lit.span, lit.value, None, lit.base))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_super_member(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::StaticMemberExpression(member) => member.object.is_super(),
+        Expression::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
+
+fn is_super_assignment_target(target: &AssignmentTarget<'_>) -> bool {
+    match target {
+        AssignmentTarget::StaticMemberExpression(member) => member.object.is_super(),
+        AssignmentTarget::ComputedMemberExpression(member) => member.object.is_super(),
+        _ => false,
+    }
+}
+
+/// Visitor to point references to class declaration's outer binding, to the inner binding.
+struct ClassReferenceRetargeter<'a, 'v> {
+    from: SymbolId,
+    to: SymbolId,
+    ctx: &'v mut TraverseCtx<'a>,
+}
+
+impl<'a> Visit<'a> for ClassReferenceRetargeter<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let reference_id = ident.reference_id();
+        let scoping = self.ctx.scoping_mut();
+        if scoping.get_reference(reference_id).symbol_id() == Some(self.from) {
+            scoping.get_reference_mut(reference_id).set_symbol_id(self.to);
+            scoping.delete_resolved_reference(self.from, reference_id);
+            scoping.add_resolved_reference(self.to, reference_id);
+        }
+    }
+}
+
+/// Visitor to find whether a function contains a reference with a certain name.
+///
+/// Used to avoid naming a method function with a name which would shadow a reference inside it.
+struct IdentifierReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl IdentifierReferenceFinder<'_> {
+    fn contains(name: &str, func: &Function<'_>) -> bool {
+        let mut finder = IdentifierReferenceFinder { name, found: false };
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for IdentifierReferenceFinder<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == self.name {
+            self.found = true;
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.found {
+            walk::walk_expression(self, expr);
+        }
+    }
+}
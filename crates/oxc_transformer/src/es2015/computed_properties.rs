@@ -0,0 +1,308 @@
+//! ES2015: Computed Properties
+//!
+//! This plugin transforms computed keys of object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = { a: 1, [b]: 2, c: 3, get [d]() {} };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = babelHelpers.defineAccessor(
+//!   "get",
+//!   babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, b, 2), "c", 3),
+//!   d,
+//!   function () {},
+//! );
+//! ```
+//!
+//! Properties before the first computed key are kept in the object literal. The rest are defined
+//! in order, so that keys and values are evaluated in the same order as in the object literal.
+//! To avoid deeply nested calls, objects with more than 10 properties to define are stored in a
+//! temporary variable, and defined in chunks of 10: `(_obj = { a: 1 }, defineProperty(..._obj...), _obj)`.
+//!
+//! With the `setComputedProperties` assumption (or `loose` option), properties are assigned instead:
+//!
+//! ```js
+//! var _obj;
+//! var obj = (_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, babelHelpers.defineAccessor("get", _obj, d, function () {}), _obj);
+//! ```
+//!
+//! ## Missing features
+//!
+//! * Objects containing spread properties are not transformed.
+//! * Objects containing methods which use `super` after a computed key are not transformed,
+//!   and an error is reported. The methods would lose their home object (`ES2015ObjectSuper`).
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-computed-properties](https://babel.dev/docs/babel-plugin-transform-computed-properties).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-computed-properties>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::SPAN;
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::create_assignment,
+};
+
+/// Maximum number of properties defined by nested helper calls.
+const CHUNK_SIZE: usize = 10;
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComputedPropertiesOptions {
+    /// Enables the `setComputedProperties` assumption.
+    pub loose: bool,
+}
+
+pub struct ComputedProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    set_computed_properties: bool,
+}
+
+impl<'a, 'ctx> ComputedProperties<'a, 'ctx> {
+    pub fn new(options: ComputedPropertiesOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            set_computed_properties: options.loose || ctx.assumptions.set_computed_properties,
+        }
+    }
+}
+
+impl<'a> ComputedProperties<'a, '_> {
+    pub fn exit_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { return };
+        let Some(first_computed) = object.properties.iter().position(
+            |property| matches!(property, ObjectPropertyKind::ObjectProperty(p) if p.computed),
+        ) else {
+            return;
+        };
+        if object.properties.iter().any(ObjectPropertyKind::is_spread) {
+            return;
+        }
+        // Methods before the first computed key stay in the object literal
+        if let Some((span, is_method)) =
+            object.properties[first_computed..].iter().find_map(|property| {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+                let Expression::FunctionExpression(func) = &property.value else { return None };
+                if property.method || property.kind != PropertyKind::Init {
+                    SuperFinder::find(func).map(|span| (span, property.method))
+                } else {
+                    None
+                }
+            })
+        {
+            // Shorthand properties plugin has already reported an error for the method
+            if !(is_method && self.ctx.is_shorthand_properties_plugin_enabled) {
+                self.ctx.error(
+                    OxcDiagnostic::error(
+                        "`super` in methods of objects with computed keys is not supported yet.",
+                    )
+                    .with_label(span),
+                );
+            }
+            return;
+        }
+
+        let span = object.span;
+        let mut properties = object.properties.take_in(ctx.ast).into_iter();
+        let init_properties = ctx.ast.vec_from_iter(properties.by_ref().take(first_computed));
+        let init = ctx.ast.expression_object(SPAN, init_properties);
+        let properties = properties.map(|property| match property {
+            ObjectPropertyKind::ObjectProperty(property) => property,
+            ObjectPropertyKind::SpreadProperty(_) => unreachable!(),
+        });
+
+        *expr = if self.set_computed_properties {
+            self.transform_loose(init, properties, span, ctx)
+        } else {
+            self.transform_spec(init, properties, span, ctx)
+        };
+    }
+
+    /// `{ a: 1, [b]: 2 }` -> `babelHelpers.defineProperty({ a: 1 }, b, 2)`
+    fn transform_spec(
+        &self,
+        init: Expression<'a>,
+        properties: impl ExactSizeIterator<Item = ArenaBox<'a, ObjectProperty<'a>>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if properties.len() <= CHUNK_SIZE {
+            let mut object = init;
+            for property in properties {
+                object = self.define_property(object, property, ctx);
+            }
+            if let Expression::CallExpression(call) = &mut object {
+                call.span = span;
+            }
+            return object;
+        }
+
+        // `(_obj = { a: 1 }, defineProperty(defineProperty(_obj, b, 2), ...), ..., _obj)`
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+        let mut expressions = ctx.ast.vec1(create_assignment(&binding, init, ctx));
+        let mut object = None;
+        for (index, property) in properties.enumerate() {
+            if index % CHUNK_SIZE == 0 {
+                expressions.extend(object.take());
+            }
+            let target = object.take().unwrap_or_else(|| binding.create_read_expression(ctx));
+            object = Some(self.define_property(target, property, ctx));
+        }
+        expressions.extend(object);
+        expressions.push(binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(span, expressions)
+    }
+
+    /// `{ a: 1, [b]: 2, c: 3 }` -> `(_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, _obj)`
+    fn transform_loose(
+        &self,
+        init: Expression<'a>,
+        properties: impl Iterator<Item = ArenaBox<'a, ObjectProperty<'a>>>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = self.ctx.var_declarations.create_uid_var("obj", ctx);
+        let mut expressions = ctx.ast.vec1(create_assignment(&binding, init, ctx));
+        for property in properties {
+            let object = binding.create_read_expression(ctx);
+            let expression = if property.kind == PropertyKind::Init {
+                assign_property(object, property.unbox(), ctx)
+            } else {
+                self.define_property(object, property, ctx)
+            };
+            expressions.push(expression);
+        }
+        expressions.push(binding.create_read_expression(ctx));
+        ctx.ast.expression_sequence(span, expressions)
+    }
+
+    /// * `[a]: 1` -> `babelHelpers.defineProperty(object, a, 1)`
+    /// * `get [a]() {}` -> `babelHelpers.defineAccessor("get", object, a, function () {})`
+    fn define_property(
+        &self,
+        object: Expression<'a>,
+        property: ArenaBox<'a, ObjectProperty<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let ObjectProperty { kind, key, value, .. } = property.unbox();
+        let key = computed_key(key, ctx);
+        let (helper, mut arguments) = match kind {
+            PropertyKind::Init => (Helper::DefineProperty, ctx.ast.vec_with_capacity(3)),
+            PropertyKind::Get | PropertyKind::Set => {
+                // The accessor becomes a function expression
+                if let Expression::FunctionExpression(func) = &value {
+                    ctx.scoping_mut()
+                        .scope_flags_mut(func.scope_id())
+                        .remove(ScopeFlags::GetAccessor | ScopeFlags::SetAccessor);
+                }
+                let kind = if kind == PropertyKind::Get { "get" } else { "set" };
+                let mut arguments = ctx.ast.vec_with_capacity(4);
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(SPAN, kind, None)));
+                (Helper::DefineAccessor, arguments)
+            }
+        };
+        arguments.extend([Argument::from(object), Argument::from(key), Argument::from(value)]);
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+}
+
+/// `[a]: 1` -> `object[a] = 1`, `b: 2` -> `object.b = 2`
+fn assign_property<'a>(
+    object: Expression<'a>,
+    property: ObjectProperty<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let member = match property.key {
+        PropertyKey::StaticIdentifier(ident) => ctx.ast.member_expression_static(
+            SPAN,
+            object,
+            ctx.ast.identifier_name(ident.span, ident.name),
+            false,
+        ),
+        key => {
+            let key = computed_key(key, ctx);
+            ctx.ast.member_expression_computed(SPAN, object, key, false)
+        }
+    };
+    ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(member),
+        property.value,
+    )
+}
+
+/// Key of a property as an expression. `a` -> `"a"`, `[a]` -> `a`
+fn computed_key<'a>(key: PropertyKey<'a>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => {
+            ctx.ast.expression_string_literal(ident.span, ident.name, None)
+        }
+        PropertyKey::PrivateIdentifier(_) => {
+            unreachable!("object literal keys cannot be private identifiers")
+        }
+        key => key.into_expression(),
+    }
+}
+
+/// Visitor to find `super` in a method, which refers to the object the method is defined in.
+#[derive(Default)]
+struct SuperFinder {
+    span: Option<Span>,
+}
+
+impl SuperFinder {
+    fn find(func: &Function<'_>) -> Option<Span> {
+        let mut finder = Self::default();
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.span
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, it: &Super) {
+        self.span.get_or_insert(it.span);
+    }
+
+    // `super` in nested functions refers to their own home object
+    #[inline]
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    /// Only computed keys of class elements are evaluated in the method.
+    fn visit_class_body(&mut self, body: &ClassBody<'a>) {
+        for element in &body.body {
+            if let Some(key) = element.property_key().filter(|_| element.computed()) {
+                self.visit_property_key(key);
+            }
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.span.is_none() {
+            walk::walk_expression(self, expr);
+        }
+    }
+}
@@ -0,0 +1,1015 @@
+//! ES2015: Destructuring
+//!
+//! This plugin transforms destructuring patterns into plain variable declarations and assignments.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var { a, b: [c, d = 1], ...rest } = obj;
+//! [x, y] = [y, x];
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _x;
+//! var a = obj.a,
+//!   _obj$b = babelHelpers.slicedToArray(obj.b, 2),
+//!   c = _obj$b[0],
+//!   _obj$b$ = _obj$b[1],
+//!   d = _obj$b$ === void 0 ? 1 : _obj$b$,
+//!   rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+//! _x = babelHelpers.slicedToArray([y, x], 2), x = _x[0], y = _x[1];
+//! ```
+//!
+//! Patterns are handled in variable declarations, assignment expressions, `for-in` / `for-of` heads,
+//! catch clauses and function parameters. The latter are moved into the function body, which is
+//! done by the [parameters](super::parameters) plugin if it is enabled.
+//!
+//! With the `iterableIsArray` assumption (or `loose` option), array patterns are assumed to destructure
+//! arrays and are read by index directly, rather than via the `slicedToArray` / `toArray` helpers.
+//! With the `arrayLikeIsIterable` assumption (or `allowArrayLike` option), array-like objects are also
+//! accepted, via the `maybeArrayLike` helper.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-destructuring](https://babel.dev/docs/babel-plugin-transform-destructuring).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-destructuring>
+//! * Destructuring assignment: <https://tc39.es/ecma262/#sec-destructuring-assignment>
+
+use std::mem;
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::{
+    BoundNames, ToJsString, is_global_reference::WithoutGlobalReferenceInformation,
+};
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{GetSpan, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{
+    Ancestor, BoundIdentifier, MaybeBoundIdentifier, Traverse, ast_operations::GatherNodeParts,
+};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DestructuringOptions {
+    /// Enables the `iterableIsArray` assumption.
+    pub loose: bool,
+
+    /// Enables the `arrayLikeIsIterable` assumption.
+    pub allow_array_like: bool,
+}
+
+pub struct Destructuring<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+
+    iterable_is_array: bool,
+
+    array_like_is_iterable: bool,
+}
+
+impl<'a, 'ctx> Destructuring<'a, 'ctx> {
+    pub fn new(options: DestructuringOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            iterable_is_array: options.loose || ctx.assumptions.iterable_is_array,
+            array_like_is_iterable: options.allow_array_like
+                || ctx.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Destructuring<'a, '_> {
+    // `export var { a, b } = obj;` -> `var { a, b } = obj; export { a, b };`
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Statement::ExportNamedDeclaration(export) = stmt else { return };
+        let Some(Declaration::VariableDeclaration(decl)) = &export.declaration else { return };
+        if decl.declarations.iter().all(|declarator| declarator.id.kind.is_binding_identifier()) {
+            return;
+        }
+
+        let mut specifiers = ctx.ast.vec();
+        decl.bound_names(&mut |ident| {
+            let local = BoundIdentifier::from_binding_ident(ident);
+            specifiers.push(ctx.ast.export_specifier(
+                SPAN,
+                ModuleExportName::IdentifierReference(local.create_read_reference(ctx)),
+                ctx.ast.module_export_name_identifier_name(SPAN, ident.name),
+                ImportOrExportKind::Value,
+            ));
+        });
+        let export_stmt = ctx.ast.plain_export_named_declaration(export.span, specifiers, None);
+
+        let Some(Declaration::VariableDeclaration(decl)) = export.declaration.take() else {
+            unreachable!()
+        };
+        let new_stmt = Statement::VariableDeclaration(decl);
+        self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+        self.ctx
+            .statement_injector
+            .insert_after(&new_stmt, Statement::ExportNamedDeclaration(export_stmt));
+        *stmt = new_stmt;
+    }
+
+    // `[a, b] = c`
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Expression::AssignmentExpression(assign_expr) = expr {
+            if matches!(
+                assign_expr.left,
+                AssignmentTarget::ArrayAssignmentTarget(_)
+                    | AssignmentTarget::ObjectAssignmentTarget(_)
+            ) {
+                self.transform_assignment_expression(expr, ctx);
+            }
+        }
+    }
+
+    // `var [a, b] = c`
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if decl.declarations.iter().any(|declarator| {
+            declarator.init.is_some() && !declarator.id.kind.is_binding_identifier()
+        }) {
+            self.transform_variable_declaration(decl, ctx);
+        }
+    }
+
+    // `try {} catch ({ a }) {}`
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        Self::transform_catch_clause(clause, ctx);
+    }
+
+    // `for (var [a, b] in c);` `for ([a, b] in c);`
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+
+    // `for (var [a, b] of c);` `for ([a, b] of c);`
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = stmt.scope_id();
+        Self::transform_for_statement_left(&mut stmt.left, &mut stmt.body, scope_id, ctx);
+    }
+}
+
+impl<'a> Destructuring<'a, '_> {
+    /// Move patterns out of parameters into declarations at the start of the function body.
+    ///
+    /// `function f({ a }, [b] = c) {}` -> `function f(_ref, _ref2 = c) { var { a } = _ref, [b] = _ref2; }`
+    ///
+    /// The inserted declarations are lowered when they are visited.
+    /// Only used when the parameters plugin is disabled, otherwise it is responsible for this.
+    pub fn transform_params(
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut declarators = ctx.ast.vec();
+        let patterns = params
+            .items
+            .iter_mut()
+            .map(|param| match &mut param.pattern {
+                BindingPattern {
+                    kind: BindingPatternKind::AssignmentPattern(assign_pat), ..
+                } => &mut assign_pat.left,
+                pattern => pattern,
+            })
+            .chain(params.rest.as_mut().map(|rest| &mut rest.argument));
+        for pattern in patterns {
+            if pattern.kind.is_destructuring_pattern() {
+                let binding =
+                    ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable);
+                let pattern = mem::replace(pattern, binding.create_binding_pattern(ctx));
+                let init = binding.create_read_expression(ctx);
+                let kind = VariableDeclarationKind::Var;
+                declarators.push(ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    pattern,
+                    Some(init),
+                    false,
+                ));
+            }
+        }
+        if !declarators.is_empty() {
+            let kind = VariableDeclarationKind::Var;
+            let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
+            statements.insert(0, Statement::VariableDeclaration(decl));
+        }
+    }
+
+    /// `var [a, b] = c` -> `var _c = slicedToArray(c, 2), a = _c[0], b = _c[1]`
+    fn transform_variable_declaration(
+        &self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (scope_id, flags) = match decl.kind {
+            VariableDeclarationKind::Var => {
+                (ctx.current_hoist_scope_id(), SymbolFlags::FunctionScopedVariable)
+            }
+            VariableDeclarationKind::Const => (
+                ctx.current_scope_id(),
+                SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+            ),
+            _ => (ctx.current_scope_id(), SymbolFlags::BlockScopedVariable),
+        };
+
+        let mut lowering = DeclarationLowering {
+            kind: decl.kind,
+            scope_id,
+            flags,
+            names: vec![],
+            declarators: ctx.ast.vec_with_capacity(decl.declarations.len()),
+        };
+        for declarator in decl.declarations.take_in(ctx.ast) {
+            match declarator.init {
+                Some(init) if !declarator.id.kind.is_binding_identifier() => {
+                    lowering.names.clear();
+                    declarator.id.bound_names(&mut |ident| lowering.names.push(ident.name));
+                    self.lower_binding_pattern(declarator.id, init, &mut lowering, ctx);
+                }
+                _ => lowering.declarators.push(declarator),
+            }
+        }
+        decl.declarations = lowering.declarators;
+    }
+
+    /// `[a, b] = c` -> `(_c = c, _c2 = slicedToArray(_c, 2), a = _c2[0], b = _c2[1], _c)`
+    ///
+    /// The trailing reference is omitted when the result is unused.
+    fn transform_assignment_expression(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let is_statement = ctx
+            .ancestors()
+            .find(|ancestor| !matches!(ancestor, Ancestor::ParenthesizedExpressionExpression(_)))
+            .is_some_and(|ancestor| matches!(ancestor, Ancestor::ExpressionStatementExpression(_)));
+        let Expression::AssignmentExpression(assign_expr) = expr.take_in(ctx.ast) else {
+            unreachable!()
+        };
+        let AssignmentExpression { span, left, right, .. } = assign_expr.unbox();
+
+        let mut lowering =
+            AssignmentLowering { ctx: self.ctx, names: vec![], expressions: ctx.ast.vec() };
+        collect_assignment_target_names(&left, &mut lowering.names);
+
+        if is_statement {
+            self.lower_assignment_target(left, right, &mut lowering, ctx);
+        } else {
+            // The value of the assignment expression is the right-hand side.
+            let mut source = Source::new(right, 2, &mut lowering, ctx);
+            let value = source.read(ctx);
+            self.lower_assignment_target(left, value, &mut lowering, ctx);
+            let result = source.read(ctx);
+            lowering.expressions.push(result);
+        }
+
+        let mut expressions = lowering.expressions;
+        *expr = if expressions.len() == 1 {
+            expressions.pop().unwrap()
+        } else {
+            ctx.ast.expression_sequence(span, expressions)
+        };
+    }
+
+    /// `try {} catch ({ a }) {}` -> `try {} catch (_ref) { let { a } = _ref; }`
+    fn transform_catch_clause(clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = clause.body.scope_id();
+        let Some(param) = &mut clause.param else { return };
+        if param.pattern.kind.is_binding_identifier() {
+            return;
+        }
+
+        let binding = ctx.generate_uid(
+            "ref",
+            scope_id,
+            SymbolFlags::FunctionScopedVariable | SymbolFlags::CatchVariable,
+        );
+        let pattern = mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+
+        // Bindings are now declared by `let` in the catch body.
+        pattern.bound_names(&mut |ident| {
+            ctx.scoping_mut()
+                .symbol_flags_mut(ident.symbol_id())
+                .remove(SymbolFlags::CatchVariable);
+        });
+
+        let kind = VariableDeclarationKind::Let;
+        let init = binding.create_read_expression(ctx);
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        clause.body.body.insert(0, Statement::VariableDeclaration(decl));
+    }
+
+    /// `for (const [a, b] of c) {}` -> `for (const _ref of c) { const [a, b] = _ref; }`
+    /// `for ([a, b] of c) {}` -> `var _ref; for (_ref of c) { [a, b] = _ref; }`
+    fn transform_for_statement_left(
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        for_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let statement = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let kind = decl.kind;
+                let declarator = &mut decl.declarations[0];
+                if declarator.id.kind.is_binding_identifier() {
+                    return;
+                }
+                let binding = if kind.is_var() {
+                    ctx.generate_uid_in_current_hoist_scope("ref")
+                } else {
+                    let flags = if kind.is_const() {
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable
+                    } else {
+                        SymbolFlags::BlockScopedVariable
+                    };
+                    ctx.generate_uid("ref", for_scope_id, flags)
+                };
+                let pattern = mem::replace(&mut declarator.id, binding.create_binding_pattern(ctx));
+                let body_scope_id = Self::ensure_block_body(body, for_scope_id, ctx);
+                if !kind.is_var() {
+                    // Move the bindings from the loop head scope to the scope of the loop body.
+                    pattern.bound_names(&mut |ident| {
+                        let scoping = ctx.scoping_mut();
+                        scoping.set_symbol_scope_id(ident.symbol_id(), body_scope_id);
+                        scoping.move_binding(for_scope_id, body_scope_id, ident.name.into());
+                    });
+                }
+                let init = binding.create_read_expression(ctx);
+                let declarator =
+                    ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+                let decl =
+                    ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+                Statement::VariableDeclaration(decl)
+            }
+            ForStatementLeft::ArrayAssignmentTarget(_)
+            | ForStatementLeft::ObjectAssignmentTarget(_) => {
+                let binding = ctx.generate_uid_in_current_hoist_scope("ref");
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    binding.create_binding_pattern(ctx),
+                    None,
+                    false,
+                );
+                let decl =
+                    ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                *left = ForStatementLeft::VariableDeclaration(decl);
+                Self::ensure_block_body(body, for_scope_id, ctx);
+                let value = binding.create_read_expression(ctx);
+                let expr =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, expr)
+            }
+            _ => return,
+        };
+        let Statement::BlockStatement(block) = body else { unreachable!() };
+        block.body.insert(0, statement);
+    }
+
+    /// Wrap `stmt` in a block statement if it is not one already. Returns the block's scope.
+    fn ensure_block_body(
+        stmt: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ScopeId {
+        if let Statement::BlockStatement(block) = stmt {
+            return block.scope_id();
+        }
+        let scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let (span, stmts) = if let Statement::EmptyStatement(empty_stmt) = stmt {
+            (empty_stmt.span, ctx.ast.vec())
+        } else {
+            (stmt.span(), ctx.ast.vec1(stmt.take_in(ctx.ast)))
+        };
+        *stmt = ctx.ast.statement_block_with_scope_id(span, stmts, scope_id);
+        scope_id
+    }
+}
+
+// Lowering of binding patterns, i.e. patterns in declarations.
+impl<'a> Destructuring<'a, '_> {
+    fn lower_binding_pattern(
+        &self,
+        pattern: BindingPattern<'a>,
+        value: Expression<'a>,
+        lowering: &mut DeclarationLowering<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match pattern.kind {
+            BindingPatternKind::BindingIdentifier(_) => lowering.push(pattern, value, ctx),
+            BindingPatternKind::AssignmentPattern(assign_pat) => {
+                let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                let value = Self::default_value(value, right, lowering, ctx);
+                self.lower_binding_pattern(left, value, lowering, ctx);
+            }
+            BindingPatternKind::ObjectPattern(object_pat) => {
+                let ObjectPattern { properties, rest, .. } = object_pat.unbox();
+                let rest = rest.map(|rest| rest.unbox().argument);
+                let Some(mut source) =
+                    self.object_source(value, properties.len(), rest.is_some(), lowering, ctx)
+                else {
+                    return;
+                };
+                let mut excluded = rest.as_ref().map(|_| ctx.ast.vec());
+                for property in properties {
+                    let value = self.object_property_value(
+                        &mut source,
+                        property.key,
+                        property.computed,
+                        excluded.as_mut(),
+                        lowering,
+                        ctx,
+                    );
+                    self.lower_binding_pattern(property.value, value, lowering, ctx);
+                }
+                if let (Some(rest), Some(excluded)) = (rest, excluded) {
+                    let value = self.object_rest_value(&mut source, excluded, ctx);
+                    self.lower_binding_pattern(rest, value, lowering, ctx);
+                }
+            }
+            BindingPatternKind::ArrayPattern(array_pat) => {
+                let ArrayPattern { elements, rest, .. } = array_pat.unbox();
+                let rest = rest.map(|rest| rest.unbox().argument);
+                let len = elements.len();
+                let reads = elements.iter().flatten().count() + usize::from(rest.is_some());
+                let Some(mut source) =
+                    self.array_source(value, len, rest.is_some(), reads, lowering, ctx)
+                else {
+                    return;
+                };
+                for (index, element) in elements.into_iter().enumerate() {
+                    if let Some(element) = element {
+                        let value = Self::array_element_value(&mut source, index, ctx);
+                        self.lower_binding_pattern(element, value, lowering, ctx);
+                    }
+                }
+                if let Some(rest) = rest {
+                    let value = Self::array_rest_value(&mut source, len, ctx);
+                    self.lower_binding_pattern(rest, value, lowering, ctx);
+                }
+            }
+        }
+    }
+}
+
+// Lowering of assignment target patterns, i.e. patterns in assignment expressions.
+impl<'a> Destructuring<'a, '_> {
+    fn lower_assignment_target(
+        &self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        lowering: &mut AssignmentLowering<'a, '_>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTarget::ObjectAssignmentTarget(object_target) => {
+                let ObjectAssignmentTarget { properties, rest, .. } = object_target.unbox();
+                let rest = rest.map(|rest| rest.target);
+                let Some(mut source) =
+                    self.object_source(value, properties.len(), rest.is_some(), lowering, ctx)
+                else {
+                    return;
+                };
+                let mut excluded = rest.as_ref().map(|_| ctx.ast.vec());
+                for property in properties {
+                    match property {
+                        AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                            let AssignmentTargetPropertyIdentifier { binding, init, .. } =
+                                ident.unbox();
+                            if let Some(excluded) = excluded.as_mut() {
+                                let key =
+                                    ctx.ast.expression_string_literal(SPAN, binding.name, None);
+                                excluded.push(ArrayExpressionElement::from(key));
+                            }
+                            let property = ctx.ast.identifier_name(SPAN, binding.name);
+                            let object = source.read(ctx);
+                            let mut value = Expression::from(
+                                ctx.ast.member_expression_static(SPAN, object, property, false),
+                            );
+                            if let Some(init) = init {
+                                value = Self::default_value(value, init, lowering, ctx);
+                            }
+                            let target = AssignmentTarget::AssignmentTargetIdentifier(
+                                ctx.ast.alloc(binding),
+                            );
+                            lowering.push(target, value, ctx);
+                        }
+                        AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                            let AssignmentTargetPropertyProperty {
+                                name, binding, computed, ..
+                            } = property.unbox();
+                            let value = self.object_property_value(
+                                &mut source,
+                                name,
+                                computed,
+                                excluded.as_mut(),
+                                lowering,
+                                ctx,
+                            );
+                            self.lower_assignment_target_maybe_default(
+                                binding, value, lowering, ctx,
+                            );
+                        }
+                    }
+                }
+                if let (Some(rest), Some(excluded)) = (rest, excluded) {
+                    let value = self.object_rest_value(&mut source, excluded, ctx);
+                    self.lower_assignment_target(rest, value, lowering, ctx);
+                }
+            }
+            AssignmentTarget::ArrayAssignmentTarget(array_target) => {
+                let ArrayAssignmentTarget { elements, rest, .. } = array_target.unbox();
+                let rest = rest.map(|rest| rest.target);
+                let len = elements.len();
+                let reads = elements.iter().flatten().count() + usize::from(rest.is_some());
+                let Some(mut source) =
+                    self.array_source(value, len, rest.is_some(), reads, lowering, ctx)
+                else {
+                    return;
+                };
+                for (index, element) in elements.into_iter().enumerate() {
+                    if let Some(element) = element {
+                        let value = Self::array_element_value(&mut source, index, ctx);
+                        self.lower_assignment_target_maybe_default(element, value, lowering, ctx);
+                    }
+                }
+                if let Some(rest) = rest {
+                    let value = Self::array_rest_value(&mut source, len, ctx);
+                    self.lower_assignment_target(rest, value, lowering, ctx);
+                }
+            }
+            target => lowering.push(target, value, ctx),
+        }
+    }
+
+    fn lower_assignment_target_maybe_default(
+        &self,
+        target: AssignmentTargetMaybeDefault<'a>,
+        value: Expression<'a>,
+        lowering: &mut AssignmentLowering<'a, '_>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(with_default) => {
+                let AssignmentTargetWithDefault { binding, init, .. } = with_default.unbox();
+                let value = Self::default_value(value, init, lowering, ctx);
+                self.lower_assignment_target(binding, value, lowering, ctx);
+            }
+            target => {
+                self.lower_assignment_target(target.into_assignment_target(), value, lowering, ctx);
+            }
+        }
+    }
+}
+
+// Shared by both kinds of patterns.
+impl<'a> Destructuring<'a, '_> {
+    /// `_value === void 0 ? default : _value`
+    fn default_value<L: Lowering<'a>>(
+        value: Expression<'a>,
+        default: Expression<'a>,
+        lowering: &mut L,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let binding = lowering.temp(value, ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        );
+        let alternate = binding.create_read_expression(ctx);
+        ctx.ast.expression_conditional(SPAN, test, default, alternate)
+    }
+
+    /// Prepare `value` to be read once for every property and once for the rest element.
+    ///
+    /// Returns `None` for an empty pattern `{}`, which only throws if `value` is nullish.
+    fn object_source<L: Lowering<'a>>(
+        &self,
+        value: Expression<'a>,
+        properties: usize,
+        has_rest: bool,
+        lowering: &mut L,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Source<'a>> {
+        let reads = match (properties, has_rest) {
+            // `babelHelpers.objectDestructuringEmpty(value)`
+            (0, false) => {
+                let arguments = ctx.ast.vec1(Argument::from(value));
+                let call = self.ctx.helper_call_expr(
+                    Helper::ObjectDestructuringEmpty,
+                    SPAN,
+                    arguments,
+                    ctx,
+                );
+                lowering.evaluate(call, ctx);
+                return None;
+            }
+            // `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(_value), _value))`
+            (0, true) => 2,
+            _ => properties + usize::from(has_rest),
+        };
+        Some(Source::new(value, reads, lowering, ctx))
+    }
+
+    /// `value.key` or `value[key]`.
+    ///
+    /// When the pattern has a rest element, the key is also recorded in `excluded`,
+    /// which requires computed keys to be stored in a temporary variable.
+    fn object_property_value<L: Lowering<'a>>(
+        &self,
+        source: &mut Source<'a>,
+        key: PropertyKey<'a>,
+        computed: bool,
+        excluded: Option<&mut ArenaVec<'a, ArrayExpressionElement<'a>>>,
+        lowering: &mut L,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if let PropertyKey::StaticIdentifier(ident) = &key {
+            if !computed {
+                if let Some(excluded) = excluded {
+                    let key = ctx.ast.expression_string_literal(ident.span, ident.name, None);
+                    excluded.push(ArrayExpressionElement::from(key));
+                }
+                let property = ctx.ast.identifier_name(ident.span, ident.name);
+                let object = source.read(ctx);
+                return Expression::from(
+                    ctx.ast.member_expression_static(SPAN, object, property, false),
+                );
+            }
+        }
+
+        let mut key = key.into_expression();
+        if let Some(excluded) = excluded {
+            if key.is_literal() {
+                // `{ 1: a, ...rest }` -> `["1"]`
+                let name = key.to_js_string(&WithoutGlobalReferenceInformation {}).unwrap();
+                let name = ctx.ast.atom_from_cow(&name);
+                let name = ctx.ast.expression_string_literal(key.span(), name, None);
+                excluded.push(ArrayExpressionElement::from(name));
+            } else {
+                // `{ [a]: b, ...rest }` -> `_a = babelHelpers.toPropertyKey(a)`
+                let binding = lowering.create_temp(&key, ctx);
+                let arguments = ctx.ast.vec1(Argument::from(key));
+                let value = self.ctx.helper_call_expr(Helper::ToPropertyKey, SPAN, arguments, ctx);
+                lowering.push_temp(&binding, value, ctx);
+                excluded.push(ArrayExpressionElement::from(binding.create_read_expression(ctx)));
+                key = binding.create_read_expression(ctx);
+            }
+        }
+        let object = source.read(ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, key, false))
+    }
+
+    /// `babelHelpers.objectWithoutProperties(value, ["a", "b"])`
+    /// or `babelHelpers.extends({}, (babelHelpers.objectDestructuringEmpty(value), value))`.
+    fn object_rest_value(
+        &self,
+        source: &mut Source<'a>,
+        excluded: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if excluded.is_empty() {
+            let arguments = ctx.ast.vec1(Argument::from(source.read(ctx)));
+            let check =
+                self.ctx.helper_call_expr(Helper::ObjectDestructuringEmpty, SPAN, arguments, ctx);
+            let sequence = ctx
+                .ast
+                .expression_sequence(SPAN, ctx.ast.vec_from_array([check, source.read(ctx)]));
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_object(SPAN, ctx.ast.vec())),
+                Argument::from(sequence),
+            ]);
+            self.ctx.helper_call_expr(Helper::Extends, SPAN, arguments, ctx)
+        } else {
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(source.read(ctx)),
+                Argument::from(ctx.ast.expression_array(SPAN, excluded)),
+            ]);
+            self.ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+        }
+    }
+
+    /// Prepare `value` to be read once for every element and once for the rest element.
+    ///
+    /// Unless `iterableIsArray` is assumed, `value` is first converted to an array:
+    /// `babelHelpers.slicedToArray(value, len)` or `babelHelpers.toArray(value)` with a rest element.
+    ///
+    /// Returns `None` if no elements are read.
+    fn array_source<L: Lowering<'a>>(
+        &self,
+        value: Expression<'a>,
+        len: usize,
+        has_rest: bool,
+        reads: usize,
+        lowering: &mut L,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Source<'a>> {
+        if self.iterable_is_array {
+            if reads == 0 {
+                lowering.evaluate(value, ctx);
+                return None;
+            }
+            return Some(Source::new(value, reads, lowering, ctx));
+        }
+
+        // Name the temporary variable after the original value, rather than the helper call.
+        let binding = (reads > 0).then(|| lowering.create_temp(&value, ctx));
+        let value = self.array_from(value, len, has_rest, ctx);
+        if let Some(binding) = binding {
+            lowering.push_temp(&binding, value, ctx);
+            Some(Source::from_binding(&binding))
+        } else {
+            lowering.evaluate(value, ctx);
+            None
+        }
+    }
+
+    fn array_from(
+        &self,
+        value: Expression<'a>,
+        len: usize,
+        has_rest: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let helper = if has_rest { Helper::ToArray } else { Helper::SlicedToArray };
+        let mut arguments = ctx.ast.vec_with_capacity(3);
+        if self.array_like_is_iterable {
+            // `babelHelpers.maybeArrayLike(babelHelpers.slicedToArray, value, len)`
+            arguments.push(Argument::from(self.ctx.helper_load(helper, ctx)));
+        }
+        arguments.push(Argument::from(value));
+        if !has_rest {
+            arguments.push(Argument::from(create_number(len, ctx)));
+        }
+        let helper = if self.array_like_is_iterable { Helper::MaybeArrayLike } else { helper };
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `value[index]`
+    fn array_element_value(
+        source: &mut Source<'a>,
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = source.read(ctx);
+        let index = create_number(index, ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, index, false))
+    }
+
+    /// `value.slice(index)`
+    fn array_rest_value(
+        source: &mut Source<'a>,
+        index: usize,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = source.read(ctx);
+        let property = ctx.ast.identifier_name(SPAN, "slice");
+        let callee =
+            Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false));
+        let arguments = ctx.ast.vec1(Argument::from(create_number(index, ctx)));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+}
+
+/// A value which is read by a pattern one or more times.
+struct Source<'a> {
+    /// The original expression, used for the first read.
+    expression: Option<Expression<'a>>,
+    /// Identifier used for the subsequent reads.
+    reference: Option<MaybeBoundIdentifier<'a>>,
+}
+
+impl<'a> Source<'a> {
+    /// Store `value` in a temporary variable if it is read more than once,
+    /// unless it is an identifier which is not assigned by the pattern.
+    fn new<L: Lowering<'a>>(
+        value: Expression<'a>,
+        reads: usize,
+        lowering: &mut L,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Self {
+        if reads <= 1 {
+            return Self::from_expression(value);
+        }
+        if let Expression::Identifier(ident) = &value {
+            if !lowering.names().contains(&ident.name) {
+                let reference = MaybeBoundIdentifier::from_identifier_reference(ident, ctx);
+                return Self { expression: Some(value), reference: Some(reference) };
+            }
+        }
+        Self::from_binding(&lowering.temp(value, ctx))
+    }
+
+    fn from_expression(expression: Expression<'a>) -> Self {
+        Self { expression: Some(expression), reference: None }
+    }
+
+    fn from_binding(binding: &BoundIdentifier<'a>) -> Self {
+        Self { expression: None, reference: Some(binding.to_maybe_bound_identifier()) }
+    }
+
+    fn read(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        self.expression.take().unwrap_or_else(|| {
+            self.reference.as_ref().expect("value is only read once").create_read_expression(ctx)
+        })
+    }
+}
+
+/// Receives the declarators or assignments produced by lowering a pattern.
+trait Lowering<'a> {
+    type Target;
+
+    /// Names assigned by the pattern, whose values can change while the pattern is lowered.
+    fn names(&self) -> &[Atom<'a>];
+
+    /// Assign `value` to `target`.
+    fn push(&mut self, target: Self::Target, value: Expression<'a>, ctx: &mut TraverseCtx<'a>);
+
+    /// Create a temporary variable, named after `node`.
+    fn create_temp<N: GatherNodeParts<'a>>(
+        &mut self,
+        node: &N,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a>;
+
+    /// Assign `value` to a temporary variable created by [`Lowering::create_temp`].
+    fn push_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    );
+
+    /// Evaluate `value` for its side effects only.
+    fn evaluate(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>);
+
+    /// Store `value` in a new temporary variable.
+    fn temp(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let binding = self.create_temp(&value, ctx);
+        self.push_temp(&binding, value, ctx);
+        binding
+    }
+}
+
+struct DeclarationLowering<'a> {
+    kind: VariableDeclarationKind,
+    scope_id: ScopeId,
+    flags: SymbolFlags,
+    names: Vec<Atom<'a>>,
+    declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+}
+
+impl<'a> Lowering<'a> for DeclarationLowering<'a> {
+    type Target = BindingPattern<'a>;
+
+    fn names(&self) -> &[Atom<'a>] {
+        &self.names
+    }
+
+    fn push(&mut self, id: BindingPattern<'a>, init: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let declarator = ctx.ast.variable_declarator(SPAN, self.kind, id, Some(init), false);
+        self.declarators.push(declarator);
+    }
+
+    fn create_temp<N: GatherNodeParts<'a>>(
+        &mut self,
+        node: &N,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        ctx.generate_uid_based_on_node(node, self.scope_id, self.flags)
+    }
+
+    fn push_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.push(binding.create_binding_pattern(ctx), value, ctx);
+    }
+
+    fn evaluate(&mut self, value: Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let binding = ctx.generate_uid("ref", self.scope_id, self.flags);
+        self.push_temp(&binding, value, ctx);
+    }
+}
+
+struct AssignmentLowering<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    names: Vec<Atom<'a>>,
+    expressions: ArenaVec<'a, Expression<'a>>,
+}
+
+impl<'a> Lowering<'a> for AssignmentLowering<'a, '_> {
+    type Target = AssignmentTarget<'a>;
+
+    fn names(&self) -> &[Atom<'a>] {
+        &self.names
+    }
+
+    fn push(
+        &mut self,
+        target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let op = AssignmentOperator::Assign;
+        self.expressions.push(ctx.ast.expression_assignment(SPAN, op, target, value));
+    }
+
+    fn create_temp<N: GatherNodeParts<'a>>(
+        &mut self,
+        node: &N,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> BoundIdentifier<'a> {
+        self.ctx.var_declarations.create_uid_var_based_on_node(node, ctx)
+    }
+
+    fn push_temp(
+        &mut self,
+        binding: &BoundIdentifier<'a>,
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.push(binding.create_write_target(ctx), value, ctx);
+    }
+
+    fn evaluate(&mut self, value: Expression<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.expressions.push(value);
+    }
+}
+
+/// Collect the names of identifiers assigned by `target`.
+fn collect_assignment_target_names<'a>(target: &AssignmentTarget<'a>, names: &mut Vec<Atom<'a>>) {
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => names.push(ident.name),
+        AssignmentTarget::ObjectAssignmentTarget(object_target) => {
+            for property in &object_target.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                        names.push(ident.binding.name);
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        collect_assignment_target_maybe_default_names(&property.binding, names);
+                    }
+                }
+            }
+            if let Some(rest) = &object_target.rest {
+                collect_assignment_target_names(&rest.target, names);
+            }
+        }
+        AssignmentTarget::ArrayAssignmentTarget(array_target) => {
+            for element in array_target.elements.iter().flatten() {
+                collect_assignment_target_maybe_default_names(element, names);
+            }
+            if let Some(rest) = &array_target.rest {
+                collect_assignment_target_names(&rest.target, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_assignment_target_maybe_default_names<'a>(
+    target: &AssignmentTargetMaybeDefault<'a>,
+    names: &mut Vec<Atom<'a>>,
+) {
+    match target {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(with_default) => {
+            collect_assignment_target_names(&with_default.binding, names);
+        }
+        target => collect_assignment_target_names(target.to_assignment_target(), names),
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
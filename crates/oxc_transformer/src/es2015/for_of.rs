@@ -0,0 +1,531 @@
+//! ES2015: For-Of
+//!
+//! This plugin transforms `for...of` loops.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! for (var x of xs) {
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+//! try {
+//!   for (_iterator.s(); !(_step = _iterator.n()).done;) {
+//!     var x = _step.value;
+//!     foo(x);
+//!   }
+//! } catch (err) {
+//!   _iterator.e(err);
+//! } finally {
+//!   _iterator.f();
+//! }
+//! ```
+//!
+//! With the `skipForOfIteratorClosing` assumption (or `loose` option), the iterator is not closed
+//! when the loop exits early, so no `try` statement is needed:
+//!
+//! ```js
+//! for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(xs), _step; !(_step = _iterator()).done;) {
+//!   var x = _step.value;
+//!   foo(x);
+//! }
+//! ```
+//!
+//! Array literals (or any value, with the `assumeArray` option) are iterated by index:
+//!
+//! ```js
+//! for (var _i = 0, _arr = [1, 2]; _i < _arr.length; _i++) {
+//!   var x = _arr[_i];
+//!   foo(x);
+//! }
+//! ```
+//!
+//! With the `allowArrayLike` option (or `arrayLikeIsIterable` assumption), array-like objects
+//! without `[Symbol.iterator]` can also be iterated.
+//!
+//! `for await` loops are transformed by the async generator functions plugin.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-for-of](https://babel.dev/docs/babel-plugin-transform-for-of).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-for-of>
+//! * For-in, for-of and for-await statements: <https://tc39.es/ecma262/#sec-for-in-and-for-of-statements>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::{
+        ast_builder::{create_assignment, create_member_callee, create_property_access},
+        scope_reparenter::ScopeReparenter,
+    },
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ForOfOptions {
+    /// Enables the `skipForOfIteratorClosing` assumption.
+    pub loose: bool,
+    /// Iterate all values by index, as if they were arrays.
+    pub assume_array: bool,
+    /// Allow iterating array-like objects which do not have `[Symbol.iterator]`.
+    pub allow_array_like: bool,
+}
+
+pub struct ForOf<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    skip_for_of_iterator_closing: bool,
+    assume_array: bool,
+    allow_array_like: bool,
+}
+
+impl<'a, 'ctx> ForOf<'a, 'ctx> {
+    pub fn new(options: ForOfOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            skip_for_of_iterator_closing: options.loose
+                || ctx.assumptions.skip_for_of_iterator_closing,
+            assume_array: options.assume_array,
+            allow_array_like: options.allow_array_like || ctx.assumptions.array_like_is_iterable,
+        }
+    }
+}
+
+impl<'a> ForOf<'a, '_> {
+    /// Transform `for...of` loops on exit from the statement, so that the labels of a labeled loop
+    /// can be kept on the `for` loop which replaces it.
+    pub fn exit_statement(&self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        match stmt {
+            Statement::ForOfStatement(for_of) => {
+                // Transformed on exit from the `LabeledStatement`
+                if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) || for_of.r#await {
+                    return;
+                }
+            }
+            Statement::LabeledStatement(_) => {
+                if matches!(ctx.parent(), Ancestor::LabeledStatementBody(_)) {
+                    return;
+                }
+                let mut body = &*stmt;
+                while let Statement::LabeledStatement(labeled) = body {
+                    body = &labeled.body;
+                }
+                if !matches!(body, Statement::ForOfStatement(for_of) if !for_of.r#await) {
+                    return;
+                }
+            }
+            _ => return,
+        }
+
+        let allow_multiple_statements = matches!(
+            ctx.parent(),
+            Ancestor::ProgramBody(_)
+                | Ancestor::FunctionBodyStatements(_)
+                | Ancestor::BlockStatementBody(_)
+                | Ancestor::SwitchCaseConsequent(_)
+                | Ancestor::StaticBlockBody(_)
+                | Ancestor::TSModuleBlockBody(_)
+        );
+        let parent_scope_id = if allow_multiple_statements {
+            ctx.current_scope_id()
+        } else {
+            ctx.create_child_scope_of_current(ScopeFlags::empty())
+        };
+
+        let mut statements = self.transform(stmt, parent_scope_id, ctx);
+        let mut new_stmt = statements.pop().unwrap();
+        if allow_multiple_statements {
+            self.ctx.statement_injector.move_insertions(stmt, &new_stmt);
+            if !statements.is_empty() {
+                self.ctx.statement_injector.insert_many_before(&new_stmt, statements);
+            }
+        } else {
+            // `if (a) for (x of y);` -> `if (a) { var _iterator = ...; try { ... } }`
+            statements.push(new_stmt);
+            new_stmt = ctx.ast.statement_block_with_scope_id(
+                SPAN,
+                ctx.ast.vec_from_iter(statements),
+                parent_scope_id,
+            );
+        }
+        *stmt = new_stmt;
+    }
+
+    /// Transform `for...of` loop, which may be labeled, into statements.
+    fn transform(
+        &self,
+        stmt: &mut Statement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        // `a: b: for (x of y) {}` -> `["a", "b"]`, `for (x of y) {}`
+        let mut labels = vec![];
+        let mut body = stmt;
+        while let Statement::LabeledStatement(labeled) = body {
+            labels.push(labeled.label.clone());
+            body = &mut labeled.body;
+        }
+        let Statement::ForOfStatement(for_of) = body else { unreachable!() };
+
+        let is_array = self.assume_array || matches!(for_of.right, Expression::ArrayExpression(_));
+        if is_array || self.skip_for_of_iterator_closing {
+            let for_statement = if is_array {
+                Self::transform_array(for_of, parent_scope_id, ctx)
+            } else {
+                self.transform_loose(for_of, parent_scope_id, ctx)
+            };
+            return vec![wrap_in_labels(for_statement, labels, ctx)];
+        }
+
+        let try_block_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let (declaration, iterator, for_statement) =
+            self.transform_spec(for_of, try_block_scope_id, ctx);
+        let for_statement = wrap_in_labels(for_statement, labels, ctx);
+        let try_statement =
+            Self::create_try_statement(for_statement, &iterator, try_block_scope_id, ctx);
+        vec![declaration, try_statement]
+    }
+
+    /// ```js
+    /// var _iterator = babelHelpers.createForOfIteratorHelper(right), _step;
+    /// for (_iterator.s(); !(_step = _iterator.n()).done;) { left = _step.value; body }
+    /// ```
+    ///
+    /// Returns the `var` declaration, the iterator binding and the `for` statement.
+    fn transform_spec(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Statement<'a>, BoundIdentifier<'a>, Statement<'a>) {
+        let iterator = ctx.generate_uid_in_current_hoist_scope("iterator");
+        let step = ctx.generate_uid_in_current_hoist_scope("step");
+
+        let right = for_of.right.take_in(ctx.ast);
+        let init = self.create_iterator_helper(Helper::CreateForOfIterator, right, ctx);
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                iterator.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, step.create_binding_pattern(ctx), None, false),
+        ]);
+        let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarations,
+            false,
+        ));
+
+        // `_iterator.s()`
+        let callee = create_member_callee(iterator.create_read_expression(ctx), "s", ctx);
+        let init = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false);
+        // `!(_step = _iterator.n()).done`
+        let callee = create_member_callee(iterator.create_read_expression(ctx), "n", ctx);
+        let next = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false);
+        let test = Self::create_step_test(&step, next, ctx);
+
+        let for_statement = Self::create_for_statement(
+            for_of,
+            Some(ForStatementInit::from(init)),
+            Some(test),
+            None,
+            create_property_access(SPAN, step.create_read_expression(ctx), "value", ctx),
+            parent_scope_id,
+            ctx,
+        );
+        (declaration, iterator, for_statement)
+    }
+
+    /// ```js
+    /// for (var _iterator = babelHelpers.createForOfIteratorHelperLoose(right), _step; !(_step = _iterator()).done;) {
+    ///   left = _step.value;
+    ///   body
+    /// }
+    /// ```
+    fn transform_loose(
+        &self,
+        for_of: &mut ForOfStatement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let iterator = ctx.generate_uid_in_current_hoist_scope("iterator");
+        let step = ctx.generate_uid_in_current_hoist_scope("step");
+
+        let right = for_of.right.take_in(ctx.ast);
+        let helper = self.create_iterator_helper(Helper::CreateForOfIteratorLoose, right, ctx);
+        let init = create_var_init([(&iterator, Some(helper)), (&step, None)], ctx);
+        let next = ctx.ast.expression_call(
+            SPAN,
+            iterator.create_read_expression(ctx),
+            NONE,
+            ctx.ast.vec(),
+            false,
+        );
+        let test = Self::create_step_test(&step, next, ctx);
+        Self::create_for_statement(
+            for_of,
+            Some(init),
+            Some(test),
+            None,
+            create_property_access(SPAN, step.create_read_expression(ctx), "value", ctx),
+            parent_scope_id,
+            ctx,
+        )
+    }
+
+    /// ```js
+    /// for (var _i = 0, _arr = right; _i < _arr.length; _i++) {
+    ///   left = _arr[_i];
+    ///   body
+    /// }
+    /// ```
+    fn transform_array(
+        for_of: &mut ForOfStatement<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let index = ctx.generate_uid_in_current_hoist_scope("i");
+        let array = if matches!(for_of.right, Expression::ArrayExpression(_)) {
+            ctx.generate_uid_in_current_hoist_scope("arr")
+        } else {
+            ctx.generate_uid_in_current_hoist_scope_based_on_node(&for_of.right)
+        };
+
+        let right = for_of.right.take_in(ctx.ast);
+        let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+        let init = create_var_init([(&index, Some(zero)), (&array, Some(right))], ctx);
+        // `_i < _arr.length`
+        let length = create_property_access(SPAN, array.create_read_expression(ctx), "length", ctx);
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            index.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            length,
+        );
+        // `_i++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            index.create_read_write_simple_target(ctx),
+        );
+        // `_arr[_i]`
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            array.create_read_expression(ctx),
+            index.create_read_expression(ctx),
+            false,
+        ));
+        Self::create_for_statement(
+            for_of,
+            Some(init),
+            Some(test),
+            Some(update),
+            value,
+            parent_scope_id,
+            ctx,
+        )
+    }
+
+    /// `babelHelpers.createForOfIteratorHelper(right)`, with `true` as 2nd argument if array-like objects are allowed.
+    fn create_iterator_helper(
+        &self,
+        helper: Helper,
+        right: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(right));
+        if self.allow_array_like {
+            arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+        }
+        self.ctx.helper_call_expr(helper, SPAN, arguments, ctx)
+    }
+
+    /// `!(_step = next).done`
+    fn create_step_test(
+        step: &BoundIdentifier<'a>,
+        next: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let assignment = ctx.ast.expression_parenthesized(SPAN, create_assignment(step, next, ctx));
+        let done = create_property_access(SPAN, assignment, "done", ctx);
+        ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, done)
+    }
+
+    /// Create `for (init; test; update) { left = value; body }`.
+    ///
+    /// The scope of the `for...of` statement, which contains the bindings declared by `left`,
+    /// becomes the scope of the new loop body.
+    fn create_for_statement(
+        for_of: &mut ForOfStatement<'a>,
+        init: Option<ForStatementInit<'a>>,
+        test: Option<Expression<'a>>,
+        update: Option<Expression<'a>>,
+        value: Expression<'a>,
+        parent_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let for_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let body_scope_id = for_of.scope_id();
+        ctx.scoping_mut().change_scope_parent_id(body_scope_id, Some(for_scope_id));
+
+        let mut body = ctx.ast.vec_with_capacity(2);
+        body.push(Self::create_left_statement(&mut for_of.left, value, ctx));
+        match for_of.body.take_in(ctx.ast) {
+            // Flatten the body block into the new body, unless it declares bindings which
+            // could conflict with those declared by `left`
+            Statement::BlockStatement(block)
+                if ctx.scoping().get_bindings(block.scope_id()).is_empty() =>
+            {
+                let block = block.unbox();
+                let mut reparenter = ScopeReparenter::new(ctx.scoping_mut(), body_scope_id);
+                for stmt in &block.body {
+                    reparenter.visit_statement(stmt);
+                }
+                ctx.scoping_mut().delete_scope(block.scope_id());
+                body.extend(block.body);
+            }
+            Statement::EmptyStatement(_) => {}
+            stmt => body.push(stmt),
+        }
+        let body = ctx.ast.statement_block_with_scope_id(SPAN, body, body_scope_id);
+        ctx.ast.statement_for_with_scope_id(for_of.span, init, test, update, body, for_scope_id)
+    }
+
+    /// * `for (var x of y)` -> `var x = value;`
+    /// * `for (x of y)` -> `x = value;`
+    fn create_left_statement(
+        left: &mut ForStatementLeft<'a>,
+        value: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        match left {
+            ForStatementLeft::VariableDeclaration(declaration) => {
+                let mut declarations = declaration.declarations.take_in(ctx.ast);
+                declarations[0].init = Some(value);
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    declaration.kind,
+                    declarations,
+                    false,
+                ))
+            }
+            left @ match_assignment_target!(ForStatementLeft) => {
+                let target = left.to_assignment_target_mut().take_in(ctx.ast);
+                let assignment =
+                    ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+                ctx.ast.statement_expression(SPAN, assignment)
+            }
+        }
+    }
+
+    /// ```js
+    /// try {
+    ///   for_statement
+    /// } catch (err) {
+    ///   _iterator.e(err);
+    /// } finally {
+    ///   _iterator.f();
+    /// }
+    /// ```
+    fn create_try_statement(
+        for_statement: Statement<'a>,
+        iterator: &BoundIdentifier<'a>,
+        block_scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let parent_scope_id = ctx.scoping().scope_parent_id(block_scope_id).unwrap();
+        let block = ctx.ast.alloc_block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(for_statement),
+            block_scope_id,
+        );
+
+        // `catch (err) { _iterator.e(err); }`
+        let catch_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::CatchClause);
+        let catch_block_scope_id = ctx.create_child_scope(catch_scope_id, ScopeFlags::empty());
+        let err = ctx.generate_binding(
+            Atom::from("err"),
+            catch_block_scope_id,
+            SymbolFlags::CatchVariable | SymbolFlags::FunctionScopedVariable,
+        );
+        let callee = create_member_callee(iterator.create_read_expression(ctx), "e", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(err.create_read_expression(ctx)));
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        let catch_body = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            catch_block_scope_id,
+        );
+        let handler = ctx.ast.catch_clause_with_scope_id(
+            SPAN,
+            Some(ctx.ast.catch_parameter(SPAN, err.create_binding_pattern(ctx))),
+            catch_body,
+            catch_scope_id,
+        );
+
+        // `finally { _iterator.f(); }`
+        let finally_scope_id = ctx.create_child_scope(parent_scope_id, ScopeFlags::empty());
+        let callee = create_member_callee(iterator.create_read_expression(ctx), "f", ctx);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec(), false);
+        let finalizer = ctx.ast.block_statement_with_scope_id(
+            SPAN,
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, call)),
+            finally_scope_id,
+        );
+
+        ctx.ast.statement_try(SPAN, block, Some(handler), Some(finalizer))
+    }
+}
+
+/// `var a = init, b`
+fn create_var_init<'a, const N: usize>(
+    declarators: [(&BoundIdentifier<'a>, Option<Expression<'a>>); N],
+    ctx: &TraverseCtx<'a>,
+) -> ForStatementInit<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarations: ArenaVec<'a, _> =
+        ctx.ast.vec_from_iter(declarators.into_iter().map(|(binding, init)| {
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                binding.create_binding_pattern(ctx),
+                init,
+                false,
+            )
+        }));
+    ctx.ast.for_statement_init_variable_declaration(SPAN, kind, declarations, false)
+}
+
+/// `a: b: stmt`
+fn wrap_in_labels<'a>(
+    stmt: Statement<'a>,
+    labels: Vec<LabelIdentifier<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    labels.into_iter().rev().fold(stmt, |body, label| ctx.ast.statement_labeled(SPAN, label, body))
+}
@@ -0,0 +1,1688 @@
+//! Explodes a generator body into a list of statements, split into the cases of a `switch`
+//! statement which is driven by the regenerator runtime's context object.
+//!
+//! Each statement or expression which contains a "leap" (`yield`, `break`, `continue`, `return`
+//! or `throw`) is broken down, so that execution can be suspended and resumed at any `yield`.
+//! Statements and expressions without leaps are emitted unchanged.
+//!
+//! Based on [regenerator-transform/src/emit.js](https://github.com/facebook/regenerator/blob/main/packages/transform/src/emit.js).
+
+use std::mem;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Box as ArenaBox, CloneIn, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    utils::{scope_reparenter::ScopeReparenter, var_hoister::binding_pattern_to_target},
+};
+
+use super::{
+    hoist::BindingRenamer,
+    leap::{Entry, LeapManager},
+    meta::{
+        expression_contains_leap, property_key_contains_leap, statement_contains_leap,
+        target_contains_leap,
+    },
+};
+
+/// A location in the exploded statement list, which can be jumped to.
+///
+/// Index into [`Emitter::locs`]. Its value is not known until the location is marked.
+#[derive(Clone, Copy)]
+pub(super) struct Loc(usize);
+
+/// A temporary value stored on the context object, e.g. `_context.t0`.
+#[derive(Clone, Copy)]
+struct Temp<'a>(Atom<'a>);
+
+enum JumpTarget<'a> {
+    Loc(Loc),
+    /// `_context.t0 === 1 ? 3 : _context.t0 === 2 ? 5 : 7`
+    Cases {
+        discriminant: Temp<'a>,
+        cases: Vec<(Expression<'a>, Loc)>,
+        default: Loc,
+    },
+}
+
+/// An entry in the exploded statement list.
+///
+/// Statements referring to locations are only built once all locations are known.
+enum Item<'a> {
+    Statement(Statement<'a>),
+    /// `_context.next = loc; break;`
+    Jump(JumpTarget<'a>),
+    /// `if (test) { _context.next = loc; break; }`
+    JumpIf(Expression<'a>, Loc),
+    /// `_context.next = loc; return arg;`
+    Yield(Loc, Option<Expression<'a>>),
+    /// `return _context.delegateYield(arg, "t0", loc);`
+    DelegateYield(Expression<'a>, Temp<'a>, Loc),
+    /// `return _context.abrupt("break", loc);`
+    Abrupt(&'static str, Loc),
+    /// `return _context.finish(loc);`
+    Finish(Loc),
+    /// `_context.prev = loc;`
+    Prev(Loc),
+    /// `target = _context["catch"](loc);`
+    Catch(Option<AssignmentTarget<'a>>, Loc),
+}
+
+impl Item<'_> {
+    /// Number of statements this item expands to. Locations are numbered by statement.
+    fn len(&self) -> usize {
+        match self {
+            Self::Jump(_) | Self::Yield(..) => 2,
+            _ => 1,
+        }
+    }
+
+    /// `true` if this item contains no code from the original function, so it can be dropped
+    /// when unreachable.
+    fn is_generated(&self) -> bool {
+        matches!(
+            self,
+            Self::Jump(JumpTarget::Loc(_)) | Self::Abrupt(..) | Self::Finish(_) | Self::Prev(_)
+        )
+    }
+}
+
+/// Locations of a `try` statement, passed to the runtime.
+struct TryEntry {
+    first: Loc,
+    catch: Option<Loc>,
+    finally: Option<Loc>,
+    after: Loc,
+}
+
+/// Result of exploding a generator body.
+pub(super) struct Exploded<'a> {
+    /// `while (1) switch (_context.prev = _context.next) { ... }`
+    pub body: Statement<'a>,
+    /// `[[1, 5], [8,, 12, 15]]`, if the body contains any `try` statements.
+    pub try_locs: Option<Expression<'a>>,
+    /// Bindings to declare with `var` in the outer function.
+    pub vars: Vec<(Span, BoundIdentifier<'a>)>,
+    /// Function declarations to move to the outer function.
+    pub function_declarations: Vec<Statement<'a>>,
+}
+
+pub(super) struct Emitter<'a, 'c> {
+    transform_ctx: &'c TransformCtx<'a>,
+    ctx: &'c mut TraverseCtx<'a>,
+    /// `_context` parameter of the inner function.
+    context: BoundIdentifier<'a>,
+    /// Scope of the outer function.
+    function_scope_id: ScopeId,
+    /// Scope of the `switch` statement.
+    switch_scope_id: ScopeId,
+    items: Vec<Item<'a>>,
+    /// Number of statements `items` expands to.
+    listing_len: usize,
+    /// Statement indexes which begin a `case`.
+    marked: FxHashSet<usize>,
+    /// Values of locations. `None` until the location is marked.
+    locs: Vec<Option<usize>>,
+    try_entries: Vec<TryEntry>,
+    leaps: LeapManager<'a>,
+    next_temp_id: usize,
+    vars: Vec<(Span, BoundIdentifier<'a>)>,
+    declared_symbols: FxHashSet<SymbolId>,
+    function_declarations: Vec<Statement<'a>>,
+}
+
+impl<'a, 'c> Emitter<'a, 'c> {
+    pub fn new(
+        context: BoundIdentifier<'a>,
+        function_scope_id: ScopeId,
+        switch_scope_id: ScopeId,
+        vars: Vec<(Span, BoundIdentifier<'a>)>,
+        transform_ctx: &'c TransformCtx<'a>,
+        ctx: &'c mut TraverseCtx<'a>,
+    ) -> Self {
+        let declared_symbols = vars.iter().map(|(_, binding)| binding.symbol_id).collect();
+        let mut marked = FxHashSet::default();
+        // The first statement always begins a case
+        marked.insert(0);
+        Self {
+            transform_ctx,
+            ctx,
+            context,
+            function_scope_id,
+            switch_scope_id,
+            items: vec![],
+            listing_len: 0,
+            marked,
+            locs: vec![],
+            try_entries: vec![],
+            leaps: LeapManager::default(),
+            next_temp_id: 0,
+            vars,
+            declared_symbols,
+            function_declarations: vec![],
+        }
+    }
+
+    // ----------------------------- Locations and items -----------------------------
+
+    fn loc(&mut self) -> Loc {
+        self.locs.push(None);
+        Loc(self.locs.len() - 1)
+    }
+
+    /// Set location to the current position, so it begins a new `case`.
+    fn mark(&mut self, loc: Loc) -> Loc {
+        let index = self.listing_len;
+        debug_assert!(self.locs[loc.0].is_none_or(|value| value == index));
+        self.locs[loc.0] = Some(index);
+        self.marked.insert(index);
+        loc
+    }
+
+    /// Location of the current position, which does not begin a new `case`.
+    fn unmarked_current_loc(&mut self) -> Loc {
+        self.locs.push(Some(self.listing_len));
+        Loc(self.locs.len() - 1)
+    }
+
+    fn emit(&mut self, item: Item<'a>) {
+        self.listing_len += item.len();
+        self.items.push(item);
+    }
+
+    fn emit_statement(&mut self, stmt: Statement<'a>) {
+        self.emit(Item::Statement(stmt));
+    }
+
+    fn emit_expression(&mut self, expr: Expression<'a>) {
+        let stmt = self.ctx.ast.statement_expression(expr.span(), expr);
+        self.emit_statement(stmt);
+    }
+
+    fn jump(&mut self, loc: Loc) {
+        self.emit(Item::Jump(JumpTarget::Loc(loc)));
+    }
+
+    fn jump_if(&mut self, test: Expression<'a>, loc: Loc) {
+        self.emit(Item::JumpIf(test, loc));
+    }
+
+    fn jump_if_not(&mut self, test: Expression<'a>, loc: Loc) {
+        let test = match test {
+            // Avoid double negation
+            Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::LogicalNot => {
+                unary.unbox().argument
+            }
+            test => self.ctx.ast.expression_unary(SPAN, UnaryOperator::LogicalNot, test),
+        };
+        self.jump_if(test, loc);
+    }
+
+    // ----------------------------- Context object -----------------------------
+
+    fn context_member(&mut self, name: Atom<'a>) -> MemberExpression<'a> {
+        let object = self.context.create_read_expression(self.ctx);
+        let property = self.ctx.ast.identifier_name(SPAN, name);
+        self.ctx.ast.member_expression_static(SPAN, object, property, false)
+    }
+
+    /// `_context.name`
+    fn context_property(&mut self, name: &'static str) -> Expression<'a> {
+        Expression::from(self.context_member(Atom::from(name)))
+    }
+
+    /// `_context.name = value`
+    fn context_assign(&mut self, name: &'static str, value: Expression<'a>) -> Expression<'a> {
+        let target = AssignmentTarget::from(self.context_member(Atom::from(name)));
+        self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value)
+    }
+
+    /// `_context.name(...arguments)`
+    fn context_call(
+        &mut self,
+        name: &'static str,
+        arguments: ArenaVec<'a, Argument<'a>>,
+    ) -> Expression<'a> {
+        let callee = self.context_property(name);
+        self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    fn make_temp(&mut self) -> Temp<'a> {
+        let name = self.ctx.ast.atom(&format!("t{}", self.next_temp_id));
+        self.next_temp_id += 1;
+        Temp(name)
+    }
+
+    /// `_context.t0`
+    fn temp_expression(&mut self, temp: Temp<'a>) -> Expression<'a> {
+        Expression::from(self.context_member(temp.0))
+    }
+
+    /// Emit `_context.t0 = value;`.
+    fn assign_temp(&mut self, temp: Temp<'a>, value: Expression<'a>) {
+        let target = AssignmentTarget::from(self.context_member(temp.0));
+        let assignment =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assignment);
+    }
+
+    /// Emit `_context.t0 = value;`, and return `_context.t0`.
+    fn emit_assign_temp(&mut self, temp: Temp<'a>, value: Expression<'a>) -> Expression<'a> {
+        self.assign_temp(temp, value);
+        self.temp_expression(temp)
+    }
+
+    fn error(&self, message: &'static str, span: Span) {
+        self.transform_ctx.error(
+            OxcDiagnostic::error(format!("Generators transform does not support {message}"))
+                .with_label(span),
+        );
+    }
+
+    // ----------------------------- Declarations -----------------------------
+
+    /// Declare a binding with `var` in the outer function.
+    fn declare(&mut self, ident: &BindingIdentifier<'a>) {
+        let symbol_id = ident.symbol_id();
+        let flags = self.ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(
+            SymbolFlags::BlockScopedVariable
+                | SymbolFlags::ConstVariable
+                | SymbolFlags::Class
+                | SymbolFlags::Function
+                | SymbolFlags::CatchVariable,
+        );
+        flags.insert(SymbolFlags::FunctionScopedVariable);
+        if self.declared_symbols.insert(symbol_id) {
+            self.vars.push((ident.span, BoundIdentifier::from_binding_ident(ident)));
+        }
+    }
+
+    /// `let a = 1, b;` -> `a = 1`
+    ///
+    /// In a loop, bindings without an initializer are reset: `let a;` -> `a = void 0`.
+    fn lexical_declaration_to_expression(
+        &mut self,
+        decl: ArenaBox<'a, VariableDeclaration<'a>>,
+    ) -> Option<Expression<'a>> {
+        let decl = decl.unbox();
+        let in_loop = self.leaps.is_in_loop();
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations {
+            declarator.id.bound_names(&mut |ident| self.declare(ident));
+            let init = match declarator.init {
+                Some(init) => init,
+                None if in_loop => self.ctx.ast.void_0(SPAN),
+                None => continue,
+            };
+            let target = binding_pattern_to_target(declarator.id, self.ctx);
+            expressions.push(self.ctx.ast.expression_assignment(
+                declarator.span,
+                AssignmentOperator::Assign,
+                target,
+                init,
+            ));
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+
+    /// `class A {}` -> `A = class {}`
+    fn class_declaration_to_expression(
+        &mut self,
+        mut class: ArenaBox<'a, Class<'a>>,
+    ) -> Expression<'a> {
+        let span = class.span;
+        let ident = class.id.take().unwrap();
+        self.declare(&ident);
+        class.r#type = ClassType::ClassExpression;
+        let target = BoundIdentifier::from_binding_ident(&ident)
+            .create_spanned_write_target(ident.span, self.ctx);
+        self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            target,
+            Expression::ClassExpression(class),
+        )
+    }
+
+    /// `function f() {}` -> `f = function () {};`
+    fn function_declaration_to_statement(
+        &mut self,
+        mut func: ArenaBox<'a, Function<'a>>,
+    ) -> Statement<'a> {
+        let span = func.span;
+        let ident = func.id.take().unwrap();
+        self.declare(&ident);
+        func.r#type = FunctionType::FunctionExpression;
+        let target = BoundIdentifier::from_binding_ident(&ident)
+            .create_spanned_write_target(ident.span, self.ctx);
+        let assignment = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            target,
+            Expression::FunctionExpression(func),
+        );
+        self.ctx.ast.statement_expression(span, assignment)
+    }
+
+    /// Move bindings of a block scope which is being removed to the outer function's scope,
+    /// renaming any which would clash with or shadow another binding.
+    ///
+    /// Returns the bindings which were renamed.
+    fn remove_scope(&mut self, scope_id: ScopeId) -> FxHashMap<SymbolId, Atom<'a>> {
+        let mut renamed = FxHashMap::default();
+        let symbol_ids = self.ctx.scoping().iter_bindings_in(scope_id).collect::<Vec<_>>();
+        for symbol_id in symbol_ids {
+            let mut name = self.ctx.ast.atom(self.ctx.scoping().symbol_name(symbol_id));
+            let scoping = self.ctx.scoping();
+            if scoping.find_binding(self.function_scope_id, &name).is_some()
+                || scoping.root_unresolved_references().contains_key(name.as_str())
+            {
+                let new_name = self.ctx.generate_uid_name(&name);
+                self.ctx.scoping_mut().rename_symbol(symbol_id, scope_id, &new_name);
+                renamed.insert(symbol_id, new_name);
+                name = new_name;
+            }
+            let scoping = self.ctx.scoping_mut();
+            scoping.move_binding(scope_id, self.function_scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, self.function_scope_id);
+        }
+        self.ctx.scoping_mut().delete_scope(scope_id);
+        renamed
+    }
+
+    // ----------------------------- Statements -----------------------------
+
+    /// Explode a list of statements.
+    ///
+    /// Function declarations at the top level of the function body are moved to the outer function.
+    /// Other function declarations become assignments at the start of the list.
+    pub fn explode_statements(
+        &mut self,
+        stmts: ArenaVec<'a, Statement<'a>>,
+        is_function_body: bool,
+    ) {
+        let mut rest = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            match stmt {
+                Statement::FunctionDeclaration(func) => {
+                    if is_function_body {
+                        self.function_declarations.push(Statement::FunctionDeclaration(func));
+                    } else {
+                        let stmt = self.function_declaration_to_statement(func);
+                        self.emit_statement(stmt);
+                    }
+                }
+                stmt => rest.push(stmt),
+            }
+        }
+        for stmt in rest {
+            self.explode_statement(stmt, None);
+        }
+    }
+
+    fn explode_statement(&mut self, stmt: Statement<'a>, label: Option<Atom<'a>>) {
+        // Lexical declarations are always converted, as each `case` is a separate block
+        let stmt = match stmt {
+            Statement::VariableDeclaration(decl) => {
+                if let Some(expr) = self.lexical_declaration_to_expression(decl) {
+                    self.explode_expression_statement(expr);
+                }
+                return;
+            }
+            Statement::ClassDeclaration(class) => {
+                let expr = self.class_declaration_to_expression(class);
+                self.explode_expression_statement(expr);
+                return;
+            }
+            stmt => stmt,
+        };
+
+        if !statement_contains_leap(&stmt) {
+            self.emit_statement(stmt);
+            return;
+        }
+
+        match stmt {
+            Statement::ExpressionStatement(stmt) => {
+                self.explode_expression_statement(stmt.unbox().expression);
+            }
+            Statement::BlockStatement(mut block) => {
+                let renamed = self.remove_scope(block.scope_id());
+                if !renamed.is_empty() {
+                    BindingRenamer::new(self.ctx.scoping(), &renamed)
+                        .visit_block_statement(&mut block);
+                }
+                self.explode_statements(block.unbox().body, false);
+            }
+            Statement::LabeledStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let after = self.loc();
+                self.leaps.push(Entry::Labeled { break_loc: after, label: stmt.label.name });
+                self.explode_statement(stmt.body, Some(stmt.label.name));
+                self.leaps.pop();
+                self.mark(after);
+            }
+            Statement::WhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let before = self.loc();
+                let after = self.loc();
+                self.mark(before);
+                let test = self.explode_expression(stmt.test);
+                self.jump_if_not(test, after);
+                self.leaps.push(Entry::Loop { break_loc: after, continue_loc: before, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.jump(before);
+                self.mark(after);
+            }
+            Statement::DoWhileStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let first = self.loc();
+                let test = self.loc();
+                let after = self.loc();
+                self.mark(first);
+                self.leaps.push(Entry::Loop { break_loc: after, continue_loc: test, label });
+                self.explode_statement(stmt.body, None);
+                self.leaps.pop();
+                self.mark(test);
+                let test = self.explode_expression(stmt.test);
+                self.jump_if(test, first);
+                self.mark(after);
+            }
+            Statement::ForStatement(mut stmt) => {
+                let renamed = self.remove_scope(stmt.scope_id());
+                if !renamed.is_empty() {
+                    BindingRenamer::new(self.ctx.scoping(), &renamed)
+                        .visit_for_statement(&mut stmt);
+                }
+                self.explode_for_statement(stmt.unbox(), label);
+            }
+            Statement::ForInStatement(mut stmt) => {
+                let renamed = self.remove_scope(stmt.scope_id());
+                if !renamed.is_empty() {
+                    BindingRenamer::new(self.ctx.scoping(), &renamed)
+                        .visit_for_in_statement(&mut stmt);
+                }
+                let stmt = stmt.unbox();
+                self.explode_for_in_or_of(false, stmt.left, stmt.right, stmt.body, label);
+            }
+            Statement::ForOfStatement(mut stmt) => {
+                if stmt.r#await {
+                    self.error("`for await`", stmt.span);
+                }
+                let renamed = self.remove_scope(stmt.scope_id());
+                if !renamed.is_empty() {
+                    BindingRenamer::new(self.ctx.scoping(), &renamed)
+                        .visit_for_of_statement(&mut stmt);
+                }
+                let stmt = stmt.unbox();
+                self.explode_for_in_or_of(true, stmt.left, stmt.right, stmt.body, label);
+            }
+            Statement::BreakStatement(stmt) => match self.leaps.break_loc(stmt.label.as_ref()) {
+                Some(loc) => self.emit(Item::Abrupt("break", loc)),
+                None => self.error("this `break` statement", stmt.span),
+            },
+            Statement::ContinueStatement(stmt) => {
+                match self.leaps.continue_loc(stmt.label.as_ref()) {
+                    Some(loc) => self.emit(Item::Abrupt("continue", loc)),
+                    None => self.error("this `continue` statement", stmt.span),
+                }
+            }
+            Statement::SwitchStatement(mut stmt) => {
+                let renamed = self.remove_scope(stmt.scope_id());
+                if !renamed.is_empty() {
+                    BindingRenamer::new(self.ctx.scoping(), &renamed)
+                        .visit_switch_statement(&mut stmt);
+                }
+                self.explode_switch_statement(stmt.unbox());
+            }
+            Statement::IfStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let else_loc = stmt.alternate.as_ref().map(|_| self.loc());
+                let after = self.loc();
+                let test = self.explode_expression(stmt.test);
+                self.jump_if_not(test, else_loc.unwrap_or(after));
+                self.explode_statement(stmt.consequent, None);
+                if let (Some(else_loc), Some(alternate)) = (else_loc, stmt.alternate) {
+                    self.jump(after);
+                    self.mark(else_loc);
+                    self.explode_statement(alternate, None);
+                }
+                self.mark(after);
+            }
+            Statement::ReturnStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let mut arguments = self.ctx.ast.vec1(Argument::from(
+                    self.ctx.ast.expression_string_literal(SPAN, "return", None),
+                ));
+                if let Some(argument) = stmt.argument {
+                    arguments.push(Argument::from(self.explode_expression(argument)));
+                }
+                let abrupt = self.context_call("abrupt", arguments);
+                let stmt = self.ctx.ast.statement_return(stmt.span, Some(abrupt));
+                self.emit_statement(stmt);
+            }
+            Statement::ThrowStatement(stmt) => {
+                let stmt = stmt.unbox();
+                let argument = self.explode_expression(stmt.argument);
+                let stmt = self.ctx.ast.statement_throw(stmt.span, argument);
+                self.emit_statement(stmt);
+            }
+            Statement::TryStatement(stmt) => self.explode_try_statement(stmt.unbox()),
+            stmt => {
+                self.error("`yield` in this statement", stmt.span());
+                self.emit_statement(stmt);
+            }
+        }
+    }
+
+    fn explode_for_statement(&mut self, stmt: ForStatement<'a>, label: Option<Atom<'a>>) {
+        let head = self.loc();
+        let update = self.loc();
+        let after = self.loc();
+
+        match stmt.init {
+            Some(ForStatementInit::VariableDeclaration(decl)) => {
+                if let Some(expr) = self.lexical_declaration_to_expression(decl) {
+                    self.explode_expression_statement(expr);
+                }
+            }
+            Some(init) => self.explode_expression_statement(init.into_expression()),
+            None => {}
+        }
+
+        self.mark(head);
+        if let Some(test) = stmt.test {
+            let test = self.explode_expression(test);
+            self.jump_if_not(test, after);
+        }
+
+        self.leaps.push(Entry::Loop { break_loc: after, continue_loc: update, label });
+        self.explode_statement(stmt.body, None);
+        self.leaps.pop();
+
+        self.mark(update);
+        if let Some(update) = stmt.update {
+            self.explode_expression_statement(update);
+        }
+        self.jump(head);
+        self.mark(after);
+    }
+
+    /// ```js
+    /// for (k in obj) body
+    /// // ->
+    /// _context.t0 = regeneratorRuntime.keys(obj);
+    /// case 1:
+    ///   if ((_context.t1 = _context.t0()).done) { _context.next = 5; break; }
+    ///   k = _context.t1.value;
+    ///   body
+    /// ```
+    ///
+    /// `for (x of iterable)` is the same, using `regeneratorRuntime.values(iterable)` and calling
+    /// `_context.t0.next()`. The iterator is not closed when the loop is exited early.
+    fn explode_for_in_or_of(
+        &mut self,
+        is_for_of: bool,
+        left: ForStatementLeft<'a>,
+        right: Expression<'a>,
+        body: Statement<'a>,
+        label: Option<Atom<'a>>,
+    ) {
+        let head = self.loc();
+        let after = self.loc();
+
+        let iterator = self.make_temp();
+        let right = self.explode_expression(right);
+        let method = if is_for_of { "values" } else { "keys" };
+        let runtime = self.transform_ctx.regenerator_runtime(self.ctx);
+        let callee = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            runtime,
+            self.ctx.ast.identifier_name(SPAN, method),
+            false,
+        ));
+        let init = self.ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            self.ctx.ast.vec1(Argument::from(right)),
+            false,
+        );
+        self.assign_temp(iterator, init);
+
+        self.mark(head);
+        let info = self.make_temp();
+        let next = if is_for_of {
+            let object = self.temp_expression(iterator);
+            let callee = Expression::from(self.ctx.ast.member_expression_static(
+                SPAN,
+                object,
+                self.ctx.ast.identifier_name(SPAN, "next"),
+                false,
+            ));
+            self.ctx.ast.expression_call(SPAN, callee, NONE, self.ctx.ast.vec(), false)
+        } else {
+            let callee = self.temp_expression(iterator);
+            self.ctx.ast.expression_call(SPAN, callee, NONE, self.ctx.ast.vec(), false)
+        };
+        let info_target = AssignmentTarget::from(self.context_member(info.0));
+        let assign =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, info_target, next);
+        let done = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            self.ctx.ast.expression_parenthesized(SPAN, assign),
+            self.ctx.ast.identifier_name(SPAN, "done"),
+            false,
+        ));
+        self.jump_if(done, after);
+
+        let target = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let declarator = decl.unbox().declarations.into_iter().next().unwrap();
+                declarator.id.bound_names(&mut |ident| self.declare(ident));
+                binding_pattern_to_target(declarator.id, self.ctx)
+            }
+            left => self.explode_target(left.into_assignment_target()),
+        };
+        let info_expr = self.temp_expression(info);
+        let value = Expression::from(self.ctx.ast.member_expression_static(
+            SPAN,
+            info_expr,
+            self.ctx.ast.identifier_name(SPAN, "value"),
+            false,
+        ));
+        let assign =
+            self.ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+        self.emit_expression(assign);
+
+        self.leaps.push(Entry::Loop { break_loc: after, continue_loc: head, label });
+        self.explode_statement(body, None);
+        self.leaps.pop();
+
+        self.jump(head);
+        self.mark(after);
+    }
+
+    fn explode_switch_statement(&mut self, stmt: SwitchStatement<'a>) {
+        // Always save the discriminant in a temp, in case the case tests overwrite
+        // values like `_context.sent`
+        let discriminant_temp = self.make_temp();
+        let discriminant = self.explode_expression(stmt.discriminant);
+        self.assign_temp(discriminant_temp, discriminant);
+
+        let after = self.loc();
+        let default = self.loc();
+
+        let mut case_locs = Vec::with_capacity(stmt.cases.len());
+        let mut tests = vec![];
+        let mut consequents = vec![];
+        for case in stmt.cases {
+            if let Some(test) = case.test {
+                if expression_contains_leap(&test) {
+                    self.error("`yield` in a `case` test", test.span());
+                }
+                let loc = self.loc();
+                tests.push((test, loc));
+                case_locs.push(loc);
+            } else {
+                case_locs.push(default);
+            }
+            consequents.push(case.consequent);
+        }
+
+        self.emit(Item::Jump(JumpTarget::Cases {
+            discriminant: discriminant_temp,
+            cases: tests,
+            default,
+        }));
+
+        self.leaps.push(Entry::Switch { break_loc: after });
+        for (loc, consequent) in case_locs.into_iter().zip(consequents) {
+            self.mark(loc);
+            self.explode_statements(consequent, false);
+        }
+        self.leaps.pop();
+
+        self.mark(after);
+        if self.locs[default.0].is_none() {
+            self.mark(default);
+        }
+    }
+
+    fn explode_try_statement(&mut self, stmt: TryStatement<'a>) {
+        let after = self.loc();
+        let catch_loc = stmt.handler.as_ref().map(|_| self.loc());
+        let finally_loc = stmt.finalizer.as_ref().map(|_| self.loc());
+
+        let first_loc = self.unmarked_current_loc();
+        self.try_entries.push(TryEntry {
+            first: first_loc,
+            catch: catch_loc,
+            finally: finally_loc,
+            after,
+        });
+        self.emit(Item::Prev(first_loc));
+
+        self.explode_statement(Statement::BlockStatement(stmt.block), None);
+
+        if let (Some(catch_loc), Some(mut handler)) = (catch_loc, stmt.handler) {
+            // If there is a `finally` block, jump over the `catch` block to it
+            self.jump(finally_loc.unwrap_or(after));
+            self.mark(catch_loc);
+            self.emit(Item::Prev(catch_loc));
+
+            // The catch parameter becomes a binding in the outer function
+            let renamed = self.remove_scope(handler.scope_id());
+            if !renamed.is_empty() {
+                BindingRenamer::new(self.ctx.scoping(), &renamed).visit_catch_clause(&mut handler);
+            }
+            let handler = handler.unbox();
+            let target = handler.param.map(|param| {
+                param.pattern.bound_names(&mut |ident| self.declare(ident));
+                binding_pattern_to_target(param.pattern, self.ctx)
+            });
+            self.emit(Item::Catch(target, first_loc));
+
+            self.explode_statement(Statement::BlockStatement(handler.body), None);
+        }
+
+        if let (Some(finally_loc), Some(finalizer)) = (finally_loc, stmt.finalizer) {
+            self.mark(finally_loc);
+            self.emit(Item::Prev(finally_loc));
+            self.explode_statement(Statement::BlockStatement(finalizer), None);
+            self.emit(Item::Finish(finally_loc));
+        }
+
+        self.mark(after);
+        self.emit(Item::Prev(after));
+    }
+
+    // ----------------------------- Expressions -----------------------------
+
+    fn explode_expression(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode(expr, false).unwrap()
+    }
+
+    fn explode_expression_statement(&mut self, expr: Expression<'a>) {
+        self.explode(expr, true);
+    }
+
+    /// Emit the expression as a statement if its result is ignored, otherwise return it.
+    fn finish(&mut self, expr: Expression<'a>, ignore_result: bool) -> Option<Expression<'a>> {
+        if ignore_result {
+            self.emit_expression(expr);
+            None
+        } else {
+            Some(expr)
+        }
+    }
+
+    /// Explode a child expression, and store its result in a temp unless it is a literal.
+    ///
+    /// In general a temp is required when any sibling contains a `yield`, as the value could
+    /// change before the parent expression is evaluated.
+    fn explode_via_temp(
+        &mut self,
+        temp: Option<Temp<'a>>,
+        expr: Expression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let result = self.explode(expr, ignore_result)?;
+        if temp.is_some() || !is_literal(&result) {
+            let temp = temp.unwrap_or_else(|| self.make_temp());
+            Some(self.emit_assign_temp(temp, result))
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Explode an expression, storing its result in `temp`, or discarding it if `temp` is `None`.
+    fn explode_into(&mut self, temp: Option<Temp<'a>>, expr: Expression<'a>) {
+        if let Some(temp) = temp {
+            let value = self.explode_expression(expr);
+            self.assign_temp(temp, value);
+        } else {
+            self.explode(expr, true);
+        }
+    }
+
+    fn explode_to_temp(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        self.explode_via_temp(None, expr, false).unwrap()
+    }
+
+    fn explode_argument(&mut self, argument: Argument<'a>) -> Argument<'a> {
+        match argument {
+            Argument::SpreadElement(spread) => {
+                let spread = spread.unbox();
+                let argument = self.explode_to_temp(spread.argument);
+                self.ctx.ast.argument_spread_element(spread.span, argument)
+            }
+            argument => Argument::from(self.explode_to_temp(argument.into_expression())),
+        }
+    }
+
+    fn explode(&mut self, expr: Expression<'a>, ignore_result: bool) -> Option<Expression<'a>> {
+        if !expression_contains_leap(&expr) {
+            return self.finish(expr, ignore_result);
+        }
+
+        match expr {
+            Expression::ParenthesizedExpression(expr) => {
+                self.explode(expr.unbox().expression, ignore_result)
+            }
+            Expression::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                let expr = Expression::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    member.optional,
+                ));
+                self.finish(expr, ignore_result)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                let property = self.explode_to_temp(member.expression);
+                let expr = Expression::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    member.optional,
+                ));
+                self.finish(expr, ignore_result)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                let expr =
+                    Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object,
+                        member.field,
+                        member.optional,
+                    ));
+                self.finish(expr, ignore_result)
+            }
+            Expression::CallExpression(call) => {
+                let expr = self.explode_call_expression(call.unbox());
+                self.finish(expr, ignore_result)
+            }
+            Expression::NewExpression(new) => {
+                let new = new.unbox();
+                let callee = self.explode_to_temp(new.callee);
+                let arguments = self.ctx.ast.vec_from_iter(
+                    new.arguments.into_iter().map(|argument| self.explode_argument(argument)),
+                );
+                let expr = self.ctx.ast.expression_new(new.span, callee, NONE, arguments);
+                self.finish(expr, ignore_result)
+            }
+            Expression::ObjectExpression(object) => {
+                let object = object.unbox();
+                let mut properties = self.ctx.ast.vec_with_capacity(object.properties.len());
+                for property in object.properties {
+                    properties.push(self.explode_object_property(property));
+                }
+                let expr = self.ctx.ast.expression_object(object.span, properties);
+                self.finish(expr, ignore_result)
+            }
+            Expression::ArrayExpression(array) => {
+                let array = array.unbox();
+                let mut elements = self.ctx.ast.vec_with_capacity(array.elements.len());
+                for element in array.elements {
+                    let element = match element {
+                        ArrayExpressionElement::SpreadElement(spread) => {
+                            let spread = spread.unbox();
+                            let argument = self.explode_to_temp(spread.argument);
+                            self.ctx
+                                .ast
+                                .array_expression_element_spread_element(spread.span, argument)
+                        }
+                        element @ ArrayExpressionElement::Elision(_) => element,
+                        element => ArrayExpressionElement::from(
+                            self.explode_to_temp(element.into_expression()),
+                        ),
+                    };
+                    elements.push(element);
+                }
+                let expr = self.ctx.ast.expression_array(array.span, elements);
+                self.finish(expr, ignore_result)
+            }
+            Expression::SequenceExpression(sequence) => {
+                let sequence = sequence.unbox();
+                let last_index = sequence.expressions.len() - 1;
+                let mut result = None;
+                for (index, expr) in sequence.expressions.into_iter().enumerate() {
+                    if index == last_index {
+                        result = self.explode(expr, ignore_result);
+                    } else {
+                        self.explode(expr, true);
+                    }
+                }
+                result
+            }
+            Expression::LogicalExpression(logical) => {
+                let logical = logical.unbox();
+                let after = self.loc();
+                let result = if ignore_result { None } else { Some(self.make_temp()) };
+                let left = self.explode_via_temp(result, logical.left, false).unwrap();
+                self.jump_over_logical_right(logical.operator, left, after);
+                self.explode_into(result, logical.right);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp))
+            }
+            Expression::ConditionalExpression(conditional) => {
+                let conditional = conditional.unbox();
+                let else_loc = self.loc();
+                let after = self.loc();
+                let test = self.explode_expression(conditional.test);
+                self.jump_if_not(test, else_loc);
+                let result = if ignore_result { None } else { Some(self.make_temp()) };
+                self.explode_into(result, conditional.consequent);
+                self.jump(after);
+                self.mark(else_loc);
+                self.explode_into(result, conditional.alternate);
+                self.mark(after);
+                result.map(|temp| self.temp_expression(temp))
+            }
+            Expression::UnaryExpression(unary) => {
+                let unary = unary.unbox();
+                // Can't (and don't need to) break up the syntax of the argument, e.g. `delete a[b]`
+                let argument = self.explode_expression(unary.argument);
+                let expr = self.ctx.ast.expression_unary(unary.span, unary.operator, argument);
+                self.finish(expr, ignore_result)
+            }
+            Expression::BinaryExpression(binary) => {
+                let binary = binary.unbox();
+                let left = self.explode_to_temp(binary.left);
+                let right = self.explode_to_temp(binary.right);
+                let expr =
+                    self.ctx.ast.expression_binary(binary.span, left, binary.operator, right);
+                self.finish(expr, ignore_result)
+            }
+            Expression::PrivateInExpression(private_in) => {
+                let private_in = private_in.unbox();
+                let right = self.explode_to_temp(private_in.right);
+                let expr =
+                    self.ctx.ast.expression_private_in(private_in.span, private_in.left, right);
+                self.finish(expr, ignore_result)
+            }
+            Expression::AssignmentExpression(assignment) => {
+                self.explode_assignment_expression(assignment.unbox(), ignore_result)
+            }
+            Expression::UpdateExpression(update) => {
+                let update = update.unbox();
+                let argument = self.explode_simple_target(update.argument);
+                let expr = self.ctx.ast.expression_update(
+                    update.span,
+                    update.operator,
+                    update.prefix,
+                    argument,
+                );
+                self.finish(expr, ignore_result)
+            }
+            Expression::YieldExpression(expr) => {
+                let expr = expr.unbox();
+                let after = self.loc();
+                let argument = expr.argument.map(|argument| self.explode_expression(argument));
+                if expr.delegate {
+                    let result = self.make_temp();
+                    let argument = argument.unwrap();
+                    self.emit(Item::DelegateYield(argument, result, after));
+                    self.mark(after);
+                    return (!ignore_result).then(|| self.temp_expression(result));
+                }
+                self.emit(Item::Yield(after, argument));
+                self.mark(after);
+                (!ignore_result).then(|| self.context_property("sent"))
+            }
+            Expression::TemplateLiteral(template) => {
+                let template = template.unbox();
+                let expressions = self.ctx.ast.vec_from_iter(
+                    template.expressions.into_iter().map(|expr| self.explode_to_temp(expr)),
+                );
+                let expr = self.ctx.ast.expression_template_literal(
+                    template.span,
+                    template.quasis,
+                    expressions,
+                );
+                self.finish(expr, ignore_result)
+            }
+            Expression::ImportExpression(import) => {
+                let import = import.unbox();
+                let source = self.explode_to_temp(import.source);
+                let options = import.options.map(|options| self.explode_to_temp(options));
+                let expr =
+                    self.ctx.ast.expression_import(import.span, source, options, import.phase);
+                self.finish(expr, ignore_result)
+            }
+            expr => {
+                let message = match &expr {
+                    Expression::ChainExpression(_) => "`yield` in an optional chain",
+                    Expression::TaggedTemplateExpression(_) => "`yield` in a tagged template",
+                    Expression::ClassExpression(_) => "`yield` in a class",
+                    _ => "`yield` in this expression",
+                };
+                self.error(message, expr.span());
+                self.finish(expr, ignore_result)
+            }
+        }
+    }
+
+    /// Jump to `after` if the right side of a logical expression should not be evaluated.
+    fn jump_over_logical_right(
+        &mut self,
+        operator: LogicalOperator,
+        left: Expression<'a>,
+        after: Loc,
+    ) {
+        match operator {
+            LogicalOperator::And => self.jump_if_not(left, after),
+            LogicalOperator::Or => self.jump_if(left, after),
+            LogicalOperator::Coalesce => {
+                let null = self.ctx.ast.expression_null_literal(SPAN);
+                let test =
+                    self.ctx.ast.expression_binary(SPAN, left, BinaryOperator::Inequality, null);
+                self.jump_if(test, after);
+            }
+        }
+    }
+
+    fn explode_call_expression(&mut self, call: CallExpression<'a>) -> Expression<'a> {
+        if call.optional {
+            self.error("`yield` in an optional chain", call.span);
+        }
+        let has_leaping_arguments = call.arguments.iter().any(|argument| match argument {
+            Argument::SpreadElement(spread) => expression_contains_leap(&spread.argument),
+            argument => expression_contains_leap(argument.to_expression()),
+        });
+
+        let mut this_argument = None;
+        let callee = if call.callee.is_member_expression() {
+            if has_leaping_arguments {
+                // The callee must be evaluated before the arguments, but the object of the member
+                // expression must still be `this` for the call.
+                // `a.b(yield)` -> `_context.t0 = a; _context.t0.b.call(_context.t0, _context.sent)`
+                let member = call.callee.into_member_expression();
+                let object_temp = self.make_temp();
+                let callee = match member {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_via_temp(Some(object_temp), member.object, false).unwrap();
+                        self.ctx.ast.member_expression_static(
+                            member.span,
+                            object,
+                            member.property,
+                            false,
+                        )
+                    }
+                    MemberExpression::ComputedMemberExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_via_temp(Some(object_temp), member.object, false).unwrap();
+                        let property = self.explode_to_temp(member.expression);
+                        self.ctx.ast.member_expression_computed(
+                            member.span,
+                            object,
+                            property,
+                            false,
+                        )
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        let member = member.unbox();
+                        let object =
+                            self.explode_via_temp(Some(object_temp), member.object, false).unwrap();
+                        self.ctx.ast.member_expression_private_field_expression(
+                            member.span,
+                            object,
+                            member.field,
+                            false,
+                        )
+                    }
+                };
+                this_argument = Some(self.temp_expression(object_temp));
+                Expression::from(self.ctx.ast.member_expression_static(
+                    SPAN,
+                    Expression::from(callee),
+                    self.ctx.ast.identifier_name(SPAN, "call"),
+                    false,
+                ))
+            } else {
+                self.explode_expression(call.callee)
+            }
+        } else {
+            let callee = self.explode_to_temp(call.callee);
+            if callee.is_member_expression() {
+                // The callee was not a member expression, so must not receive a `this` value.
+                // `(0, _context.t0)()`
+                let expressions = self.ctx.ast.vec_from_array([self.ctx.ast.number_0(), callee]);
+                self.ctx.ast.expression_sequence(SPAN, expressions)
+            } else {
+                callee
+            }
+        };
+
+        let arguments = if has_leaping_arguments {
+            let mut arguments = self.ctx.ast.vec_with_capacity(call.arguments.len() + 1);
+            if let Some(this_argument) = this_argument {
+                arguments.push(Argument::from(this_argument));
+            }
+            for argument in call.arguments {
+                arguments.push(self.explode_argument(argument));
+            }
+            arguments
+        } else {
+            call.arguments
+        };
+
+        self.ctx.ast.expression_call(call.span, callee, NONE, arguments, false)
+    }
+
+    fn explode_object_property(
+        &mut self,
+        property: ObjectPropertyKind<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        match property {
+            ObjectPropertyKind::ObjectProperty(mut property) => {
+                if property.computed && property_key_contains_leap(&property.key) {
+                    let key = property.key.take_in(self.ctx.ast).into_expression();
+                    property.key = PropertyKey::from(self.explode_to_temp(key));
+                }
+                if property.kind == PropertyKind::Init && !property.method {
+                    let value = property.value.take_in(self.ctx.ast);
+                    property.value = self.explode_to_temp(value);
+                    property.shorthand = false;
+                }
+                ObjectPropertyKind::ObjectProperty(property)
+            }
+            ObjectPropertyKind::SpreadProperty(spread) => {
+                let spread = spread.unbox();
+                let argument = self.explode_to_temp(spread.argument);
+                self.ctx.ast.object_property_kind_spread_property(spread.span, argument)
+            }
+        }
+    }
+
+    fn explode_assignment_expression(
+        &mut self,
+        assignment: AssignmentExpression<'a>,
+        ignore_result: bool,
+    ) -> Option<Expression<'a>> {
+        let span = assignment.span;
+        if assignment.operator == AssignmentOperator::Assign {
+            // The left side does not need to be read before the right side is evaluated
+            let target = self.explode_target(assignment.left);
+            let right = self.explode_expression(assignment.right);
+            let expr =
+                self.ctx.ast.expression_assignment(span, AssignmentOperator::Assign, target, right);
+            return self.finish(expr, ignore_result);
+        }
+
+        // Compound assignment to a pattern is a syntax error
+        let target = assignment.left.into_simple_assignment_target();
+        let (target, value) = self.explode_simple_target_with_value(target);
+
+        // The left side is read before the right side is evaluated.
+        // `x += yield y` -> `_context.t0 = x; x = _context.t0 += _context.sent`
+        let temp = self.make_temp();
+
+        if let Some(operator) = assignment.operator.to_logical_operator() {
+            // `x ||= yield y` -> `_context.t0 = x; if (_context.t0) goto after; _context.t0 = x = _context.sent;`
+            let value = self.emit_assign_temp(temp, value);
+            let after = self.loc();
+            self.jump_over_logical_right(operator, value, after);
+            let right = self.explode_expression(assignment.right);
+            let assign = self.ctx.ast.expression_assignment(
+                span,
+                AssignmentOperator::Assign,
+                AssignmentTarget::from(target),
+                right,
+            );
+            self.assign_temp(temp, assign);
+            self.mark(after);
+            return (!ignore_result).then(|| self.temp_expression(temp));
+        }
+
+        self.assign_temp(temp, value);
+        let right = self.explode_expression(assignment.right);
+        let temp_target = AssignmentTarget::from(self.context_member(temp.0));
+        let operation =
+            self.ctx.ast.expression_assignment(SPAN, assignment.operator, temp_target, right);
+        let expr = self.ctx.ast.expression_assignment(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::from(target),
+            operation,
+        );
+        self.finish(expr, ignore_result)
+    }
+
+    fn explode_target(&mut self, target: AssignmentTarget<'a>) -> AssignmentTarget<'a> {
+        if !target_contains_leap(&target) {
+            return target;
+        }
+        match target {
+            target @ match_simple_assignment_target!(AssignmentTarget) => AssignmentTarget::from(
+                self.explode_simple_target(target.into_simple_assignment_target()),
+            ),
+            target => {
+                self.error("`yield` in a destructuring assignment", target.span());
+                target
+            }
+        }
+    }
+
+    fn explode_simple_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> SimpleAssignmentTarget<'a> {
+        match target {
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                SimpleAssignmentTarget::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                let property = self.explode_to_temp(member.expression);
+                SimpleAssignmentTarget::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ))
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let object = self.explode_expression(member.object);
+                SimpleAssignmentTarget::from(
+                    self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object,
+                        member.field,
+                        false,
+                    ),
+                )
+            }
+            target => {
+                self.error("`yield` in this assignment target", target.span());
+                target
+            }
+        }
+    }
+
+    /// Explode an assignment target, and create an expression reading its current value.
+    ///
+    /// The object and computed property of a member expression are stored in temps,
+    /// so they are only evaluated once.
+    fn explode_simple_target_with_value(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> (SimpleAssignmentTarget<'a>, Expression<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let value = self.ctx.create_ident_expr(
+                    ident.span,
+                    ident.name,
+                    symbol_id,
+                    ReferenceFlags::Read,
+                );
+                (SimpleAssignmentTarget::AssignmentTargetIdentifier(ident), value)
+            }
+            SimpleAssignmentTarget::StaticMemberExpression(member) => {
+                let member = member.unbox();
+                let (object, object_value) = self.explode_reusable(member.object);
+                let value = Expression::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object_value,
+                    member.property.clone(),
+                    false,
+                ));
+                let target = SimpleAssignmentTarget::from(self.ctx.ast.member_expression_static(
+                    member.span,
+                    object,
+                    member.property,
+                    false,
+                ));
+                (target, value)
+            }
+            SimpleAssignmentTarget::ComputedMemberExpression(member) => {
+                let member = member.unbox();
+                let (object, object_value) = self.explode_reusable(member.object);
+                let (property, property_value) = self.explode_reusable(member.expression);
+                let value = Expression::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object_value,
+                    property_value,
+                    false,
+                ));
+                let target = SimpleAssignmentTarget::from(self.ctx.ast.member_expression_computed(
+                    member.span,
+                    object,
+                    property,
+                    false,
+                ));
+                (target, value)
+            }
+            SimpleAssignmentTarget::PrivateFieldExpression(member) => {
+                let member = member.unbox();
+                let (object, object_value) = self.explode_reusable(member.object);
+                let value =
+                    Expression::from(self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object_value,
+                        member.field.clone(),
+                        false,
+                    ));
+                let target = SimpleAssignmentTarget::from(
+                    self.ctx.ast.member_expression_private_field_expression(
+                        member.span,
+                        object,
+                        member.field,
+                        false,
+                    ),
+                );
+                (target, value)
+            }
+            target => {
+                self.error("`yield` in this assignment target", target.span());
+                let value = self.ctx.ast.void_0(SPAN);
+                (target, value)
+            }
+        }
+    }
+
+    /// Explode an expression which is used twice, returning two copies of it.
+    fn explode_reusable(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        match expr {
+            Expression::Identifier(ident) => {
+                let symbol_id = self.ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+                let copy = self.ctx.create_ident_expr(
+                    ident.span,
+                    ident.name,
+                    symbol_id,
+                    ReferenceFlags::Read,
+                );
+                (Expression::Identifier(ident), copy)
+            }
+            Expression::ThisExpression(this) => {
+                let copy = self.ctx.ast.expression_this(this.span);
+                (Expression::ThisExpression(this), copy)
+            }
+            expr if is_literal(&expr) && !matches!(expr, Expression::TemplateLiteral(_)) => {
+                let copy = expr.clone_in(self.ctx.ast.allocator);
+                (expr, copy)
+            }
+            expr => {
+                let temp = self.make_temp();
+                let expr = self.explode_via_temp(Some(temp), expr, false).unwrap();
+                (expr, self.temp_expression(temp))
+            }
+        }
+    }
+
+    // ----------------------------- Output -----------------------------
+
+    fn loc_expression(&self, loc: Loc) -> Expression<'a> {
+        let value = self.locs[loc.0].expect("location was never marked");
+        #[expect(clippy::cast_precision_loss)]
+        let value = value as f64;
+        self.ctx.ast.expression_numeric_literal(SPAN, value, None, NumberBase::Decimal)
+    }
+
+    /// `_context.next = loc; break;`
+    fn build_jump(&mut self, target: Expression<'a>, stmts: &mut Vec<Statement<'a>>) {
+        let assign = self.context_assign("next", target);
+        stmts.push(self.ctx.ast.statement_expression(SPAN, assign));
+        stmts.push(self.ctx.ast.statement_break(SPAN, None));
+    }
+
+    fn build_item(&mut self, item: Item<'a>, stmts: &mut Vec<Statement<'a>>) {
+        match item {
+            Item::Statement(stmt) => stmts.push(stmt),
+            Item::Jump(JumpTarget::Loc(loc)) => {
+                let target = self.loc_expression(loc);
+                self.build_jump(target, stmts);
+            }
+            Item::Jump(JumpTarget::Cases { discriminant, cases, default }) => {
+                let mut target = self.loc_expression(default);
+                for (test, loc) in cases.into_iter().rev() {
+                    let discriminant = self.temp_expression(discriminant);
+                    let test = self.ctx.ast.expression_binary(
+                        SPAN,
+                        discriminant,
+                        BinaryOperator::StrictEquality,
+                        test,
+                    );
+                    let loc = self.loc_expression(loc);
+                    target = self.ctx.ast.expression_conditional(SPAN, test, loc, target);
+                }
+                self.build_jump(target, stmts);
+            }
+            Item::JumpIf(test, loc) => {
+                let target = self.loc_expression(loc);
+                let mut body = vec![];
+                self.build_jump(target, &mut body);
+                let scope_id =
+                    self.ctx.create_child_scope(self.switch_scope_id, ScopeFlags::empty());
+                let block = self.ctx.ast.statement_block_with_scope_id(
+                    SPAN,
+                    self.ctx.ast.vec_from_iter(body),
+                    scope_id,
+                );
+                stmts.push(self.ctx.ast.statement_if(SPAN, test, block, None));
+            }
+            Item::Yield(loc, argument) => {
+                let target = self.loc_expression(loc);
+                let assign = self.context_assign("next", target);
+                stmts.push(self.ctx.ast.statement_expression(SPAN, assign));
+                stmts.push(self.ctx.ast.statement_return(SPAN, argument));
+            }
+            Item::DelegateYield(argument, temp, loc) => {
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(argument),
+                    Argument::from(self.ctx.ast.expression_string_literal(SPAN, temp.0, None)),
+                    Argument::from(self.loc_expression(loc)),
+                ]);
+                let call = self.context_call("delegateYield", arguments);
+                stmts.push(self.ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Item::Abrupt(kind, loc) => {
+                let arguments = self.ctx.ast.vec_from_array([
+                    Argument::from(self.ctx.ast.expression_string_literal(SPAN, kind, None)),
+                    Argument::from(self.loc_expression(loc)),
+                ]);
+                let call = self.context_call("abrupt", arguments);
+                stmts.push(self.ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Item::Finish(loc) => {
+                let arguments = self.ctx.ast.vec1(Argument::from(self.loc_expression(loc)));
+                let call = self.context_call("finish", arguments);
+                stmts.push(self.ctx.ast.statement_return(SPAN, Some(call)));
+            }
+            Item::Prev(loc) => {
+                let value = self.loc_expression(loc);
+                let assign = self.context_assign("prev", value);
+                stmts.push(self.ctx.ast.statement_expression(SPAN, assign));
+            }
+            Item::Catch(target, loc) => {
+                // `_context["catch"](loc)`
+                let object = self.context.create_read_expression(self.ctx);
+                let property = self.ctx.ast.expression_string_literal(SPAN, "catch", None);
+                let callee = Expression::from(
+                    self.ctx.ast.member_expression_computed(SPAN, object, property, false),
+                );
+                let arguments = self.ctx.ast.vec1(Argument::from(self.loc_expression(loc)));
+                let mut expr = self.ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+                if let Some(target) = target {
+                    expr = self.ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        target,
+                        expr,
+                    );
+                }
+                stmts.push(self.ctx.ast.statement_expression(SPAN, expr));
+            }
+        }
+    }
+
+    /// `[[1, 5], [8,, 12, 15]]`
+    fn build_try_locs(&mut self) -> Option<Expression<'a>> {
+        if self.try_entries.is_empty() {
+            return None;
+        }
+        let try_entries = mem::take(&mut self.try_entries);
+        let entries = self.ctx.ast.vec_from_iter(try_entries.into_iter().map(|entry| {
+            let mut locs = self.ctx.ast.vec_with_capacity(4);
+            locs.push(ArrayExpressionElement::from(self.loc_expression(entry.first)));
+            locs.push(match entry.catch {
+                Some(loc) => ArrayExpressionElement::from(self.loc_expression(loc)),
+                None => self.ctx.ast.array_expression_element_elision(SPAN),
+            });
+            if let Some(finally_loc) = entry.finally {
+                locs.push(ArrayExpressionElement::from(self.loc_expression(finally_loc)));
+                locs.push(ArrayExpressionElement::from(self.loc_expression(entry.after)));
+            }
+            ArrayExpressionElement::from(self.ctx.ast.expression_array(SPAN, locs))
+        }));
+        Some(self.ctx.ast.expression_array(SPAN, entries))
+    }
+
+    /// Build the dispatch loop from the exploded statements.
+    ///
+    /// ```js
+    /// while (1) switch (_context.prev = _context.next) {
+    ///   case 0: ...
+    ///   case 3:
+    ///   case "end":
+    ///     return _context.stop();
+    /// }
+    /// ```
+    pub fn finish_body(mut self) -> Exploded<'a> {
+        let final_loc = self.listing_len;
+        let mut cases = self.ctx.ast.vec();
+        let mut current: Option<(usize, Vec<Statement<'a>>)> = None;
+        let mut index = 0;
+        let mut already_ended = false;
+        for item in mem::take(&mut self.items) {
+            let len = item.len();
+            if self.marked.contains(&index) {
+                if let Some((case_index, consequent)) = current.take() {
+                    cases.push(self.build_case(case_index, consequent));
+                }
+                current = Some((index, vec![]));
+                already_ended = false;
+            }
+            index += len;
+            // Unreachable statements after a completion statement are dropped
+            if already_ended && item.is_generated() {
+                continue;
+            }
+            let (_, consequent) = current.as_mut().unwrap();
+            let start = consequent.len();
+            self.build_item(item, consequent);
+            already_ended |= consequent[start..].iter().any(is_completion_statement);
+        }
+        if let Some((case_index, consequent)) = current.take() {
+            cases.push(self.build_case(case_index, consequent));
+        }
+
+        // So that the runtime can jump to the final location without knowing its value,
+        // `"end"` is provided as a synonym
+        let final_test = self.ctx.ast.expression_numeric_literal(
+            SPAN,
+            #[expect(clippy::cast_precision_loss)]
+            {
+                final_loc as f64
+            },
+            None,
+            NumberBase::Decimal,
+        );
+        cases.push(self.ctx.ast.switch_case(SPAN, Some(final_test), self.ctx.ast.vec()));
+        let stop = self.context_call("stop", self.ctx.ast.vec());
+        cases.push(self.ctx.ast.switch_case(
+            SPAN,
+            Some(self.ctx.ast.expression_string_literal(SPAN, "end", None)),
+            self.ctx.ast.vec1(self.ctx.ast.statement_return(SPAN, Some(stop))),
+        ));
+
+        // Scopes within the exploded statements are now children of the `switch` statement's scope
+        let mut reparenter = ScopeReparenter::new(self.ctx.scoping_mut(), self.switch_scope_id);
+        for case in &cases {
+            reparenter.visit_statements(&case.consequent);
+        }
+
+        let next = self.context_property("next");
+        let discriminant = self.context_assign("prev", next);
+        let switch = self.ctx.ast.statement_switch_with_scope_id(
+            SPAN,
+            discriminant,
+            cases,
+            self.switch_scope_id,
+        );
+        let one = self.ctx.ast.expression_numeric_literal(SPAN, 1.0, None, NumberBase::Decimal);
+        let body = self.ctx.ast.statement_while(SPAN, one, switch);
+
+        let try_locs = self.build_try_locs();
+        Exploded {
+            body,
+            try_locs,
+            vars: self.vars,
+            function_declarations: self.function_declarations,
+        }
+    }
+
+    fn build_case(&self, index: usize, consequent: Vec<Statement<'a>>) -> SwitchCase<'a> {
+        #[expect(clippy::cast_precision_loss)]
+        let test =
+            self.ctx.ast.expression_numeric_literal(SPAN, index as f64, None, NumberBase::Decimal);
+        self.ctx.ast.switch_case(SPAN, Some(test), self.ctx.ast.vec_from_iter(consequent))
+    }
+}
+
+fn is_completion_statement(stmt: &Statement<'_>) -> bool {
+    matches!(
+        stmt,
+        Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+            | Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+    )
+}
+
+/// Literal values which cannot change while the generator is suspended.
+fn is_literal(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        expr => expr.is_literal(),
+    }
+}
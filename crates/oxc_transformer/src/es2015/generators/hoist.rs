@@ -0,0 +1,114 @@
+//! Preparation of a generator body before it is exploded.
+//!
+//! * `arguments` is replaced with a reference to a `_args` binding in the outer function.
+//! * Bindings of block scopes which are removed are renamed where necessary.
+//! * Scopes are re-parented after the body is moved into the inner function.
+//!
+//! Based on [regenerator-transform/src/hoist.js](https://github.com/facebook/regenerator/blob/main/packages/transform/src/hoist.js).
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::VisitMut;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping, SymbolId};
+use oxc_span::{Atom, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+/// Replaces `arguments` in a generator body with a reference to a binding in the outer function,
+/// and records whether the body uses `this` or `super`.
+///
+/// Arrow functions are visited, as they inherit `this` and `arguments`. Other functions are not.
+pub(super) struct ArgumentsReplacer<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    function_scope_id: ScopeId,
+    /// `_args` binding in the outer function, created when `arguments` is first found.
+    pub arguments: Option<BoundIdentifier<'a>>,
+    pub uses_this: bool,
+    /// Span of first `super` found.
+    pub super_span: Option<Span>,
+}
+
+impl<'a, 'c> ArgumentsReplacer<'a, 'c> {
+    pub fn new(function_scope_id: ScopeId, ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { ctx, function_scope_id, arguments: None, uses_this: false, super_span: None }
+    }
+}
+
+impl<'a> VisitMut<'a> for ArgumentsReplacer<'a, '_> {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name != "arguments" {
+            return;
+        }
+        let reference = self.ctx.scoping().get_reference(ident.reference_id());
+        if reference.symbol_id().is_some() {
+            return;
+        }
+        let flags = reference.flags();
+        self.ctx.delete_reference_for_identifier(ident);
+        let binding = self.arguments.get_or_insert_with(|| {
+            self.ctx.generate_uid(
+                "args",
+                self.function_scope_id,
+                oxc_semantic::SymbolFlags::FunctionScopedVariable,
+            )
+        });
+        *ident = binding.create_spanned_reference(ident.span, flags, self.ctx);
+    }
+
+    fn visit_this_expression(&mut self, _expr: &mut ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_super(&mut self, expr: &mut Super) {
+        self.super_span.get_or_insert(expr.span);
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    // Only the heritage and computed keys of a class are evaluated in the enclosing function
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_expression(super_class);
+        }
+        for element in &mut class.body.body {
+            let key = match element {
+                ClassElement::MethodDefinition(method) if method.computed => &mut method.key,
+                ClassElement::PropertyDefinition(prop) if prop.computed => &mut prop.key,
+                ClassElement::AccessorProperty(prop) if prop.computed => &mut prop.key,
+                _ => continue,
+            };
+            if let Some(key) = key.as_expression_mut() {
+                self.visit_expression(key);
+            }
+        }
+    }
+}
+
+/// Renames bindings, and references to them, which were renamed when hoisted out of a block scope.
+pub(super) struct BindingRenamer<'a, 's> {
+    scoping: &'s Scoping,
+    renamed: &'s FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 's> BindingRenamer<'a, 's> {
+    pub fn new(scoping: &'s Scoping, renamed: &'s FxHashMap<SymbolId, Atom<'a>>) -> Self {
+        Self { scoping, renamed }
+    }
+}
+
+impl<'a> VisitMut<'a> for BindingRenamer<'a, '_> {
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        if let Some(&name) = self.renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        let symbol_id = self.scoping.get_reference(ident.reference_id()).symbol_id();
+        if let Some(&name) = symbol_id.and_then(|symbol_id| self.renamed.get(&symbol_id)) {
+            ident.name = name;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Targets of `break` and `continue` statements within an exploded generator body.
+//!
+//! Based on [regenerator-transform/src/leap.js](https://github.com/facebook/regenerator/blob/main/packages/transform/src/leap.js).
+
+use oxc_ast::ast::LabelIdentifier;
+use oxc_span::Atom;
+
+use super::emit::Loc;
+
+pub(super) enum Entry<'a> {
+    Loop { break_loc: Loc, continue_loc: Loc, label: Option<Atom<'a>> },
+    Switch { break_loc: Loc },
+    Labeled { break_loc: Loc, label: Atom<'a> },
+}
+
+#[derive(Default)]
+pub(super) struct LeapManager<'a> {
+    entries: Vec<Entry<'a>>,
+}
+
+impl<'a> LeapManager<'a> {
+    pub fn push(&mut self, entry: Entry<'a>) {
+        self.entries.push(entry);
+    }
+
+    pub fn pop(&mut self) {
+        self.entries.pop();
+    }
+
+    /// `true` if within an exploded loop.
+    pub fn is_in_loop(&self) -> bool {
+        self.entries.iter().any(|entry| matches!(entry, Entry::Loop { .. }))
+    }
+
+    /// Location to jump to for `break` / `break label`.
+    pub fn break_loc(&self, label: Option<&LabelIdentifier<'a>>) -> Option<Loc> {
+        let label = label.map(|label| label.name);
+        self.entries.iter().rev().find_map(|entry| match (entry, label) {
+            (
+                Entry::Loop { break_loc, label: Some(name), .. }
+                | Entry::Labeled { break_loc, label: name },
+                Some(label),
+            ) if *name == label => Some(*break_loc),
+            (Entry::Loop { break_loc, .. } | Entry::Switch { break_loc }, None) => Some(*break_loc),
+            _ => None,
+        })
+    }
+
+    /// Location to jump to for `continue` / `continue label`.
+    pub fn continue_loc(&self, label: Option<&LabelIdentifier<'a>>) -> Option<Loc> {
+        let label = label.map(|label| label.name);
+        self.entries.iter().rev().find_map(|entry| match entry {
+            Entry::Loop { continue_loc, label: name, .. } if label.is_none() || *name == label => {
+                Some(*continue_loc)
+            }
+            _ => None,
+        })
+    }
+}
@@ -0,0 +1,78 @@
+//! Detection of nodes which must be exploded into the generator's state machine.
+//!
+//! Based on [regenerator-transform/src/meta.js](https://github.com/facebook/regenerator/blob/main/packages/transform/src/meta.js).
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_syntax::scope::ScopeFlags;
+
+/// Finds `yield`, `break`, `continue`, `return` and `throw`, which may transfer control
+/// out of the current statement. Nested functions and static blocks are not searched.
+#[derive(Default)]
+struct LeapFinder {
+    found: bool,
+}
+
+pub(super) fn statement_contains_leap(stmt: &Statement<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+pub(super) fn expression_contains_leap(expr: &Expression<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_expression(expr);
+    finder.found
+}
+
+pub(super) fn target_contains_leap(target: &AssignmentTarget<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_assignment_target(target);
+    finder.found
+}
+
+pub(super) fn property_key_contains_leap(key: &PropertyKey<'_>) -> bool {
+    let mut finder = LeapFinder::default();
+    finder.visit_property_key(key);
+    finder.found
+}
+
+impl<'a> Visit<'a> for LeapFinder {
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_break_statement(&mut self, _stmt: &BreakStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_continue_statement(&mut self, _stmt: &ContinueStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_throw_statement(&mut self, _stmt: &ThrowStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.found {
+            walk::walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if !self.found {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+}
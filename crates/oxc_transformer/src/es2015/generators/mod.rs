@@ -0,0 +1,353 @@
+//! ES2015: Generators
+//!
+//! This plugin transforms generator functions into state machines driven by the regenerator runtime.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function* foo(x) {
+//!   try {
+//!     yield x;
+//!   } finally {
+//!     cleanup();
+//!   }
+//! }
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _marked = /*#__PURE__*/ babelHelpers.regeneratorRuntime().mark(foo);
+//! function foo(x) {
+//!   return babelHelpers.regeneratorRuntime().wrap(function foo$(_context) {
+//!     while (1) switch (_context.prev = _context.next) {
+//!       case 0:
+//!         _context.prev = 0;
+//!         _context.next = 3;
+//!         return x;
+//!       case 3:
+//!         _context.prev = 3;
+//!         cleanup();
+//!         return _context.finish(3);
+//!       case 6:
+//!       case "end":
+//!         return _context.stop();
+//!     }
+//!   }, _marked, null, [[0,, 3, 6]]);
+//! }
+//! ```
+//!
+//! The body of the generator is moved into an inner function, which is called by the runtime each
+//! time the generator is resumed. `_context.next` records where to continue from.
+//! Statements and expressions containing `yield` (or jumps which may cross a `yield`) are broken up
+//! into `case`s of the `switch`, and values which must survive a `yield` are stored on the context
+//! object (`_context.t0`). All other code is left as is.
+//!
+//! Bindings declared in the generator body are hoisted to `var`s in the outer function,
+//! so they persist between calls to the inner function. `arguments` is replaced with an `_args`
+//! binding in the outer function.
+//!
+//! With `HelperLoaderMode::Runtime`, the runtime is imported from `@babel/runtime/regenerator`.
+//!
+//! Not supported: `yield` inside destructuring assignment targets, optional chains,
+//! tagged templates, classes, `case` tests and `with` statements; `super` in generators;
+//! closing iterators when a `for of` loop is exited early.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [regenerator-transform](https://github.com/facebook/regenerator/tree/main/packages/transform),
+//! as used by [@babel/plugin-transform-regenerator](https://babel.dev/docs/babel-plugin-transform-regenerator).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-regenerator>
+//! * Regenerator runtime: <https://github.com/facebook/regenerator/tree/main/packages/runtime>
+//! * Generator function definitions: <https://tc39.es/ecma262/#sec-generator-function-definitions>
+
+use oxc_allocator::{Box as ArenaBox, TakeIn};
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::VisitMut;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::identifier::is_identifier_name;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    es2017::AsyncGeneratorExecutor,
+    state::TransformState,
+    utils::var_hoister::VarHoister,
+};
+
+mod emit;
+mod hoist;
+mod leap;
+mod meta;
+
+use emit::{Emitter, Exploded};
+use hoist::ArgumentsReplacer;
+
+pub struct Generators<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> Generators<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Generators<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::FunctionExpression(func) = expr else { return };
+        // Methods have already been transformed in `exit_function`
+        if !Self::is_transformable(func) {
+            return;
+        }
+        let Expression::FunctionExpression(func) = expr.take_in(ctx.ast) else { unreachable!() };
+        *expr = Self::transform_function_expression(func, self.ctx, ctx);
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !Self::is_transformable(func) {
+            return;
+        }
+        match ctx.parent() {
+            // `class A { *foo() {} }`, `({ *foo() {} })`
+            ancestor if AsyncGeneratorExecutor::is_class_method_like_ancestor(ancestor) => {
+                let name = match ancestor {
+                    Ancestor::MethodDefinitionValue(method) => method.key().static_name(),
+                    Ancestor::ObjectPropertyValue(property) => property.key().static_name(),
+                    _ => unreachable!(),
+                };
+                let name = match name {
+                    Some(name) if is_identifier_name(&name) => ctx.ast.atom(&name),
+                    _ => Atom::from("_callee"),
+                };
+                Self::transform_function(func, name, None, self.ctx, ctx);
+            }
+            // `function* foo() {}`
+            _ if func.is_declaration() => {
+                let id = if let Some(id) = &func.id {
+                    BoundIdentifier::from_binding_ident(id)
+                } else {
+                    // `export default function* () {}`
+                    let binding =
+                        ctx.generate_uid_in_current_scope("callee", SymbolFlags::Function);
+                    func.id = Some(binding.create_binding_identifier(ctx));
+                    binding
+                };
+                // `var _marked = regeneratorRuntime().mark(foo);`
+                let mark = self.mark(id.create_read_expression(ctx), ctx);
+                let marked =
+                    self.ctx.var_declarations.create_uid_var_with_init("marked", mark, ctx);
+                let outer_fn = marked.create_read_expression(ctx);
+                Self::transform_function(func, id.name, Some(outer_fn), self.ctx, ctx);
+            }
+            // Function expressions are transformed in `exit_expression`
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Generators<'a, '_> {
+    fn is_transformable(func: &Function<'a>) -> bool {
+        func.generator && !func.r#async && func.body.is_some()
+    }
+
+    /// `regeneratorRuntime().mark(func)`
+    fn mark(&self, func: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        Self::create_mark_call(func, self.ctx, ctx)
+    }
+
+    fn create_mark_call(
+        func: Expression<'a>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let runtime = transform_ctx.regenerator_runtime(ctx);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            runtime,
+            ctx.ast.identifier_name(SPAN, "mark"),
+            false,
+        ));
+        ctx.ast.expression_call_with_pure(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(func)),
+            false,
+            true,
+        )
+    }
+
+    /// Transform a generator function expression.
+    ///
+    /// `function* () {}` -> `regeneratorRuntime().mark(function _callee() { ... })`
+    ///
+    /// Also used by the `async-to-generator` and `async-generator-functions` plugins,
+    /// to transform the generator functions they create.
+    pub(crate) fn transform_function_expression(
+        mut func: ArenaBox<'a, Function<'a>>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let id = if let Some(id) = &func.id {
+            BoundIdentifier::from_binding_ident(id)
+        } else {
+            let binding = ctx.generate_uid("callee", func.scope_id(), SymbolFlags::Function);
+            func.id = Some(binding.create_binding_identifier(ctx));
+            binding
+        };
+        let outer_fn = id.create_read_expression(ctx);
+        Self::transform_function(&mut func, id.name, Some(outer_fn), transform_ctx, ctx);
+        Self::create_mark_call(Expression::FunctionExpression(func), transform_ctx, ctx)
+    }
+
+    /// Move the body of a generator function into an inner function, which is wrapped by the runtime.
+    ///
+    /// ```js
+    /// function foo(a) {
+    ///   var x, _args = arguments;
+    ///   return regeneratorRuntime().wrap(function foo$(_context) {
+    ///     while (1) switch (_context.prev = _context.next) { ... }
+    ///   }, outerFn, this, tryLocs);
+    /// }
+    /// ```
+    fn transform_function(
+        func: &mut Function<'a>,
+        name: Atom<'a>,
+        outer_fn: Option<Expression<'a>>,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        func.generator = false;
+        let scope_id = func.scope_id();
+        let body = func.body.as_mut().unwrap();
+        let mut statements = body.statements.take_in(ctx.ast);
+
+        let mut hoister = VarHoister::new(ctx);
+        hoister.visit_statements(&mut statements);
+        let vars = hoister.vars;
+
+        let mut replacer = ArgumentsReplacer::new(scope_id, ctx);
+        replacer.visit_statements(&mut statements);
+        let ArgumentsReplacer { arguments, uses_this, super_span, .. } = replacer;
+        if let Some(span) = super_span {
+            transform_ctx.error(
+                OxcDiagnostic::error("Generators transform does not support `super` in generators")
+                    .with_label(span),
+            );
+        }
+
+        let inner_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+        let switch_scope_id = ctx.create_child_scope(inner_scope_id, ScopeFlags::empty());
+        let context =
+            ctx.generate_uid("context", inner_scope_id, SymbolFlags::FunctionScopedVariable);
+        let inner_name = ctx.ast.atom_from_strs_array([name.as_str(), "$"]);
+        let inner_id = ctx.generate_binding(inner_name, inner_scope_id, SymbolFlags::Function);
+
+        let mut emitter =
+            Emitter::new(context.clone(), scope_id, switch_scope_id, vars, transform_ctx, ctx);
+        emitter.explode_statements(statements, true);
+        let Exploded { body: loop_stmt, try_locs, vars, function_declarations } =
+            emitter.finish_body();
+
+        // `function foo$(_context) { while (1) switch ... }`
+        let params = ctx.ast.alloc_formal_parameters(
+            SPAN,
+            FormalParameterKind::FormalParameter,
+            ctx.ast.vec1(ctx.ast.plain_formal_parameter(SPAN, context.create_binding_pattern(ctx))),
+            NONE,
+        );
+        let inner_body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), ctx.ast.vec1(loop_stmt));
+        let inner = ctx.ast.expression_function_with_scope_id_and_pure(
+            SPAN,
+            FunctionType::FunctionExpression,
+            Some(inner_id.create_binding_identifier(ctx)),
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(inner_body),
+            inner_scope_id,
+            false,
+        );
+
+        // `regeneratorRuntime().wrap(foo$, outerFn, this, tryLocs)`
+        let mut arguments_list = ctx.ast.vec_with_capacity(4);
+        arguments_list.push(Argument::from(inner));
+        arguments_list.push(Argument::from(
+            outer_fn.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN)),
+        ));
+        if uses_this {
+            arguments_list.push(Argument::from(ctx.ast.expression_this(SPAN)));
+        } else if try_locs.is_some() {
+            arguments_list.push(Argument::from(ctx.ast.expression_null_literal(SPAN)));
+        }
+        if let Some(try_locs) = try_locs {
+            arguments_list.push(Argument::from(try_locs));
+        }
+        let runtime = transform_ctx.regenerator_runtime(ctx);
+        let callee = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            runtime,
+            ctx.ast.identifier_name(SPAN, "wrap"),
+            false,
+        ));
+        let wrap = ctx.ast.expression_call(SPAN, callee, NONE, arguments_list, false);
+
+        // `var x, _args = arguments;`
+        let mut declarations = ctx.ast.vec_with_capacity(vars.len() + 1);
+        for (span, binding) in vars {
+            let kind = ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                span,
+                binding.name,
+                binding.symbol_id,
+            );
+            let pattern = ctx.ast.binding_pattern(kind, NONE, false);
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                pattern,
+                None,
+                false,
+            ));
+        }
+        if let Some(arguments) = arguments {
+            let init = ctx.create_unbound_ident_expr(
+                SPAN,
+                Atom::from("arguments"),
+                oxc_semantic::ReferenceFlags::Read,
+            );
+            declarations.push(ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                arguments.create_binding_pattern(ctx),
+                Some(init),
+                false,
+            ));
+        }
+
+        let body = func.body.as_mut().unwrap();
+        let mut statements = ctx.ast.vec_with_capacity(function_declarations.len() + 2);
+        if !declarations.is_empty() {
+            statements.push(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                declarations,
+                false,
+            )));
+        }
+        statements.extend(function_declarations);
+        statements.push(ctx.ast.statement_return(SPAN, Some(wrap)));
+        body.statements = statements;
+    }
+}
@@ -1,29 +1,304 @@
+use oxc_ast::ast::*;
 use oxc_traverse::Traverse;
 
-use crate::{context::TransformCtx, state::TransformState};
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
 
 mod arrow_functions;
+mod block_scoping;
+mod classes;
+mod computed_properties;
+mod destructuring;
+mod for_of;
+mod generators;
 mod options;
+mod parameters;
+mod shorthand_properties;
+mod spread;
+mod template_literals;
 
 pub use arrow_functions::{ArrowFunctions, ArrowFunctionsOptions};
+pub use block_scoping::{BlockScoping, BlockScopingOptions};
+pub use classes::{Classes, ClassesOptions};
+pub use computed_properties::{ComputedProperties, ComputedPropertiesOptions};
+pub use destructuring::{Destructuring, DestructuringOptions};
+pub use for_of::{ForOf, ForOfOptions};
+pub use generators::Generators;
 pub use options::ES2015Options;
+pub use parameters::{Parameters, ParametersOptions};
+pub use shorthand_properties::ShorthandProperties;
+pub use spread::{Spread, SpreadOptions};
+pub use template_literals::{TemplateLiterals, TemplateLiteralsOptions};
 
 pub struct ES2015<'a, 'ctx> {
-    #[expect(unused)]
     options: ES2015Options,
 
     // Plugins
     #[expect(unused)]
     arrow_functions: ArrowFunctions<'a, 'ctx>,
+    block_scoping: BlockScoping<'a, 'ctx>,
+    classes: Classes<'a, 'ctx>,
+    computed_properties: ComputedProperties<'a, 'ctx>,
+    destructuring: Destructuring<'a, 'ctx>,
+    for_of: ForOf<'a, 'ctx>,
+    generators: Generators<'a, 'ctx>,
+    parameters: Parameters,
+    shorthand_properties: ShorthandProperties<'a, 'ctx>,
+    spread: Spread<'a, 'ctx>,
+    template_literals: TemplateLiterals<'a, 'ctx>,
 }
 
 impl<'a, 'ctx> ES2015<'a, 'ctx> {
     pub fn new(options: ES2015Options, ctx: &'ctx TransformCtx<'a>) -> Self {
         Self {
             arrow_functions: ArrowFunctions::new(options.arrow_function.unwrap_or_default(), ctx),
+            block_scoping: BlockScoping::new(options.block_scoping.unwrap_or_default(), ctx),
+            classes: Classes::new(options.classes.unwrap_or_default(), ctx),
+            computed_properties: ComputedProperties::new(
+                options.computed_properties.unwrap_or_default(),
+                ctx,
+            ),
+            destructuring: Destructuring::new(options.destructuring.unwrap_or_default(), ctx),
+            for_of: ForOf::new(options.for_of.unwrap_or_default(), ctx),
+            generators: Generators::new(ctx),
+            parameters: Parameters::new(options.parameters.unwrap_or_default(), ctx),
+            shorthand_properties: ShorthandProperties::new(ctx),
+            spread: Spread::new(options.spread.unwrap_or_default(), ctx),
+            template_literals: TemplateLiterals::new(
+                options.template_literals.unwrap_or_default(),
+                ctx,
+            ),
             options,
         }
     }
 }
 
-impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {}
+impl<'a> Traverse<'a, TransformState<'a>> for ES2015<'a, '_> {
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.template_literals.is_some() {
+            self.template_literals.exit_program(program, ctx);
+        }
+    }
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes.is_some() {
+            self.classes.exit_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_statement(stmt, ctx);
+        }
+        if self.options.for_of.is_some() {
+            self.for_of.exit_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.classes.is_some() {
+            self.classes.exit_expression(expr, ctx);
+        }
+        if self.options.generators {
+            self.generators.exit_expression(expr, ctx);
+        }
+        if self.options.template_literals.is_some() {
+            self.template_literals.exit_expression(expr, ctx);
+        }
+        if self.options.spread.is_some() {
+            self.spread.exit_expression(expr, ctx);
+        }
+        if self.options.shorthand_properties {
+            self.shorthand_properties.exit_expression(expr, ctx);
+        }
+        if self.options.computed_properties.is_some() {
+            self.computed_properties.exit_expression(expr, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_expression(expr, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_binding_identifier(ident, ctx);
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_identifier_reference(ident, ctx);
+        }
+    }
+
+    fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.parameters.is_some() {
+            self.parameters.transform_function(func, ctx);
+        } else if self.options.destructuring.is_some() {
+            let scope_id = func.scope_id();
+            if let Some(body) = func.body.as_mut() {
+                Destructuring::transform_params(
+                    &mut func.params,
+                    &mut body.statements,
+                    scope_id,
+                    ctx,
+                );
+            }
+        }
+    }
+
+    fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.generators {
+            self.generators.exit_function(func, ctx);
+        }
+    }
+
+    fn enter_arrow_function_expression(
+        &mut self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.parameters.is_some() {
+            // Async arrow functions are not transformed into function expressions,
+            // so they have no `arguments` object of their own.
+            let use_arguments = self.options.arrow_function.is_some() && !arrow.r#async;
+            self.parameters.transform_arrow(arrow, use_arguments, ctx);
+        } else if self.options.destructuring.is_some() {
+            let scope_id = arrow.scope_id();
+            Destructuring::transform_params(
+                &mut arrow.params,
+                &mut arrow.body.statements,
+                scope_id,
+                ctx,
+            );
+        }
+    }
+
+    fn enter_variable_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_variable_declaration(decl, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_variable_declaration(decl, ctx);
+        }
+    }
+
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_block_statement(block, ctx);
+        }
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_switch_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_do_while_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_in_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_in_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_for_of_statement(stmt, ctx);
+        }
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.enter_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.block_scoping.is_some() {
+            self.block_scoping.exit_for_of_statement(stmt, ctx);
+        }
+    }
+
+    fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.options.destructuring.is_some() {
+            self.destructuring.enter_catch_clause(clause, ctx);
+        }
+    }
+}
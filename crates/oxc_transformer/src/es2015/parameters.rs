@@ -0,0 +1,483 @@
+//! ES2015: Parameters
+//!
+//! This plugin transforms default parameters, rest parameters and destructured parameters.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! function foo(a, b = 1, { c }, ...rest) {}
+//! ```
+//!
+//! Output:
+//! ```js
+//! function foo(a) {
+//!   var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+//!   var { c } = arguments.length > 2 ? arguments[2] : void 0;
+//!   for (var _len = arguments.length, rest = new Array(_len > 3 ? _len - 3 : 0), _key = 3; _key < _len; _key++) {
+//!     rest[_key - 3] = arguments[_key];
+//!   }
+//! }
+//! ```
+//!
+//! Parameters from the first one with a default value onwards are removed, so that the function's
+//! `length` is preserved. With the `ignoreFunctionLength` assumption (or `loose` option), they are
+//! kept instead and their defaults are assigned in the function body:
+//!
+//! ```js
+//! function foo(a, b) {
+//!   if (b === void 0) { b = 1; }
+//! }
+//! ```
+//!
+//! The same applies to setters, which must have exactly one parameter, and to arrow functions which
+//! are not transformed into function expressions, as they have no `arguments` object.
+//!
+//! Destructured parameters are replaced by temporary variables, and the patterns are declared at
+//! the start of the function body, to be lowered by the [destructuring](super::destructuring) plugin.
+//!
+//! ## Missing features
+//!
+//! * Rest parameters of arrow functions are only transformed if the arrow functions plugin is enabled.
+//! * Default values referencing a variable of the same name declared in the function body are not renamed.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-parameters](https://babel.dev/docs/babel-plugin-transform-parameters).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-parameters>
+//! * Function definitions: <https://tc39.es/ecma262/#sec-function-definitions>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::SPAN;
+use oxc_syntax::number::NumberBase;
+use oxc_traverse::{Ancestor, BoundIdentifier};
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ParametersOptions {
+    /// Enables the `ignoreFunctionLength` assumption.
+    pub loose: bool,
+}
+
+pub struct Parameters {
+    ignore_function_length: bool,
+}
+
+impl Parameters {
+    pub fn new(options: ParametersOptions, ctx: &TransformCtx) -> Self {
+        Self { ignore_function_length: options.loose || ctx.assumptions.ignore_function_length }
+    }
+}
+
+impl<'a> Parameters {
+    /// `function foo(a = 1) {}` -> `function foo() { var a = ... }`
+    pub fn transform_function(&self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
+        let scope_id = func.scope_id();
+        let Some(body) = func.body.as_mut() else { return };
+        let is_setter = match ctx.parent() {
+            Ancestor::MethodDefinitionValue(method) => *method.kind() == MethodDefinitionKind::Set,
+            Ancestor::ObjectPropertyValue(property) => *property.kind() == PropertyKind::Set,
+            _ => false,
+        };
+        let keep_params = self.ignore_function_length || is_setter;
+        Self::transform_params(
+            &mut func.params,
+            &mut body.statements,
+            scope_id,
+            true,
+            keep_params,
+            ctx,
+        );
+    }
+
+    /// `(a = 1) => {}` -> `(a) => { if (a === void 0) { a = 1; } }`
+    ///
+    /// `arguments` can only be used if the arrow function is going to be transformed into a function expression.
+    pub fn transform_arrow(
+        &self,
+        arrow: &mut ArrowFunctionExpression<'a>,
+        use_arguments: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let keep_params = self.ignore_function_length || !use_arguments;
+        let scope_id = arrow.scope_id();
+        Self::transform_params(
+            &mut arrow.params,
+            &mut arrow.body.statements,
+            scope_id,
+            use_arguments,
+            keep_params,
+            ctx,
+        );
+    }
+
+    fn transform_params(
+        params: &mut FormalParameters<'a>,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        use_arguments: bool,
+        keep_params: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if params.rest.is_none()
+            && params.items.iter().all(|param| param.pattern.kind.is_binding_identifier())
+        {
+            return;
+        }
+
+        // Parameters from the first one with a default value onwards are read from `arguments`.
+        let first_removed = if keep_params {
+            params.items.len()
+        } else {
+            params
+                .items
+                .iter()
+                .position(|param| param.pattern.kind.is_assignment_pattern())
+                .unwrap_or(params.items.len())
+        };
+
+        let mut new_statements = ctx.ast.vec();
+        for (index, param) in params.items.iter_mut().enumerate() {
+            if index >= first_removed {
+                let pattern = param.pattern.take_in(ctx.ast);
+                // `var a = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1`
+                // `var a = arguments.length > 1 ? arguments[1] : void 0`
+                let (pattern, init) = match pattern.kind {
+                    BindingPatternKind::AssignmentPattern(assign_pat) => {
+                        let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                        (left, Self::create_argument_with_default(index, right, scope_id, ctx))
+                    }
+                    kind => {
+                        let pattern = ctx.ast.binding_pattern(kind, NONE, false);
+                        (pattern, Self::create_argument(index, scope_id, ctx))
+                    }
+                };
+                new_statements.push(Self::create_var_declaration(pattern, init, ctx));
+                continue;
+            }
+
+            match &mut param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(_) => {}
+                // `function foo(a = 1) {}` -> `function foo(a) { if (a === void 0) { a = 1; } }`
+                BindingPatternKind::AssignmentPattern(assign_pat)
+                    if assign_pat.left.kind.is_binding_identifier() =>
+                {
+                    let BindingPatternKind::AssignmentPattern(assign_pat) =
+                        param.pattern.take_in(ctx.ast).kind
+                    else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                    let binding =
+                        BoundIdentifier::from_binding_ident(left.get_binding_identifier().unwrap());
+                    let test = Self::create_is_undefined(&binding, ctx);
+                    let assign = ctx.ast.expression_assignment(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        binding.create_write_target(ctx),
+                        right,
+                    );
+                    let consequent = Self::create_block(
+                        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assign)),
+                        scope_id,
+                        ctx,
+                    );
+                    new_statements.push(ctx.ast.statement_if(SPAN, test, consequent, None));
+                    param.pattern = left;
+                }
+                // `function foo({ a } = {}) {}` -> `function foo(_ref) { var { a } = _ref === void 0 ? {} : _ref; }`
+                BindingPatternKind::AssignmentPattern(_) => {
+                    let binding = Self::create_param_binding(scope_id, ctx);
+                    let pattern =
+                        std::mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+                    let BindingPatternKind::AssignmentPattern(assign_pat) = pattern.kind else {
+                        unreachable!()
+                    };
+                    let AssignmentPattern { left, right, .. } = assign_pat.unbox();
+                    let test = Self::create_is_undefined(&binding, ctx);
+                    let alternate = binding.create_read_expression(ctx);
+                    let init = ctx.ast.expression_conditional(SPAN, test, right, alternate);
+                    new_statements.push(Self::create_var_declaration(left, init, ctx));
+                }
+                // `function foo({ a }) {}` -> `function foo(_ref) { var { a } = _ref; }`
+                BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => {
+                    let binding = Self::create_param_binding(scope_id, ctx);
+                    let pattern =
+                        std::mem::replace(&mut param.pattern, binding.create_binding_pattern(ctx));
+                    let init = binding.create_read_expression(ctx);
+                    new_statements.push(Self::create_var_declaration(pattern, init, ctx));
+                }
+            }
+        }
+        let param_count = params.items.len();
+        params.items.truncate(first_removed);
+
+        if let Some(mut rest) = params.rest.take() {
+            if use_arguments {
+                // `for (var _len = arguments.length, rest = new Array(...), _key = 1; ...) {}`
+                let pattern = rest.unbox().argument;
+                Self::create_rest_loop(pattern, param_count, &mut new_statements, scope_id, ctx);
+            } else {
+                if !rest.argument.kind.is_binding_identifier() {
+                    // `(...[a]) => {}` -> `(..._ref) => { var [a] = _ref; }`
+                    let binding = Self::create_param_binding(scope_id, ctx);
+                    let pattern =
+                        std::mem::replace(&mut rest.argument, binding.create_binding_pattern(ctx));
+                    let init = binding.create_read_expression(ctx);
+                    new_statements.push(Self::create_var_declaration(pattern, init, ctx));
+                }
+                params.rest = Some(rest);
+            }
+        }
+
+        statements.splice(0..0, new_statements);
+    }
+
+    fn create_param_binding(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        ctx.generate_uid("ref", scope_id, SymbolFlags::FunctionScopedVariable)
+    }
+
+    /// `var pattern = init;`
+    fn create_var_declaration(
+        pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+        let decl = ctx.ast.alloc_variable_declaration(SPAN, kind, ctx.ast.vec1(declarator), false);
+        Statement::VariableDeclaration(decl)
+    }
+
+    fn create_block(
+        statements: ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        ctx.ast.statement_block_with_scope_id(SPAN, statements, scope_id)
+    }
+
+    /// `binding === void 0`
+    fn create_is_undefined(
+        binding: &BoundIdentifier<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        ctx.ast.expression_binary(
+            SPAN,
+            binding.create_read_expression(ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.void_0(SPAN),
+        )
+    }
+
+    /// `arguments.length > index ? arguments[index] : void 0`
+    fn create_argument(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let test = Self::create_has_argument(index, scope_id, ctx);
+        let consequent = Self::create_argument_at(index, scope_id, ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, ctx.ast.void_0(SPAN))
+    }
+
+    /// `arguments.length > index && arguments[index] !== void 0 ? arguments[index] : default`
+    fn create_argument_with_default(
+        index: usize,
+        default: Expression<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let has_argument = Self::create_has_argument(index, scope_id, ctx);
+        let is_defined = ctx.ast.expression_binary(
+            SPAN,
+            Self::create_argument_at(index, scope_id, ctx),
+            BinaryOperator::StrictInequality,
+            ctx.ast.void_0(SPAN),
+        );
+        let test = ctx.ast.expression_logical(SPAN, has_argument, LogicalOperator::And, is_defined);
+        let consequent = Self::create_argument_at(index, scope_id, ctx);
+        ctx.ast.expression_conditional(SPAN, test, consequent, default)
+    }
+
+    /// `arguments.length > index`
+    fn create_has_argument(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let length = Self::create_arguments_length(scope_id, ctx);
+        let index = create_number(index, ctx);
+        ctx.ast.expression_binary(SPAN, length, BinaryOperator::GreaterThan, index)
+    }
+
+    /// `arguments[index]`
+    fn create_argument_at(
+        index: usize,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = Self::create_arguments_ident(scope_id, ctx);
+        let index = create_number(index, ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, index, false))
+    }
+
+    /// `arguments.length`
+    fn create_arguments_length(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let object = Self::create_arguments_ident(scope_id, ctx);
+        let property = ctx.ast.identifier_name(SPAN, "length");
+        Expression::from(ctx.ast.member_expression_static(SPAN, object, property, false))
+    }
+
+    /// `arguments` reference.
+    fn create_arguments_ident(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let symbol_id = ctx.scoping().find_binding(scope_id, "arguments");
+        ctx.create_ident_expr(SPAN, Atom::from("arguments"), symbol_id, ReferenceFlags::Read)
+    }
+
+    /// ```js
+    /// for (var _len = arguments.length, rest = new Array(_len > start ? _len - start : 0), _key = start; _key < _len; _key++) {
+    ///   rest[_key - start] = arguments[_key];
+    /// }
+    /// ```
+    ///
+    /// A destructured rest element is stored in a temporary variable, and declared after the loop.
+    fn create_rest_loop(
+        pattern: BindingPattern<'a>,
+        start: usize,
+        statements: &mut ArenaVec<'a, Statement<'a>>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let (rest, rest_pattern, pattern) =
+            if let BindingPatternKind::BindingIdentifier(ident) = &pattern.kind {
+                (BoundIdentifier::from_binding_ident(ident), pattern, None)
+            } else {
+                let binding = Self::create_param_binding(scope_id, ctx);
+                let rest_pattern = binding.create_binding_pattern(ctx);
+                (binding, rest_pattern, Some(pattern))
+            };
+        let flags = SymbolFlags::FunctionScopedVariable;
+        let len = ctx.generate_uid("len", scope_id, flags);
+        let key = ctx.generate_uid("key", scope_id, flags);
+
+        // `_len > start ? _len - start : 0`
+        let size = if start == 0 {
+            len.create_read_expression(ctx)
+        } else {
+            let test = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::GreaterThan,
+                create_number(start, ctx),
+            );
+            let consequent = ctx.ast.expression_binary(
+                SPAN,
+                len.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            );
+            ctx.ast.expression_conditional(SPAN, test, consequent, create_number(0, ctx))
+        };
+        let array = ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+        let array = ctx.ast.expression_new(SPAN, array, NONE, ctx.ast.vec1(Argument::from(size)));
+
+        let kind = VariableDeclarationKind::Var;
+        let declarators = ctx.ast.vec_from_array([
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                len.create_binding_pattern(ctx),
+                Some(Self::create_arguments_length(scope_id, ctx)),
+                false,
+            ),
+            ctx.ast.variable_declarator(SPAN, kind, rest_pattern, Some(array), false),
+            ctx.ast.variable_declarator(
+                SPAN,
+                kind,
+                key.create_binding_pattern(ctx),
+                Some(create_number(start, ctx)),
+                false,
+            ),
+        ]);
+        let init = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
+        let init = ForStatementInit::VariableDeclaration(init);
+
+        // `_key < _len`
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            key.create_read_expression(ctx),
+            BinaryOperator::LessThan,
+            len.create_read_expression(ctx),
+        );
+        // `_key++`
+        let update = ctx.ast.expression_update(
+            SPAN,
+            UpdateOperator::Increment,
+            false,
+            key.create_read_write_simple_target(ctx),
+        );
+
+        // `rest[_key - start] = arguments[_key]`
+        let index = if start == 0 {
+            key.create_read_expression(ctx)
+        } else {
+            ctx.ast.expression_binary(
+                SPAN,
+                key.create_read_expression(ctx),
+                BinaryOperator::Subtraction,
+                create_number(start, ctx),
+            )
+        };
+        let target = AssignmentTarget::from(ctx.ast.member_expression_computed(
+            SPAN,
+            rest.create_read_expression(ctx),
+            index,
+            false,
+        ));
+        let value = Expression::from(ctx.ast.member_expression_computed(
+            SPAN,
+            Self::create_arguments_ident(scope_id, ctx),
+            key.create_read_expression(ctx),
+            false,
+        ));
+        let assign = ctx.ast.expression_assignment(SPAN, AssignmentOperator::Assign, target, value);
+
+        let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+        let body = Self::create_block(
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assign)),
+            for_scope_id,
+            ctx,
+        );
+        statements.push(ctx.ast.statement_for_with_scope_id(
+            SPAN,
+            Some(init),
+            Some(test),
+            Some(update),
+            body,
+            for_scope_id,
+        ));
+
+        if let Some(pattern) = pattern {
+            let init = rest.create_read_expression(ctx);
+            statements.push(Self::create_var_declaration(pattern, init, ctx));
+        }
+    }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn create_number<'a>(value: usize, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_numeric_literal(SPAN, value as f64, None, NumberBase::Decimal)
+}
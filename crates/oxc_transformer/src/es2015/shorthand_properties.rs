@@ -0,0 +1,117 @@
+//! ES2015: Shorthand Properties
+//!
+//! This plugin transforms shorthand properties and methods of object literals.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! var obj = { a, b() {}, *c() {}, __proto__ };
+//! ```
+//!
+//! Output:
+//! ```js
+//! var obj = { a: a, b: function () {}, c: function* () {}, ["__proto__"]: __proto__ };
+//! ```
+//!
+//! A `__proto__` key is made computed, as `__proto__: value` would set the object's prototype,
+//! unlike the shorthand property or method.
+//!
+//! ## Missing features
+//!
+//! * Methods which use `super` are not transformed, as `super` is not allowed in a function expression.
+//!   An error is reported for these.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-shorthand-properties](https://babel.dev/docs/babel-plugin-transform-shorthand-properties).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-shorthand-properties>
+//! * Object initializer: <https://tc39.es/ecma262/#sec-object-initializer>
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{SPAN, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::context::{TransformCtx, TraverseCtx};
+
+pub struct ShorthandProperties<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> ShorthandProperties<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> ShorthandProperties<'a, '_> {
+    pub fn exit_expression(&self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+        let Expression::ObjectExpression(object) = expr else { return };
+        for property in &mut object.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+            if property.shorthand {
+                property.shorthand = false;
+            } else if property.method {
+                let Expression::FunctionExpression(func) = &property.value else { continue };
+                if let Some(span) = SuperFinder::find(func) {
+                    self.ctx.error(
+                        OxcDiagnostic::error(
+                            "`super` in object literal methods is not supported yet.",
+                        )
+                        .with_label(span),
+                    );
+                    continue;
+                }
+                property.method = false;
+            } else {
+                continue;
+            }
+
+            // `{ __proto__ }` -> `{ ["__proto__"]: __proto__ }`
+            if !property.computed && property.key.is_specific_static_name("__proto__") {
+                property.key =
+                    PropertyKey::from(ctx.ast.expression_string_literal(SPAN, "__proto__", None));
+                property.computed = true;
+            }
+        }
+    }
+}
+
+/// Visitor to find `super` in a method.
+struct SuperFinder {
+    span: Option<Span>,
+}
+
+impl SuperFinder {
+    fn find(func: &Function<'_>) -> Option<Span> {
+        let mut finder = SuperFinder { span: None };
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.span
+    }
+}
+
+impl<'a> Visit<'a> for SuperFinder {
+    fn visit_super(&mut self, expr: &Super) {
+        self.span.get_or_insert(expr.span);
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.span.is_none() {
+            walk::walk_expression(self, expr);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class_body(&mut self, _body: &ClassBody<'a>) {}
+}
@@ -0,0 +1,283 @@
+//! ES2015: Spread
+//!
+//! This plugin transforms spread elements in array literals, function calls and `new` expressions.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! [a, ...b];
+//! foo(...b);
+//! obj.foo(a, ...b);
+//! new Foo(...b);
+//! ```
+//!
+//! Output:
+//! ```js
+//! [a].concat(babelHelpers.toConsumableArray(b));
+//! foo.apply(void 0, babelHelpers.toConsumableArray(b));
+//! obj.foo.apply(obj, [a].concat(babelHelpers.toConsumableArray(b)));
+//! babelHelpers.construct(Foo, babelHelpers.toConsumableArray(b));
+//! ```
+//!
+//! If the object of a method call may have side effects, it is stored in a temporary variable
+//! so that it is only evaluated once: `(_foo$bar = foo.bar).baz.apply(_foo$bar, ...)`.
+//!
+//! With the `iterableIsArray` assumption (or `loose` option), spread values are assumed to be arrays,
+//! and are passed to `concat` or `apply` as is.
+//!
+//! ## Missing features
+//!
+//! * `super(...args)` is only transformed by the classes plugin, which leaves spread elements
+//!   in the array of arguments it creates.
+//! * Spread elements in optional calls `foo?.(...args)` are not transformed.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-spread](https://babel.dev/docs/babel-plugin-transform-spread).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-spread>
+//! * Spread syntax: <https://tc39.es/ecma262/#sec-array-initializer>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::ReferenceFlags;
+use oxc_span::{Atom, SPAN};
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::{create_call_call, create_member_callee, create_property_access},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SpreadOptions {
+    /// Enables the `iterableIsArray` assumption.
+    pub loose: bool,
+}
+
+pub struct Spread<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    iterable_is_array: bool,
+}
+
+impl<'a, 'ctx> Spread<'a, 'ctx> {
+    pub fn new(options: SpreadOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx, iterable_is_array: options.loose || ctx.assumptions.iterable_is_array }
+    }
+}
+
+impl<'a> Spread<'a, '_> {
+    pub fn exit_expression(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::ArrayExpression(array) => {
+                if array.elements.iter().any(ArrayExpressionElement::is_spread) {
+                    *expr = self.transform_array(array, ctx);
+                }
+            }
+            Expression::CallExpression(call) => {
+                if call.arguments.iter().any(Argument::is_spread) && !call.callee.is_super() {
+                    self.transform_call(call, ctx);
+                }
+            }
+            Expression::NewExpression(new) => {
+                if new.arguments.iter().any(Argument::is_spread) {
+                    *expr = self.transform_new(new, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// * `[...a]` -> `babelHelpers.toConsumableArray(a)`
+    /// * `[a, ...b, c]` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    fn transform_array(
+        &self,
+        array: &mut ArrayExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = array.elements.take_in(ctx.ast);
+        let is_single_spread = elements.len() == 1;
+        let mut parts = self.build_parts(elements, ctx);
+
+        // A single converted spread element is already a new array.
+        // `[...[1, 2]]` and `[...a]` with `iterableIsArray` must still be copied.
+        if is_single_spread && matches!(parts[0], Part::Converted(_)) {
+            return parts.pop().unwrap().into_expression();
+        }
+
+        // Call `concat` on a new array, as a spread value could have its own `concat` method.
+        let first = if matches!(parts[0], Part::Elements(_)) {
+            parts.remove(0).into_expression()
+        } else {
+            ctx.ast.expression_array(SPAN, ctx.ast.vec())
+        };
+        let callee = create_member_callee(first, "concat", ctx);
+        let arguments = ctx
+            .ast
+            .vec_from_iter(parts.into_iter().map(|part| Argument::from(part.into_expression())));
+        ctx.ast.expression_call(array.span, callee, NONE, arguments, false)
+    }
+
+    /// * `foo(...a)` -> `foo.apply(void 0, babelHelpers.toConsumableArray(a))`
+    /// * `obj.foo(a, ...b)` -> `obj.foo.apply(obj, [a].concat(babelHelpers.toConsumableArray(b)))`
+    fn transform_call(&self, call: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let arguments = call.arguments.take_in(ctx.ast);
+        let is_spread_arguments = matches!(
+            arguments.as_slice(),
+            [Argument::SpreadElement(spread)] if spread.argument.is_specific_id("arguments")
+        );
+        let arguments = if is_spread_arguments {
+            // `foo(...arguments)` -> `foo.apply(void 0, arguments)`
+            let Some(Argument::SpreadElement(spread)) = arguments.into_iter().next() else {
+                unreachable!()
+            };
+            spread.unbox().argument
+        } else {
+            let elements = arguments_to_elements(arguments, ctx);
+            Self::concat_parts(self.build_parts(elements, ctx), ctx)
+        };
+
+        let this = match &mut call.callee {
+            Expression::StaticMemberExpression(member) => {
+                self.take_context(&mut member.object, ctx)
+            }
+            Expression::ComputedMemberExpression(member) => {
+                self.take_context(&mut member.object, ctx)
+            }
+            Expression::PrivateFieldExpression(member) => {
+                self.take_context(&mut member.object, ctx)
+            }
+            _ => ctx.ast.void_0(SPAN),
+        };
+
+        let callee = call.callee.take_in(ctx.ast);
+        call.callee = create_member_callee(callee, "apply", ctx);
+        call.arguments = ctx.ast.vec_from_array([Argument::from(this), Argument::from(arguments)]);
+    }
+
+    /// `new Foo(...a)` -> `babelHelpers.construct(Foo, babelHelpers.toConsumableArray(a))`
+    fn transform_new(
+        &self,
+        new: &mut NewExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let elements = arguments_to_elements(new.arguments.take_in(ctx.ast), ctx);
+        let arguments = Self::concat_parts(self.build_parts(elements, ctx), ctx);
+        let callee = new.callee.take_in(ctx.ast);
+        let arguments = ctx.ast.vec_from_array([Argument::from(callee), Argument::from(arguments)]);
+        self.ctx.helper_call_expr(Helper::Construct, new.span, arguments, ctx)
+    }
+
+    /// Get the `this` value for the call of a method of `object`.
+    ///
+    /// `foo.bar.baz(...a)` -> `(_foo$bar = foo.bar).baz.apply(_foo$bar, ...)`
+    fn take_context(
+        &self,
+        object: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if object.is_super() {
+            return ctx.ast.expression_this(SPAN);
+        }
+        let (assignment, reference) =
+            self.ctx.duplicate_expression(object.take_in(ctx.ast), true, ctx);
+        *object = assignment;
+        reference
+    }
+
+    /// `a, ...b, c` -> `[a].concat(babelHelpers.toConsumableArray(b), [c])`
+    fn concat_parts(mut parts: Vec<Part<'a>>, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+        let first = parts.remove(0).into_expression();
+        if parts.is_empty() {
+            return first;
+        }
+        let callee = create_member_callee(first, "concat", ctx);
+        let arguments = ctx
+            .ast
+            .vec_from_iter(parts.into_iter().map(|part| Argument::from(part.into_expression())));
+        ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+    }
+
+    /// Split elements into arrays of consecutive elements which are not spread, and spread values.
+    fn build_parts(
+        &self,
+        elements: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Part<'a>> {
+        let mut parts = vec![];
+        let mut current = ctx.ast.vec();
+        for element in elements {
+            if let ArrayExpressionElement::SpreadElement(spread) = element {
+                if !current.is_empty() {
+                    parts.push(Part::Elements(ctx.ast.expression_array(SPAN, current)));
+                    current = ctx.ast.vec();
+                }
+                parts.push(self.convert_spread(spread.unbox().argument, ctx));
+            } else {
+                current.push(element);
+            }
+        }
+        if !current.is_empty() {
+            parts.push(Part::Elements(ctx.ast.expression_array(SPAN, current)));
+        }
+        parts
+    }
+
+    /// Convert the value of a spread element into an array.
+    ///
+    /// * `[1, 2]` -> `[1, 2]`
+    /// * `arguments` -> `Array.prototype.slice.call(arguments)`
+    /// * `a` -> `babelHelpers.toConsumableArray(a)`
+    fn convert_spread(&self, argument: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Part<'a> {
+        if argument.is_specific_id("arguments") {
+            let array =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("Array"), ReferenceFlags::Read);
+            let prototype = create_property_access(SPAN, array, "prototype", ctx);
+            let slice = create_property_access(SPAN, prototype, "slice", ctx);
+            let call = create_call_call(slice, argument, SPAN, ctx);
+            return Part::Converted(call);
+        }
+        if self.iterable_is_array || matches!(argument, Expression::ArrayExpression(_)) {
+            return Part::Value(argument);
+        }
+        let arguments = ctx.ast.vec1(Argument::from(argument));
+        Part::Converted(self.ctx.helper_call_expr(Helper::ToConsumableArray, SPAN, arguments, ctx))
+    }
+}
+
+/// `(a, ...b)` -> `[a, ...b]`
+fn arguments_to_elements<'a>(
+    arguments: ArenaVec<'a, Argument<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> ArenaVec<'a, ArrayExpressionElement<'a>> {
+    ctx.ast.vec_from_iter(arguments.into_iter().map(|argument| match argument {
+        Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+        argument => ArrayExpressionElement::from(argument.into_expression()),
+    }))
+}
+
+/// Part of the list of elements of an array or arguments of a call.
+enum Part<'a> {
+    /// `[a, b]` for elements which are not spread.
+    Elements(Expression<'a>),
+    /// Value of a spread element which is already a new array.
+    Converted(Expression<'a>),
+    /// Value of a spread element which is used as is.
+    Value(Expression<'a>),
+}
+
+impl<'a> Part<'a> {
+    fn into_expression(self) -> Expression<'a> {
+        match self {
+            Self::Elements(expr) | Self::Converted(expr) | Self::Value(expr) => expr,
+        }
+    }
+}
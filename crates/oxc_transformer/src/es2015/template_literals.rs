@@ -0,0 +1,245 @@
+//! ES2015: Template Literals
+//!
+//! This plugin transforms template literals and tagged templates.
+//!
+//! > This plugin is included in `preset-env`, in ES2015
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! `a${b}c${d}`;
+//! tag`a${b}\n`;
+//! ```
+//!
+//! Output:
+//! ```js
+//! var _templateObject;
+//! "a".concat(b, "c").concat(d);
+//! tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", "\n"], ["a", "\\n"])), b);
+//! ```
+//!
+//! Each `concat` call receives at most one expression which is not a literal, so expressions are
+//! converted to strings in the same order as in the template literal.
+//! With the `ignoreToPrimitiveHint` assumption (or `loose` option), `+` is used instead:
+//!
+//! ```js
+//! "a" + b + "c" + d;
+//! ```
+//!
+//! The strings array of a tagged template is created once, and cached in a variable at top level.
+//! The raw strings array is only passed to the helper if it differs from the cooked strings.
+//! With the `mutableTemplateObject` assumption (or `loose` option), the `taggedTemplateLiteralLoose`
+//! helper is used, which does not freeze the arrays.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@babel/plugin-transform-template-literals](https://babel.dev/docs/babel-plugin-transform-template-literals).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/babel/babel/blob/v7.26.2/packages/babel-plugin-transform-template-literals>
+//! * Template literals: <https://tc39.es/ecma262/#sec-template-literals>
+
+use serde::Deserialize;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::SPAN;
+
+use crate::{
+    common::helper_loader::Helper,
+    context::{TransformCtx, TraverseCtx},
+    utils::ast_builder::{create_assignment, create_member_callee},
+};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLiteralsOptions {
+    /// Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    pub loose: bool,
+}
+
+pub struct TemplateLiterals<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    ignore_to_primitive_hint: bool,
+    mutable_template_object: bool,
+    /// Variables which cache the strings arrays of tagged templates, declared at top level on exit.
+    template_objects: Vec<VariableDeclarator<'a>>,
+}
+
+impl<'a, 'ctx> TemplateLiterals<'a, 'ctx> {
+    pub fn new(options: TemplateLiteralsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            ctx,
+            ignore_to_primitive_hint: options.loose || ctx.assumptions.ignore_to_primitive_hint,
+            mutable_template_object: options.loose || ctx.assumptions.mutable_template_object,
+            template_objects: vec![],
+        }
+    }
+}
+
+impl<'a> TemplateLiterals<'a, '_> {
+    /// `var _templateObject, _templateObject2;`
+    pub fn exit_program(&mut self, _program: &mut Program<'a>, ctx: &TraverseCtx<'a>) {
+        if self.template_objects.is_empty() {
+            return;
+        }
+        let declarators = ctx.ast.vec_from_iter(self.template_objects.drain(..));
+        let kind = VariableDeclarationKind::Var;
+        let declaration = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
+        self.ctx.top_level_statements.insert_statement(Statement::VariableDeclaration(declaration));
+    }
+
+    pub fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::TemplateLiteral(template) => {
+                *expr = self.transform_template_literal(template, ctx);
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                *expr = self.transform_tagged_template(tagged, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    /// `` `a${b}c` `` -> `"a".concat(b, "c")`
+    fn transform_template_literal(
+        &self,
+        template: &mut TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let span = template.span;
+        let mut nodes = Vec::with_capacity(template.quasis.len() + template.expressions.len());
+        let mut expressions = template.expressions.take_in(ctx.ast).into_iter();
+        for quasi in &template.quasis {
+            let cooked = quasi.value.cooked.expect("untagged template literal has cooked strings");
+            if !cooked.is_empty() {
+                nodes.push(ctx.ast.expression_string_literal_with_lone_surrogates(
+                    SPAN,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                ));
+            }
+            if let Some(expression) = expressions.next() {
+                nodes.push(expression);
+            }
+        }
+
+        // The result must be a string, so must start with a string.
+        // `+` converts the left side to a string if the right side is a string.
+        let starts_with_string = nodes.first().is_some_and(is_string_literal)
+            || (self.ignore_to_primitive_hint && nodes.get(1).is_some_and(is_string_literal));
+        if !starts_with_string {
+            nodes.insert(0, ctx.ast.expression_string_literal(SPAN, "", None));
+        }
+
+        let mut nodes = nodes.into_iter();
+        let mut result = nodes.next().unwrap();
+        if self.ignore_to_primitive_hint {
+            // `"a" + b + "c"`
+            for node in nodes {
+                result = ctx.ast.expression_binary(SPAN, result, BinaryOperator::Addition, node);
+            }
+        } else {
+            // `"a".concat(b, "c").concat(d)`
+            let mut has_non_literal = false;
+            for node in nodes {
+                let can_be_added = node.is_literal() || !has_non_literal;
+                if !node.is_literal() {
+                    has_non_literal = true;
+                }
+                if can_be_added {
+                    if let Expression::CallExpression(call) = &mut result {
+                        call.arguments.push(Argument::from(node));
+                        continue;
+                    }
+                }
+                let callee = create_member_callee(result, "concat", ctx);
+                result = ctx.ast.expression_call(
+                    SPAN,
+                    callee,
+                    NONE,
+                    ctx.ast.vec1(Argument::from(node)),
+                    false,
+                );
+            }
+        }
+
+        if let Expression::CallExpression(call) = &mut result {
+            call.span = span;
+        }
+        result
+    }
+
+    /// ``tag`a${b}` `` -> `tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral(["a", ""])), b)`
+    fn transform_tagged_template(
+        &mut self,
+        tagged: &mut TaggedTemplateExpression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let quasis = &tagged.quasi.quasis;
+        let is_raw_equal_to_cooked = quasis
+            .iter()
+            .all(|quasi| quasi.value.cooked.is_some_and(|cooked| cooked == quasi.value.raw));
+
+        let strings = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+            ArrayExpressionElement::from(match quasi.value.cooked {
+                Some(cooked) => ctx.ast.expression_string_literal_with_lone_surrogates(
+                    SPAN,
+                    cooked,
+                    None,
+                    quasi.lone_surrogates,
+                ),
+                None => ctx.ast.void_0(SPAN),
+            })
+        }));
+        let mut arguments = ctx.ast.vec1(Argument::from(ctx.ast.expression_array(SPAN, strings)));
+        if !is_raw_equal_to_cooked {
+            let raws = ctx.ast.vec_from_iter(quasis.iter().map(|quasi| {
+                ArrayExpressionElement::from(ctx.ast.expression_string_literal(
+                    SPAN,
+                    quasi.value.raw,
+                    None,
+                ))
+            }));
+            arguments.push(Argument::from(ctx.ast.expression_array(SPAN, raws)));
+        }
+        let helper = if self.mutable_template_object {
+            Helper::TaggedTemplateLiteralLoose
+        } else {
+            Helper::TaggedTemplateLiteral
+        };
+        let template_object = self.ctx.helper_call_expr(helper, SPAN, arguments, ctx);
+
+        // `_templateObject || (_templateObject = template_object)`
+        let binding =
+            ctx.generate_uid_in_root_scope("templateObject", SymbolFlags::FunctionScopedVariable);
+        self.template_objects.push(ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Var,
+            binding.create_binding_pattern(ctx),
+            None,
+            false,
+        ));
+        let cached = ctx.ast.expression_logical(
+            SPAN,
+            binding.create_read_expression(ctx),
+            LogicalOperator::Or,
+            create_assignment(&binding, template_object, ctx),
+        );
+
+        let mut arguments =
+            ArenaVec::with_capacity_in(tagged.quasi.expressions.len() + 1, ctx.ast.allocator);
+        arguments.push(Argument::from(cached));
+        arguments.extend(tagged.quasi.expressions.take_in(ctx.ast).into_iter().map(Argument::from));
+        let tag = tagged.tag.take_in(ctx.ast);
+        ctx.ast.expression_call(tagged.span, tag, NONE, arguments, false)
+    }
+}
+
+fn is_string_literal(expr: &Expression<'_>) -> bool {
+    matches!(expr, Expression::StringLiteral(_))
+}
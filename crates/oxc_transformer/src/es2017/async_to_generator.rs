@@ -67,6 +67,7 @@ use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
 use crate::{
     common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
+    es2015::Generators,
     state::TransformState,
 };
 
@@ -691,7 +692,12 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
     ) -> Expression<'a> {
         let mut function = Self::create_function(None, params, body, scope_id, ctx);
         function.generator = true;
-        let arguments = ctx.ast.vec1(Argument::FunctionExpression(function));
+        let function = if self.ctx.is_generators_plugin_enabled {
+            Generators::transform_function_expression(function, self.ctx, ctx)
+        } else {
+            Expression::FunctionExpression(function)
+        };
+        let arguments = ctx.ast.vec1(Argument::from(function));
         self.ctx.helper_call_expr(self.helper, SPAN, arguments, ctx)
     }
 
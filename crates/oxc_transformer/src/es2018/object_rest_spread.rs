@@ -95,7 +95,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for ObjectRestSpread<'a, '_> {
     fn exit_program(&mut self, _node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
         if !self.excluded_variable_declarators.is_empty() {
             let declarators = ctx.ast.vec_from_iter(self.excluded_variable_declarators.drain(..));
-            let kind = VariableDeclarationKind::Const;
+            let kind = declarators[0].kind;
             let declaration = ctx.ast.alloc_variable_declaration(SPAN, kind, declarators, false);
             let statement = Statement::VariableDeclaration(declaration);
             self.ctx.top_level_statements.insert_statement(statement);
@@ -1108,11 +1108,17 @@ impl<'a> SpreadPair<'a> {
                 && ctx.scoping.current_scope_id() != ctx.scoping().root_scope_id()
             {
                 // Move the key_expression to the root scope.
-                let bound_identifier = ctx.generate_uid_in_root_scope(
-                    "excluded",
-                    SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
-                );
-                let kind = VariableDeclarationKind::Const;
+                // Declared with `var` if block scoping plugin is enabled, as it never visits
+                // statements inserted at program exit.
+                let (kind, flags) = if transform_ctx.is_block_scoping_plugin_enabled {
+                    (VariableDeclarationKind::Var, SymbolFlags::FunctionScopedVariable)
+                } else {
+                    (
+                        VariableDeclarationKind::Const,
+                        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+                    )
+                };
+                let bound_identifier = ctx.generate_uid_in_root_scope("excluded", flags);
                 let declarator = ctx.ast.variable_declarator(
                     SPAN,
                     kind,
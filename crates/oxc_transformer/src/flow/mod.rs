@@ -0,0 +1,141 @@
+//! [plugin-transform-flow-strip-types](https://babeljs.io/docs/babel-plugin-transform-flow-strip-types)
+//!
+//! Flow syntax is parsed into TypeScript AST nodes when `ParseOptions::flow` is enabled,
+//! e.g. `type T = ?string` is a `TSTypeAliasDeclaration` and `(x: any)` is a `TSAsExpression`.
+//! Stripping it is therefore done by the [TypeScript](crate::typescript) plugin,
+//! configured by [`FlowOptions::apply_to_typescript_options`] to behave like the Babel plugin.
+//!
+//! ## Example
+//!
+//! In:  `function foo(one: any, two: number, three?): string {}`
+//! Out: `function foo(one, two, three) {}`
+//!
+//! The TypeScript plugin does not delete the scopes, symbols and references of the types it strips
+//! from `Scoping`. [`FlowStripTypes`] deletes them once the program has been transformed.
+
+use std::cell::Cell;
+
+use compact_str::CompactString;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ReferenceId, ScopeFlags, ScopeId, SymbolId};
+use oxc_traverse::Traverse;
+
+use crate::{TypeScriptOptions, context::TraverseCtx, state::TransformState};
+
+/// <https://babeljs.io/docs/babel-plugin-transform-flow-strip-types#options>
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct FlowOptions {
+    /// When enabled, type-only class fields are only removed if they are prefixed with the
+    /// `declare` modifier. Otherwise class fields without an initializer are removed.
+    ///
+    /// Defaults to `false`.
+    pub allow_declare_fields: bool,
+}
+
+impl FlowOptions {
+    /// Override the options of the TypeScript plugin which differ in the Flow plugin.
+    /// Other options, e.g. the JSX pragmas, are kept as configured.
+    pub(crate) fn apply_to_typescript_options(self, options: &mut TypeScriptOptions) {
+        // Flow removes `import type` and `import typeof`, but keeps all other imports.
+        options.only_remove_type_imports = true;
+        options.allow_declare_fields = self.allow_declare_fields;
+    }
+}
+
+/// Deletes the semantic data of the stripped Flow types.
+pub struct FlowStripTypes;
+
+impl FlowStripTypes {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for FlowStripTypes {
+    /// Types are stripped in many places, so rather than deleting the semantic data of each
+    /// of them, delete the scopes, symbols and references which are no longer in the AST.
+    ///
+    /// Must run after all other plugins have inserted their statements.
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let mut ids = SemanticIdsCollector::default();
+        ids.visit_program(program);
+
+        let scoping = ctx.scoping_mut();
+        for &scope_id in &ids.scopes {
+            if scoping.has_scope_child_ids() {
+                let stripped_scope_ids = scoping
+                    .get_scope_child_ids(scope_id)
+                    .iter()
+                    .copied()
+                    .filter(|scope_id| !ids.scopes.contains(scope_id))
+                    .collect::<Vec<_>>();
+                if !stripped_scope_ids.is_empty() {
+                    scoping.remove_child_scopes(scope_id, &stripped_scope_ids);
+                }
+            }
+
+            let stripped_names = scoping
+                .get_bindings(scope_id)
+                .iter()
+                .filter(|(_, symbol_id)| !ids.symbols.contains(*symbol_id))
+                .map(|(&name, _)| CompactString::from(name))
+                .collect::<Vec<_>>();
+            for name in stripped_names {
+                scoping.remove_binding(scope_id, &name);
+            }
+        }
+
+        for &symbol_id in &ids.symbols {
+            let stripped_reference_ids = scoping
+                .get_resolved_reference_ids(symbol_id)
+                .iter()
+                .copied()
+                .filter(|reference_id| !ids.references.contains(reference_id))
+                .collect::<Vec<_>>();
+            for reference_id in stripped_reference_ids {
+                scoping.delete_resolved_reference(symbol_id, reference_id);
+            }
+        }
+
+        let stripped_references = scoping
+            .root_unresolved_references()
+            .iter()
+            .flat_map(|(&name, reference_ids)| {
+                reference_ids
+                    .iter()
+                    .filter(|reference_id| !ids.references.contains(*reference_id))
+                    .map(move |&reference_id| (CompactString::from(name), reference_id))
+            })
+            .collect::<Vec<_>>();
+        for (name, reference_id) in stripped_references {
+            scoping.delete_root_unresolved_reference(&name, reference_id);
+        }
+    }
+}
+
+/// Collects the `ScopeId`s, `SymbolId`s and `ReferenceId`s in the AST.
+#[derive(Default)]
+struct SemanticIdsCollector {
+    scopes: FxHashSet<ScopeId>,
+    symbols: FxHashSet<SymbolId>,
+    references: FxHashSet<ReferenceId>,
+}
+
+impl<'a> Visit<'a> for SemanticIdsCollector {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        self.scopes.insert(scope_id.get().unwrap());
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.symbols.insert(ident.symbol_id());
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.insert(ident.reference_id());
+    }
+}
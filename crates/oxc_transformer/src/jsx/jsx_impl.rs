@@ -616,8 +616,14 @@ impl<'a> JsxImpl<'a, '_> {
                         let kind = PropertyKind::Init;
                         let key = Self::get_attribute_name(name, ctx);
                         let value = self.transform_jsx_attribute_value(value, ctx);
+                        // `ref={ref}` -> `{ ref }`
+                        let shorthand = matches!(
+                            (&key, &value),
+                            (PropertyKey::StaticIdentifier(key), Expression::Identifier(ident))
+                                if key.name == ident.name
+                        );
                         let object_property = ctx.ast.object_property_kind_object_property(
-                            span, kind, key, value, false, false, false,
+                            span, kind, key, value, false, shorthand, false,
                         );
                         properties.push(object_property);
                     }
@@ -31,7 +31,9 @@ mod es2019;
 mod es2020;
 mod es2021;
 mod es2022;
+mod flow;
 mod jsx;
+mod module;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -49,7 +51,9 @@ use es2019::ES2019;
 use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
+use flow::FlowStripTypes;
 use jsx::Jsx;
+use module::ModuleTransform;
 use proposals::ExplicitResourceManagement;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
@@ -60,7 +64,11 @@ pub use crate::{
     common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
-    es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ES2015Options, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
     es2016::ES2016Options,
     es2017::ES2017Options,
     es2018::ES2018Options,
@@ -68,7 +76,9 @@ pub use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
+    flow::FlowOptions,
     jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    module::{CommonJsOptions, ImportInterop, LazyImports},
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
@@ -91,10 +101,12 @@ pub struct Transformer<'a> {
     allocator: &'a Allocator,
 
     typescript: TypeScriptOptions,
+    flow: Option<FlowOptions>,
     decorator: DecoratorOptions,
     jsx: JsxOptions,
     env: EnvOptions,
     proposals: ProposalOptions,
+    commonjs: CommonJsOptions,
 }
 
 impl<'a> Transformer<'a> {
@@ -104,10 +116,12 @@ impl<'a> Transformer<'a> {
             ctx,
             allocator,
             typescript: options.typescript.clone(),
+            flow: options.flow,
             decorator: options.decorator,
             jsx: options.jsx.clone(),
             env: options.env,
             proposals: options.proposals,
+            commonjs: options.commonjs.clone(),
         }
     }
 
@@ -122,6 +136,12 @@ impl<'a> Transformer<'a> {
         self.ctx.source_type = program.source_type;
         self.ctx.source_text = program.source_text;
 
+        // Flow syntax is represented by TypeScript AST nodes, and is stripped by the TypeScript plugin.
+        let flow = self.flow.filter(|_| program.source_type.is_javascript());
+        if let Some(flow) = flow {
+            flow.apply_to_typescript_options(&mut self.typescript);
+        }
+
         if program.source_type.is_jsx() {
             jsx::update_options_with_comments(
                 &program.comments,
@@ -138,9 +158,7 @@ impl<'a> Transformer<'a> {
                 .proposals
                 .explicit_resource_management
                 .then(|| ExplicitResourceManagement::new(&self.ctx)),
-            x0_typescript: program
-                .source_type
-                .is_typescript()
+            x0_typescript: (program.source_type.is_typescript() || flow.is_some())
                 .then(|| TypeScript::new(&self.typescript, &self.ctx)),
             x1_jsx: Jsx::new(self.jsx, self.env.es2018.object_rest_spread, ast_builder, &self.ctx),
             x2_es2022: ES2022::new(
@@ -157,10 +175,22 @@ impl<'a> Transformer<'a> {
             x2_es2017: ES2017::new(self.env.es2017, &self.ctx),
             x3_es2015: ES2015::new(self.env.es2015, &self.ctx),
             x4_regexp: RegExp::new(self.env.regexp, &self.ctx),
+            flow: flow.map(|_| FlowStripTypes::new()),
         };
 
         let state = TransformState::default();
-        let scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
+        let mut scoping = traverse_mut(&mut transformer, allocator, program, scoping, state);
+
+        if program.source_type.is_module()
+            && matches!(
+                self.ctx.module,
+                Module::CommonJS | Module::Amd | Module::Umd | Module::SystemJs
+            )
+        {
+            let mut module = ModuleTransform::new(&self.commonjs, &self.ctx);
+            let state = TransformState::default();
+            scoping = traverse_mut(&mut module, allocator, program, scoping, state);
+        }
         let helpers_used = self.ctx.helper_loader.used_helpers.borrow_mut().drain().collect();
         #[expect(deprecated)]
         TransformerReturn { errors: self.ctx.take_errors(), scoping, helpers_used }
@@ -180,10 +210,10 @@ struct TransformerImpl<'a, 'ctx> {
     x2_es2018: ES2018<'a, 'ctx>,
     x2_es2017: ES2017<'a, 'ctx>,
     x2_es2016: ES2016<'a, 'ctx>,
-    #[expect(unused)]
     x3_es2015: ES2015<'a, 'ctx>,
     x4_regexp: RegExp<'a, 'ctx>,
     common: Common<'a, 'ctx>,
+    flow: Option<FlowStripTypes>,
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
@@ -204,7 +234,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
         self.x2_es2022.exit_program(program, ctx);
         self.x2_es2018.exit_program(program, ctx);
+        self.x3_es2015.exit_program(program, ctx);
         self.common.exit_program(program, ctx);
+        if let Some(flow) = self.flow.as_mut() {
+            flow.exit_program(program, ctx);
+        }
     }
 
     // ALPHASORT
@@ -218,6 +252,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_arrow_function_expression(arrow, ctx);
         }
         self.x2_es2018.enter_arrow_function_expression(arrow, ctx);
+        self.x3_es2015.enter_arrow_function_expression(arrow, ctx);
     }
 
     fn enter_variable_declaration(
@@ -226,6 +261,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.x2_es2018.enter_variable_declaration(decl, ctx);
+        self.x3_es2015.enter_variable_declaration(decl, ctx);
     }
 
     fn enter_variable_declarator(
@@ -248,6 +284,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_binding_identifier(node, ctx);
+        self.x3_es2015.enter_binding_identifier(node, ctx);
     }
 
     fn enter_identifier_reference(
@@ -256,6 +293,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         ctx: &mut TraverseCtx<'a>,
     ) {
         self.common.enter_identifier_reference(node, ctx);
+        self.x3_es2015.enter_identifier_reference(node, ctx);
     }
 
     fn enter_binding_pattern(&mut self, pat: &mut BindingPattern<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -314,6 +352,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
 
     #[inline]
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.decorator.enter_expression(expr, ctx);
         self.common.enter_expression(expr, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
@@ -323,6 +362,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2020.enter_expression(expr, ctx);
         self.x2_es2018.enter_expression(expr, ctx);
         self.x2_es2016.enter_expression(expr, ctx);
+        self.x3_es2015.enter_expression(expr, ctx);
         self.x4_regexp.enter_expression(expr, ctx);
     }
 
@@ -332,6 +372,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x2_es2022.exit_expression(expr, ctx);
         self.x2_es2018.exit_expression(expr, ctx);
         self.x2_es2017.exit_expression(expr, ctx);
+        self.x3_es2015.exit_expression(expr, ctx);
     }
 
     fn enter_simple_assignment_target(
@@ -384,6 +425,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     fn enter_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
         self.common.enter_function(func, ctx);
         self.x2_es2018.enter_function(func, ctx);
+        self.x3_es2015.enter_function(func, ctx);
     }
 
     fn exit_function(&mut self, func: &mut Function<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -393,6 +435,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.x1_jsx.exit_function(func, ctx);
         self.x2_es2018.exit_function(func, ctx);
         self.x2_es2017.exit_function(func, ctx);
+        self.x3_es2015.exit_function(func, ctx);
         self.common.exit_function(func, ctx);
     }
 
@@ -557,6 +600,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         self.decorator.exit_statement(stmt, ctx);
         self.x2_es2018.exit_statement(stmt, ctx);
         self.x2_es2017.exit_statement(stmt, ctx);
+        self.x3_es2015.exit_statement(stmt, ctx);
     }
 
     fn enter_tagged_template_expression(
@@ -570,10 +614,12 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.decorator.enter_statement(stmt, ctx);
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_statement(stmt, ctx);
         }
         self.x2_es2018.enter_statement(stmt, ctx);
+        self.x3_es2015.enter_statement(stmt, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_statement(stmt, ctx);
         }
@@ -585,6 +631,18 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         }
     }
 
+    fn enter_block_statement(&mut self, block: &mut BlockStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.enter_block_statement(block, ctx);
+    }
+
+    fn enter_switch_statement(
+        &mut self,
+        stmt: &mut SwitchStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.enter_switch_statement(stmt, ctx);
+    }
+
     fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_if_statement(stmt, ctx);
@@ -595,6 +653,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_while_statement(stmt, ctx);
+    }
+
+    fn exit_while_statement(&mut self, stmt: &mut WhileStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_while_statement(stmt, ctx);
     }
 
     fn enter_do_while_statement(
@@ -605,12 +668,26 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_do_while_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_do_while_statement(stmt, ctx);
+    }
+
+    fn exit_do_while_statement(
+        &mut self,
+        stmt: &mut DoWhileStatement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        self.x3_es2015.exit_do_while_statement(stmt, ctx);
     }
 
     fn enter_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_for_statement(stmt, ctx);
         }
+        self.x3_es2015.enter_for_statement(stmt, ctx);
+    }
+
+    fn exit_for_statement(&mut self, stmt: &mut ForStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_statement(stmt, ctx);
     }
 
     fn enter_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -621,6 +698,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             explicit_resource_management.enter_for_of_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_of_statement(stmt, ctx);
+        self.x3_es2015.enter_for_of_statement(stmt, ctx);
+    }
+
+    fn exit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_of_statement(stmt, ctx);
     }
 
     fn enter_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -628,6 +710,11 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
             typescript.enter_for_in_statement(stmt, ctx);
         }
         self.x2_es2018.enter_for_in_statement(stmt, ctx);
+        self.x3_es2015.enter_for_in_statement(stmt, ctx);
+    }
+
+    fn exit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.x3_es2015.exit_for_in_statement(stmt, ctx);
     }
 
     fn enter_try_statement(&mut self, stmt: &mut TryStatement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -639,6 +726,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
     fn enter_catch_clause(&mut self, clause: &mut CatchClause<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x2_es2019.enter_catch_clause(clause, ctx);
         self.x2_es2018.enter_catch_clause(clause, ctx);
+        self.x3_es2015.enter_catch_clause(clause, ctx);
     }
 
     fn enter_import_declaration(
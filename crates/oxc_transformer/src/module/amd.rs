@@ -0,0 +1,160 @@
+//! AMD (Asynchronous Module Definition), as used by RequireJS.
+//!
+//! ```js
+//! define(["require", "exports", "module", "foo"], function (_require, _exports, _module, _foo) {
+//!   "use strict";
+//!   // ...
+//! });
+//! ```
+//!
+//! * `import.meta.url` -> `new URL(_module.uri, document.baseURI).href`
+//! * `import(source)` ->
+//!   `new Promise(function (_resolve, _reject) { _require([source], function (imported) { _resolve(babelHelpers.interopRequireWildcard(imported)); }, _reject); })`
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_property_access};
+
+use super::{ExportsTarget, ModuleTransform, create_function_expression};
+
+impl<'a> ModuleTransform<'a, '_> {
+    pub(super) fn wrap_amd(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let header = self.create_header(ctx);
+        let helpers = self.take_helper_imports(program, ctx);
+        let body = self.create_module_body(header, program, ctx);
+        let scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::Function);
+        *ctx.scoping_mut().scope_flags_mut(scope_id) |= ScopeFlags::StrictMode;
+        Self::move_root_bindings(scope_id, ctx);
+
+        // `["require", "exports", "module", "foo"]`, `(_require, _exports, _module, _foo)`
+        let mut dependencies = vec![];
+        if let Some(require) = &self.amd_require {
+            dependencies.push((Atom::from("require"), require));
+        }
+        if let Some(ExportsTarget::Object(exports)) = &self.exports {
+            dependencies.push((Atom::from("exports"), exports));
+        }
+        if let Some(module) = &self.amd_module {
+            dependencies.push((Atom::from("module"), module));
+        }
+        dependencies.extend(
+            self.metadata.sources.iter().map(|(source, metadata)| (*source, &metadata.binding)),
+        );
+        dependencies.extend(helpers.iter().map(|(source, binding)| (*source, binding)));
+
+        let names = ctx.ast.vec_from_iter(dependencies.iter().map(|(source, _)| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, *source, None))
+        }));
+        let names = ctx.ast.expression_array(SPAN, names);
+        let params = dependencies.iter().map(|(_, binding)| *binding).collect::<Vec<_>>();
+        let directives = Self::take_directives(program, ctx);
+        let factory = create_function_expression(&params, directives, body, scope_id, ctx);
+
+        // `define([...], function (...) { ... });`
+        let define =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec_from_array([Argument::from(names), Argument::from(factory)]);
+        let call = ctx.ast.expression_call(SPAN, define, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    /// `import.meta.url` -> `new URL(_module.uri, document.baseURI).href`
+    pub(super) fn amd_import_meta_url(
+        &mut self,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let module = self
+            .amd_module
+            .get_or_insert_with(|| {
+                ctx.generate_uid_in_root_scope("module", SymbolFlags::FunctionScopedVariable)
+            })
+            .create_read_expression(ctx);
+        let uri = create_property_access(SPAN, module, "uri", ctx);
+        let document =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("document"), ReferenceFlags::Read);
+        let base_uri = create_property_access(SPAN, document, "baseURI", ctx);
+        let url = ctx.create_unbound_ident_expr(SPAN, Atom::from("URL"), ReferenceFlags::Read);
+        let arguments = ctx.ast.vec_from_array([Argument::from(uri), Argument::from(base_uri)]);
+        let url = ctx.ast.expression_new(SPAN, url, NONE, arguments);
+        create_property_access(span, url, "href", ctx)
+    }
+
+    /// `import(source)` ->
+    /// `new Promise(function (_resolve, _reject) { _require([source], function (imported) { _resolve(babelHelpers.interopRequireWildcard(imported)); }, _reject); })`
+    pub(super) fn amd_dynamic_import(
+        &mut self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ImportExpression(import) = expr else { unreachable!() };
+        let span = import.span;
+        let source = import.source.take_in(ctx.ast);
+        let require = self
+            .amd_require
+            .get_or_insert_with(|| {
+                ctx.generate_uid_in_root_scope("require", SymbolFlags::FunctionScopedVariable)
+            })
+            .clone();
+
+        let flags = ScopeFlags::Function | ScopeFlags::StrictMode;
+        let executor_scope_id = ctx.insert_scope_below_expression(&source, flags);
+        let resolve =
+            ctx.generate_uid("resolve", executor_scope_id, SymbolFlags::FunctionScopedVariable);
+        let reject =
+            ctx.generate_uid("reject", executor_scope_id, SymbolFlags::FunctionScopedVariable);
+
+        // `function (imported) { _resolve(babelHelpers.interopRequireWildcard(imported)); }`
+        let callback_scope_id = ctx.create_child_scope(executor_scope_id, flags);
+        let imported = ctx.generate_binding(
+            Atom::from("imported"),
+            callback_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let callback = {
+            let namespace =
+                self.interop_require_wildcard(imported.create_read_expression(ctx), ctx);
+            let resolve = create_call(&resolve, namespace, ctx);
+            let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, resolve));
+            create_function_expression(&[&imported], ctx.ast.vec(), body, callback_scope_id, ctx)
+        };
+
+        // `_require([source], callback, _reject);`
+        let require_call = {
+            let sources = ctx.ast.vec1(ArrayExpressionElement::from(source));
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(ctx.ast.expression_array(SPAN, sources)),
+                Argument::from(callback),
+                Argument::from(reject.create_read_expression(ctx)),
+            ]);
+            let callee = require.create_read_expression(ctx);
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+        };
+
+        let body = ctx.ast.vec1(ctx.ast.statement_expression(SPAN, require_call));
+        let executor = create_function_expression(
+            &[&resolve, &reject],
+            ctx.ast.vec(),
+            body,
+            executor_scope_id,
+            ctx,
+        );
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        *expr = ctx.ast.expression_new(span, promise, NONE, ctx.ast.vec1(Argument::from(executor)));
+    }
+}
+
+/// `callee(argument)`
+fn create_call<'a>(
+    callee: &BoundIdentifier<'a>,
+    argument: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = callee.create_read_expression(ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(argument)), false)
+}
@@ -0,0 +1,290 @@
+//! CommonJS, as used by Node.js.
+//!
+//! ```js
+//! "use strict";
+//! Object.defineProperty(exports, "__esModule", { value: true });
+//! exports.a = void 0;
+//! var _foo = babelHelpers.interopRequireDefault(require("foo"));
+//! // ...
+//! ```
+//!
+//! * `import.meta.url` -> `require("url").pathToFileURL(__filename).href`
+//! * `import(source)` -> `Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require(source)))`
+//!
+//! With the `lazy` option, a module is loaded the first time one of its bindings is read:
+//!
+//! ```js
+//! function _foo() {
+//!   const data = babelHelpers.interopRequireDefault(require("foo"));
+//!   _foo = function () { return data; };
+//!   return data;
+//! }
+//! _foo().default;
+//! ```
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::TraverseCtx,
+    utils::ast_builder::{create_assignment, create_property_access},
+};
+
+use super::{ModuleTransform, Source, create_function_expression};
+
+/// Globals of a CommonJS module, which top level bindings must not shadow.
+const COMMONJS_GLOBALS: [&str; 5] = ["exports", "module", "require", "__filename", "__dirname"];
+
+impl<'a> ModuleTransform<'a, '_> {
+    /// Mark modules which are loaded lazily, and rename top level bindings which shadow CommonJS globals.
+    pub(super) fn commonjs_prepare(&mut self, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        for (specifier, source) in &mut self.metadata.sources {
+            // `import "foo"` -> `require("foo");` has no binding
+            if source.is_side_effect_only() {
+                ctx.scoping_mut().remove_binding(root_scope_id, &source.binding.name);
+                continue;
+            }
+            // Modules whose exports are all re-exported are always loaded
+            if source.reexport_all || !self.commonjs.lazy.is_lazy(specifier) {
+                continue;
+            }
+            source.lazy = true;
+            *ctx.scoping_mut().symbol_flags_mut(source.binding.symbol_id) = SymbolFlags::Function;
+        }
+
+        // `const require = createRequire(import.meta.url)` -> `const _require = ...`
+        for name in COMMONJS_GLOBALS {
+            let Some(symbol_id) = ctx.scoping().get_root_binding(name) else { continue };
+            let new_name = ctx.generate_uid_name(name);
+            ctx.scoping_mut().rename_symbol(symbol_id, root_scope_id, &new_name);
+            self.commonjs_renamed.insert(symbol_id, new_name);
+        }
+        for export in self.metadata.local_exports.values_mut() {
+            if let Some(&name) = self.commonjs_renamed.get(&export.binding.symbol_id) {
+                export.binding.name = name;
+            }
+        }
+    }
+
+    pub(super) fn wrap_commonjs(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let header = self.create_header(ctx);
+
+        // Runtime helper modules export the helper itself: `var _helper = require("@babel/runtime/helpers/helper");`
+        let helpers = self.take_helper_imports(program, ctx);
+        let mut body = ctx.ast.vec_from_iter(helpers.into_iter().map(|(source, binding)| {
+            let require =
+                create_require_call(ctx.ast.expression_string_literal(SPAN, source, None), ctx);
+            create_var(&binding, require, ctx)
+        }));
+        body.extend(self.create_module_body(header, program, ctx));
+        program.body = body;
+
+        if self.commonjs.strict_mode && !program.directives.iter().any(Directive::is_use_strict) {
+            program.directives.insert(0, ctx.ast.use_strict_directive());
+        }
+    }
+
+    /// Load an imported module.
+    ///
+    /// * `import "foo"` -> `require("foo");`
+    /// * `import foo from "foo"` -> `var _foo = babelHelpers.interopRequireDefault(require("foo"));`
+    /// * lazy: `function _foo() { ... }`
+    pub(super) fn create_require(
+        &self,
+        specifier: Atom<'a>,
+        source: &Source<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        let require =
+            create_require_call(ctx.ast.expression_string_literal(SPAN, specifier, None), ctx);
+        if source.is_side_effect_only() {
+            return ctx.ast.statement_expression(SPAN, require);
+        }
+        let value = self.interop_require(source, require, ctx);
+        if source.lazy {
+            create_lazy_function(&source.binding, value, ctx)
+        } else {
+            create_var(&source.binding, value, ctx)
+        }
+    }
+
+    /// `import.meta.url` -> `require("url").pathToFileURL(__filename).href`
+    pub(super) fn commonjs_import_meta_url(
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let url = create_require_call(ctx.ast.expression_string_literal(SPAN, "url", None), ctx);
+        let callee = create_property_access(SPAN, url, "pathToFileURL", ctx);
+        let filename =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("__filename"), ReferenceFlags::Read);
+        let file_url = ctx.ast.expression_call(
+            SPAN,
+            callee,
+            NONE,
+            ctx.ast.vec1(Argument::from(filename)),
+            false,
+        );
+        create_property_access(span, file_url, "href", ctx)
+    }
+
+    /// * `import("foo")` -> `Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("foo")))`
+    /// * `import(foo)` -> `Promise.resolve(`${foo}`).then((s) => babelHelpers.interopRequireWildcard(require(s)))`
+    ///
+    /// The module is loaded asynchronously, as it would be by `import()`.
+    /// A specifier which is not a string is converted to a string before the module is loaded.
+    pub(super) fn commonjs_dynamic_import(
+        &self,
+        expr: &mut Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let Expression::ImportExpression(import) = expr else { unreachable!() };
+        let span = import.span;
+        let source = import.source.take_in(ctx.ast);
+
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+        let (resolved, param, specifier) = if matches!(source, Expression::StringLiteral(_)) {
+            (None, None, source)
+        } else {
+            let param = ctx.generate_binding(
+                Atom::from("s"),
+                scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            // `${source}`
+            let empty = TemplateElementValue { raw: Atom::from(""), cooked: Some(Atom::from("")) };
+            let quasis = ctx.ast.vec_from_array([
+                ctx.ast.template_element(SPAN, empty.clone(), false),
+                ctx.ast.template_element(SPAN, empty, true),
+            ]);
+            let template = ctx.ast.expression_template_literal(SPAN, quasis, ctx.ast.vec1(source));
+            let specifier = param.create_read_expression(ctx);
+            (Some(template), Some(param), specifier)
+        };
+
+        // `(s) => babelHelpers.interopRequireWildcard(require(s))`
+        let namespace = self.interop_require_wildcard(create_require_call(specifier, ctx), ctx);
+        let items =
+            ctx.ast.vec_from_iter(param.iter().map(|param| {
+                ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))
+            }));
+        let params = ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            items,
+            NONE,
+        );
+        let body = ctx.ast.function_body(
+            SPAN,
+            ctx.ast.vec(),
+            ctx.ast.vec1(ctx.ast.statement_expression(SPAN, namespace)),
+        );
+        let callback = ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+        );
+
+        // `Promise.resolve(resolved).then(callback)`
+        let promise =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Promise"), ReferenceFlags::Read);
+        let resolve = create_property_access(SPAN, promise, "resolve", ctx);
+        let arguments = ctx.ast.vec_from_iter(resolved.map(Argument::from));
+        let resolved = ctx.ast.expression_call(SPAN, resolve, NONE, arguments, false);
+        let then = create_property_access(SPAN, resolved, "then", ctx);
+        let arguments = ctx.ast.vec1(Argument::from(callback));
+        *expr = ctx.ast.expression_call(span, then, NONE, arguments, false);
+    }
+}
+
+/// `require(specifier)`
+fn create_require_call<'a>(specifier: Expression<'a>, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let require = ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+    ctx.ast.expression_call(SPAN, require, NONE, ctx.ast.vec1(Argument::from(specifier)), false)
+}
+
+/// `var binding = init;`
+fn create_var<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        kind,
+        binding.create_binding_pattern(ctx),
+        Some(init),
+        false,
+    );
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// ```js
+/// function _foo() {
+///   const data = value;
+///   _foo = function () { return data; };
+///   return data;
+/// }
+/// ```
+fn create_lazy_function<'a>(
+    binding: &BoundIdentifier<'a>,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let root_scope_id = ctx.scoping().root_scope_id();
+    let scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+    let data = ctx.generate_binding(
+        Atom::from("data"),
+        scope_id,
+        SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+    );
+
+    // `const data = value;`
+    let kind = VariableDeclarationKind::Const;
+    let declarator = ctx.ast.variable_declarator(
+        SPAN,
+        kind,
+        data.create_binding_pattern(ctx),
+        Some(value),
+        false,
+    );
+    let declaration = Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ));
+
+    // `_foo = function () { return data; };`
+    let getter_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::Function);
+    let getter_body =
+        ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(data.create_read_expression(ctx))));
+    let getter = create_function_expression(&[], ctx.ast.vec(), getter_body, getter_scope_id, ctx);
+    let replace = ctx.ast.statement_expression(SPAN, create_assignment(binding, getter, ctx));
+
+    // `return data;`
+    let return_data = ctx.ast.statement_return(SPAN, Some(data.create_read_expression(ctx)));
+
+    let params =
+        ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, ctx.ast.vec(), NONE);
+    let body = ctx.ast.function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec_from_array([declaration, replace, return_data]),
+    );
+    Statement::FunctionDeclaration(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionDeclaration,
+        SPAN,
+        Some(binding.create_binding_identifier(ctx)),
+        params,
+        body,
+        scope_id,
+    ))
+}
@@ -0,0 +1,286 @@
+//! Statements at the top of a module which use an exports object (CommonJS, AMD, UMD).
+//!
+//! ```js
+//! Object.defineProperty(_exports, "__esModule", { value: true });
+//! _exports.a = _exports.b = void 0;
+//! _exports.f = f;
+//! _foo = babelHelpers.interopRequireDefault(_foo);
+//! Object.defineProperty(_exports, "x", { enumerable: true, get: function () { return _foo.x; } });
+//! Object.keys(_bar).forEach(function (key) { /* ... */ });
+//! ```
+//!
+//! CommonJS loads each module in place of its interop assignment:
+//! `var _foo = babelHelpers.interopRequireDefault(require("foo"));`
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::TraverseCtx,
+    utils::ast_builder::{create_assignment, create_property_access},
+};
+
+use super::{
+    ExportsTarget, ModuleTransform, create_define_property, create_function_expression,
+    create_member_access, create_object,
+};
+
+impl<'a> ModuleTransform<'a, '_> {
+    pub(super) fn create_header(&self, ctx: &mut TraverseCtx<'a>) -> Vec<Statement<'a>> {
+        let mut stmts = vec![];
+        let exports = self.exports.as_ref().filter(|exports| exports.is_object());
+
+        if let Some(exports) = exports {
+            if self.metadata.has_exports {
+                // `Object.defineProperty(_exports, "__esModule", { value: true });`
+                let descriptor =
+                    create_object([("value", ctx.ast.expression_boolean_literal(SPAN, true))], ctx);
+                let object = exports.create_object_expression(ctx);
+                let expr =
+                    create_define_property(object, Atom::from("__esModule"), descriptor, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+
+            // `_exports.a = _exports.b = void 0;`
+            // Function declarations are hoisted, so they are exported immediately: `_exports.f = f;`
+            let mut uninitialized = vec![];
+            let mut function_exports = vec![];
+            for export in self.metadata.local_exports.values() {
+                if ctx.scoping().symbol_flags(export.binding.symbol_id).is_function() {
+                    let value = export.binding.create_read_expression(ctx);
+                    let expr = exports.create_export(&export.names, value, ctx);
+                    function_exports.push(ctx.ast.statement_expression(SPAN, expr));
+                } else {
+                    uninitialized.extend(export.names.iter().copied());
+                }
+            }
+            if !uninitialized.is_empty() {
+                let expr = exports.create_export(&uninitialized, ctx.ast.void_0(SPAN), ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+            stmts.extend(function_exports);
+        }
+
+        let explicit_names = self.explicit_export_names();
+
+        for (&specifier, source) in &self.metadata.sources {
+            if self.ctx.module.is_commonjs() {
+                // `var _foo = babelHelpers.interopRequireWildcard(require("foo"));`
+                stmts.push(self.create_require(specifier, source, ctx));
+            } else if self.requires_interop(source) {
+                // `_foo = babelHelpers.interopRequireWildcard(_foo);`
+                let binding = &source.binding;
+                let module = binding.create_read_expression(ctx);
+                let value = self.interop_require(source, module, ctx);
+                let expr = create_assignment(binding, value, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+
+            let Some(exports) = exports else { continue };
+
+            // `Object.defineProperty(_exports, "x", { enumerable: true, get: function () { return _foo.x; } });`
+            for &(exported, imported) in &source.reexports {
+                let object = Self::create_source_object(source, SPAN, ctx);
+                let value = match self.imported_member(imported) {
+                    Some(imported) => create_member_access(SPAN, object, imported, ctx),
+                    None => object,
+                };
+                let descriptor = create_getter_descriptor(value, ctx);
+                let object = exports.create_object_expression(ctx);
+                let expr = create_define_property(object, exported, descriptor, ctx);
+                stmts.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+
+            if source.reexport_all {
+                stmts.push(create_export_all(exports, &source.binding, &explicit_names, ctx));
+            }
+        }
+
+        stmts
+    }
+
+    /// Body of the module's factory function: header, temporary variables, then the program's body.
+    pub(super) fn create_module_body(
+        &mut self,
+        header: Vec<Statement<'a>>,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Statement<'a>> {
+        let mut body = ctx.ast.vec_from_iter(header);
+        body.extend(Self::create_var_declaration(self.temps.drain(..), ctx));
+        for stmt in program.body.take_in(ctx.ast) {
+            let exports = self.create_declaration_exports(&stmt, ctx);
+            body.push(stmt);
+            body.extend(exports);
+        }
+        body
+    }
+
+    /// Exports for declarations which cannot be updated by wrapping an initializer.
+    ///
+    /// * `let { a } = obj;` -> `let { a } = obj; _exports.a = a;`
+    /// * `class C {}` -> `class C {} _exports.C = C;`
+    fn create_declaration_exports(
+        &self,
+        stmt: &Statement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
+        let mut bindings = vec![];
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                for declarator in &decl.declarations {
+                    if declarator.id.kind.is_destructuring_pattern() {
+                        declarator.id.bound_names(&mut |ident| bindings.push(ident.symbol_id()));
+                    }
+                }
+            }
+            Statement::ClassDeclaration(class) => {
+                if let Some(id) = &class.id {
+                    bindings.push(id.symbol_id());
+                }
+            }
+            _ => {}
+        }
+        bindings
+            .into_iter()
+            .filter_map(|symbol_id| self.metadata.local_exports.get(&symbol_id))
+            .map(|export| {
+                let value = export.binding.create_read_expression(ctx);
+                let expr = self.create_export(&export.names, value, ctx);
+                ctx.ast.statement_expression(SPAN, expr)
+            })
+            .collect()
+    }
+}
+
+/// `{ enumerable: true, get: function () { return value; } }`
+fn create_getter_descriptor<'a>(
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Function | ScopeFlags::StrictMode);
+    let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+    let getter = create_function_expression(&[], ctx.ast.vec(), body, scope_id, ctx);
+    create_object(
+        [("enumerable", ctx.ast.expression_boolean_literal(SPAN, true)), ("get", getter)],
+        ctx,
+    )
+}
+
+/// ```js
+/// Object.keys(_foo).forEach(function (key) {
+///   if (key === "default" || key === "__esModule" || key === "a") return;
+///   if (key in _exports && _exports[key] === _foo[key]) return;
+///   Object.defineProperty(_exports, key, { enumerable: true, get: function () { return _foo[key]; } });
+/// });
+/// ```
+fn create_export_all<'a>(
+    exports: &ExportsTarget<'a>,
+    source: &BoundIdentifier<'a>,
+    explicit_names: &[Atom<'a>],
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let callback_scope_id =
+        ctx.create_child_scope_of_current(ScopeFlags::Function | ScopeFlags::StrictMode);
+    let key = ctx.generate_binding(
+        Atom::from("key"),
+        callback_scope_id,
+        SymbolFlags::FunctionScopedVariable,
+    );
+
+    // `key === "default" || key === "__esModule" || key === "a"`
+    let excluded = ["default", "__esModule"]
+        .into_iter()
+        .map(Atom::from)
+        .chain(explicit_names.iter().copied().filter(|name| name != "default"))
+        .map(|name| {
+            let left = key.create_read_expression(ctx);
+            let right = ctx.ast.expression_string_literal(SPAN, name, None);
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictEquality, right)
+        })
+        .collect::<Vec<_>>();
+    let test = excluded
+        .into_iter()
+        .reduce(|left, right| ctx.ast.expression_logical(SPAN, left, LogicalOperator::Or, right))
+        .unwrap();
+    let skip_excluded =
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None);
+
+    // `key in _exports && _exports[key] === _foo[key]`
+    let create_computed = |object: Expression<'a>, ctx: &mut TraverseCtx<'a>| {
+        let property = key.create_read_expression(ctx);
+        Expression::from(ctx.ast.member_expression_computed(SPAN, object, property, false))
+    };
+    let key_in_exports = ctx.ast.expression_binary(
+        SPAN,
+        key.create_read_expression(ctx),
+        BinaryOperator::In,
+        exports.create_object_expression(ctx),
+    );
+    let is_same = {
+        let exports_value = create_computed(exports.create_object_expression(ctx), ctx);
+        let source_value = create_computed(source.create_read_expression(ctx), ctx);
+        ctx.ast.expression_binary(SPAN, exports_value, BinaryOperator::StrictEquality, source_value)
+    };
+    let test = ctx.ast.expression_logical(SPAN, key_in_exports, LogicalOperator::And, is_same);
+    let skip_existing =
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_return(SPAN, None), None);
+
+    // `Object.defineProperty(_exports, key, { enumerable: true, get: function () { return _foo[key]; } })`
+    let define = {
+        let descriptor = {
+            let getter_scope_id = ctx.create_child_scope(
+                callback_scope_id,
+                ScopeFlags::Function | ScopeFlags::StrictMode,
+            );
+            let value = create_computed(source.create_read_expression(ctx), ctx);
+            let body = ctx.ast.vec1(ctx.ast.statement_return(SPAN, Some(value)));
+            let getter = create_function_expression(&[], ctx.ast.vec(), body, getter_scope_id, ctx);
+            create_object(
+                [("enumerable", ctx.ast.expression_boolean_literal(SPAN, true)), ("get", getter)],
+                ctx,
+            )
+        };
+        let object_ctor =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+        let callee = create_property_access(SPAN, object_ctor, "defineProperty", ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(exports.create_object_expression(ctx)),
+            Argument::from(key.create_read_expression(ctx)),
+            Argument::from(descriptor),
+        ]);
+        ctx.ast.statement_expression(
+            SPAN,
+            ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+        )
+    };
+
+    let body = ctx.ast.vec_from_array([skip_excluded, skip_existing, define]);
+    let callback = create_function_expression(&[&key], ctx.ast.vec(), body, callback_scope_id, ctx);
+
+    // `Object.keys(_foo).forEach(callback)`
+    let object_ctor =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let keys = create_property_access(SPAN, object_ctor, "keys", ctx);
+    let keys = ctx.ast.expression_call(
+        SPAN,
+        keys,
+        NONE,
+        ctx.ast.vec1(Argument::from(source.create_read_expression(ctx))),
+        false,
+    );
+    let for_each = create_property_access(SPAN, keys, "forEach", ctx);
+    let expr = ctx.ast.expression_call(
+        SPAN,
+        for_each,
+        NONE,
+        ctx.ast.vec1(Argument::from(callback)),
+        false,
+    );
+    ctx.ast.statement_expression(SPAN, expr)
+}
@@ -0,0 +1,311 @@
+//! Collection of the imports and exports of a module.
+//!
+//! Import and export declarations are removed from the program body. Declarations which were exported
+//! are kept as plain declarations, and `export default <expr>` is replaced with a statement which updates
+//! the `default` export.
+
+use indexmap::IndexMap;
+use rustc_hash::{FxBuildHasher, FxHashMap};
+
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{SymbolFlags, SymbolId};
+use oxc_span::{Atom, SPAN};
+use oxc_traverse::{BoundIdentifier, ast_operations::to_identifier};
+
+use crate::context::TraverseCtx;
+
+use super::ExportsTarget;
+
+type FxIndexMap<K, V> = IndexMap<K, V, FxBuildHasher>;
+
+/// A module which is imported or re-exported from.
+pub struct Source<'a> {
+    /// Binding which holds the module's namespace. e.g. `_foo` for `"./foo"`.
+    pub binding: BoundIdentifier<'a>,
+    /// Bindings created by `import` declarations, with the name they import.
+    /// Imported name is `None` for namespace imports.
+    pub imports: Vec<(Option<Atom<'a>>, BoundIdentifier<'a>)>,
+    /// `export { imported as exported } from "source"`, as `(exported, imported)` pairs.
+    /// Imported name is `None` for `export * as ns from "source"`.
+    pub reexports: Vec<(Atom<'a>, Option<Atom<'a>>)>,
+    /// `true` if the module has `export * from "source"`.
+    pub reexport_all: bool,
+    /// `true` if the module is loaded when first used (CommonJS `lazy` option).
+    pub lazy: bool,
+}
+
+impl<'a> Source<'a> {
+    fn uses_name(&self, predicate: impl Fn(Option<&Atom<'a>>) -> bool) -> bool {
+        self.imports.iter().any(|(imported, _)| predicate(imported.as_ref()))
+            || self.reexports.iter().any(|(_, imported)| predicate(imported.as_ref()))
+    }
+
+    /// `true` if the default export of the module is used.
+    pub fn uses_default(&self) -> bool {
+        self.uses_name(|name| name.is_some_and(|name| name == "default"))
+    }
+
+    /// `true` if the namespace object of the module is used.
+    pub fn uses_namespace(&self) -> bool {
+        self.uses_name(|name| name.is_none())
+    }
+
+    /// `true` if the module is only imported for its side effects.
+    pub fn is_side_effect_only(&self) -> bool {
+        self.imports.is_empty() && self.reexports.is_empty() && !self.reexport_all
+    }
+
+    /// `true` if named exports of the module other than `default` are used.
+    pub fn uses_named(&self) -> bool {
+        self.uses_name(|name| name.is_some_and(|name| name != "default"))
+    }
+}
+
+/// A binding declared in the module which is exported.
+pub struct LocalExport<'a> {
+    pub binding: BoundIdentifier<'a>,
+    /// Names it is exported as.
+    pub names: Vec<Atom<'a>>,
+}
+
+#[derive(Default)]
+pub struct ModuleMetadata<'a> {
+    /// Modules which are imported or re-exported from, in order of first appearance.
+    pub sources: FxIndexMap<Atom<'a>, Source<'a>>,
+    /// Exported bindings declared in the module, in order of first export.
+    pub local_exports: FxIndexMap<SymbolId, LocalExport<'a>>,
+    /// Import bindings, with the index of their source in `sources` and the name they import.
+    pub import_bindings: FxHashMap<SymbolId, (usize, Option<Atom<'a>>)>,
+    /// `true` if the module has any exports.
+    pub has_exports: bool,
+}
+
+impl<'a> ModuleMetadata<'a> {
+    /// Exported names of the binding a reference refers to.
+    pub fn exported_names(
+        &self,
+        ident: &IdentifierReference<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<&[Atom<'a>]> {
+        if self.local_exports.is_empty() {
+            return None;
+        }
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+        self.local_exports.get(&symbol_id).map(|export| export.names.as_slice())
+    }
+
+    /// Remove `import` and `export` declarations from `program`, recording what they import and export.
+    pub fn collect(
+        &mut self,
+        program: &mut Program<'a>,
+        exports: Option<&ExportsTarget<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut new_body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(decl) => self.collect_import(decl.unbox(), ctx),
+                Statement::ExportAllDeclaration(decl) => {
+                    self.has_exports = true;
+                    let ExportAllDeclaration { source, exported, .. } = decl.unbox();
+                    let source = self.get_or_insert_source(source.value, ctx);
+                    match exported {
+                        Some(exported) => source.reexports.push((exported.name(), None)),
+                        None => source.reexport_all = true,
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(stmt) = self.collect_export_named(decl.unbox(), ctx) {
+                        new_body.push(stmt);
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let Some(stmt) = self.collect_export_default(decl.unbox(), exports, ctx) {
+                        new_body.push(stmt);
+                    }
+                }
+                _ => new_body.push(stmt),
+            }
+        }
+        program.body = new_body;
+    }
+
+    fn collect_import(&mut self, decl: ImportDeclaration<'a>, ctx: &mut TraverseCtx<'a>) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let index = self.get_or_insert_source_index(decl.source.value, ctx);
+        let Some(specifiers) = decl.specifiers else { return };
+        for specifier in specifiers {
+            let (imported, local) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if specifier.import_kind.is_type() {
+                        continue;
+                    }
+                    (Some(specifier.imported.name()), specifier.unbox().local)
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (Some(Atom::from("default")), specifier.unbox().local)
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    (None, specifier.unbox().local)
+                }
+            };
+            let local = BoundIdentifier::from_binding_ident(&local);
+            self.import_bindings.insert(local.symbol_id, (index, imported));
+            self.sources[index].imports.push((imported, local));
+        }
+    }
+
+    fn collect_export_named(
+        &mut self,
+        decl: ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        if decl.export_kind.is_type() {
+            return None;
+        }
+        if let Some(declaration) = decl.declaration {
+            if declaration.is_typescript_syntax() {
+                return None;
+            }
+            self.has_exports = true;
+            match &declaration {
+                Declaration::VariableDeclaration(decl) => {
+                    decl.bound_names(&mut |ident| self.add_local_export(ident.name, ident));
+                }
+                Declaration::FunctionDeclaration(func) => {
+                    let ident = func.id.as_ref().unwrap();
+                    self.add_local_export(ident.name, ident);
+                }
+                Declaration::ClassDeclaration(class) => {
+                    let ident = class.id.as_ref().unwrap();
+                    self.add_local_export(ident.name, ident);
+                }
+                _ => {}
+            }
+            return Some(Statement::from(declaration));
+        }
+
+        if let Some(source) = decl.source {
+            let source = self.get_or_insert_source(source.value, ctx);
+            for specifier in decl.specifiers {
+                if specifier.export_kind.is_value() {
+                    source
+                        .reexports
+                        .push((specifier.exported.name(), Some(specifier.local.name())));
+                }
+            }
+            self.has_exports = true;
+            return None;
+        }
+
+        for specifier in decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            self.has_exports = true;
+            let exported = specifier.exported.name();
+            let ModuleExportName::IdentifierReference(local) = specifier.local else {
+                unreachable!("local name of an export specifier without a source is an identifier");
+            };
+            let symbol_id = ctx.scoping().get_reference(local.reference_id()).symbol_id();
+            ctx.delete_reference_for_identifier(&local);
+            let Some(symbol_id) = symbol_id else { continue };
+            if let Some(&(index, imported)) = self.import_bindings.get(&symbol_id) {
+                // `import { a } from "source"; export { a as b }` is a re-export
+                self.sources[index].reexports.push((exported, imported));
+            } else {
+                let binding = BoundIdentifier::new(local.name, symbol_id);
+                self.local_exports
+                    .entry(symbol_id)
+                    .or_insert_with(|| LocalExport { binding, names: vec![] })
+                    .names
+                    .push(exported);
+            }
+        }
+        None
+    }
+
+    fn collect_export_default(
+        &mut self,
+        decl: ExportDefaultDeclaration<'a>,
+        exports: Option<&ExportsTarget<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let default = Atom::from("default");
+        match decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(mut func) => {
+                self.has_exports = true;
+                let id = func.id.get_or_insert_with(|| {
+                    ctx.generate_uid_in_root_scope("default", SymbolFlags::Function)
+                        .create_binding_identifier(ctx)
+                });
+                self.add_local_export(default, id);
+                Some(Statement::FunctionDeclaration(func))
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(mut class) => {
+                self.has_exports = true;
+                let id = class.id.get_or_insert_with(|| {
+                    ctx.generate_uid_in_root_scope("default", SymbolFlags::Class)
+                        .create_binding_identifier(ctx)
+                });
+                self.add_local_export(default, id);
+                Some(Statement::ClassDeclaration(class))
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => None,
+            expr @ match_expression!(ExportDefaultDeclarationKind) => {
+                self.has_exports = true;
+                let expr = expr.into_expression();
+                let exports = exports.expect("module with exports has an exports target");
+                let expr = exports.create_export(&[default], expr, ctx);
+                Some(ctx.ast.statement_expression(SPAN, expr))
+            }
+        }
+    }
+
+    fn add_local_export(&mut self, exported: Atom<'a>, ident: &BindingIdentifier<'a>) {
+        let binding = BoundIdentifier::from_binding_ident(ident);
+        self.local_exports
+            .entry(binding.symbol_id)
+            .or_insert_with(|| LocalExport { binding, names: vec![] })
+            .names
+            .push(exported);
+    }
+
+    fn get_or_insert_source(
+        &mut self,
+        source: Atom<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> &mut Source<'a> {
+        let index = self.get_or_insert_source_index(source, ctx);
+        &mut self.sources[index]
+    }
+
+    fn get_or_insert_source_index(&mut self, source: Atom<'a>, ctx: &mut TraverseCtx<'a>) -> usize {
+        if let Some(index) = self.sources.get_index_of(&source) {
+            return index;
+        }
+        let name = module_name(&source);
+        let binding = ctx.generate_uid_in_root_scope(&name, SymbolFlags::FunctionScopedVariable);
+        let source_metadata = Source {
+            binding,
+            imports: vec![],
+            reexports: vec![],
+            reexport_all: false,
+            lazy: false,
+        };
+        self.sources.insert_full(source, source_metadata).0
+    }
+}
+
+/// Name of a module, derived from its path.
+///
+/// `"./foo/bar-baz.js"` -> `barBaz`
+pub fn module_name(source: &str) -> String {
+    let name = source.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+    let name = name.split_once('.').map_or(name, |(name, _)| name);
+    to_identifier(name.to_string())
+}
@@ -0,0 +1,924 @@
+//! Module Transforms
+//!
+//! Converts ES modules to other module formats:
+//!
+//! * [`Module::CommonJS`] - <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs>
+//! * [`Module::Amd`] - <https://babeljs.io/docs/babel-plugin-transform-modules-amd>
+//! * [`Module::Umd`] - <https://babeljs.io/docs/babel-plugin-transform-modules-umd>
+//! * [`Module::SystemJs`] - <https://babeljs.io/docs/babel-plugin-transform-modules-systemjs>
+//!
+//! Unlike other transforms, module transforms run in a separate traversal after all other transforms
+//! have finished. This is so that `import`s inserted by other transforms (e.g. for runtime helpers),
+//! and `import`s which TypeScript elides, are final by the time the module is converted.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! import foo, { bar } from "foo";
+//! export let count = 0;
+//! export function inc() { count++; }
+//! foo(bar);
+//! ```
+//!
+//! Output (AMD):
+//! ```js
+//! define(["exports", "foo"], function (_exports, _foo) {
+//!   "use strict";
+//!   Object.defineProperty(_exports, "__esModule", { value: true });
+//!   _exports.count = void 0;
+//!   _exports.inc = inc;
+//!   _foo = babelHelpers.interopRequireWildcard(_foo);
+//!   let count = _exports.count = 0;
+//!   function inc() { _exports.count = ++count; }
+//!   (0, _foo.default)(_foo.bar);
+//! });
+//! ```
+//!
+//! ## Implementation
+//!
+//! 1. `enter_program` removes all `import` and `export` declarations, recording what is imported and
+//!    exported in [`ModuleMetadata`].
+//! 2. During traversal:
+//!    * References to imports are replaced with property accesses on the imported module
+//!      (CommonJS, AMD, UMD). SystemJS instead assigns imports to local variables in its `setters`.
+//!    * Assignments to exported bindings are wrapped to also update the export.
+//!    * Top-level `this` is replaced with `undefined`.
+//!    * `import.meta` and `import()` are replaced with the module format's equivalents.
+//! 3. `exit_program` wraps the program in the module format's wrapper.
+//!
+//! [`Module::CommonJS`]: crate::Module::CommonJS
+//! [`Module::Amd`]: crate::Module::Amd
+//! [`Module::Umd`]: crate::Module::Umd
+//! [`Module::SystemJs`]: crate::Module::SystemJs
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, SPAN};
+use oxc_syntax::{identifier::is_identifier_name, operator::AssignmentOperator};
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    Helper,
+    common::{module_imports::ModuleImports, top_level_statements::TopLevelStatements},
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+    utils::ast_builder::{create_assignment, create_property_access},
+};
+
+mod amd;
+mod commonjs;
+mod header;
+mod metadata;
+mod options;
+mod system_js;
+mod umd;
+
+use metadata::{ModuleMetadata, Source};
+pub use options::{CommonJsOptions, ImportInterop, LazyImports};
+
+/// How exports are updated.
+#[derive(Clone)]
+pub enum ExportsTarget<'a> {
+    /// `_exports.name = value` (AMD, UMD)
+    Object(BoundIdentifier<'a>),
+    /// `exports.name = value` (CommonJS)
+    CommonJs,
+    /// `_export("name", value)` (SystemJS)
+    Function(BoundIdentifier<'a>),
+}
+
+impl<'a> ExportsTarget<'a> {
+    /// Create an expression which sets the exports `names` to `value`, and evaluates to `value`.
+    ///
+    /// `["a", "b"]` -> `_exports.a = _exports.b = value` or `_export("a", _export("b", value))`
+    pub fn create_export(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        names.iter().rev().fold(value, |value, &name| match self {
+            Self::Object(_) | Self::CommonJs => {
+                let target = create_exports_member(self.create_object_expression(ctx), name, ctx);
+                ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                )
+            }
+            Self::Function(export) => {
+                let arguments = ctx.ast.vec_from_array([
+                    Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+                    Argument::from(value),
+                ]);
+                let callee = export.create_read_expression(ctx);
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+        })
+    }
+
+    /// The exports object: `_exports` (AMD, UMD) or `exports` (CommonJS).
+    fn create_object_expression(&self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        match self {
+            Self::Object(exports) => exports.create_read_expression(ctx),
+            Self::CommonJs => {
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("exports"), ReferenceFlags::Read)
+            }
+            Self::Function(_) => unreachable!("SystemJS has no exports object"),
+        }
+    }
+
+    fn is_object(&self) -> bool {
+        matches!(self, Self::Object(_) | Self::CommonJs)
+    }
+
+    fn binding(&self) -> &BoundIdentifier<'a> {
+        match self {
+            Self::Object(binding) | Self::Function(binding) => binding,
+            Self::CommonJs => unreachable!("CommonJS `exports` is not a binding"),
+        }
+    }
+}
+
+pub struct ModuleTransform<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    commonjs: &'ctx CommonJsOptions,
+    /// `importInterop` of CommonJS. Other module formats always use Babel's interop.
+    import_interop: ImportInterop,
+    metadata: ModuleMetadata<'a>,
+    /// `exports` for CommonJS, `_exports` for AMD / UMD, `_export` for SystemJS.
+    /// `None` for CommonJS / AMD / UMD modules without exports.
+    exports: Option<ExportsTarget<'a>>,
+    /// Temporary variables used when updating exports, declared at top of the module.
+    temps: Vec<BoundIdentifier<'a>>,
+    /// AMD `require` dependency, created when `import()` is used
+    amd_require: Option<BoundIdentifier<'a>>,
+    /// AMD `module` dependency, created when `import.meta` is used
+    amd_module: Option<BoundIdentifier<'a>>,
+    /// SystemJS `_context`
+    system_context: Option<BoundIdentifier<'a>>,
+    /// SystemJS bindings hoisted to the register function as `var`s
+    system_hoisted: Vec<BoundIdentifier<'a>>,
+    /// CommonJS top level bindings which shadowed `require`, `exports` etc., with their new names
+    commonjs_renamed: FxHashMap<SymbolId, Atom<'a>>,
+}
+
+impl<'a, 'ctx> ModuleTransform<'a, 'ctx> {
+    pub fn new(commonjs: &'ctx CommonJsOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let import_interop =
+            if ctx.module.is_commonjs() { commonjs.import_interop } else { ImportInterop::Babel };
+        Self {
+            ctx,
+            commonjs,
+            import_interop,
+            metadata: ModuleMetadata::default(),
+            exports: None,
+            temps: vec![],
+            amd_require: None,
+            amd_module: None,
+            system_context: None,
+            system_hoisted: vec![],
+            commonjs_renamed: FxHashMap::default(),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ModuleTransform<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.ctx.module.is_system_js() {
+            let export =
+                ctx.generate_uid_in_root_scope("export", SymbolFlags::FunctionScopedVariable);
+            self.exports = Some(ExportsTarget::Function(export));
+            self.system_context = Some(
+                ctx.generate_uid_in_root_scope("context", SymbolFlags::FunctionScopedVariable),
+            );
+        } else if program.body.iter().any(|stmt| {
+            matches!(
+                stmt,
+                Statement::ExportAllDeclaration(_)
+                    | Statement::ExportNamedDeclaration(_)
+                    | Statement::ExportDefaultDeclaration(_)
+            )
+        }) {
+            self.exports = Some(if self.ctx.module.is_commonjs() {
+                ExportsTarget::CommonJs
+            } else {
+                let exports =
+                    ctx.generate_uid_in_root_scope("exports", SymbolFlags::FunctionScopedVariable);
+                ExportsTarget::Object(exports)
+            });
+        }
+
+        self.metadata.collect(program, self.exports.as_ref(), ctx);
+
+        if self.ctx.module.is_system_js() {
+            self.system_js_hoist_declarations(program, ctx);
+        } else {
+            // References to imports are replaced with member expressions, so the bindings are removed
+            let root_scope_id = ctx.scoping().root_scope_id();
+            for source in self.metadata.sources.values() {
+                for (_, local) in &source.imports {
+                    ctx.scoping_mut().remove_binding(root_scope_id, &local.name);
+                }
+            }
+        }
+
+        if self.ctx.module.is_commonjs() {
+            self.commonjs_prepare(ctx);
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        match self.ctx.module {
+            crate::Module::CommonJS => self.wrap_commonjs(program, ctx),
+            crate::Module::Amd => self.wrap_amd(program, ctx),
+            crate::Module::Umd => self.wrap_umd(program, ctx),
+            crate::Module::SystemJs => self.wrap_system_js(program, ctx),
+            _ => unreachable!(),
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::Identifier(_) => {
+                if !self.ctx.module.is_system_js() {
+                    self.transform_import_reference(expr, ctx);
+                }
+            }
+            Expression::ThisExpression(this) => {
+                if Self::is_top_level_this(ctx) {
+                    *expr = ctx.ast.void_0(this.span);
+                }
+            }
+            Expression::StaticMemberExpression(member) => {
+                if member.property.name == "url"
+                    && matches!(&member.object, Expression::MetaProperty(meta) if meta.meta.name == "import")
+                {
+                    *expr = self.transform_import_meta_url(member.span, ctx);
+                }
+            }
+            Expression::MetaProperty(meta) => {
+                if meta.meta.name == "import" {
+                    *expr = self.transform_import_meta(meta.span, ctx);
+                }
+            }
+            Expression::ImportExpression(_) => self.transform_dynamic_import(expr, ctx),
+            _ => {}
+        }
+    }
+
+    fn enter_binding_identifier(
+        &mut self,
+        ident: &mut BindingIdentifier<'a>,
+        _ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(&name) = self.commonjs_renamed.get(&ident.symbol_id()) {
+            ident.name = name;
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if self.commonjs_renamed.is_empty() {
+            return;
+        }
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        if let Some(&name) = symbol_id.and_then(|id| self.commonjs_renamed.get(&id)) {
+            ident.name = name;
+        }
+    }
+
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.metadata.local_exports.is_empty() {
+            return;
+        }
+        match expr {
+            Expression::AssignmentExpression(_) => self.transform_assignment(expr, ctx),
+            Expression::UpdateExpression(_) => self.transform_update(expr, ctx),
+            _ => {}
+        }
+    }
+
+    fn exit_variable_declarator(
+        &mut self,
+        decl: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // `export let a = 1` -> `let a = _exports.a = 1`
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+        let Some(init) = &mut decl.init else { return };
+        let Some(export) = self.metadata.local_exports.get(&ident.symbol_id()) else { return };
+        let Some(exports) = &self.exports else { return };
+        *init = exports.create_export(&export.names, init.take_in(ctx.ast), ctx);
+    }
+
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.metadata.local_exports.is_empty() {
+            return;
+        }
+        match stmt {
+            Statement::ForInStatement(for_in) => {
+                let scope_id = for_in.scope_id();
+                let ForInStatement { left, body, .. } = &mut **for_in;
+                self.transform_for_in_of_left(left, body, scope_id, ctx);
+            }
+            Statement::ForOfStatement(for_of) => {
+                let scope_id = for_of.scope_id();
+                let ForOfStatement { left, body, .. } = &mut **for_of;
+                self.transform_for_in_of_left(left, body, scope_id, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+// Shared transforms
+impl<'a> ModuleTransform<'a, '_> {
+    /// `foo` -> `_foo.default`, `bar` -> `_foo.bar`, `ns` -> `_foo`.
+    /// Callees are wrapped to not pass the module as `this`: `bar()` -> `(0, _foo.bar)()`.
+    fn transform_import_reference(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.metadata.import_bindings.is_empty() {
+            return;
+        }
+        let Expression::Identifier(ident) = expr else { unreachable!() };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        let Some((index, imported)) = self.metadata.import_bindings.get(&symbol_id) else {
+            return;
+        };
+        let span = ident.span;
+        ctx.delete_reference_for_identifier(ident);
+
+        let source = &self.metadata.sources[*index];
+        let object = Self::create_source_object(source, span, ctx);
+        let Some(imported) = self.imported_member(*imported) else {
+            *expr = object;
+            return;
+        };
+        let member = create_member_access(span, object, imported, ctx);
+        *expr = if matches!(
+            ctx.parent(),
+            Ancestor::CallExpressionCallee(_) | Ancestor::TaggedTemplateExpressionTag(_)
+        ) {
+            // `(0, _foo.bar)`
+            let zero = ctx.ast.expression_numeric_literal(SPAN, 0.0, None, NumberBase::Decimal);
+            ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([zero, member]))
+        } else {
+            member
+        };
+    }
+
+    /// `this` is `undefined` at top level of a module.
+    fn is_top_level_this(ctx: &TraverseCtx<'a>) -> bool {
+        !ctx.ancestors().any(|ancestor| {
+            matches!(
+                ancestor,
+                Ancestor::FunctionParams(_)
+                    | Ancestor::FunctionBody(_)
+                    | Ancestor::ClassBody(_)
+                    | Ancestor::StaticBlockBody(_)
+            )
+        })
+    }
+
+    /// `import.meta.url`
+    fn transform_import_meta_url(
+        &mut self,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match self.ctx.module {
+            crate::Module::CommonJS => Self::commonjs_import_meta_url(span, ctx),
+            crate::Module::Amd => self.amd_import_meta_url(span, ctx),
+            crate::Module::Umd => Self::umd_import_meta_url(span, ctx),
+            _ => {
+                let meta = self.transform_import_meta(span, ctx);
+                create_property_access(span, meta, "url", ctx)
+            }
+        }
+    }
+
+    /// `import.meta`
+    fn transform_import_meta(&mut self, span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if self.ctx.module.is_system_js() {
+            // `_context.meta`
+            let context = self.system_context.as_ref().unwrap().create_read_expression(ctx);
+            return create_property_access(span, context, "meta", ctx);
+        }
+        // `({ url: <import.meta.url> })`
+        let url = self.transform_import_meta_url(span, ctx);
+        let property = ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, "url"),
+            url,
+            false,
+            false,
+            false,
+        );
+        let object = ctx.ast.expression_object(span, ctx.ast.vec1(property));
+        ctx.ast.expression_parenthesized(span, object)
+    }
+
+    /// `import(source)`
+    fn transform_dynamic_import(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match self.ctx.module {
+            crate::Module::CommonJS => self.commonjs_dynamic_import(expr, ctx),
+            crate::Module::Amd => self.amd_dynamic_import(expr, ctx),
+            crate::Module::SystemJs => {
+                // `_context.import(source)`
+                let Expression::ImportExpression(import) = expr.take_in(ctx.ast) else {
+                    unreachable!()
+                };
+                let import = import.unbox();
+                let context = self.system_context.as_ref().unwrap().create_read_expression(ctx);
+                let callee = create_property_access(SPAN, context, "import", ctx);
+                let mut arguments = ctx.ast.vec1(Argument::from(import.source));
+                if let Some(options) = import.options {
+                    arguments.push(Argument::from(options));
+                }
+                *expr = ctx.ast.expression_call(import.span, callee, NONE, arguments, false);
+            }
+            // UMD has no standard way to load a module asynchronously, so `import()` is left as is
+            _ => {}
+        }
+    }
+
+    /// `a = 1` -> `_exports.a = a = 1`
+    /// `[a, b] = arr` -> `(_tmp = [a, b] = arr, _exports.a = a, _exports.b = b, _tmp)`
+    fn transform_assignment(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::AssignmentExpression(assign) = expr else { unreachable!() };
+        if let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left {
+            let Some(names) = self.metadata.exported_names(ident, ctx) else { return };
+            let names = names.to_vec();
+            let exports = self.exports.clone().unwrap();
+            *expr = exports.create_export(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let mut idents = vec![];
+        collect_assignment_target_identifiers(&assign.left, &mut idents);
+        let exported = idents
+            .into_iter()
+            .filter_map(|ident| {
+                let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id()?;
+                let export = self.metadata.local_exports.get(&symbol_id)?;
+                Some((export.binding.clone(), export.names.clone()))
+            })
+            .collect::<Vec<_>>();
+        if exported.is_empty() {
+            return;
+        }
+
+        let span = assign.span;
+        let is_statement = matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_));
+        let temp = (!is_statement).then(|| self.create_temp(ctx));
+        let assignment = expr.take_in(ctx.ast);
+        let mut expressions = ctx.ast.vec_with_capacity(exported.len() + 2);
+        expressions.push(match &temp {
+            Some(temp) => create_assignment(temp, assignment, ctx),
+            None => assignment,
+        });
+        for (binding, names) in exported {
+            let value = binding.create_read_expression(ctx);
+            expressions.push(self.create_export(&names, value, ctx));
+        }
+        if let Some(temp) = temp {
+            expressions.push(temp.create_read_expression(ctx));
+        }
+        *expr = ctx.ast.expression_sequence(span, expressions);
+    }
+
+    /// `++a` -> `_exports.a = ++a`
+    /// `a++` -> `(_tmp = a++, _exports.a = a, _tmp)`
+    fn transform_update(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        let Expression::UpdateExpression(update) = expr else { unreachable!() };
+        let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &update.argument else {
+            return;
+        };
+        let Some(names) = self.metadata.exported_names(ident, ctx) else { return };
+        let names = names.to_vec();
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id().unwrap();
+        let binding = BoundIdentifier::new(ident.name, symbol_id);
+        let span = update.span;
+
+        // `a++;` -> `_exports.a = ++a;`
+        if matches!(ctx.parent(), Ancestor::ExpressionStatementExpression(_)) {
+            update.prefix = true;
+        }
+        if update.prefix {
+            *expr = self.create_export(&names, expr.take_in(ctx.ast), ctx);
+            return;
+        }
+
+        let temp = self.create_temp(ctx);
+        let update = create_assignment(&temp, expr.take_in(ctx.ast), ctx);
+        let value = binding.create_read_expression(ctx);
+        let export = self.create_export(&names, value, ctx);
+        let result = temp.create_read_expression(ctx);
+        *expr = ctx.ast.expression_sequence(span, ctx.ast.vec_from_array([update, export, result]));
+    }
+
+    /// `for (a of arr) body` -> `for (a of arr) { _exports.a = a; body }`
+    fn transform_for_in_of_left(
+        &self,
+        left: &ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+        scope_id: ScopeId,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let mut exported = vec![];
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                decl.bound_names(&mut |ident| {
+                    if let Some(export) = self.metadata.local_exports.get(&ident.symbol_id()) {
+                        exported.push((export.binding.clone(), export.names.clone()));
+                    }
+                });
+            }
+            left => {
+                let mut idents = vec![];
+                collect_assignment_target_identifiers(left.to_assignment_target(), &mut idents);
+                for ident in idents {
+                    let reference = ctx.scoping().get_reference(ident.reference_id());
+                    if let Some(export) =
+                        reference.symbol_id().and_then(|id| self.metadata.local_exports.get(&id))
+                    {
+                        exported.push((export.binding.clone(), export.names.clone()));
+                    }
+                }
+            }
+        }
+        if exported.is_empty() {
+            return;
+        }
+
+        let stmts = exported.into_iter().map(|(binding, names)| {
+            let value = binding.create_read_expression(ctx);
+            let export = self.create_export(&names, value, ctx);
+            ctx.ast.statement_expression(SPAN, export)
+        });
+        let stmts = stmts.collect::<Vec<_>>();
+        if let Statement::BlockStatement(block) = body {
+            block.body.splice(0..0, stmts);
+        } else {
+            let scope_id =
+                ctx.insert_scope_below_statement_from_scope_id(body, scope_id, ScopeFlags::empty());
+            let mut block_body = ctx.ast.vec_from_iter(stmts);
+            let span = body.span();
+            block_body.push(body.take_in(ctx.ast));
+            *body = ctx.ast.statement_block_with_scope_id(span, block_body, scope_id);
+        }
+    }
+
+    fn create_export(
+        &self,
+        names: &[Atom<'a>],
+        value: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        self.exports.as_ref().unwrap().create_export(names, value, ctx)
+    }
+
+    fn create_temp(&mut self, ctx: &mut TraverseCtx<'a>) -> BoundIdentifier<'a> {
+        let temp = ctx.generate_uid_in_root_scope("tmp", SymbolFlags::FunctionScopedVariable);
+        self.temps.push(temp.clone());
+        temp
+    }
+
+    /// `var _tmp, _tmp2;`
+    fn create_var_declaration(
+        bindings: impl IntoIterator<Item = BoundIdentifier<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let kind = VariableDeclarationKind::Var;
+        let declarations = ctx.ast.vec_from_iter(bindings.into_iter().map(|binding| {
+            // Keep span of the original declaration
+            let span = ctx.scoping().symbol_span(binding.symbol_id);
+            let pattern = ctx.ast.binding_pattern(
+                ctx.ast.binding_pattern_kind_binding_identifier_with_symbol_id(
+                    span,
+                    binding.name,
+                    binding.symbol_id,
+                ),
+                NONE,
+                false,
+            );
+            ctx.ast.variable_declarator(SPAN, kind, pattern, None, false)
+        }));
+        if declarations.is_empty() {
+            return None;
+        }
+        Some(Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+            SPAN,
+            kind,
+            declarations,
+            false,
+        )))
+    }
+
+    /// Flush helper `import`s added by this transform, and return them as `(source, binding)` pairs.
+    ///
+    /// Runtime helper modules export the helper itself, so the binding can be a module dependency.
+    fn take_helper_imports(
+        &self,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<(Atom<'a>, BoundIdentifier<'a>)> {
+        if self.ctx.module_imports.is_empty() {
+            return vec![];
+        }
+        ModuleImports::new(self.ctx).exit_program(program, ctx);
+        TopLevelStatements::new(self.ctx).exit_program(program, ctx);
+
+        let mut imports = vec![];
+        program.body.retain(|stmt| {
+            let Statement::ImportDeclaration(decl) = stmt else { return true };
+            for specifier in decl.specifiers.iter().flatten() {
+                imports.push((
+                    decl.source.value,
+                    BoundIdentifier::from_binding_ident(specifier.local()),
+                ));
+            }
+            false
+        });
+        for (_, binding) in &imports {
+            *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                SymbolFlags::FunctionScopedVariable;
+        }
+        imports
+    }
+
+    /// Names exported explicitly, which `export *` must not overwrite.
+    fn explicit_export_names(&self) -> Vec<Atom<'a>> {
+        self.metadata
+            .local_exports
+            .values()
+            .flat_map(|export| export.names.iter().copied())
+            .chain(
+                self.metadata
+                    .sources
+                    .values()
+                    .flat_map(|source| source.reexports.iter().map(|(exported, _)| *exported)),
+            )
+            .collect()
+    }
+
+    /// Move all bindings in root scope to the scope of the module wrapper function.
+    fn move_root_bindings(scope_id: ScopeId, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let bindings = ctx
+            .scoping()
+            .get_bindings(root_scope_id)
+            .iter()
+            .map(|(&name, &symbol_id)| (ctx.ast.atom(name), symbol_id))
+            .collect::<Vec<_>>();
+        let scoping = ctx.scoping_mut();
+        for (name, symbol_id) in bindings {
+            scoping.move_binding(root_scope_id, scope_id, &name);
+            scoping.set_symbol_scope_id(symbol_id, scope_id);
+        }
+    }
+
+    /// Move program's directives into the wrapper function's body, ensuring it has `"use strict"`.
+    fn take_directives(
+        program: &mut Program<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Directive<'a>> {
+        let mut directives = program.directives.take_in(ctx.ast);
+        if !directives.iter().any(Directive::is_use_strict) {
+            directives.insert(0, ctx.ast.use_strict_directive());
+        }
+        directives
+    }
+
+    /// The imported module: `_foo`, or `_foo()` if it is loaded lazily.
+    fn create_source_object(
+        source: &Source<'a>,
+        span: Span,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let object = source.binding.create_spanned_read_expression(span, ctx);
+        if source.lazy {
+            ctx.ast.expression_call(span, object, NONE, ctx.ast.vec(), false)
+        } else {
+            object
+        }
+    }
+
+    /// Property of the imported module which holds an imported name. `None` for the module itself.
+    ///
+    /// With `importInterop: "node"`, the default import is the module itself.
+    fn imported_member(&self, imported: Option<Atom<'a>>) -> Option<Atom<'a>> {
+        imported.filter(|name| !(self.import_interop.is_node() && name == "default"))
+    }
+
+    /// Convert a module object to a namespace object, according to `importInterop`.
+    ///
+    /// * babel: `babelHelpers.interopRequireWildcard(module)`
+    /// * node: `babelHelpers.interopRequireWildcard(module, true)`
+    /// * none: `module`
+    fn interop_require_wildcard(
+        &self,
+        module: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut arguments = ctx.ast.vec1(Argument::from(module));
+        match self.import_interop {
+            ImportInterop::Babel => {}
+            ImportInterop::Node => {
+                arguments.push(Argument::from(ctx.ast.expression_boolean_literal(SPAN, true)));
+            }
+            ImportInterop::None => return arguments.pop().unwrap().into_expression(),
+        }
+        self.ctx.helper_call_expr(Helper::InteropRequireWildcard, SPAN, arguments, ctx)
+    }
+
+    /// Convert the value of a module to what its imports need, according to `importInterop`.
+    ///
+    /// `module` -> `babelHelpers.interopRequireDefault(module)` or
+    /// `babelHelpers.interopRequireWildcard(module)`, or `module` if no conversion is required.
+    fn interop_require(
+        &self,
+        source: &Source<'a>,
+        module: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if !self.requires_interop(source) {
+            return module;
+        }
+        if self.import_interop.is_babel() && !source.uses_namespace() && !source.uses_named() {
+            let arguments = ctx.ast.vec1(Argument::from(module));
+            self.ctx.helper_call_expr(Helper::InteropRequireDefault, SPAN, arguments, ctx)
+        } else {
+            self.interop_require_wildcard(module, ctx)
+        }
+    }
+
+    /// `true` if the value of the module must be converted for its imports.
+    fn requires_interop(&self, source: &Source<'a>) -> bool {
+        match self.import_interop {
+            ImportInterop::Babel => source.uses_default() || source.uses_namespace(),
+            ImportInterop::Node => source.uses_namespace(),
+            ImportInterop::None => false,
+        }
+    }
+}
+
+/// `_exports.name`, or `_exports["name"]` if `name` is not a valid identifier.
+fn create_exports_member<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> MemberExpression<'a> {
+    if is_identifier_name(&name) {
+        let property = ctx.ast.identifier_name(SPAN, name);
+        ctx.ast.member_expression_static(SPAN, object, property, false)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        ctx.ast.member_expression_computed(SPAN, object, property, false)
+    }
+}
+
+/// `object.name`, or `object["name"]` if `name` is not a valid identifier.
+fn create_member_access<'a>(
+    span: Span,
+    object: Expression<'a>,
+    name: Atom<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    if is_identifier_name(&name) {
+        create_property_access(span, object, &name, ctx)
+    } else {
+        let property = ctx.ast.expression_string_literal(SPAN, name, None);
+        Expression::from(ctx.ast.member_expression_computed(span, object, property, false))
+    }
+}
+
+/// `function (a, b) { body }`
+fn create_function_expression<'a>(
+    params: &[&BoundIdentifier<'a>],
+    directives: ArenaVec<'a, Directive<'a>>,
+    body: ArenaVec<'a, Statement<'a>>,
+    scope_id: ScopeId,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let items = ctx.ast.vec_from_iter(
+        params
+            .iter()
+            .map(|param| ctx.ast.plain_formal_parameter(SPAN, param.create_binding_pattern(ctx))),
+    );
+    let params = ctx.ast.formal_parameters(SPAN, FormalParameterKind::FormalParameter, items, NONE);
+    let body = ctx.ast.function_body(SPAN, directives, body);
+    Expression::FunctionExpression(ctx.ast.alloc_plain_function_with_scope_id(
+        FunctionType::FunctionExpression,
+        SPAN,
+        None,
+        params,
+        body,
+        scope_id,
+    ))
+}
+
+/// `Object.defineProperty(object, "name", descriptor)`
+fn create_define_property<'a>(
+    object: Expression<'a>,
+    name: Atom<'a>,
+    descriptor: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let object_ctor =
+        ctx.create_unbound_ident_expr(SPAN, Atom::from("Object"), ReferenceFlags::Read);
+    let callee = create_property_access(SPAN, object_ctor, "defineProperty", ctx);
+    let arguments = ctx.ast.vec_from_array([
+        Argument::from(object),
+        Argument::from(ctx.ast.expression_string_literal(SPAN, name, None)),
+        Argument::from(descriptor),
+    ]);
+    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+}
+
+/// `{ key: value, ... }`
+fn create_object<'a>(
+    properties: impl IntoIterator<Item = (&'static str, Expression<'a>)>,
+    ctx: &TraverseCtx<'a>,
+) -> Expression<'a> {
+    let properties = ctx.ast.vec_from_iter(properties.into_iter().map(|(key, value)| {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, key),
+            value,
+            false,
+            false,
+            false,
+        )
+    }));
+    ctx.ast.expression_object(SPAN, properties)
+}
+
+/// `typeof name`, where `name` is a global.
+fn create_typeof_global<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    let ident = ctx.create_unbound_ident_expr(SPAN, Atom::from(name), ReferenceFlags::Read);
+    ctx.ast.expression_unary(SPAN, UnaryOperator::Typeof, ident)
+}
+
+/// Collect identifiers which are assigned to by an assignment target.
+///
+/// `[a, { b, c: d = e }]` -> `a`, `b`, `d`
+fn collect_assignment_target_identifiers<'b, 'a>(
+    target: &'b AssignmentTarget<'a>,
+    idents: &mut Vec<&'b IdentifierReference<'a>>,
+) {
+    fn collect_maybe_default<'b, 'a>(
+        target: &'b AssignmentTargetMaybeDefault<'a>,
+        idents: &mut Vec<&'b IdentifierReference<'a>>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                collect_assignment_target_identifiers(&target.binding, idents);
+            }
+            target => {
+                collect_assignment_target_identifiers(target.to_assignment_target(), idents);
+            }
+        }
+    }
+
+    match target {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => idents.push(ident),
+        AssignmentTarget::ArrayAssignmentTarget(array) => {
+            for element in array.elements.iter().flatten() {
+                collect_maybe_default(element, idents);
+            }
+            if let Some(rest) = &array.rest {
+                collect_assignment_target_identifiers(&rest.target, idents);
+            }
+        }
+        AssignmentTarget::ObjectAssignmentTarget(object) => {
+            for property in &object.properties {
+                match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        idents.push(&property.binding);
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        collect_maybe_default(&property.binding, idents);
+                    }
+                }
+            }
+            if let Some(rest) = &object.rest {
+                collect_assignment_target_identifiers(&rest.target, idents);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+fn default_as_true() -> bool {
+    true
+}
+
+/// Options for [`Module::CommonJS`](crate::Module::CommonJS).
+///
+/// <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs#options>
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CommonJsOptions {
+    /// Defer evaluation of imported modules until their bindings are first used.
+    ///
+    /// <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs#lazy>
+    pub lazy: LazyImports,
+
+    /// Insert `"use strict"` at the top of the output.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub strict_mode: bool,
+
+    /// How imports are converted to the value of `require()`.
+    ///
+    /// <https://babeljs.io/docs/babel-plugin-transform-modules-commonjs#importinterop>
+    pub import_interop: ImportInterop,
+}
+
+impl Default for CommonJsOptions {
+    fn default() -> Self {
+        Self {
+            lazy: LazyImports::default(),
+            strict_mode: true,
+            import_interop: ImportInterop::Babel,
+        }
+    }
+}
+
+/// Which imported modules are evaluated lazily.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum LazyImports {
+    /// `true`: modules with a bare specifier (containing no `.`), e.g. `"lodash"` but not `"./foo"`.
+    /// `false`: no modules.
+    Bool(bool),
+    /// Modules with these exact specifiers.
+    Sources(Vec<String>),
+}
+
+impl Default for LazyImports {
+    fn default() -> Self {
+        Self::Bool(false)
+    }
+}
+
+impl LazyImports {
+    /// `true` if the module `source` is evaluated lazily.
+    pub fn is_lazy(&self, source: &str) -> bool {
+        match self {
+            Self::Bool(lazy) => *lazy && !source.contains('.'),
+            Self::Sources(sources) => sources.iter().any(|s| s == source),
+        }
+    }
+}
+
+/// How the value of `require()` is interpreted as an ES module.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportInterop {
+    /// Modules with `__esModule` are ES modules. For other modules, `module.exports` is the default export.
+    ///
+    /// Uses the `interopRequireDefault` and `interopRequireWildcard` helpers.
+    #[default]
+    Babel,
+    /// `module.exports` is always the default export, like Node.js does when importing CommonJS from ESM.
+    Node,
+    /// All modules are ES modules compiled to CommonJS. No helpers are used.
+    None,
+}
+
+impl ImportInterop {
+    pub fn is_babel(self) -> bool {
+        matches!(self, Self::Babel)
+    }
+
+    pub fn is_node(self) -> bool {
+        matches!(self, Self::Node)
+    }
+}
@@ -0,0 +1,417 @@
+//! SystemJS `System.register` format.
+//!
+//! ```js
+//! System.register(["foo"], function (_export, _context) {
+//!   "use strict";
+//!   var a, count;
+//!   function inc() { _export("count", ++count); }
+//!   _export("inc", inc);
+//!   return {
+//!     setters: [function (_foo) { a = _foo.a; }],
+//!     execute: function () {
+//!       _export("count", count = 0);
+//!     }
+//!   };
+//! });
+//! ```
+//!
+//! Imports are variables which are updated by the module's `setters` whenever the imported module's
+//! exports change. Top-level declarations are hoisted to the register function, so they are visible
+//! to hoisted functions and setters. `let`, `const` and `class` declarations become assignments.
+//!
+//! * `import.meta` -> `_context.meta`
+//! * `import(source)` -> `_context.import(source)`
+
+use oxc_allocator::TakeIn;
+use oxc_ast::{NONE, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::{ReferenceFlags, ScopeFlags, ScopeId, SymbolFlags};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{
+    context::TraverseCtx,
+    utils::{
+        ast_builder::{create_assignment, create_property_access},
+        scope_reparenter::ScopeReparenter,
+        var_hoister::VarHoister,
+    },
+};
+
+use super::{
+    ModuleTransform, create_function_expression, create_member_access, create_object,
+    metadata::Source,
+};
+
+impl<'a> ModuleTransform<'a, '_> {
+    /// Convert top-level declarations to assignments, recording their bindings to be hoisted.
+    ///
+    /// * `let a = 1;` -> `a = 1;`
+    /// * `class C {}` -> `C = class {};`
+    pub(super) fn system_js_hoist_declarations(
+        &mut self,
+        program: &mut Program<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for source in self.metadata.sources.values() {
+            for (_, local) in &source.imports {
+                *ctx.scoping_mut().symbol_flags_mut(local.symbol_id) =
+                    SymbolFlags::FunctionScopedVariable;
+                self.system_hoisted.push(local.clone());
+            }
+        }
+
+        for stmt in &mut program.body {
+            match stmt {
+                Statement::VariableDeclaration(decl)
+                    if matches!(
+                        decl.kind,
+                        VariableDeclarationKind::Let | VariableDeclarationKind::Const
+                    ) =>
+                {
+                    // Converted to assignments by `VarHoister` below
+                    decl.kind = VariableDeclarationKind::Var;
+                    for declarator in &mut decl.declarations {
+                        declarator.kind = VariableDeclarationKind::Var;
+                        declarator.id.bound_names(&mut |ident| {
+                            *ctx.scoping_mut().symbol_flags_mut(ident.symbol_id()) =
+                                SymbolFlags::FunctionScopedVariable;
+                        });
+                    }
+                }
+                Statement::ClassDeclaration(class) if !class.declare => {
+                    let id = class.id.take().unwrap();
+                    let binding = BoundIdentifier::from_binding_ident(&id);
+                    *ctx.scoping_mut().symbol_flags_mut(binding.symbol_id) =
+                        SymbolFlags::FunctionScopedVariable;
+                    class.r#type = ClassType::ClassExpression;
+                    let span = class.span;
+                    let Statement::ClassDeclaration(class) = stmt.take_in(ctx.ast) else {
+                        unreachable!()
+                    };
+                    let target = binding.create_spanned_write_target(id.span, ctx);
+                    let assign = ctx.ast.expression_assignment(
+                        span,
+                        AssignmentOperator::Assign,
+                        target,
+                        Expression::ClassExpression(class),
+                    );
+                    *stmt = ctx.ast.statement_expression(span, assign);
+                    self.system_hoisted.push(binding);
+                }
+                _ => {}
+            }
+        }
+
+        let mut hoister = VarHoister::new(ctx);
+        hoister.visit_statements(&mut program.body);
+        self.system_hoisted.extend(hoister.vars.into_iter().map(|(_, binding)| binding));
+    }
+
+    pub(super) fn wrap_system_js(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let flags = ScopeFlags::Function | ScopeFlags::StrictMode;
+        let register_scope_id = ctx.create_child_scope(root_scope_id, flags);
+        let execute_scope_id = ctx.create_child_scope(register_scope_id, flags);
+
+        let mut top_level_await = TopLevelAwait(false);
+        top_level_await.visit_statements(&program.body);
+
+        // Function declarations are hoisted to the register function, everything else is executed
+        let mut functions = vec![];
+        let mut execute_body = ctx.ast.vec();
+        for stmt in program.body.take_in(ctx.ast) {
+            if matches!(stmt, Statement::FunctionDeclaration(_)) {
+                ScopeReparenter::new(ctx.scoping_mut(), register_scope_id).visit_statement(&stmt);
+                functions.push(stmt);
+            } else {
+                ScopeReparenter::new(ctx.scoping_mut(), execute_scope_id).visit_statement(&stmt);
+                execute_body.push(stmt);
+            }
+        }
+        Self::move_root_bindings(register_scope_id, ctx);
+
+        let mut body = ctx.ast.vec();
+        let hoisted = self.system_hoisted.drain(..).chain(self.temps.drain(..));
+        body.extend(Self::create_var_declaration(hoisted, ctx));
+        body.extend(functions);
+
+        // `_export("f", f);`
+        for export in self.metadata.local_exports.values() {
+            if ctx.scoping().symbol_flags(export.binding.symbol_id).is_function() {
+                let value = export.binding.create_read_expression(ctx);
+                let expr = self.create_export(&export.names, value, ctx);
+                body.push(ctx.ast.statement_expression(SPAN, expr));
+            }
+        }
+
+        // `return { setters: [...], execute: function () { ... } };`
+        let explicit_names = self.explicit_export_names();
+        let setters = self
+            .metadata
+            .sources
+            .values()
+            .map(|source| {
+                let setter = self.create_setter(source, register_scope_id, &explicit_names, ctx);
+                ArrayExpressionElement::from(setter)
+            })
+            .collect::<Vec<_>>();
+        let setters = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(setters));
+        let mut execute =
+            create_function_expression(&[], ctx.ast.vec(), execute_body, execute_scope_id, ctx);
+        if top_level_await.0 {
+            let Expression::FunctionExpression(func) = &mut execute else { unreachable!() };
+            func.r#async = true;
+        }
+        let result = create_object([("setters", setters), ("execute", execute)], ctx);
+        body.push(ctx.ast.statement_return(SPAN, Some(result)));
+
+        let export = self.exports.as_ref().unwrap().binding();
+        let context = self.system_context.as_ref().unwrap();
+        let directives = Self::take_directives(program, ctx);
+        let register = create_function_expression(
+            &[export, context],
+            directives,
+            body,
+            register_scope_id,
+            ctx,
+        );
+
+        // `System.register(["foo"], function (_export, _context) { ... });`
+        let dependencies = ctx.ast.vec_from_iter(self.metadata.sources.keys().map(|source| {
+            ArrayExpressionElement::from(ctx.ast.expression_string_literal(SPAN, *source, None))
+        }));
+        let dependencies = ctx.ast.expression_array(SPAN, dependencies);
+        let system =
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("System"), ReferenceFlags::Read);
+        let callee = create_property_access(SPAN, system, "register", ctx);
+        let arguments =
+            ctx.ast.vec_from_array([Argument::from(dependencies), Argument::from(register)]);
+        let call = ctx.ast.expression_call(SPAN, callee, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    /// ```js
+    /// function (_foo) {
+    ///   a = _foo.a;
+    ///   ns = _foo;
+    ///   _export({ b: _foo.b, c: _foo.c });
+    /// }
+    /// ```
+    fn create_setter(
+        &self,
+        source: &Source<'a>,
+        register_scope_id: ScopeId,
+        explicit_names: &[Atom<'a>],
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let scope_id = ctx
+            .create_child_scope(register_scope_id, ScopeFlags::Function | ScopeFlags::StrictMode);
+        let module = &source.binding;
+        ctx.scoping_mut().move_binding(register_scope_id, scope_id, &module.name);
+        ctx.scoping_mut().set_symbol_scope_id(module.symbol_id, scope_id);
+
+        let mut body = ctx.ast.vec();
+        for (imported, local) in &source.imports {
+            let value = module.create_read_expression(ctx);
+            let value = match imported {
+                Some(imported) => create_member_access(SPAN, value, *imported, ctx),
+                None => value,
+            };
+            let assign = create_assignment(local, value, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, assign));
+        }
+
+        let reexports = source
+            .reexports
+            .iter()
+            .map(|&(exported, imported)| {
+                let value = module.create_read_expression(ctx);
+                let value = match imported {
+                    Some(imported) => create_member_access(SPAN, value, imported, ctx),
+                    None => value,
+                };
+                (exported, value)
+            })
+            .collect::<Vec<_>>();
+
+        let exports = self.exports.as_ref().unwrap();
+        if source.reexport_all {
+            // `var _exportObj = {}; for (var _key in _foo) { ... } _exportObj.b = _foo.b; _export(_exportObj);`
+            let export_obj =
+                ctx.generate_uid("exportObj", scope_id, SymbolFlags::FunctionScopedVariable);
+            body.push(create_var(&export_obj, ctx.ast.expression_object(SPAN, ctx.ast.vec()), ctx));
+            body.push(create_copy_exports(&export_obj, module, explicit_names, scope_id, ctx));
+            for (exported, value) in reexports {
+                let object = export_obj.create_read_expression(ctx);
+                let target = create_member_access(SPAN, object, exported, ctx);
+                let target = SimpleAssignmentTarget::from(target.into_member_expression());
+                let assign = ctx.ast.expression_assignment(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::from(target),
+                    value,
+                );
+                body.push(ctx.ast.statement_expression(SPAN, assign));
+            }
+            let expr = create_export_call(exports, export_obj.create_read_expression(ctx), ctx);
+            body.push(ctx.ast.statement_expression(SPAN, expr));
+        } else if reexports.len() == 1 {
+            // `_export("b", _foo.b);`
+            let (exported, value) = reexports.into_iter().next().unwrap();
+            let expr = exports.create_export(&[exported], value, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, expr));
+        } else if !reexports.is_empty() {
+            // `_export({ b: _foo.b, c: _foo.c });`
+            let properties =
+                ctx.ast.vec_from_iter(reexports.into_iter().map(|(exported, value)| {
+                    ctx.ast.object_property_kind_object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        create_property_key(exported, ctx),
+                        value,
+                        false,
+                        false,
+                        false,
+                    )
+                }));
+            let object = ctx.ast.expression_object(SPAN, properties);
+            let expr = create_export_call(exports, object, ctx);
+            body.push(ctx.ast.statement_expression(SPAN, expr));
+        }
+
+        create_function_expression(&[module], ctx.ast.vec(), body, scope_id, ctx)
+    }
+}
+
+/// `_export(object)`
+fn create_export_call<'a>(
+    exports: &super::ExportsTarget<'a>,
+    object: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let callee = exports.binding().create_read_expression(ctx);
+    ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec1(Argument::from(object)), false)
+}
+
+/// `var binding = init;`
+fn create_var<'a>(
+    binding: &BoundIdentifier<'a>,
+    init: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> Statement<'a> {
+    let kind = VariableDeclarationKind::Var;
+    let pattern = binding.create_binding_pattern(ctx);
+    let declarator = ctx.ast.variable_declarator(SPAN, kind, pattern, Some(init), false);
+    Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ))
+}
+
+/// `name` or `"name"`
+fn create_property_key<'a>(name: Atom<'a>, ctx: &TraverseCtx<'a>) -> PropertyKey<'a> {
+    if oxc_syntax::identifier::is_identifier_name(&name) {
+        ctx.ast.property_key_static_identifier(SPAN, name)
+    } else {
+        PropertyKey::from(ctx.ast.expression_string_literal(SPAN, name, None))
+    }
+}
+
+/// ```js
+/// for (var _key in _foo) {
+///   if (_key !== "default" && _key !== "__esModule" && _key !== "a") _exportObj[_key] = _foo[_key];
+/// }
+/// ```
+fn create_copy_exports<'a>(
+    export_obj: &BoundIdentifier<'a>,
+    module: &BoundIdentifier<'a>,
+    explicit_names: &[Atom<'a>],
+    scope_id: ScopeId,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let key = ctx.generate_uid("key", scope_id, SymbolFlags::FunctionScopedVariable);
+    let for_scope_id = ctx.create_child_scope(scope_id, ScopeFlags::empty());
+    let block_scope_id = ctx.create_child_scope(for_scope_id, ScopeFlags::empty());
+
+    let test = ["default", "__esModule"]
+        .into_iter()
+        .map(Atom::from)
+        .chain(explicit_names.iter().copied().filter(|name| name != "default"))
+        .map(|name| {
+            let left = key.create_read_expression(ctx);
+            let right = ctx.ast.expression_string_literal(SPAN, name, None);
+            ctx.ast.expression_binary(SPAN, left, BinaryOperator::StrictInequality, right)
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .reduce(|left, right| ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right))
+        .unwrap();
+
+    let target = ctx.ast.member_expression_computed(
+        SPAN,
+        export_obj.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    );
+    let value = Expression::from(ctx.ast.member_expression_computed(
+        SPAN,
+        module.create_read_expression(ctx),
+        key.create_read_expression(ctx),
+        false,
+    ));
+    let assign = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(SimpleAssignmentTarget::from(target)),
+        value,
+    );
+    let if_stmt =
+        ctx.ast.statement_if(SPAN, test, ctx.ast.statement_expression(SPAN, assign), None);
+    let body = ctx.ast.statement_block_with_scope_id(SPAN, ctx.ast.vec1(if_stmt), block_scope_id);
+
+    let kind = VariableDeclarationKind::Var;
+    let declarator =
+        ctx.ast.variable_declarator(SPAN, kind, key.create_binding_pattern(ctx), None, false);
+    let left = ForStatementLeft::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+        SPAN,
+        kind,
+        ctx.ast.vec1(declarator),
+        false,
+    ));
+    let right = module.create_read_expression(ctx);
+    ctx.ast.statement_for_in_with_scope_id(SPAN, left, right, body, for_scope_id)
+}
+
+/// Finds `await` at top level of a module.
+struct TopLevelAwait(bool);
+
+impl<'a> Visit<'a> for TopLevelAwait {
+    fn visit_await_expression(&mut self, _expr: &AwaitExpression<'a>) {
+        self.0 = true;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await {
+            self.0 = true;
+        } else {
+            walk::walk_for_of_statement(self, stmt);
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind == VariableDeclarationKind::AwaitUsing {
+            self.0 = true;
+        } else {
+            walk::walk_variable_declaration(self, decl);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &ArrowFunctionExpression<'a>) {}
+}
@@ -0,0 +1,327 @@
+//! UMD (Universal Module Definition).
+//!
+//! The module can be loaded by an AMD loader, as CommonJS, or as a script which reads its dependencies
+//! from, and sets its exports on, the global object.
+//!
+//! ```js
+//! (function (global, factory) {
+//!   if (typeof define === "function" && define.amd) {
+//!     define(["exports", "foo"], factory);
+//!   } else if (typeof exports !== "undefined") {
+//!     factory(exports, require("foo"));
+//!   } else {
+//!     var mod = { exports: {} };
+//!     factory(mod.exports, global.foo);
+//!     global.input = mod.exports;
+//!   }
+//! })(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function (_exports, _foo) {
+//!   "use strict";
+//!   // ...
+//! });
+//! ```
+//!
+//! * `import.meta.url` ->
+//!   `(typeof document === "undefined" ? require("url").pathToFileURL(__filename).href : document.currentScript && document.currentScript.src || document.baseURI)`
+//! * `import(source)` is left as is, as there is no loader-agnostic way to load a module asynchronously.
+
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use oxc_traverse::BoundIdentifier;
+
+use crate::{context::TraverseCtx, utils::ast_builder::create_property_access};
+
+use super::{
+    ExportsTarget, ModuleTransform, create_function_expression, create_object,
+    create_typeof_global, metadata::module_name,
+};
+
+impl<'a> ModuleTransform<'a, '_> {
+    pub(super) fn wrap_umd(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let header = self.create_header(ctx);
+        let helpers = self.take_helper_imports(program, ctx);
+        let body = self.create_module_body(header, program, ctx);
+        let factory_scope_id = ctx.insert_scope_below_statements(&body, ScopeFlags::Function);
+        *ctx.scoping_mut().scope_flags_mut(factory_scope_id) |= ScopeFlags::StrictMode;
+        Self::move_root_bindings(factory_scope_id, ctx);
+
+        let exports = match &self.exports {
+            Some(ExportsTarget::Object(exports)) => Some(exports),
+            _ => None,
+        };
+        let dependencies = self
+            .metadata
+            .sources
+            .iter()
+            .map(|(source, metadata)| (*source, &metadata.binding))
+            .chain(helpers.iter().map(|(source, binding)| (*source, binding)))
+            .collect::<Vec<_>>();
+
+        let factory = {
+            let params = exports
+                .into_iter()
+                .chain(dependencies.iter().map(|(_, binding)| *binding))
+                .collect::<Vec<_>>();
+            let directives = Self::take_directives(program, ctx);
+            create_function_expression(&params, directives, body, factory_scope_id, ctx)
+        };
+
+        let root_scope_id = ctx.scoping().root_scope_id();
+        let wrapper_scope_id = ctx.create_child_scope(root_scope_id, ScopeFlags::Function);
+        let global = ctx.generate_binding(
+            Atom::from("global"),
+            wrapper_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+        let factory_binding = ctx.generate_binding(
+            Atom::from("factory"),
+            wrapper_scope_id,
+            SymbolFlags::FunctionScopedVariable,
+        );
+
+        // `define(["exports", "foo"], factory);`
+        let amd = {
+            let names = exports
+                .map(|_| Atom::from("exports"))
+                .into_iter()
+                .chain(dependencies.iter().map(|(source, _)| *source))
+                .map(|source| {
+                    ArrayExpressionElement::from(
+                        ctx.ast.expression_string_literal(SPAN, source, None),
+                    )
+                });
+            let names = ctx.ast.expression_array(SPAN, ctx.ast.vec_from_iter(names));
+            let define =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+            let arguments = ctx.ast.vec_from_array([
+                Argument::from(names),
+                Argument::from(factory_binding.create_read_expression(ctx)),
+            ]);
+            ctx.ast.expression_call(SPAN, define, NONE, arguments, false)
+        };
+
+        // `factory(exports, require("foo"));`
+        let commonjs = {
+            let exports = exports.map(|_| {
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("exports"), ReferenceFlags::Read)
+            });
+            let requires = dependencies
+                .iter()
+                .map(|(source, _)| {
+                    let require = ctx.create_unbound_ident_expr(
+                        SPAN,
+                        Atom::from("require"),
+                        ReferenceFlags::Read,
+                    );
+                    let source = ctx.ast.expression_string_literal(SPAN, *source, None);
+                    let arguments = ctx.ast.vec1(Argument::from(source));
+                    ctx.ast.expression_call(SPAN, require, NONE, arguments, false)
+                })
+                .collect::<Vec<_>>();
+            let arguments = exports.into_iter().chain(requires).map(Argument::from);
+            let callee = factory_binding.create_read_expression(ctx);
+            ctx.ast.expression_call(SPAN, callee, NONE, ctx.ast.vec_from_iter(arguments), false)
+        };
+
+        // `var mod = { exports: {} }; factory(mod.exports, global.foo); global.input = mod.exports;`
+        let globals = {
+            let module = ctx.generate_binding(
+                Atom::from("mod"),
+                wrapper_scope_id,
+                SymbolFlags::FunctionScopedVariable,
+            );
+            let declaration = {
+                let init = create_object(
+                    [("exports", ctx.ast.expression_object(SPAN, ctx.ast.vec()))],
+                    ctx,
+                );
+                let kind = VariableDeclarationKind::Var;
+                let declarator = ctx.ast.variable_declarator(
+                    SPAN,
+                    kind,
+                    module.create_binding_pattern(ctx),
+                    Some(init),
+                    false,
+                );
+                Statement::VariableDeclaration(ctx.ast.alloc_variable_declaration(
+                    SPAN,
+                    kind,
+                    ctx.ast.vec1(declarator),
+                    false,
+                ))
+            };
+            let module_exports = |ctx: &mut TraverseCtx<'a>| {
+                create_property_access(SPAN, module.create_read_expression(ctx), "exports", ctx)
+            };
+            let exports = exports.map(|_| module_exports(ctx));
+            let dependencies = dependencies
+                .iter()
+                .map(|(source, _)| create_global_member(&global, &module_name(source), ctx))
+                .collect::<Vec<_>>();
+            let arguments = exports.into_iter().chain(dependencies).map(Argument::from);
+            let callee = factory_binding.create_read_expression(ctx);
+            let call = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec_from_iter(arguments),
+                false,
+            );
+
+            let target = create_global_member(&global, &module_name(&self.ctx.filename), ctx);
+            let Expression::StaticMemberExpression(target) = target else { unreachable!() };
+            let assign = ctx.ast.expression_assignment(
+                SPAN,
+                AssignmentOperator::Assign,
+                AssignmentTarget::StaticMemberExpression(target),
+                module_exports(ctx),
+            );
+            ctx.ast.vec_from_array([
+                declaration,
+                ctx.ast.statement_expression(SPAN, call),
+                ctx.ast.statement_expression(SPAN, assign),
+            ])
+        };
+
+        // `if (typeof define === "function" && define.amd) { ... } else if (typeof exports !== "undefined") { ... } else { ... }`
+        let if_statement = {
+            let create_block = |stmts, ctx: &mut TraverseCtx<'a>| {
+                let scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::empty());
+                ctx.ast.statement_block_with_scope_id(SPAN, stmts, scope_id)
+            };
+            let amd_test = {
+                let is_function = ctx.ast.expression_binary(
+                    SPAN,
+                    create_typeof_global("define", ctx),
+                    BinaryOperator::StrictEquality,
+                    ctx.ast.expression_string_literal(SPAN, "function", None),
+                );
+                let define =
+                    ctx.create_unbound_ident_expr(SPAN, Atom::from("define"), ReferenceFlags::Read);
+                let define_amd = create_property_access(SPAN, define, "amd", ctx);
+                ctx.ast.expression_logical(SPAN, is_function, LogicalOperator::And, define_amd)
+            };
+            let commonjs_test = create_is_defined("exports", ctx);
+            let amd_block =
+                create_block(ctx.ast.vec1(ctx.ast.statement_expression(SPAN, amd)), ctx);
+            let commonjs_block =
+                create_block(ctx.ast.vec1(ctx.ast.statement_expression(SPAN, commonjs)), ctx);
+            let globals_block = create_block(globals, ctx);
+            let alternate =
+                ctx.ast.statement_if(SPAN, commonjs_test, commonjs_block, Some(globals_block));
+            ctx.ast.statement_if(SPAN, amd_test, amd_block, Some(alternate))
+        };
+
+        let wrapper = create_function_expression(
+            &[&global, &factory_binding],
+            ctx.ast.vec(),
+            ctx.ast.vec1(if_statement),
+            wrapper_scope_id,
+            ctx,
+        );
+        let wrapper = ctx.ast.expression_parenthesized(SPAN, wrapper);
+
+        // `typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this`
+        let global_object = {
+            let self_test = create_is_defined("self", ctx);
+            let self_ident =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("self"), ReferenceFlags::Read);
+            let self_or_this = ctx.ast.expression_conditional(
+                SPAN,
+                self_test,
+                self_ident,
+                ctx.ast.expression_this(SPAN),
+            );
+            let global_this_test = create_is_defined("globalThis", ctx);
+            let global_this =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("globalThis"), ReferenceFlags::Read);
+            ctx.ast.expression_conditional(SPAN, global_this_test, global_this, self_or_this)
+        };
+
+        let arguments =
+            ctx.ast.vec_from_array([Argument::from(global_object), Argument::from(factory)]);
+        let call = ctx.ast.expression_call(SPAN, wrapper, NONE, arguments, false);
+        program.body.push(ctx.ast.statement_expression(SPAN, call));
+    }
+
+    /// `import.meta.url` ->
+    /// `(typeof document === "undefined" ? require("url").pathToFileURL(__filename).href : document.currentScript && document.currentScript.src || document.baseURI)`
+    pub(super) fn umd_import_meta_url(span: Span, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let document = |ctx: &mut TraverseCtx<'a>| {
+            ctx.create_unbound_ident_expr(SPAN, Atom::from("document"), ReferenceFlags::Read)
+        };
+
+        let test = ctx.ast.expression_binary(
+            SPAN,
+            create_typeof_global("document", ctx),
+            BinaryOperator::StrictEquality,
+            ctx.ast.expression_string_literal(SPAN, "undefined", None),
+        );
+
+        // `require("url").pathToFileURL(__filename).href`
+        let file_url = {
+            let require =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("require"), ReferenceFlags::Read);
+            let url = ctx.ast.expression_string_literal(SPAN, "url", None);
+            let url = ctx.ast.expression_call(
+                SPAN,
+                require,
+                NONE,
+                ctx.ast.vec1(Argument::from(url)),
+                false,
+            );
+            let callee = create_property_access(SPAN, url, "pathToFileURL", ctx);
+            let filename =
+                ctx.create_unbound_ident_expr(SPAN, Atom::from("__filename"), ReferenceFlags::Read);
+            let call = ctx.ast.expression_call(
+                SPAN,
+                callee,
+                NONE,
+                ctx.ast.vec1(Argument::from(filename)),
+                false,
+            );
+            create_property_access(SPAN, call, "href", ctx)
+        };
+
+        // `document.currentScript && document.currentScript.src || document.baseURI`
+        let script_url = {
+            let current_script = create_property_access(SPAN, document(ctx), "currentScript", ctx);
+            let current_script_src = {
+                let current_script =
+                    create_property_access(SPAN, document(ctx), "currentScript", ctx);
+                create_property_access(SPAN, current_script, "src", ctx)
+            };
+            let src = ctx.ast.expression_logical(
+                SPAN,
+                current_script,
+                LogicalOperator::And,
+                current_script_src,
+            );
+            let base_uri = create_property_access(SPAN, document(ctx), "baseURI", ctx);
+            ctx.ast.expression_logical(SPAN, src, LogicalOperator::Or, base_uri)
+        };
+
+        let url = ctx.ast.expression_conditional(SPAN, test, file_url, script_url);
+        ctx.ast.expression_parenthesized(span, url)
+    }
+}
+
+/// `typeof name !== "undefined"`
+fn create_is_defined<'a>(name: &'static str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    ctx.ast.expression_binary(
+        SPAN,
+        create_typeof_global(name, ctx),
+        BinaryOperator::StrictInequality,
+        ctx.ast.expression_string_literal(SPAN, "undefined", None),
+    )
+}
+
+/// `global.name`
+fn create_global_member<'a>(
+    global: &BoundIdentifier<'a>,
+    name: &str,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    create_property_access(SPAN, global.create_read_expression(ctx), name, ctx)
+}
@@ -178,6 +178,10 @@ impl BabelOptions {
         self.plugins.syntax_typescript.is_some()
     }
 
+    pub fn is_flow(&self) -> bool {
+        self.plugins.syntax_flow || self.plugins.flow.is_some()
+    }
+
     pub fn is_typescript_definition(&self) -> bool {
         self.plugins.syntax_typescript.is_some_and(|o| o.dts)
     }
@@ -1,8 +1,16 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, FlowOptions, TypeScriptOptions,
+    es2015::{
+        ArrowFunctionsOptions, BlockScopingOptions, ClassesOptions, ComputedPropertiesOptions,
+        DestructuringOptions, ForOfOptions, ParametersOptions, SpreadOptions,
+        TemplateLiteralsOptions,
+    },
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::JsxOptions,
+    module::CommonJsOptions,
 };
 
 use super::PluginPresetEntries;
@@ -27,11 +35,14 @@ pub struct BabelPlugins {
     // syntax
     pub syntax_typescript: Option<SyntaxTypeScriptOptions>,
     pub syntax_jsx: bool,
+    pub syntax_flow: bool,
     // decorators
     pub syntax_decorators: Option<SyntaxDecoratorOptions>,
     pub proposal_decorators: Option<SyntaxDecoratorOptions>,
     // ts
     pub typescript: Option<TypeScriptOptions>,
+    // flow
+    pub flow: Option<FlowOptions>,
     // jsx
     pub react_jsx: Option<JsxOptions>,
     pub react_jsx_dev: Option<JsxOptions>,
@@ -39,7 +50,10 @@ pub struct BabelPlugins {
     pub react_jsx_source: bool,
     pub react_display_name: bool,
     // modules
-    pub modules_commonjs: bool,
+    pub modules_commonjs: Option<CommonJsOptions>,
+    pub modules_amd: bool,
+    pub modules_umd: bool,
+    pub modules_systemjs: bool,
     // regexp
     pub sticky_flag: bool,
     pub unicode_flag: bool,
@@ -52,6 +66,16 @@ pub struct BabelPlugins {
     pub set_notation: bool,
     // ES2015
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    pub block_scoping: Option<BlockScopingOptions>,
+    pub classes: Option<ClassesOptions>,
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    pub destructuring: Option<DestructuringOptions>,
+    pub for_of: Option<ForOfOptions>,
+    pub generators: bool,
+    pub parameters: Option<ParametersOptions>,
+    pub shorthand_properties: bool,
+    pub spread: Option<SpreadOptions>,
+    pub template_literals: Option<TemplateLiteralsOptions>,
     // ES2016
     pub exponentiation_operator: bool,
     // ES2017
@@ -86,6 +110,7 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                     p.syntax_typescript = Some(entry.value::<SyntaxTypeScriptOptions>()?);
                 }
                 "jsx" | "syntax-jsx" => p.syntax_jsx = true,
+                "flow" | "syntax-flow" => p.syntax_flow = true,
                 "syntax-decorators" => {
                     p.syntax_decorators = Some(entry.value::<SyntaxDecoratorOptions>()?);
                 }
@@ -96,6 +121,9 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                     p.typescript =
                         entry.value::<TypeScriptOptions>().map_err(|err| p.errors.push(err)).ok();
                 }
+                "transform-flow-strip-types" => {
+                    p.flow = entry.value::<FlowOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
                 "transform-react-jsx" => {
                     #[derive(Deserialize, Default)]
                     struct Pure {
@@ -120,7 +148,13 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
-                "transform-modules-commonjs" => p.modules_commonjs = true,
+                "transform-modules-commonjs" => {
+                    p.modules_commonjs =
+                        entry.value::<CommonJsOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-modules-amd" => p.modules_amd = true,
+                "transform-modules-umd" => p.modules_umd = true,
+                "transform-modules-systemjs" => p.modules_systemjs = true,
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
                 "transform-dotall-regex" => p.dot_all_flag = true,
@@ -135,6 +169,45 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                         .map_err(|err| p.errors.push(err))
                         .ok();
                 }
+                "transform-block-scoping" => {
+                    p.block_scoping =
+                        entry.value::<BlockScopingOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-classes" => {
+                    p.classes =
+                        entry.value::<ClassesOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-computed-properties" => {
+                    p.computed_properties = entry
+                        .value::<ComputedPropertiesOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-destructuring" => {
+                    p.destructuring = entry
+                        .value::<DestructuringOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
+                "transform-for-of" => {
+                    p.for_of = entry.value::<ForOfOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-regenerator" => p.generators = true,
+                "transform-parameters" => {
+                    p.parameters =
+                        entry.value::<ParametersOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-shorthand-properties" => p.shorthand_properties = true,
+                "transform-spread" => {
+                    p.spread =
+                        entry.value::<SpreadOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "transform-template-literals" => {
+                    p.template_literals = entry
+                        .value::<TemplateLiteralsOptions>()
+                        .map_err(|err| p.errors.push(err))
+                        .ok();
+                }
                 "transform-exponentiation-operator" => p.exponentiation_operator = true,
                 "transform-async-to-generator" => p.async_to_generator = true,
                 "transform-object-rest-spread" => {
@@ -13,7 +13,9 @@ use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::ES2022Options,
+    flow::FlowOptions,
     jsx::JsxOptions,
+    module::CommonJsOptions,
     proposals::ProposalOptions,
     regexp::RegExpOptions,
     typescript::TypeScriptOptions,
@@ -55,6 +57,11 @@ pub struct TransformOptions {
     /// [preset-typescript](https://babeljs.io/docs/babel-preset-typescript)
     pub typescript: TypeScriptOptions,
 
+    /// [plugin-transform-flow-strip-types](https://babeljs.io/docs/babel-plugin-transform-flow-strip-types)
+    ///
+    /// Only applies to JavaScript files, which must be parsed with `ParseOptions::flow` enabled.
+    pub flow: Option<FlowOptions>,
+
     /// Decorator
     pub decorator: DecoratorOptions,
 
@@ -69,6 +76,11 @@ pub struct TransformOptions {
     /// Proposals
     pub proposals: ProposalOptions,
 
+    /// [plugin-transform-modules-commonjs](https://babeljs.io/docs/babel-plugin-transform-modules-commonjs)
+    ///
+    /// Only used when [`EnvOptions::module`] is [`Module::CommonJS`].
+    pub commonjs: CommonJsOptions,
+
     pub helper_loader: HelperLoaderOptions,
 }
 
@@ -82,7 +94,12 @@ impl TransformOptions {
             cwd: PathBuf::new(),
             assumptions: CompilerAssumptions::default(),
             typescript: TypeScriptOptions::default(),
-            decorator: DecoratorOptions { legacy: true, emit_decorator_metadata: true },
+            flow: None,
+            decorator: DecoratorOptions {
+                legacy: true,
+                standard: false,
+                emit_decorator_metadata: true,
+            },
             jsx: JsxOptions {
                 development: true,
                 refresh: Some(ReactRefreshOptions::default()),
@@ -90,6 +107,7 @@ impl TransformOptions {
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
             proposals: ProposalOptions::default(),
+            commonjs: CommonJsOptions::default(),
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -157,6 +175,11 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let decorator = DecoratorOptions {
             legacy: options.plugins.legacy_decorator.is_some(),
+            standard: options
+                .plugins
+                .proposal_decorators
+                .as_ref()
+                .is_some_and(|o| o.version == "2023-11"),
             emit_decorator_metadata: options
                 .plugins
                 .legacy_decorator
@@ -203,6 +226,20 @@ impl TryFrom<&BabelOptions> for TransformOptions {
 
         let es2015 = ES2015Options {
             arrow_function: options.plugins.arrow_function.or(env.es2015.arrow_function),
+            block_scoping: options.plugins.block_scoping.or(env.es2015.block_scoping),
+            classes: options.plugins.classes.or(env.es2015.classes),
+            computed_properties: options
+                .plugins
+                .computed_properties
+                .or(env.es2015.computed_properties),
+            destructuring: options.plugins.destructuring.or(env.es2015.destructuring),
+            for_of: options.plugins.for_of.or(env.es2015.for_of),
+            generators: options.plugins.generators || env.es2015.generators,
+            parameters: options.plugins.parameters.or(env.es2015.parameters),
+            shorthand_properties: options.plugins.shorthand_properties
+                || env.es2015.shorthand_properties,
+            spread: options.plugins.spread.or(env.es2015.spread),
+            template_literals: options.plugins.template_literals.or(env.es2015.template_literals),
         };
 
         let es2016 = ES2016Options {
@@ -262,6 +299,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             cwd: options.cwd.clone().unwrap_or_default(),
             assumptions: options.assumptions,
             typescript,
+            flow: options.plugins.flow,
             decorator,
             jsx,
             env: EnvOptions {
@@ -279,6 +317,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             proposals: ProposalOptions {
                 explicit_resource_management: options.plugins.explicit_resource_management,
             },
+            commonjs: options.plugins.modules_commonjs.clone().unwrap_or_default(),
             helper_loader,
         })
     }
@@ -25,17 +25,6 @@ impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
 }
 
 impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptModule<'a, '_> {
-    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        // In Babel, it will insert `use strict` in `@babel/transform-modules-commonjs` plugin.
-        // Once we have a commonjs plugin, we can consider moving this logic there.
-        if self.ctx.module.is_commonjs() {
-            let has_use_strict = program.directives.iter().any(Directive::is_use_strict);
-            if !has_use_strict {
-                program.directives.insert(0, ctx.ast.use_strict_directive());
-            }
-        }
-    }
-
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Statement::TSExportAssignment(export_assignment) = stmt {
             *stmt = self.transform_ts_export_assignment(export_assignment, ctx);
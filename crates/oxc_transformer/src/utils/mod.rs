@@ -1 +1,3 @@
 pub mod ast_builder;
+pub mod scope_reparenter;
+pub mod var_hoister;
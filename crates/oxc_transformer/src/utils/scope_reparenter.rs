@@ -0,0 +1,33 @@
+use std::cell::Cell;
+
+use oxc_ast_visit::Visit;
+use oxc_semantic::{ScopeFlags, ScopeId, Scoping};
+
+/// Sets the parent of the outermost scopes within the visited nodes.
+///
+/// Used when AST nodes are moved into a different scope.
+pub struct ScopeReparenter<'s> {
+    scoping: &'s mut Scoping,
+    parent_scope_id: ScopeId,
+    depth: u32,
+}
+
+impl<'s> ScopeReparenter<'s> {
+    pub fn new(scoping: &'s mut Scoping, parent_scope_id: ScopeId) -> Self {
+        Self { scoping, parent_scope_id, depth: 0 }
+    }
+}
+
+impl Visit<'_> for ScopeReparenter<'_> {
+    fn enter_scope(&mut self, _flags: ScopeFlags, scope_id: &Cell<Option<ScopeId>>) {
+        if self.depth == 0 {
+            let scope_id = scope_id.get().unwrap();
+            self.scoping.change_scope_parent_id(scope_id, Some(self.parent_scope_id));
+        }
+        self.depth += 1;
+    }
+
+    fn leave_scope(&mut self) {
+        self.depth -= 1;
+    }
+}
@@ -0,0 +1,190 @@
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::ast::*;
+use oxc_ast_visit::{VisitMut, walk_mut};
+use oxc_ecmascript::BoundNames;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{SPAN, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use oxc_traverse::BoundIdentifier;
+
+use crate::context::TraverseCtx;
+
+/// Converts `var` declarations in a function body into assignments.
+///
+/// `var a = 1, b;` -> `a = 1;`
+///
+/// Nested functions are not visited, as they have their own `var` scope.
+pub struct VarHoister<'a, 'c> {
+    ctx: &'c mut TraverseCtx<'a>,
+    /// Bindings to declare in the outer function, with the span of their first declaration.
+    pub vars: Vec<(Span, BoundIdentifier<'a>)>,
+}
+
+impl<'a, 'c> VarHoister<'a, 'c> {
+    pub fn new(ctx: &'c mut TraverseCtx<'a>) -> Self {
+        Self { ctx, vars: vec![] }
+    }
+
+    fn declare(&mut self, pattern: &BindingPattern<'a>) {
+        pattern.bound_names(&mut |ident| {
+            // Skip redeclarations of the same binding, and `var`s which redeclare a parameter
+            if self.ctx.scoping().symbol_span(ident.symbol_id()) == ident.span {
+                self.vars.push((ident.span, BoundIdentifier::from_binding_ident(ident)));
+            }
+        });
+    }
+
+    fn convert_declaration(
+        &mut self,
+        decl: &mut VariableDeclaration<'a>,
+    ) -> Option<Expression<'a>> {
+        let mut expressions = self.ctx.ast.vec();
+        for declarator in decl.declarations.take_in(self.ctx.ast) {
+            self.declare(&declarator.id);
+            if let Some(init) = declarator.init {
+                let target = binding_pattern_to_target(declarator.id, self.ctx);
+                expressions.push(self.ctx.ast.expression_assignment(
+                    declarator.span,
+                    AssignmentOperator::Assign,
+                    target,
+                    init,
+                ));
+            }
+        }
+        match expressions.len() {
+            0 => None,
+            1 => expressions.pop(),
+            _ => Some(self.ctx.ast.expression_sequence(decl.span, expressions)),
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for VarHoister<'a, '_> {
+    fn visit_statements(&mut self, stmts: &mut ArenaVec<'a, Statement<'a>>) {
+        walk_mut::walk_statements(self, stmts);
+        // Remove `var` declarations without initializers
+        stmts.retain(
+            |stmt| !matches!(stmt, Statement::EmptyStatement(empty) if empty.span.is_unspanned()),
+        );
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        walk_mut::walk_statement(self, stmt);
+        if let Statement::VariableDeclaration(decl) = stmt {
+            if decl.kind.is_var() {
+                *stmt = match self.convert_declaration(decl) {
+                    Some(expr) => self.ctx.ast.statement_expression(decl.span, expr),
+                    None => self.ctx.ast.statement_empty(SPAN),
+                };
+            }
+        }
+    }
+
+    // `for (var i = 0;;)` -> `for (i = 0;;)`
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        walk_mut::walk_for_statement(self, stmt);
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &mut stmt.init {
+            if decl.kind.is_var() {
+                stmt.init = self.convert_declaration(decl).map(ForStatementInit::from);
+            }
+        }
+    }
+
+    // `for (var k in obj)` -> `for (k in obj)`
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        walk_mut::walk_for_statement_left(self, left);
+        if let ForStatementLeft::VariableDeclaration(decl) = left {
+            if decl.kind.is_var() {
+                let declarator = decl.declarations.remove(0);
+                self.declare(&declarator.id);
+                *left = ForStatementLeft::from(binding_pattern_to_target(declarator.id, self.ctx));
+            }
+        }
+    }
+
+    fn visit_function(&mut self, _func: &mut Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _arrow: &mut ArrowFunctionExpression<'a>) {}
+
+    fn visit_static_block(&mut self, _block: &mut StaticBlock<'a>) {}
+}
+
+/// Convert a binding pattern to an assignment target, creating write references to its bindings.
+///
+/// `{ a, b: [c] = d }` -> `{ a, b: [c] = d }`
+pub fn binding_pattern_to_target<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTarget<'a> {
+    match pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => {
+            BoundIdentifier::from_binding_ident(&ident).create_spanned_write_target(ident.span, ctx)
+        }
+        BindingPatternKind::ObjectPattern(pattern) => {
+            let pattern = pattern.unbox();
+            let properties =
+                ctx.ast.vec_from_iter(pattern.properties.into_iter().map(|property| {
+                    let binding = binding_pattern_to_maybe_default(property.value, ctx);
+                    ctx.ast.assignment_target_property_assignment_target_property_property(
+                        property.span,
+                        property.key,
+                        binding,
+                        property.computed,
+                    )
+                }));
+            let rest = pattern.rest.map(|rest| {
+                let rest = rest.unbox();
+                ctx.ast.assignment_target_rest(
+                    rest.span,
+                    binding_pattern_to_target(rest.argument, ctx),
+                )
+            });
+            AssignmentTarget::from(ctx.ast.assignment_target_pattern_object_assignment_target(
+                pattern.span,
+                properties,
+                rest,
+            ))
+        }
+        BindingPatternKind::ArrayPattern(pattern) => {
+            let pattern = pattern.unbox();
+            let elements = ctx.ast.vec_from_iter(pattern.elements.into_iter().map(|element| {
+                element.map(|element| binding_pattern_to_maybe_default(element, ctx))
+            }));
+            let rest = pattern.rest.map(|rest| {
+                let rest = rest.unbox();
+                ctx.ast.assignment_target_rest(
+                    rest.span,
+                    binding_pattern_to_target(rest.argument, ctx),
+                )
+            });
+            AssignmentTarget::from(ctx.ast.assignment_target_pattern_array_assignment_target(
+                pattern.span,
+                elements,
+                rest,
+            ))
+        }
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            // Only reachable via `binding_pattern_to_maybe_default`
+            let pattern = pattern.unbox();
+            binding_pattern_to_target(pattern.left, ctx)
+        }
+    }
+}
+
+fn binding_pattern_to_maybe_default<'a>(
+    pattern: BindingPattern<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> AssignmentTargetMaybeDefault<'a> {
+    match pattern.kind {
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            let pattern = pattern.unbox();
+            let binding = binding_pattern_to_target(pattern.left, ctx);
+            ctx.ast.assignment_target_maybe_default_assignment_target_with_default(
+                pattern.span,
+                binding,
+                pattern.right,
+            )
+        }
+        _ => AssignmentTargetMaybeDefault::from(binding_pattern_to_target(pattern, ctx)),
+    }
+}
@@ -11,6 +11,7 @@ fn es_target() {
 
     let cases = [
         ("es5", "() => {}"),
+        ("es5", "class A extends B { m() { return super.m(); } }"),
         ("es6", "a ** b"),
         ("es2015", "a ** b"),
         ("es2016", "async function foo() {}"),
@@ -54,6 +55,30 @@ fn es_target() {
     }
 }
 
+#[test]
+fn es5_no_lexical_declarations() {
+    let options = TransformOptions::from_target("es5").unwrap();
+    let cases = [
+        "class A extends B { m() { return super.m(); } }",
+        "export class B {}",
+        "export default class C {}",
+        "if (a) { class D {} new D(); }",
+        "for (let i = 0; i < 3; i++) { class E {} fns.push(() => [i, E]); }",
+        "try {} catch ({ message }) { const m = message; }",
+        "const { x, ...y } = z;",
+        "function g({ b, ...s }) {}",
+        "class F { [k] = 1; static [j] = 2; }",
+        "if (a) { class G { [k] = 1; } }",
+    ];
+    for case in cases {
+        let code = test(case, &options).unwrap();
+        assert!(
+            !code.contains("let ") && !code.contains("const "),
+            "ES5 output of `{case}` contains a lexical declaration:\n{code}"
+        );
+    }
+}
+
 #[test]
 fn target_list_pass() {
     // https://vite.dev/config/build-options.html#build-target
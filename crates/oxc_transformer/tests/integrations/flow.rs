@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{
+    FlowOptions, RewriteExtensionsMode, TransformOptions, Transformer, TypeScriptOptions,
+};
+
+use crate::codegen;
+
+fn test_flow(source_text: &str, options: &TransformOptions) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs())
+        .with_options(ParseOptions { flow: true, ..ParseOptions::default() })
+        .parse();
+    assert!(ret.errors.is_empty());
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new(""), options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty());
+    Codegen::new()
+        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .build(&program)
+        .code
+}
+
+#[test]
+fn flow_keeps_typescript_options() {
+    let options = TransformOptions {
+        typescript: TypeScriptOptions {
+            rewrite_import_extensions: Some(RewriteExtensionsMode::Rewrite),
+            ..TypeScriptOptions::default()
+        },
+        flow: Some(FlowOptions::default()),
+        ..TransformOptions::default()
+    };
+    let source_text = "
+        import type { T } from './types.ts';
+        import { a } from './a.ts';
+        const x: T = a;
+    ";
+    let expected = "import { a } from './a.js'; const x = a;";
+    assert_eq!(test_flow(source_text, &options), codegen(expected, SourceType::mjs()));
+}
@@ -73,6 +73,12 @@ export interface MangleOptions {
   keepNames?: boolean | MangleOptionsKeepNames
   /** Debug mangled names. */
   debug?: boolean
+  /**
+   * Mangle property names.
+   *
+   * @default undefined
+   */
+  properties?: MangleOptionsProperties
 }
 
 export interface MangleOptionsKeepNames {
@@ -90,6 +96,24 @@ export interface MangleOptionsKeepNames {
   class: boolean
 }
 
+export interface MangleOptionsProperties {
+  /**
+   * Only mangle property names matching this regular expression.
+   *
+   * All property names are mangled if not set, which is only safe if the code does not access
+   * properties of objects created outside of it.
+   */
+  regex?: string
+  /** Property names which are never mangled. */
+  reserved?: Array<string>
+  /**
+   * Do not mangle quoted property names, such as `"foo"` in `{ "foo": 1 }` or `obj["foo"]`.
+   *
+   * @default false
+   */
+  keepQuoted?: boolean
+}
+
 /** Minify synchronously. */
 export declare function minify(filename: string, sourceText: string, options?: MinifyOptions | undefined | null): MinifyResult
 
@@ -98,11 +122,25 @@ export interface MinifyOptions {
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Mangled names from a previous build, as returned in `MinifyResult.nameCache`.
+   *
+   * Top level symbols (with `mangle.toplevel`) and properties (with `mangle.properties`)
+   * are mangled to the same names as in the previous build, if possible.
+   * Pass `'{}'` in the first build. The JSON format is compatible with terser's `nameCache`.
+   */
+  nameCache?: string
 }
 
 export interface MinifyResult {
   code: string
   map?: SourceMap
+  /**
+   * Updated name cache to pass to the next build.
+   *
+   * Only returned if `nameCache` is passed.
+   */
+  nameCache?: string
 }
 export interface SourceMap {
   file?: string
@@ -16,7 +16,7 @@ use napi_derive::napi;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_minifier::Minifier;
+use oxc_minifier::{Minifier, NameCache};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -36,13 +36,23 @@ pub fn minify(
         Err(error) => return Err(napi::Error::from_reason(&error)),
     };
 
+    let name_cache = match options.name_cache.as_deref().map(NameCache::from_json).transpose() {
+        Ok(name_cache) => name_cache,
+        Err(error) => return Err(napi::Error::from_reason(error.to_string())),
+    };
+
     let allocator = Allocator::default();
 
     let source_type = SourceType::from_path(&filename).unwrap_or_default();
 
     let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
 
-    let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
+    let ret = Minifier::new(minifier_options)
+        .with_name_cache(name_cache.clone().unwrap_or_default())
+        .build(&allocator, &mut program);
+    let scoping = ret.scoping;
+    // The name cache is unchanged if nothing is mangled.
+    let name_cache = name_cache.map(|name_cache| ret.name_cache.unwrap_or(name_cache));
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -57,5 +67,9 @@ pub fn minify(
 
     let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
 
-    Ok(MinifyResult { code: ret.code, map: ret.map.map(oxc_sourcemap::napi::SourceMap::from) })
+    Ok(MinifyResult {
+        code: ret.code,
+        map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
+        name_cache: name_cache.map(|name_cache| name_cache.to_json()),
+    })
 }
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use lazy_regex::Regex;
 use napi::Either;
 use napi_derive::napi;
 
@@ -101,12 +102,18 @@ pub struct MangleOptions {
 
     /// Debug mangled names.
     pub debug: Option<bool>,
+
+    /// Mangle property names.
+    ///
+    /// @default undefined
+    pub properties: Option<MangleOptionsProperties>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        Ok(Self {
             top_level: o.toplevel.unwrap_or(default.top_level),
             keep_names: match &o.keep_names {
                 Some(Either::A(false)) => oxc_minifier::MangleOptionsKeepNames::all_false(),
@@ -115,7 +122,12 @@ impl From<&MangleOptions> for oxc_minifier::MangleOptions {
                 None => default.keep_names,
             },
             debug: o.debug.unwrap_or(default.debug),
-        }
+            mangle_props: o
+                .properties
+                .as_ref()
+                .map(oxc_minifier::MangleOptionsProps::try_from)
+                .transpose()?,
+        })
     }
 }
 
@@ -138,6 +150,35 @@ impl From<&MangleOptionsKeepNames> for oxc_minifier::MangleOptionsKeepNames {
     }
 }
 
+#[napi(object)]
+pub struct MangleOptionsProperties {
+    /// Only mangle property names matching this regular expression.
+    ///
+    /// All property names are mangled if not set, which is only safe if the code does not access
+    /// properties of objects created outside of it.
+    pub regex: Option<String>,
+
+    /// Property names which are never mangled.
+    pub reserved: Option<Vec<String>>,
+
+    /// Do not mangle quoted property names, such as `"foo"` in `{ "foo": 1 }` or `obj["foo"]`.
+    ///
+    /// @default false
+    pub keep_quoted: Option<bool>,
+}
+
+impl TryFrom<&MangleOptionsProperties> for oxc_minifier::MangleOptionsProps {
+    type Error = String;
+    fn try_from(o: &MangleOptionsProperties) -> Result<Self, Self::Error> {
+        let regex = o.regex.as_deref().map(Regex::new).transpose().map_err(|e| e.to_string())?;
+        Ok(oxc_minifier::MangleOptionsProps {
+            regex,
+            reserved: o.reserved.clone().unwrap_or_default(),
+            keep_quoted: o.keep_quoted.unwrap_or_default(),
+        })
+    }
+}
+
 #[napi(object)]
 pub struct CodegenOptions {
     /// Remove whitespace.
@@ -173,6 +214,13 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Mangled names from a previous build, as returned in `MinifyResult.nameCache`.
+    ///
+    /// Top level symbols (with `mangle.toplevel`) and properties (with `mangle.properties`)
+    /// are mangled to the same names as in the previous build, if possible.
+    /// Pass `'{}'` in the first build. The JSON format is compatible with terser's `nameCache`.
+    pub name_cache: Option<String>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -187,7 +235,7 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
         Ok(oxc_minifier::MinifierOptions { compress, mangle })
     }
@@ -198,4 +246,9 @@ pub struct MinifyResult {
     pub code: String,
 
     pub map: Option<SourceMap>,
+
+    /// Updated name cache to pass to the next build.
+    ///
+    /// Only returned if `nameCache` is passed.
+    pub name_cache: Option<String>,
 }
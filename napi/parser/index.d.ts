@@ -32,6 +32,8 @@ export declare class ParseResult {
   get program(): import("@oxc-project/types").Program
   get module(): EcmaScriptModule
   get comments(): Array<Comment>
+  /** Tokens of the source text, if `tokens` option is enabled. */
+  get tokens(): Array<Token>
   get errors(): Array<OxcError>
 }
 
@@ -215,6 +217,14 @@ export interface ParserOptions {
    * @default false
    */
   showSemanticErrors?: boolean
+  /**
+   * Collect an ESTree-compatible `tokens` array, as used by ESLint.
+   *
+   * Not supported by raw transfer.
+   *
+   * @default false
+   */
+  tokens?: boolean
 }
 
 /** Parse synchronously. */
@@ -344,6 +354,14 @@ export interface StaticImportEntry {
   isType: boolean
 }
 
+export interface Token {
+  type: 'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template' | 'PrivateIdentifier' | 'JSXIdentifier' | 'JSXText'
+  /** Source text of the token. */
+  value: string
+  start: number
+  end: number
+}
+
 export interface ValueSpan {
   value: string
   start: number
@@ -15,6 +15,7 @@ use napi_derive::napi;
 
 use oxc::{
     allocator::Allocator,
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
     parser::{ParseOptions, Parser, ParserReturn},
     semantic::SemanticBuilder,
     span::SourceType,
@@ -28,6 +29,7 @@ mod types;
 pub use raw_transfer::{
     get_buffer_offset, parse_async_raw, parse_sync_raw, raw_transfer_supported,
 };
+use types::Token;
 pub use types::{EcmaScriptModule, ParseResult, ParserOptions};
 
 mod generated {
@@ -65,6 +67,7 @@ fn parse<'a>(
     Parser::new(allocator, source_text, source_type)
         .with_options(ParseOptions {
             preserve_parens: options.preserve_parens.unwrap_or(true),
+            collect_tokens: options.tokens.unwrap_or(false),
             ..ParseOptions::default()
         })
         .parse()
@@ -89,6 +92,8 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let mut errors = OxcError::from_diagnostics(filename, &source_text, diagnostics);
 
+    let tokens = convert_tokens(&source_text, &ret.tokens);
+
     let mut comments =
         convert_utf8_to_utf16(&source_text, &mut program, &mut module_record, &mut errors);
 
@@ -120,7 +125,32 @@ fn parse_with_return(filename: &str, source_text: String, options: &ParserOption
 
     let module = EcmaScriptModule::from(&module_record);
 
-    ParseResult { program_and_fixes, module, comments, errors }
+    ParseResult { program_and_fixes, module, comments, tokens, errors }
+}
+
+/// Convert tokens to ESTree tokens, with UTF-16 offsets.
+fn convert_tokens(source_text: &str, tokens: &[oxc::parser::Token]) -> Vec<Token> {
+    if tokens.is_empty() {
+        return vec![];
+    }
+    let span_converter = Utf8ToUtf16::new(source_text);
+    let mut converter = span_converter.converter();
+    tokens
+        .iter()
+        .map(|token| {
+            let value = token.span.source_text(source_text).to_string();
+            let mut span = token.span;
+            if let Some(converter) = converter.as_mut() {
+                converter.convert_span(&mut span);
+            }
+            Token {
+                r#type: token.kind.as_str().to_string(),
+                value,
+                start: span.start,
+                end: span.end,
+            }
+        })
+        .collect()
 }
 
 /// Parse synchronously.
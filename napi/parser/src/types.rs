@@ -46,6 +46,13 @@ pub struct ParserOptions {
     ///
     /// @default false
     pub show_semantic_errors: Option<bool>,
+
+    /// Collect an ESTree-compatible `tokens` array, as used by ESLint.
+    ///
+    /// Not supported by raw transfer.
+    ///
+    /// @default false
+    pub tokens: Option<bool>,
 }
 
 #[napi]
@@ -53,6 +60,7 @@ pub struct ParseResult {
     pub(crate) program_and_fixes: String,
     pub(crate) module: EcmaScriptModule,
     pub(crate) comments: Vec<Comment>,
+    pub(crate) tokens: Vec<Token>,
     pub(crate) errors: Vec<OxcError>,
 }
 
@@ -73,6 +81,12 @@ impl ParseResult {
         mem::take(&mut self.comments)
     }
 
+    /// Tokens of the source text, if `tokens` option is enabled.
+    #[napi(getter)]
+    pub fn tokens(&mut self) -> Vec<Token> {
+        mem::take(&mut self.tokens)
+    }
+
     #[napi(getter)]
     pub fn errors(&mut self) -> Vec<OxcError> {
         mem::take(&mut self.errors)
@@ -98,6 +112,18 @@ pub struct EcmaScriptModule {
     pub import_metas: Vec<Span>,
 }
 
+#[napi(object)]
+pub struct Token {
+    #[napi(
+        ts_type = "'Boolean' | 'Identifier' | 'Keyword' | 'Null' | 'Numeric' | 'Punctuator' | 'RegularExpression' | 'String' | 'Template' | 'PrivateIdentifier' | 'JSXIdentifier' | 'JSXText'"
+    )]
+    pub r#type: String,
+    /// Source text of the token.
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+}
+
 #[napi(object)]
 pub struct Span {
     pub start: u32,
@@ -706,6 +706,24 @@ describe('parse', () => {
       expect(ret.program.body[0].range).toBeUndefined();
     });
   });
+
+  describe('tokens', () => {
+    it('should include tokens when true', () => {
+      const ret = parseSync('test.js', 'a = `😀${/x/g}` // c', { tokens: true });
+      expect(ret.tokens).toEqual([
+        { type: 'Identifier', value: 'a', start: 0, end: 1 },
+        { type: 'Punctuator', value: '=', start: 2, end: 3 },
+        { type: 'Template', value: '`😀${', start: 4, end: 9 },
+        { type: 'RegularExpression', value: '/x/g', start: 9, end: 13 },
+        { type: 'Template', value: '}`', start: 13, end: 15 },
+      ]);
+    });
+
+    it('should not include tokens by default', () => {
+      const ret = parseSync('test.js', 'a = 1');
+      expect(ret.tokens).toEqual([]);
+    });
+  });
 });
 
 describe('UTF-16 span', () => {
@@ -2,7 +2,7 @@
 // Any changes should be applied in that file too.
 
 export function wrap(result) {
-  let program, module, comments, errors;
+  let program, module, comments, tokens, errors;
   return {
     get program() {
       if (!program) program = jsonParseAst(result.program);
@@ -16,6 +16,10 @@ export function wrap(result) {
       if (!comments) comments = result.comments;
       return comments;
     },
+    get tokens() {
+      if (!tokens) tokens = result.tokens;
+      return tokens;
+    },
     get errors() {
       if (!errors) errors = result.errors;
       return errors;
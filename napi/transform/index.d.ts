@@ -48,11 +48,39 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface BlockScopingOptions {
+  /**
+   * Throw a `ReferenceError` when a binding is read before its declaration.
+   *
+   * @default false
+   */
+  tdz?: boolean
+  /**
+   * Report an error instead of moving a loop body into a function.
+   *
+   * @default false
+   */
+  throwIfClosureRequired?: boolean
+}
+
+export interface ClassesOptions {
+  /**
+   * Enables the `setClassMethods`, `superIsCallableConstructor` and `noClassCalls` assumptions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface CompilerAssumptions {
+  arrayLikeIsIterable?: boolean
   ignoreFunctionLength?: boolean
+  iterableIsArray?: boolean
+  noClassCalls?: boolean
   noDocumentAll?: boolean
   objectRestNoSymbols?: boolean
   pureGetters?: boolean
+  setClassMethods?: boolean
   /**
    * When using public class fields, assume that they don't shadow any getter in the current class,
    * in its subclasses or in its superclass. Thus, it's safe to assign them rather than using
@@ -95,6 +123,16 @@ export interface CompilerAssumptions {
    * to `true`.
    */
   setPublicClassFields?: boolean
+  superIsCallableConstructor?: boolean
+}
+
+export interface ComputedPropertiesOptions {
+  /**
+   * Enables the `setComputedProperties` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
 }
 
 export interface DecoratorOptions {
@@ -108,6 +146,16 @@ export interface DecoratorOptions {
    * @default false
    */
   legacy?: boolean
+  /**
+   * Enables the 2023-11 version of decorators, which is the version implemented by TypeScript 5.
+   *
+   * Decorators are applied at runtime by the `applyDecs2311` helper.
+   * This option is ignored when `legacy` is true.
+   *
+   * @see https://github.com/tc39/proposal-decorators
+   * @default false
+   */
+  standard?: boolean
   /**
    * Enables emitting decorator metadata.
    *
@@ -120,9 +168,65 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface DestructuringOptions {
+  /**
+   * Enables the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Enables the `arrayLikeIsIterable` assumption.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
+  /** Transform `let` and `const` declarations into `var`. */
+  blockScoping?: BlockScopingOptions
+  /** Transform classes into constructor functions. */
+  classes?: ClassesOptions
+  /** Transform computed keys of object literals. */
+  computedProperties?: ComputedPropertiesOptions
+  /** Transform destructuring patterns into plain assignments. */
+  destructuring?: DestructuringOptions
+  /** Transform `for...of` loops into loops over an iterator or an array. */
+  forOf?: ForOfOptions
+  /** Transform generator functions into state machines driven by the regenerator runtime. */
+  generators?: boolean
+  /** Transform default, rest and destructured parameters. */
+  parameters?: ParametersOptions
+  /** Transform shorthand properties and methods of object literals. */
+  shorthandProperties?: boolean
+  /** Transform spread elements in array literals, calls and `new` expressions. */
+  spread?: SpreadOptions
+  /** Transform template literals and tagged templates. */
+  templateLiterals?: TemplateLiteralsOptions
+}
+
+export interface ForOfOptions {
+  /**
+   * Enables the `skipForOfIteratorClosing` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+  /**
+   * Iterate all values by index, as if they were arrays.
+   *
+   * @default false
+   */
+  assumeArray?: boolean
+  /**
+   * Enables the `arrayLikeIsIterable` assumption.
+   *
+   * @default false
+   */
+  allowArrayLike?: boolean
 }
 
 export declare const enum HelperMode {
@@ -323,6 +427,15 @@ export interface ModuleRunnerTransformResult {
   errors: Array<OxcError>
 }
 
+export interface ParametersOptions {
+  /**
+   * Enables the `ignoreFunctionLength` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
@@ -351,6 +464,24 @@ export interface ReactRefreshOptions {
  * @returns an object containing the transformed code, source maps, and any
  * errors that occurred during parsing or transformation.
  */
+export interface SpreadOptions {
+  /**
+   * Enables the `iterableIsArray` assumption.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
+export interface TemplateLiteralsOptions {
+  /**
+   * Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+   *
+   * @default false
+   */
+  loose?: boolean
+}
+
 export declare function transform(filename: string, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
 
 /**
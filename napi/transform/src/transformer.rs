@@ -19,8 +19,8 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
-        RewriteExtensionsMode,
+        CommonJsOptions, EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime,
+        ProposalOptions, RewriteExtensionsMode,
     },
     transformer_plugins::{
         InjectGlobalVariablesConfig, InjectImport, ModuleRunnerTransform,
@@ -179,6 +179,8 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
             },
             env,
             proposals: ProposalOptions::default(),
+            commonjs: CommonJsOptions::default(),
+            flow: None,
             helper_loader: options
                 .helpers
                 .map_or_else(HelperLoaderOptions::default, HelperLoaderOptions::from),
@@ -189,10 +191,14 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
 #[napi(object)]
 #[derive(Default, Debug)]
 pub struct CompilerAssumptions {
+    pub array_like_is_iterable: Option<bool>,
     pub ignore_function_length: Option<bool>,
+    pub iterable_is_array: Option<bool>,
+    pub no_class_calls: Option<bool>,
     pub no_document_all: Option<bool>,
     pub object_rest_no_symbols: Option<bool>,
     pub pure_getters: Option<bool>,
+    pub set_class_methods: Option<bool>,
     /// When using public class fields, assume that they don't shadow any getter in the current class,
     /// in its subclasses or in its superclass. Thus, it's safe to assign them rather than using
     /// `Object.defineProperty`.
@@ -233,23 +239,33 @@ pub struct CompilerAssumptions {
     /// set both `set_public_class_fields` and [`crate::TypeScriptOptions::remove_class_fields_without_initializer`]
     /// to `true`.
     pub set_public_class_fields: Option<bool>,
+    pub super_is_callable_constructor: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
     fn from(value: CompilerAssumptions) -> Self {
         let ops = oxc::transformer::CompilerAssumptions::default();
         Self {
+            array_like_is_iterable: value
+                .array_like_is_iterable
+                .unwrap_or(ops.array_like_is_iterable),
             ignore_function_length: value
                 .ignore_function_length
                 .unwrap_or(ops.ignore_function_length),
+            iterable_is_array: value.iterable_is_array.unwrap_or(ops.iterable_is_array),
+            no_class_calls: value.no_class_calls.unwrap_or(ops.no_class_calls),
             no_document_all: value.no_document_all.unwrap_or(ops.no_document_all),
             object_rest_no_symbols: value
                 .object_rest_no_symbols
                 .unwrap_or(ops.object_rest_no_symbols),
             pure_getters: value.pure_getters.unwrap_or(ops.pure_getters),
+            set_class_methods: value.set_class_methods.unwrap_or(ops.set_class_methods),
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            super_is_callable_constructor: value
+                .super_is_callable_constructor
+                .unwrap_or(ops.super_is_callable_constructor),
             ..ops
         }
     }
@@ -369,6 +385,15 @@ pub struct DecoratorOptions {
     /// @default false
     pub legacy: Option<bool>,
 
+    /// Enables the 2023-11 version of decorators, which is the version implemented by TypeScript 5.
+    ///
+    /// Decorators are applied at runtime by the `applyDecs2311` helper.
+    /// This option is ignored when `legacy` is true.
+    ///
+    /// @see https://github.com/tc39/proposal-decorators
+    /// @default false
+    pub standard: Option<bool>,
+
     /// Enables emitting decorator metadata.
     ///
     /// This option the same as [emitDecoratorMetadata](https://www.typescriptlang.org/tsconfig/#emitDecoratorMetadata)
@@ -383,6 +408,7 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     fn from(options: DecoratorOptions) -> Self {
         oxc::transformer::DecoratorOptions {
             legacy: options.legacy.unwrap_or_default(),
+            standard: options.standard.unwrap_or_default(),
             emit_decorator_metadata: options.emit_decorator_metadata.unwrap_or_default(),
         }
     }
@@ -540,15 +566,185 @@ impl From<ArrowFunctionsOptions> for oxc::transformer::ArrowFunctionsOptions {
     }
 }
 
+#[napi(object)]
+pub struct BlockScopingOptions {
+    /// Throw a `ReferenceError` when a binding is read before its declaration.
+    ///
+    /// @default false
+    pub tdz: Option<bool>,
+    /// Report an error instead of moving a loop body into a function.
+    ///
+    /// @default false
+    pub throw_if_closure_required: Option<bool>,
+}
+
+impl From<BlockScopingOptions> for oxc::transformer::BlockScopingOptions {
+    fn from(options: BlockScopingOptions) -> Self {
+        oxc::transformer::BlockScopingOptions {
+            tdz: options.tdz.unwrap_or_default(),
+            throw_if_closure_required: options.throw_if_closure_required.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ClassesOptions {
+    /// Enables the `setClassMethods`, `superIsCallableConstructor` and `noClassCalls` assumptions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ClassesOptions> for oxc::transformer::ClassesOptions {
+    fn from(options: ClassesOptions) -> Self {
+        oxc::transformer::ClassesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct ComputedPropertiesOptions {
+    /// Enables the `setComputedProperties` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ComputedPropertiesOptions> for oxc::transformer::ComputedPropertiesOptions {
+    fn from(options: ComputedPropertiesOptions) -> Self {
+        oxc::transformer::ComputedPropertiesOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct DestructuringOptions {
+    /// Enables the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Enables the `arrayLikeIsIterable` assumption.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<DestructuringOptions> for oxc::transformer::DestructuringOptions {
+    fn from(options: DestructuringOptions) -> Self {
+        oxc::transformer::DestructuringOptions {
+            loose: options.loose.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ForOfOptions {
+    /// Enables the `skipForOfIteratorClosing` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+    /// Iterate all values by index, as if they were arrays.
+    ///
+    /// @default false
+    pub assume_array: Option<bool>,
+    /// Enables the `arrayLikeIsIterable` assumption.
+    ///
+    /// @default false
+    pub allow_array_like: Option<bool>,
+}
+
+impl From<ForOfOptions> for oxc::transformer::ForOfOptions {
+    fn from(options: ForOfOptions) -> Self {
+        oxc::transformer::ForOfOptions {
+            loose: options.loose.unwrap_or_default(),
+            assume_array: options.assume_array.unwrap_or_default(),
+            allow_array_like: options.allow_array_like.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ParametersOptions {
+    /// Enables the `ignoreFunctionLength` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<ParametersOptions> for oxc::transformer::ParametersOptions {
+    fn from(options: ParametersOptions) -> Self {
+        oxc::transformer::ParametersOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct SpreadOptions {
+    /// Enables the `iterableIsArray` assumption.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<SpreadOptions> for oxc::transformer::SpreadOptions {
+    fn from(options: SpreadOptions) -> Self {
+        oxc::transformer::SpreadOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
+#[napi(object)]
+pub struct TemplateLiteralsOptions {
+    /// Enables the `ignoreToPrimitiveHint` and `mutableTemplateObject` assumptions.
+    ///
+    /// @default false
+    pub loose: Option<bool>,
+}
+
+impl From<TemplateLiteralsOptions> for oxc::transformer::TemplateLiteralsOptions {
+    fn from(options: TemplateLiteralsOptions) -> Self {
+        oxc::transformer::TemplateLiteralsOptions { loose: options.loose.unwrap_or_default() }
+    }
+}
+
 #[napi(object)]
 pub struct Es2015Options {
     /// Transform arrow functions into function expressions.
     pub arrow_function: Option<ArrowFunctionsOptions>,
+    /// Transform `let` and `const` declarations into `var`.
+    pub block_scoping: Option<BlockScopingOptions>,
+    /// Transform classes into constructor functions.
+    pub classes: Option<ClassesOptions>,
+    /// Transform computed keys of object literals.
+    pub computed_properties: Option<ComputedPropertiesOptions>,
+    /// Transform destructuring patterns into plain assignments.
+    pub destructuring: Option<DestructuringOptions>,
+    /// Transform `for...of` loops into loops over an iterator or an array.
+    pub for_of: Option<ForOfOptions>,
+    /// Transform generator functions into state machines driven by the regenerator runtime.
+    pub generators: Option<bool>,
+    /// Transform default, rest and destructured parameters.
+    pub parameters: Option<ParametersOptions>,
+    /// Transform shorthand properties and methods of object literals.
+    pub shorthand_properties: Option<bool>,
+    /// Transform spread elements in array literals, calls and `new` expressions.
+    pub spread: Option<SpreadOptions>,
+    /// Transform template literals and tagged templates.
+    pub template_literals: Option<TemplateLiteralsOptions>,
 }
 
 impl From<Es2015Options> for oxc::transformer::ES2015Options {
     fn from(options: Es2015Options) -> Self {
-        oxc::transformer::ES2015Options { arrow_function: options.arrow_function.map(Into::into) }
+        oxc::transformer::ES2015Options {
+            arrow_function: options.arrow_function.map(Into::into),
+            block_scoping: options.block_scoping.map(Into::into),
+            classes: options.classes.map(Into::into),
+            computed_properties: options.computed_properties.map(Into::into),
+            destructuring: options.destructuring.map(Into::into),
+            for_of: options.for_of.map(Into::into),
+            generators: options.generators.unwrap_or_default(),
+            parameters: options.parameters.map(Into::into),
+            shorthand_properties: options.shorthand_properties.unwrap_or_default(),
+            spread: options.spread.map(Into::into),
+            template_literals: options.template_literals.map(Into::into),
+        }
     }
 }
 
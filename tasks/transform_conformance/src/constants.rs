@@ -34,11 +34,19 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-exponentiation-operator",
     // ES2015
     "babel-plugin-transform-arrow-functions",
+    "babel-plugin-transform-block-scoping",
+    "babel-plugin-transform-classes",
+    "babel-plugin-transform-computed-properties",
+    "babel-plugin-transform-destructuring",
+    "babel-plugin-transform-for-of",
+    "babel-plugin-transform-parameters",
+    "babel-plugin-transform-regenerator",
     // "babel-plugin-transform-function-name",
-    // "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-shorthand-properties",
+    "babel-plugin-transform-spread",
     // "babel-plugin-transform-sticky-regex",
     // "babel-plugin-transform-unicode-regex",
-    // "babel-plugin-transform-template-literals",
+    "babel-plugin-transform-template-literals",
     // "babel-plugin-transform-duplicate-keys",
     // "babel-plugin-transform-instanceof",
     // "babel-plugin-transform-new-target",
@@ -47,6 +55,8 @@ pub const PLUGINS: &[&str] = &[
     // TypeScript
     "babel-preset-typescript",
     "babel-plugin-transform-typescript",
+    // Flow
+    "babel-plugin-transform-flow-strip-types",
     // React
     "babel-preset-react",
     "babel-plugin-transform-react-jsx",
@@ -54,8 +64,13 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-transform-react-jsx-self",
     "babel-plugin-transform-react-jsx-source",
     "babel-plugin-transform-react-jsx-development",
-    // // Proposal
-    // "babel-plugin-proposal-decorators",
+    // Modules
+    "babel-plugin-transform-modules-commonjs",
+    "babel-plugin-transform-modules-amd",
+    "babel-plugin-transform-modules-umd",
+    "babel-plugin-transform-modules-systemjs",
+    // Proposal
+    "babel-plugin-proposal-decorators",
     "babel-plugin-proposal-explicit-resource-management",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
@@ -63,15 +78,8 @@ pub const PLUGINS: &[&str] = &[
     "legacy-decorators",
 ];
 
-pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] = &[
-    "proposal-decorators",
-    "transform-classes",
-    "transform-destructuring",
-    "transform-modules-commonjs",
-    "transform-parameters",
-    "transform-property-literals",
-    "transform-react-constant-elements",
-];
+pub const PLUGINS_NOT_SUPPORTED_YET: &[&str] =
+    &["transform-property-literals", "transform-react-constant-elements"];
 
 pub const SKIP_TESTS: &[&str] = &[
     // Shouldn't report in transformer
@@ -24,6 +24,7 @@ impl CompilerInterface for Driver {
     fn parse_options(&self) -> ParseOptions {
         ParseOptions {
             allow_return_outside_function: self.allow_return_outside_function,
+            flow: self.options.flow.is_some(),
             ..Default::default()
         }
     }
@@ -179,6 +179,11 @@ impl TestCase {
             return true;
         }
 
+        // Only the 2023-11 version of decorators is supported
+        if options.plugins.proposal_decorators.as_ref().is_some_and(|o| o.version != "2023-11") {
+            return true;
+        }
+
         // Skip some Babel tests.
         if let Ok(path) = self.path.strip_prefix(packages_root()) {
             // babel skip test cases that in a directory starting with a dot
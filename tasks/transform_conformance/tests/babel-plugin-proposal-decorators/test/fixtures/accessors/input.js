@@ -0,0 +1,6 @@
+class Foo {
+  @dec accessor a = 1;
+  @dec static accessor b;
+  @dec accessor #c = 3;
+  accessor d = 4;
+}
@@ -0,0 +1,55 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _get_c, _set_c, _init_extra_c;
+class Foo {
+  static {
+    [_init_b, _init_extra_b, _init_a, _init_extra_a, _init_c, _get_c, _set_c, _init_extra_c] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        1,
+        "a"
+      ],
+      [
+        dec,
+        9,
+        "b"
+      ],
+      [
+        dec,
+        1,
+        "c",
+        (o) => o.#C,
+        (o, v) => o.#C = v
+      ]
+    ], 0, (_) => #c in _).e;
+  }
+  #A = _init_a(this, 1);
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+  static #B = _init_b();
+  static get b() {
+    return this.#B;
+  }
+  static set b(v) {
+    this.#B = v;
+  }
+  static {
+    _init_extra_b();
+  }
+  #C = (_init_extra_a(this), _init_c(this, 3));
+  get #c() {
+    return _get_c(this);
+  }
+  set #c(v) {
+    _set_c(this, v);
+  }
+  #D = (_init_extra_c(this), 4);
+  get d() {
+    return this.#D;
+  }
+  set d(v) {
+    this.#D = v;
+  }
+}
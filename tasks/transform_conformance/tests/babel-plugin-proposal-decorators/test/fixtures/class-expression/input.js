@@ -0,0 +1,5 @@
+const Foo = @dec class {
+  method() {}
+};
+
+const Bar = @dec class Bar {};
@@ -0,0 +1,18 @@
+var _Class, _initClass, _Bar, _initClass2;
+const Foo = (class {
+  static {
+    [_Class, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  method() {}
+  static {
+    _initClass();
+  }
+}, _Class);
+const Bar = (class Bar {
+  static {
+    [_Bar, _initClass2] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _Bar);
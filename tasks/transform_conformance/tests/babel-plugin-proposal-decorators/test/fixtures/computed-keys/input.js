@@ -0,0 +1,6 @@
+class Foo {
+  @dec [a]() {}
+  [b]() {}
+  @dec [c] = 1;
+  @dec ["d"] = 2;
+}
@@ -0,0 +1,30 @@
+var _initProto, _computedKey, _computedKey2, _computedKey3, _init_computedKey, _init_extra_computedKey, _init_d, _init_extra_d;
+_computedKey = babelHelpers.toPropertyKey(a), _computedKey2 = babelHelpers.toPropertyKey(b), _computedKey3 = babelHelpers.toPropertyKey(c);
+class Foo {
+  static {
+    [_init_computedKey, _init_extra_computedKey, _init_d, _init_extra_d, _initProto] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        2,
+        _computedKey
+      ],
+      [
+        dec,
+        0,
+        _computedKey3
+      ],
+      [
+        dec,
+        0,
+        "d"
+      ]
+    ]).e;
+  }
+  constructor() {
+    _init_extra_d(this);
+  }
+  [_computedKey]() {}
+  [_computedKey2]() {}
+  [_computedKey3] = (_initProto(this), _init_computedKey(this, 1));
+  ["d"] = (_init_extra_computedKey(this), _init_d(this, 2));
+}
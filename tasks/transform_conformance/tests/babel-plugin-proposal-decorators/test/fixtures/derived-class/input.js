@@ -0,0 +1,12 @@
+class Foo extends Bar {
+  @dec method() {}
+
+  constructor() {
+    super();
+    console.log(this);
+  }
+}
+
+class Baz extends getBase() {
+  @dec accessor a;
+}
@@ -0,0 +1,35 @@
+var _initProto, _init_a, _init_extra_a, _ref;
+class Foo extends Bar {
+  static {
+    [_initProto] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      2,
+      "method"
+    ]], 0, void 0, Bar).e;
+  }
+  method() {}
+  constructor() {
+    _initProto(super());
+    console.log(this);
+  }
+}
+class Baz extends (_ref = getBase()) {
+  static {
+    [_init_a, _init_extra_a] = babelHelpers.applyDecs2311(this, [], [[
+      dec,
+      1,
+      "a"
+    ]], 0, void 0, _ref).e;
+  }
+  constructor(..._args) {
+    super(..._args);
+    _init_extra_a(this);
+  }
+  #A = _init_a(this);
+  get a() {
+    return this.#A;
+  }
+  set a(v) {
+    this.#A = v;
+  }
+}
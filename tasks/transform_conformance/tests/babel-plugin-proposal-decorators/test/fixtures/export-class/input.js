@@ -0,0 +1,4 @@
+@dec
+export class Foo {}
+
+export default @dec class Bar {}
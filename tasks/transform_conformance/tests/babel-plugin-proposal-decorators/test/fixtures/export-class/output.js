@@ -0,0 +1,18 @@
+var _Foo, _initClass, _Bar, _initClass2;
+export let Foo = (class Foo {
+  static {
+    [_Foo, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Foo);
+let Bar = (class Bar {
+  static {
+    [_Bar, _initClass2] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass2();
+  }
+}, _Bar);
+export default Bar;
@@ -0,0 +1 @@
+export default @dec class {}
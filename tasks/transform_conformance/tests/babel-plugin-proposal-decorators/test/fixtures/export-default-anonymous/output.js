@@ -0,0 +1,9 @@
+var _Class, _initClass;
+export default (class {
+  static {
+    [_Class, _initClass] = babelHelpers.applyDecs2311(this, [dec], []).c;
+  }
+  static {
+    _initClass();
+  }
+}, _Class);
@@ -0,0 +1,7 @@
+class Foo {
+  @dec a = 1;
+  @dec static b = 2;
+  @dec #c = 3;
+  @dec static #d;
+  e = 4;
+}
@@ -0,0 +1,42 @@
+var _init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _init_extra_c, _init_d, _init_extra_d;
+class Foo {
+  static {
+    [_init_b, _init_extra_b, _init_d, _init_extra_d, _init_a, _init_extra_a, _init_c, _init_extra_c] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        0,
+        "a"
+      ],
+      [
+        dec,
+        8,
+        "b"
+      ],
+      [
+        dec,
+        0,
+        "c",
+        (o) => o.#c,
+        (o, v) => o.#c = v
+      ],
+      [
+        dec,
+        8,
+        "d",
+        (o) => o.#d,
+        (o, v) => o.#d = v
+      ]
+    ], 0, (_) => #c in _).e;
+  }
+  a = _init_a(this, 1);
+  static b = _init_b(2);
+  static {
+    _init_extra_b();
+  }
+  #c = (_init_extra_a(this), _init_c(this, 3));
+  static #d = _init_d();
+  static {
+    _init_extra_d();
+  }
+  e = (_init_extra_c(this), 4);
+}
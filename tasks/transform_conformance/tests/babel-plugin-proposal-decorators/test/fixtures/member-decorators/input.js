@@ -0,0 +1,6 @@
+class Foo {
+  @obj.dec a;
+  @(obj[key]) b;
+  @(getDecorator()) c;
+  @decorators.get().dec d;
+}
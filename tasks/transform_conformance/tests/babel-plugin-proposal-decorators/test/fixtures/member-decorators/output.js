@@ -0,0 +1,35 @@
+var _init_a, _init_extra_a, _key, _init_b, _init_extra_b, _dec, _init_c, _init_extra_c, _decorators$get, _init_d, _init_extra_d;
+_key = key, _dec = getDecorator(), _decorators$get = decorators.get();
+class Foo {
+  static {
+    [_init_a, _init_extra_a, _init_b, _init_extra_b, _init_c, _init_extra_c, _init_d, _init_extra_d] = babelHelpers.applyDecs2311(this, [], [
+      [
+        [obj, obj.dec],
+        16,
+        "a"
+      ],
+      [
+        [obj, obj[_key]],
+        16,
+        "b"
+      ],
+      [
+        _dec,
+        0,
+        "c"
+      ],
+      [
+        [_decorators$get, _decorators$get.dec],
+        16,
+        "d"
+      ]
+    ]).e;
+  }
+  constructor() {
+    _init_extra_d(this);
+  }
+  a = _init_a(this);
+  b = (_init_extra_a(this), _init_b(this));
+  c = (_init_extra_b(this), _init_c(this));
+  d = (_init_extra_c(this), _init_d(this));
+}
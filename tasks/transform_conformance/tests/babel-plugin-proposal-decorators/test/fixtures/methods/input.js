@@ -0,0 +1,8 @@
+class Foo {
+  @dec method() {}
+  @dec get value() { return 1; }
+  @dec set value(v) {}
+  @dec static staticMethod() {}
+  @dec #private() {}
+  @dec get #privateValue() { return 1; }
+}
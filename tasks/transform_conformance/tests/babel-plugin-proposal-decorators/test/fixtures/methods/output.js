@@ -0,0 +1,57 @@
+var _initProto, _initStatic, _call_private, _call_privateValue;
+class Foo {
+  static {
+    [_call_private, _call_privateValue, _initProto, _initStatic] = babelHelpers.applyDecs2311(this, [], [
+      [
+        dec,
+        2,
+        "method"
+      ],
+      [
+        dec,
+        3,
+        "value"
+      ],
+      [
+        dec,
+        4,
+        "value"
+      ],
+      [
+        dec,
+        10,
+        "staticMethod"
+      ],
+      [
+        dec,
+        2,
+        "private",
+        function() {}
+      ],
+      [
+        dec,
+        3,
+        "privateValue",
+        function() {
+          return 1;
+        }
+      ]
+    ], 0, (_) => #private in _).e;
+    _initStatic(this);
+  }
+  constructor() {
+    _initProto(this);
+  }
+  method() {}
+  get value() {
+    return 1;
+  }
+  set value(v) {}
+  static staticMethod() {}
+  get #private() {
+    return _call_private;
+  }
+  get #privateValue() {
+    return _call_privateValue(this);
+  }
+}
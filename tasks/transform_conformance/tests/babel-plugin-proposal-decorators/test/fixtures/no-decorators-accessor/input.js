@@ -0,0 +1,4 @@
+class Foo {
+  #A = 0;
+  accessor value = 1;
+}
@@ -0,0 +1,10 @@
+class Foo {
+  #A = 0;
+  #B = 1;
+  get value() {
+    return this.#B;
+  }
+  set value(v) {
+    this.#B = v;
+  }
+}
@@ -0,0 +1,28 @@
+let a = 1;
+const b = 2;
+{
+  let a = 3;
+  const c = a + b;
+  log(a, c);
+}
+{
+  let c = 4;
+  log(c);
+}
+function f(x) {
+  if (x) {
+    let x = 1;
+    let y = 2;
+    return x + y;
+  }
+  let y = 3;
+  return y;
+}
+switch (a) {
+  case 1:
+    let d = 1;
+    log(d);
+    break;
+  default:
+    d = 2;
+}
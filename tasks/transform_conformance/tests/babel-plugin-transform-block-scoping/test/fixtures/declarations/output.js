@@ -0,0 +1,27 @@
+var a = 1;
+var b = 2;
+{
+  var _a = 3;
+  var c = _a + b;
+  log(_a, c);
+}
+{
+  var _c = 4;
+  log(_c);
+}
+function f(x) {
+  if (x) {
+    var _x = 1;
+    var _y = 2;
+    return _x + _y;
+  }
+  var y = 3;
+  return y;
+}
+switch (a) {
+  case 1:
+    var d = 1;
+    log(d);
+    break;
+  default: d = 2;
+}
@@ -0,0 +1,15 @@
+const fns = [];
+for (let i = 0; i < 3; i++) {
+  fns.push(function () {
+    return i;
+  });
+}
+for (const value of ["a", "b"]) {
+  fns.push(function () {
+    return value;
+  });
+}
+for (let i = 0; i < 2; i++) {
+  fns.push(() => i);
+}
+expect(fns.map((fn) => fn())).toEqual([0, 1, 2, "a", "b", 0, 1]);
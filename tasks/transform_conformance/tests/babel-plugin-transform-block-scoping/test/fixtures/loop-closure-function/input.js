@@ -0,0 +1,21 @@
+for (let i = 0; i < 3; i++) {
+  fns.push(function () {
+    return i;
+  });
+}
+for (const value of values) {
+  fns.push(function () {
+    return value;
+  });
+}
+for (const key in obj) {
+  fns.push(function () {
+    return this[key] + arguments.length;
+  });
+  fns.push(() => key);
+}
+for (let j = 0; j < 3; j++) {
+  fns.push(function named() {
+    return () => j;
+  });
+}
@@ -0,0 +1,33 @@
+var _loop = function(i) {
+  fns.push(function() {
+    return i;
+  });
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(value) {
+  fns.push(function() {
+    return value;
+  });
+};
+for (var value of values) {
+  _loop2(value);
+}
+var _loop3 = function(key) {
+  fns.push(function() {
+    return this[key] + arguments.length;
+  });
+  fns.push(() => key);
+};
+for (var key in obj) {
+  _loop3(key);
+}
+var _loop4 = function(j) {
+  fns.push(function named() {
+    return () => j;
+  });
+};
+for (var j = 0; j < 3; j++) {
+  _loop4(j);
+}
@@ -0,0 +1,17 @@
+for (let i = 0; i < 3; i++) {
+  fns.push(() => i);
+}
+for (let i = 0, len = arr.length; i < len; i++) {
+  const item = arr[i];
+  fns.push(function () {
+    return item;
+  });
+}
+for (const value of values) {
+  fns.push(() => value);
+}
+let n = 0;
+while (n < 3) {
+  let m = n++;
+  fns.push(() => m);
+}
@@ -0,0 +1,29 @@
+var _loop = function(i) {
+  fns.push(() => i);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+var _loop2 = function(_i) {
+  var item = arr[_i];
+  fns.push(function() {
+    return item;
+  });
+};
+for (var _i = 0, len = arr.length; _i < len; _i++) {
+  _loop2(_i);
+}
+var _loop3 = function(value) {
+  fns.push(() => value);
+};
+for (var value of values) {
+  _loop3(value);
+}
+var n = 0;
+var _loop4 = function() {
+  var m = n++;
+  fns.push(() => m);
+};
+while (n < 3) {
+  _loop4();
+}
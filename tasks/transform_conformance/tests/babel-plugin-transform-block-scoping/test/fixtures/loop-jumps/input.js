@@ -0,0 +1,21 @@
+function f(items) {
+  for (let item of items) {
+    if (item === 1) continue;
+    if (item === 2) break;
+    if (item === 3) return item;
+    fns.push(() => item);
+  }
+  outer: for (let i = 0; i < 3; i++) {
+    for (let j = 0; j < 3; j++) {
+      if (j === 1) continue;
+      if (j === 2) break;
+    }
+    switch (i) {
+      case 1:
+        break;
+      case 2:
+        continue outer;
+    }
+    fns.push(() => i);
+  }
+}
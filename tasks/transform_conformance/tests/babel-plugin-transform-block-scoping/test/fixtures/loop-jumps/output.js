@@ -0,0 +1,27 @@
+function f(items) {
+  var _loop = function(item) {
+    if (item === 1) return;
+    if (item === 2) return "break";
+    if (item === 3) return { v: item };
+    fns.push(() => item);
+  };
+  for (var item of items) {
+    var _ret = _loop(item);
+    if (_ret === "break") break;
+    if (_ret) return _ret.v;
+  }
+  var _loop2 = function(i) {
+    for (var j = 0; j < 3; j++) {
+      if (j === 1) continue;
+      if (j === 2) break;
+    }
+    switch (i) {
+      case 1: break;
+      case 2: return;
+    }
+    fns.push(() => i);
+  };
+  outer: for (var i = 0; i < 3; i++) {
+    _loop2(i);
+  }
+}
@@ -0,0 +1,12 @@
+for (let i = 0; i < 3; i++) {
+  for (let j = 0; j < 3; j++) {
+    fns.push(() => i + j);
+  }
+}
+for (const a of as) {
+  for (const b of bs) {
+    fns.push(() => b);
+  }
+  log(a);
+}
+if (cond) for (let i = 0; i < 3; i++) fns.push(() => i);
@@ -0,0 +1,31 @@
+var _loop2 = function(i) {
+  var _loop = function(j) {
+    fns.push(() => i + j);
+  };
+  for (var j = 0; j < 3; j++) {
+    _loop(j);
+  }
+};
+for (var i = 0; i < 3; i++) {
+  _loop2(i);
+}
+var _loop4 = function(a) {
+  var _loop3 = function(b) {
+    fns.push(() => b);
+  };
+  for (var b of bs) {
+    _loop3(b);
+  }
+  log(a);
+};
+for (var a of as) {
+  _loop4(a);
+}
+if (cond) {
+  var _loop5 = function(_i) {
+    fns.push(() => _i);
+  };
+  for (var _i = 0; _i < 3; _i++) {
+    _loop5(_i);
+  }
+}
@@ -0,0 +1,12 @@
+for (var i = 0; i < 2; i++) {
+  let a;
+  const b = i;
+  if (b) a = b;
+  log(a);
+}
+for (let key in obj) {
+  log(key);
+}
+for (const value of values) {
+  log(value);
+}
@@ -0,0 +1,12 @@
+for (var i = 0; i < 2; i++) {
+  var a = void 0;
+  var b = i;
+  if (b) a = b;
+  log(a);
+}
+for (var key in obj) {
+  log(key);
+}
+for (var value of values) {
+  log(value);
+}
@@ -0,0 +1,18 @@
+function f() {
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => this[i]);
+  }
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => arguments[i]);
+  }
+  for (let i = 0; i < 3; i++) {
+    fns.push(() => i);
+    i++;
+  }
+  outer: for (;;) {
+    for (let i = 0; i < 3; i++) {
+      fns.push(() => i);
+      continue outer;
+    }
+  }
+}
@@ -0,0 +1,21 @@
+function f() {
+  for (var i = 0; i < 3; i++) {
+    fns.push(() => this[i]);
+  }
+  for (var _i = 0; _i < 3; _i++) {
+    fns.push(() => arguments[_i]);
+  }
+  for (var _i2 = 0; _i2 < 3; _i2++) {
+    fns.push(() => _i2);
+    _i2++;
+  }
+  var _loop = function() {
+    for (var _i3 = 0; _i3 < 3; _i3++) {
+      fns.push(() => _i3);
+      return;
+    }
+  };
+  outer: for (;;) {
+    _loop();
+  }
+}
@@ -0,0 +1,7 @@
+for (let i = 0; i < 3; i++) {
+  var a = i, b;
+  for (var j = 0; j < i; j++) {}
+  for (var k in obj) {}
+  fns.push(() => i + a + b + j + k);
+}
+log(a, b, j, k);
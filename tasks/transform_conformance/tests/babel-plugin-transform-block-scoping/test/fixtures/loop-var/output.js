@@ -0,0 +1,11 @@
+var a, b, j, k;
+var _loop = function(i) {
+  a = i;
+  for (j = 0; j < i; j++) {}
+  for (k in obj) {}
+  fns.push(() => i + a + b + j + k);
+};
+for (var i = 0; i < 3; i++) {
+  _loop(i);
+}
+log(a, b, j, k);
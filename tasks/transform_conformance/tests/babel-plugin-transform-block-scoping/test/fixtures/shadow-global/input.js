@@ -0,0 +1,16 @@
+function f() {
+  {
+    let window = 1;
+    log(window);
+  }
+  return window;
+}
+try {
+  throw 1;
+} catch (e) {
+  let e2 = e;
+  {
+    let e = e2;
+    log(e);
+  }
+}
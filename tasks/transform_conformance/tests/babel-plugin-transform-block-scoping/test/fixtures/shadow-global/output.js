@@ -0,0 +1,16 @@
+function f() {
+  {
+    var _window = 1;
+    log(_window);
+  }
+  return window;
+}
+try {
+  throw 1;
+} catch (e) {
+  var e2 = e;
+  {
+    var _e = e2;
+    log(_e);
+  }
+}
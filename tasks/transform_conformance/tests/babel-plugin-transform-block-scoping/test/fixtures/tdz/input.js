@@ -0,0 +1,10 @@
+log(a);
+let a = 1;
+function f() {
+  return b;
+}
+{
+  log(b, typeof b);
+  let b = 2;
+  log(b);
+}
@@ -0,0 +1,10 @@
+log(babelHelpers.tdz("a"));
+var a = 1;
+function f() {
+  return b;
+}
+{
+  log(babelHelpers.tdz("b"), typeof babelHelpers.tdz("b"));
+  var _b = 2;
+  log(_b);
+}
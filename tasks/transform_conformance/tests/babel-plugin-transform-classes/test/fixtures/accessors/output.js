@@ -0,0 +1,30 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo, [
+    {
+      key: "x",
+      get: function() {
+        return this._x;
+      },
+      set: function(value) {
+        this._x = value;
+      }
+    },
+    {
+      key: computed,
+      value: function() {}
+    },
+    {
+      key: "quoted-key",
+      value: function() {}
+    }
+  ], [{
+    key: "y",
+    get: function() {
+      return 1;
+    }
+  }]);
+}();
@@ -0,0 +1,13 @@
+class Foo {
+  constructor(x) {
+    this.x = x;
+  }
+  method() {
+    return this.x;
+  }
+  static create() {
+    return new Foo(1);
+  }
+}
+
+class Empty {}
@@ -0,0 +1,25 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo(x) {
+    babelHelpers.classCallCheck(this, Foo);
+    this.x = x;
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      return this.x;
+    }
+  }], [{
+    key: "create",
+    value: function create() {
+      return new Foo(1);
+    }
+  }]);
+}();
+let Empty = /* @__PURE__ */ function() {
+  "use strict";
+  function Empty() {
+    babelHelpers.classCallCheck(this, Empty);
+  }
+  return babelHelpers.createClass(Empty);
+}();
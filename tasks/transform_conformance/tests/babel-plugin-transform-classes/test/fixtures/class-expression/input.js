@@ -0,0 +1,8 @@
+const A = class {
+  method() {}
+};
+const B = class Named extends A {
+  method() {
+    return Named;
+  }
+};
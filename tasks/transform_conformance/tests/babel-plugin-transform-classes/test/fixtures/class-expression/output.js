@@ -0,0 +1,24 @@
+const A = /* @__PURE__ */ function() {
+  "use strict";
+  function _Class() {
+    babelHelpers.classCallCheck(this, _Class);
+  }
+  return babelHelpers.createClass(_Class, [{
+    key: "method",
+    value: function method() {}
+  }]);
+}();
+const B = /* @__PURE__ */ function(_A) {
+  "use strict";
+  function Named() {
+    babelHelpers.classCallCheck(this, Named);
+    return babelHelpers.callSuper(this, Named, arguments);
+  }
+  babelHelpers.inherits(Named, _A);
+  return babelHelpers.createClass(Named, [{
+    key: "method",
+    value: function method() {
+      return Named;
+    }
+  }]);
+}(A);
@@ -0,0 +1,25 @@
+class Foo extends Bar {
+  constructor(x) {
+    super(x);
+    this.x = x;
+    const fn = () => this.x;
+    if (x) {
+      return;
+    }
+  }
+}
+
+class Spread extends Bar {
+  constructor(...args) {
+    super(...args);
+  }
+}
+
+class Default extends Bar {}
+
+class ReturnObject extends Bar {
+  constructor() {
+    super();
+    return {};
+  }
+}
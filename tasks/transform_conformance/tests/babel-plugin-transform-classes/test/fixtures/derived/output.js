@@ -0,0 +1,47 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo(x) {
+    var _this;
+    babelHelpers.classCallCheck(this, Foo);
+    _this = babelHelpers.callSuper(this, Foo, [x]);
+    _this.x = x;
+    const fn = () => _this.x;
+    if (x) {
+      return _this;
+    }
+    return _this;
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo);
+}(Bar);
+let Spread = /* @__PURE__ */ function(_Bar2) {
+  "use strict";
+  function Spread(...args) {
+    var _this2;
+    babelHelpers.classCallCheck(this, Spread);
+    _this2 = babelHelpers.callSuper(this, Spread, [...args]);
+    return _this2;
+  }
+  babelHelpers.inherits(Spread, _Bar2);
+  return babelHelpers.createClass(Spread);
+}(Bar);
+let Default = /* @__PURE__ */ function(_Bar3) {
+  "use strict";
+  function Default() {
+    babelHelpers.classCallCheck(this, Default);
+    return babelHelpers.callSuper(this, Default, arguments);
+  }
+  babelHelpers.inherits(Default, _Bar3);
+  return babelHelpers.createClass(Default);
+}(Bar);
+let ReturnObject = /* @__PURE__ */ function(_Bar4) {
+  "use strict";
+  function ReturnObject() {
+    var _this3;
+    babelHelpers.classCallCheck(this, ReturnObject);
+    _this3 = babelHelpers.callSuper(this, ReturnObject);
+    return babelHelpers.possibleConstructorReturn(_this3, {});
+  }
+  babelHelpers.inherits(ReturnObject, _Bar4);
+  return babelHelpers.createClass(ReturnObject);
+}(Bar);
@@ -0,0 +1,6 @@
+export class Foo {}
+export default class Bar extends Foo {
+  method() {
+    return Bar;
+  }
+}
@@ -0,0 +1,22 @@
+export let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+  }
+  return babelHelpers.createClass(Foo);
+}();
+let Bar = /* @__PURE__ */ function(_Foo) {
+  "use strict";
+  function Bar() {
+    babelHelpers.classCallCheck(this, Bar);
+    return babelHelpers.callSuper(this, Bar, arguments);
+  }
+  babelHelpers.inherits(Bar, _Foo);
+  return babelHelpers.createClass(Bar, [{
+    key: "method",
+    value: function method() {
+      return Bar;
+    }
+  }]);
+}(Foo);
+export { Bar as default };
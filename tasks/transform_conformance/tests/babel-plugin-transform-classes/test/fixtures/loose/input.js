@@ -0,0 +1,12 @@
+class Foo extends Bar {
+  constructor(x) {
+    super(x);
+  }
+  method() {}
+  get prop() {
+    return 1;
+  }
+  static staticMethod() {}
+}
+
+class Default extends Bar {}
@@ -0,0 +1,26 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo(x) {
+    var _this;
+    _this = _Bar.call(this, x) || this;
+    return _this;
+  }
+  babelHelpers.inheritsLoose(Foo, _Bar);
+  var _proto = Foo.prototype;
+  _proto.method = function method() {};
+  Foo.staticMethod = function staticMethod() {};
+  return babelHelpers.createClass(Foo, [{
+    key: "prop",
+    get: function() {
+      return 1;
+    }
+  }]);
+}(Bar);
+let Default = /* @__PURE__ */ function(_Bar2) {
+  "use strict";
+  function Default() {
+    return _Bar2.apply(this, arguments) || this;
+  }
+  babelHelpers.inheritsLoose(Default, _Bar2);
+  return Default;
+}(Bar);
@@ -0,0 +1,8 @@
+class Foo {
+  constructor() {
+    this.target = new.target;
+  }
+  method() {
+    return new.target;
+  }
+}
@@ -0,0 +1,13 @@
+let Foo = /* @__PURE__ */ function() {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+    this.target = this.constructor;
+  }
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      return void 0;
+    }
+  }]);
+}();
@@ -0,0 +1,11 @@
+class Foo extends Bar {
+  method() {
+    super.method(1, 2);
+    super.prop = 1;
+    super[key] += 1;
+    return super.prop + super["other"];
+  }
+  static staticMethod() {
+    return super.staticMethod();
+  }
+}
@@ -0,0 +1,23 @@
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    babelHelpers.classCallCheck(this, Foo);
+    return babelHelpers.callSuper(this, Foo, arguments);
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo, [{
+    key: "method",
+    value: function method() {
+      var _key;
+      babelHelpers.superPropGet(Foo, "method", this, 3)([1, 2]);
+      babelHelpers.superPropSet(Foo, "prop", 1, this, 1, 1);
+      babelHelpers.superPropSet(Foo, _key = key, babelHelpers.superPropGet(Foo, _key, this, 1) + 1, this, 1, 1);
+      return babelHelpers.superPropGet(Foo, "prop", this, 1) + babelHelpers.superPropGet(Foo, "other", this, 1);
+    }
+  }], [{
+    key: "staticMethod",
+    value: function staticMethod() {
+      return babelHelpers.superPropGet(Foo, "staticMethod", this, 2)([]);
+    }
+  }]);
+}(Bar);
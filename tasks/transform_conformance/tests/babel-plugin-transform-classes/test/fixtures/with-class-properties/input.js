@@ -0,0 +1,11 @@
+class Foo extends Bar {
+  x = 1;
+  static y = 2;
+  constructor() {
+    super();
+  }
+}
+
+const A = class {
+  static z = 3;
+};
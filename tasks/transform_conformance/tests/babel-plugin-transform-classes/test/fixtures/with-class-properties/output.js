@@ -0,0 +1,21 @@
+var _Class;
+let Foo = /* @__PURE__ */ function(_Bar) {
+  "use strict";
+  function Foo() {
+    var _this;
+    babelHelpers.classCallCheck(this, Foo);
+    _this = babelHelpers.callSuper(this, Foo);
+    babelHelpers.defineProperty(_this, "x", 1);
+    return _this;
+  }
+  babelHelpers.inherits(Foo, _Bar);
+  return babelHelpers.createClass(Foo);
+}(Bar);
+babelHelpers.defineProperty(Foo, "y", 2);
+const A = (_Class = /* @__PURE__ */ function() {
+  "use strict";
+  function _Class2() {
+    babelHelpers.classCallCheck(this, _Class2);
+  }
+  return babelHelpers.createClass(_Class2);
+}(), babelHelpers.defineProperty(_Class, "z", 3), _Class);
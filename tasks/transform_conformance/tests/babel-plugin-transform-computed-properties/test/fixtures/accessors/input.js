@@ -0,0 +1,7 @@
+var obj = {
+  get [a]() {
+    return 1;
+  },
+  set [a](v) {},
+  b() {},
+};
@@ -0,0 +1,3 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineAccessor("set", babelHelpers.defineAccessor("get", {}, a, function() {
+  return 1;
+}), a, function(v) {}), "b", function() {});
@@ -0,0 +1,2 @@
+var obj = { a: 1, [b]: 2, c: 3 };
+var obj2 = { [a]: 1 };
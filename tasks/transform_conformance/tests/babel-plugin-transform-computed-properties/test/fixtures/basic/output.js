@@ -0,0 +1,2 @@
+var obj = babelHelpers.defineProperty(babelHelpers.defineProperty({ a: 1 }, b, 2), "c", 3);
+var obj2 = babelHelpers.defineProperty({}, a, 1);
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = {}, babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(babelHelpers.defineProperty(_obj, a, 1), "b", 2), "c", 3), "d", 4), "e", 5), "f", 6), "g", 7), "h", 8), "i", 9), "j", 10), babelHelpers.defineProperty(babelHelpers.defineProperty(_obj, "k", 11), "l", 12), _obj);
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = { a: 1 }, _obj[b] = 2, _obj.c = 3, _obj["d-e"] = 4, babelHelpers.defineAccessor("get", _obj, f, function() {}), _obj);
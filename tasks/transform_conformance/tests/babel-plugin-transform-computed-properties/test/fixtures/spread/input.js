@@ -0,0 +1 @@
+var obj = { [a]: 1, ...b };
@@ -0,0 +1,4 @@
+var obj = {
+  [a]: 1,
+  ...b
+};
@@ -0,0 +1,9 @@
+const o = {
+  m() {
+    return super.m();
+  },
+  [k]: 1,
+  n() {
+    return { m() { return super.m(); } };
+  },
+};
@@ -0,0 +1,7 @@
+const o = babelHelpers.defineProperty(babelHelpers.defineProperty({
+  m() {
+    return super.m();
+  }
+}, k, 1), "n", function () {
+  return { m() { return super.m(); } };
+});
@@ -0,0 +1 @@
+const o = { [k]: 1, m() { return super.m(); } };
@@ -0,0 +1,5 @@
+[x, y] = [y, x];
+({ a, b: { c } } = obj);
+result = [p, q] = arr;
+({ d = 1, e: f = 2, ...g } = h);
+[obj.prop, arr[0]] = values;
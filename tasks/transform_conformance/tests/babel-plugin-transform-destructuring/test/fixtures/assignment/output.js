@@ -0,0 +1,6 @@
+var _ref, _arr, _h$d, _h$e, _values;
+_ref = babelHelpers.slicedToArray([y, x], 2), x = _ref[0], y = _ref[1];
+a = obj.a, c = obj.b.c;
+result = (_arr = babelHelpers.slicedToArray(arr, 2), p = _arr[0], q = _arr[1], arr);
+_h$d = h.d, d = _h$d === void 0 ? 1 : _h$d, _h$e = h.e, f = _h$e === void 0 ? 2 : _h$e, g = babelHelpers.objectWithoutProperties(h, ["d", "e"]);
+_values = babelHelpers.slicedToArray(values, 2), obj.prop = _values[0], arr[0] = _values[1];
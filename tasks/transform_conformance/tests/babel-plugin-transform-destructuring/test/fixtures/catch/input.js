@@ -0,0 +1,10 @@
+try {
+  f();
+} catch ({ message, code = 0 }) {
+  log(message, code);
+}
+try {
+  g();
+} catch ([first]) {
+  log(first);
+}
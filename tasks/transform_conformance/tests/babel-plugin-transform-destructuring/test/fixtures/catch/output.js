@@ -0,0 +1,12 @@
+try {
+  f();
+} catch (_ref) {
+  let message = _ref.message, _ref$code = _ref.code, code = _ref$code === void 0 ? 0 : _ref$code;
+  log(message, code);
+}
+try {
+  g();
+} catch (_ref2) {
+  let _ref3 = babelHelpers.slicedToArray(_ref2, 1), first = _ref3[0];
+  log(first);
+}
@@ -0,0 +1,7 @@
+var { a, b: [c, d = 1], ...rest } = obj;
+let [x, , ...y] = f();
+const { [k]: v, 1: w, ...others } = o;
+var {} = e;
+var [] = arr;
+var { z } = z2, [u] = u2;
+var { self } = self;
@@ -0,0 +1,7 @@
+var a = obj.a, _obj$b = babelHelpers.slicedToArray(obj.b, 2), c = _obj$b[0], _obj$b$ = _obj$b[1], d = _obj$b$ === void 0 ? 1 : _obj$b$, rest = babelHelpers.objectWithoutProperties(obj, ["a", "b"]);
+let _f = babelHelpers.toArray(f()), x = _f[0], y = _f.slice(2);
+const _k = babelHelpers.toPropertyKey(k), v = o[_k], w = o[1], others = babelHelpers.objectWithoutProperties(o, [_k, "1"]);
+var _ref = babelHelpers.objectDestructuringEmpty(e);
+var _ref2 = babelHelpers.slicedToArray(arr, 0);
+var z = z2.z, _u = babelHelpers.slicedToArray(u2, 1), u = _u[0];
+var self = self.self;
@@ -0,0 +1,3 @@
+export const { a, b: [c] } = obj;
+export let [d, ...e] = arr;
+export var f = 1;
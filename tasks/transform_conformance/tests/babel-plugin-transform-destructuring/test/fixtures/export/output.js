@@ -0,0 +1,5 @@
+const a = obj.a, _obj$b = babelHelpers.slicedToArray(obj.b, 1), c = _obj$b[0];
+export { a, c };
+let _arr = babelHelpers.toArray(arr), d = _arr[0], e = _arr.slice(1);
+export { d, e };
+export var f = 1;
@@ -0,0 +1,6 @@
+for (const [k, v] of map) {
+  use(k, v);
+}
+for (let { a, b } of list) log(a, b);
+for ([c, d] of pairs);
+for (var { length } in obj) log(length);
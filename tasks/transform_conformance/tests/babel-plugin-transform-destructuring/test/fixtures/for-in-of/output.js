@@ -0,0 +1,16 @@
+for (const _ref of map) {
+  const _ref2 = babelHelpers.slicedToArray(_ref, 2), k = _ref2[0], v = _ref2[1];
+  use(k, v);
+}
+for (let _ref3 of list) {
+  let a = _ref3.a, b = _ref3.b;
+  log(a, b);
+}
+for (var _ref4 of pairs) {
+  var _ref5;
+  _ref5 = babelHelpers.slicedToArray(_ref4, 2), c = _ref5[0], d = _ref5[1];
+}
+for (var _ref6 in obj) {
+  var length = _ref6.length;
+  log(length);
+}
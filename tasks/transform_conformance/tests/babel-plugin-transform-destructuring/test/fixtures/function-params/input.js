@@ -0,0 +1,4 @@
+function f({ a }, [b] = [], ...[c]) {
+  return a + b + c;
+}
+const g = ({ x }) => x;
@@ -0,0 +1,8 @@
+function f(_ref, _ref2 = [], ..._ref3) {
+  var a = _ref.a, _ref4 = babelHelpers.slicedToArray(_ref2, 1), b = _ref4[0], _ref5 = babelHelpers.slicedToArray(_ref3, 1), c = _ref5[0];
+  return a + b + c;
+}
+const g = (_ref6) => {
+  var x = _ref6.x;
+  return x;
+};
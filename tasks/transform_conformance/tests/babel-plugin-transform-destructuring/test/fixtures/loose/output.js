@@ -0,0 +1,3 @@
+var _ref;
+var a = arr[0], b = arr[1], c = arr.slice(2);
+_ref = [y, x], x = _ref[0], y = _ref[1];
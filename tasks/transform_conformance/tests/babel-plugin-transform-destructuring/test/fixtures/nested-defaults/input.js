@@ -0,0 +1 @@
+var { a: { b = 1 } = {}, c: [d = 2] = [] } = obj;
@@ -0,0 +1 @@
+var _obj$a = obj.a, _b = (_obj$a === void 0 ? {} : _obj$a).b, b = _b === void 0 ? 1 : _b, _obj$c = obj.c, _ref = babelHelpers.slicedToArray(_obj$c === void 0 ? [] : _obj$c, 1), _ref$ = _ref[0], d = _ref$ === void 0 ? 2 : _ref$;
@@ -0,0 +1,4 @@
+class Foo {
+  declare typeOnly: string;
+  kept: number;
+}
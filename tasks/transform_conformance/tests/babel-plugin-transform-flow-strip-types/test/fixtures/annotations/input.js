@@ -0,0 +1,12 @@
+// @flow
+function foo(one: any, two: number, three?: ?string, ...rest: Array<mixed>): string {
+  return one;
+}
+const arrow = (x: number): string => String(x);
+const curried = (x: number): (string => void) => (y) => {};
+async function bar<T: Object>(value: T): Promise<void> {}
+function pred(x: mixed): boolean %checks {
+  return typeof x === "string";
+}
+const ternary = test ? (a) : b;
+const arrowInTernary = test ? (a): number => a : b;
@@ -0,0 +1,12 @@
+// @flow
+function foo(one, two, three, ...rest) {
+  return one;
+}
+const arrow = (x) => String(x);
+const curried = (x) => (y) => {};
+async function bar(value) {}
+function pred(x) {
+  return typeof x === "string";
+}
+const ternary = test ? a : b;
+const arrowInTernary = test ? (a) => a : b;
@@ -0,0 +1,7 @@
+class Foo<T> extends Bar<T> implements Baz {
+  prop: T;
+  +covariant: number;
+  static -contravariant: string = "";
+  initialized: number = 1;
+  method<U>(a: U): void {}
+}
@@ -0,0 +1,5 @@
+class Foo extends Bar {
+  static contravariant = "";
+  initialized = 1;
+  method(a) {}
+}
@@ -0,0 +1,8 @@
+import type { Node } from "./node";
+import typeof TypeofDefault from "./default";
+import { type A, typeof B, C } from "./mixed";
+import type D, { E } from "./type-default";
+export type { Props } from "./props";
+export type Exported = { a: A };
+export interface I {}
+export { C };
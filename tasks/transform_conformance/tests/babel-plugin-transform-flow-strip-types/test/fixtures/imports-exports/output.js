@@ -0,0 +1,2 @@
+import { C } from "./mixed";
+export { C };
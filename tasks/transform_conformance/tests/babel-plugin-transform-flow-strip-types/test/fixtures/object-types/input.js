@@ -0,0 +1,10 @@
+type Exact = {| a: string, b?: number |};
+type EmptyExact = {||};
+type Inexact = { a: string, ... };
+type Spread = { ...Exact, c: boolean };
+type Indexer = { [string]: number, [key: string]: mixed };
+type Methods = { m(x: number): string, (): void };
+type Existential = Array<*>;
+function f(o: {| +a: string, -b: number |}): { a: string } {
+  return o;
+}
@@ -0,0 +1,4 @@
+const a = (value: any);
+const b = ((value: any): string);
+call((x: number), (y: string));
+const c = (value);
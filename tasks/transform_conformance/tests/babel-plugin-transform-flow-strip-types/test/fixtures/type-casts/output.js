@@ -0,0 +1,4 @@
+const a = value;
+const b = value;
+call(x, y);
+const c = value;
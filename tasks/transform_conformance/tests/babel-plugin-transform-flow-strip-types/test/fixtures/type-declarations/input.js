@@ -0,0 +1,18 @@
+type Maybe<T> = ?T;
+type Union =
+  | "a"
+  | "b";
+type Fn = (string, number) => void;
+opaque type Token: string = string;
+declare opaque type Opaque;
+export opaque type Id = number;
+interface Stack<T> {
+  push(item: T): void;
+}
+declare var __DEV__: boolean;
+declare function describe(name: string): void;
+declare class Foo<+T> {
+  bar(): T;
+}
+declare export function exported(): void;
+export const value = 1;
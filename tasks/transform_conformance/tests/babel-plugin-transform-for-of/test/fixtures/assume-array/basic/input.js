@@ -0,0 +1,3 @@
+for (const x of xs) {
+  console.log(x);
+}
@@ -0,0 +1,4 @@
+for (var _i = 0, _xs = xs; _i < _xs.length; _i++) {
+  const x = _xs[_i];
+  console.log(x);
+}
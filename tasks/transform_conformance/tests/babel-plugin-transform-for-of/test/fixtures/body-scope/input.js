@@ -0,0 +1,4 @@
+for (const x of xs) {
+  const x = 1;
+  console.log(x);
+}
@@ -0,0 +1,14 @@
+var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+try {
+  for (_iterator.s(); !(_step = _iterator.n()).done;) {
+    const x = _step.value;
+    {
+      const x = 1;
+      console.log(x);
+    }
+  }
+} catch (err) {
+  _iterator.e(err);
+} finally {
+  _iterator.f();
+}
@@ -0,0 +1,6 @@
+outer: for (const a of as) {
+  inner: for (const b of a) {
+    if (b) continue outer;
+    break inner;
+  }
+}
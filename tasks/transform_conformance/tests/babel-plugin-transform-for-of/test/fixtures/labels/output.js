@@ -0,0 +1,22 @@
+var _iterator2 = babelHelpers.createForOfIteratorHelper(as), _step2;
+try {
+  outer: for (_iterator2.s(); !(_step2 = _iterator2.n()).done;) {
+    const a = _step2.value;
+    var _iterator = babelHelpers.createForOfIteratorHelper(a), _step;
+    try {
+      inner: for (_iterator.s(); !(_step = _iterator.n()).done;) {
+        const b = _step.value;
+        if (b) continue outer;
+        break inner;
+      }
+    } catch (err) {
+      _iterator.e(err);
+    } finally {
+      _iterator.f();
+    }
+  }
+} catch (err) {
+  _iterator2.e(err);
+} finally {
+  _iterator2.f();
+}
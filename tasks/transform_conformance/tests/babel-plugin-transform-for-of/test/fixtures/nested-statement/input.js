@@ -0,0 +1 @@
+if (a) for (const x of xs) console.log(x);
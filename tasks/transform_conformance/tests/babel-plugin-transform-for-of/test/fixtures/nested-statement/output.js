@@ -0,0 +1,13 @@
+if (a) {
+  var _iterator = babelHelpers.createForOfIteratorHelper(xs), _step;
+  try {
+    for (_iterator.s(); !(_step = _iterator.n()).done;) {
+      const x = _step.value;
+      console.log(x);
+    }
+  } catch (err) {
+    _iterator.e(err);
+  } finally {
+    _iterator.f();
+  }
+}
@@ -0,0 +1,5 @@
+for (const x of xs) {
+  console.log(x);
+}
+for (y of ys);
+for (obj.z of zs) {}
@@ -0,0 +1,3 @@
+"use strict";
+"custom";
+export const a = 1;
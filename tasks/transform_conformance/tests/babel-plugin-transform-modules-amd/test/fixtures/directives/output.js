@@ -0,0 +1,7 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  "custom";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.a = void 0;
+  const a = _exports.a = 1;
+});
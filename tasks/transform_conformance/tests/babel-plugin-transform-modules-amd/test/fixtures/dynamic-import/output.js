@@ -0,0 +1,10 @@
+define(["require", "exports"], function(_require, _exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.load = void 0;
+  const load = _exports.load = () => new Promise(function(_resolve, _reject) {
+    _require(["./foo.js"], function(imported) {
+      _resolve(babelHelpers.interopRequireWildcard(imported));
+    }, _reject);
+  });
+});
@@ -0,0 +1,5 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.default = 1 + 2;
+});
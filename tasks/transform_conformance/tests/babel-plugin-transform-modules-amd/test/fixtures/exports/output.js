@@ -0,0 +1,28 @@
+define(["exports"], function(_exports) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.count = _exports.total = _exports.a = _exports["a-b"] = _exports.b = _exports.C = _exports.x = _exports.z = void 0;
+  _exports.inc = inc;
+  var _tmp;
+  let count = _exports.count = _exports.total = 0;
+  var a = _exports.a = _exports["a-b"] = 1, b;
+  function inc() {
+    _exports.count = _exports.total = ++count;
+    return count;
+  }
+  class C {}
+  _exports.C = C;
+  const { x, y: [z] } = obj;
+  _exports.x = x;
+  _exports.z = z;
+  _exports.b = b = 2;
+  [a, b] = [3, 4], _exports.a = _exports["a-b"] = a, _exports.b = b;
+  const c = (_tmp = [a] = [5], _exports.a = _exports["a-b"] = a, _tmp);
+  for (a of arr) {
+    _exports.a = _exports["a-b"] = a;
+  }
+  for (b in obj) {
+    _exports.b = b;
+    log(b);
+  }
+});
@@ -0,0 +1,5 @@
+define(["module"], function(_module) {
+  "use strict";
+  console.log(new URL(_module.uri, document.baseURI).href);
+  console.log({ url: new URL(_module.uri, document.baseURI).href });
+});
@@ -0,0 +1,15 @@
+define([
+  "./foo.js",
+  "ns",
+  "def",
+  "side-effect"
+], function(_foo, _ns, _def, _sideEffect) {
+  "use strict";
+  _foo = babelHelpers.interopRequireWildcard(_foo);
+  _ns = babelHelpers.interopRequireWildcard(_ns);
+  _def = babelHelpers.interopRequireDefault(_def);
+  (0, _foo.default)(_foo.bar, _foo["a-b"]);
+  (0, _foo.bar)`tagged`;
+  new _def.default();
+  _ns.x(typeof _def.default);
+});
@@ -0,0 +1,6 @@
+import { a } from "foo";
+export { a };
+export { b as c, default } from "foo";
+export * as ns from "bar";
+export * from "baz";
+export const local = 1;
@@ -0,0 +1,47 @@
+define([
+  "exports",
+  "foo",
+  "bar",
+  "baz"
+], function(_exports, _foo, _bar, _baz) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.local = void 0;
+  _foo = babelHelpers.interopRequireWildcard(_foo);
+  Object.defineProperty(_exports, "a", {
+    enumerable: true,
+    get: function() {
+      return _foo.a;
+    }
+  });
+  Object.defineProperty(_exports, "c", {
+    enumerable: true,
+    get: function() {
+      return _foo.b;
+    }
+  });
+  Object.defineProperty(_exports, "default", {
+    enumerable: true,
+    get: function() {
+      return _foo.default;
+    }
+  });
+  _bar = babelHelpers.interopRequireWildcard(_bar);
+  Object.defineProperty(_exports, "ns", {
+    enumerable: true,
+    get: function() {
+      return _bar;
+    }
+  });
+  Object.keys(_baz).forEach(function(key) {
+    if (key === "default" || key === "__esModule" || key === "local" || key === "a" || key === "c" || key === "ns") return;
+    if (key in _exports && _exports[key] === _baz[key]) return;
+    Object.defineProperty(_exports, key, {
+      enumerable: true,
+      get: function() {
+        return _baz[key];
+      }
+    });
+  });
+  const local = _exports.local = 1;
+});
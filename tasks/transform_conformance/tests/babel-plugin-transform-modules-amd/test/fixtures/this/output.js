@@ -0,0 +1,11 @@
+define([], function() {
+  "use strict";
+  (void 0).foo;
+  function f() {
+    return this;
+  }
+  const g = () => void 0;
+  class C {
+    x = this;
+  }
+});
@@ -0,0 +1 @@
+export const load = (name) => import(name);
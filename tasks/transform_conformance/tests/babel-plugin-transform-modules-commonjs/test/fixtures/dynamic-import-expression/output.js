@@ -0,0 +1,4 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.load = void 0;
+const load = exports.load = (name) => Promise.resolve(`${name}`).then((s) => babelHelpers.interopRequireWildcard(require(s)));
@@ -0,0 +1,4 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.load = void 0;
+const load = exports.load = () => Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("./foo.js")));
@@ -0,0 +1,15 @@
+export let count = 0;
+export var a = 1, b;
+export function inc() {
+  count++;
+  return count;
+}
+export class C {}
+export const { x, y: [z] } = obj;
+export { count as total, a as "a-b" };
+
+b = 2;
+[a, b] = [3, 4];
+const c = ([a] = [5]);
+for (a of arr) {}
+for (b in obj) log(b);
@@ -0,0 +1,26 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.count = exports.total = exports.a = exports["a-b"] = exports.b = exports.C = exports.x = exports.z = void 0;
+exports.inc = inc;
+var _tmp;
+let count = exports.count = exports.total = 0;
+var a = exports.a = exports["a-b"] = 1, b;
+function inc() {
+  exports.count = exports.total = ++count;
+  return count;
+}
+class C {}
+exports.C = C;
+const { x, y: [z] } = obj;
+exports.x = x;
+exports.z = z;
+exports.b = b = 2;
+[a, b] = [3, 4], exports.a = exports["a-b"] = a, exports.b = b;
+const c = (_tmp = [a] = [5], exports.a = exports["a-b"] = a, _tmp);
+for (a of arr) {
+  exports.a = exports["a-b"] = a;
+}
+for (b in obj) {
+  exports.b = b;
+  log(b);
+}
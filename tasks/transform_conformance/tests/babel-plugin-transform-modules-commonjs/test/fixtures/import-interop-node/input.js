@@ -0,0 +1,6 @@
+import foo, { bar } from "foo";
+import * as ns from "ns";
+export { default as def } from "def";
+
+foo(bar, ns);
+export const load = () => import("./foo.js");
@@ -0,0 +1,14 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.load = void 0;
+var _foo = require("foo");
+var _ns = babelHelpers.interopRequireWildcard(require("ns"), true);
+var _def = require("def");
+Object.defineProperty(exports, "def", {
+  enumerable: true,
+  get: function() {
+    return _def;
+  }
+});
+_foo(_foo.bar, _ns);
+const load = exports.load = () => Promise.resolve().then(() => babelHelpers.interopRequireWildcard(require("./foo.js"), true));
@@ -0,0 +1,5 @@
+import foo, { bar } from "foo";
+import * as ns from "ns";
+
+foo(bar, ns);
+export const load = () => import("./foo.js");
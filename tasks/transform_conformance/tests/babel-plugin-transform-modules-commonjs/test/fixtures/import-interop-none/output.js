@@ -0,0 +1,7 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.load = void 0;
+var _foo = require("foo");
+var _ns = require("ns");
+(0, _foo.default)(_foo.bar, _ns);
+const load = exports.load = () => Promise.resolve().then(() => require("./foo.js"));
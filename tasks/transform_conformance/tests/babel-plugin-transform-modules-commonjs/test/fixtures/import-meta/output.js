@@ -0,0 +1,3 @@
+"use strict";
+console.log(require("url").pathToFileURL(__filename).href);
+console.log({ url: require("url").pathToFileURL(__filename).href });
@@ -0,0 +1,9 @@
+import foo, { bar, "a-b" as ab } from "./foo.js";
+import * as ns from "ns";
+import def from "def";
+import "side-effect";
+
+foo(bar, ab);
+bar`tagged`;
+new def();
+ns.x(typeof def);
@@ -0,0 +1,9 @@
+"use strict";
+var _foo = babelHelpers.interopRequireWildcard(require("./foo.js"));
+var _ns = babelHelpers.interopRequireWildcard(require("ns"));
+var _def = babelHelpers.interopRequireDefault(require("def"));
+require("side-effect");
+(0, _foo.default)(_foo.bar, _foo["a-b"]);
+(0, _foo.bar)`tagged`;
+new _def.default();
+_ns.x(typeof _def.default);
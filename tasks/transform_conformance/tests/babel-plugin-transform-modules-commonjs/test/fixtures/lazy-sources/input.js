@@ -0,0 +1,5 @@
+import foo from "foo";
+import bar from "bar";
+import local from "./local.js";
+
+foo(bar, local);
@@ -0,0 +1,17 @@
+"use strict";
+var _foo = babelHelpers.interopRequireDefault(require("foo"));
+function _bar() {
+  const data = babelHelpers.interopRequireDefault(require("bar"));
+  _bar = function() {
+    return data;
+  };
+  return data;
+}
+function _local() {
+  const data = babelHelpers.interopRequireDefault(require("./local.js"));
+  _local = function() {
+    return data;
+  };
+  return data;
+}
+(0, _foo.default)(_bar().default, _local().default);
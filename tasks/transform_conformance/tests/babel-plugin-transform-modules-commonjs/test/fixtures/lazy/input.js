@@ -0,0 +1,8 @@
+import foo, { bar } from "foo";
+import * as ns from "ns";
+import local from "./local.js";
+import "side-effect";
+export { baz } from "baz";
+export * from "all";
+
+foo(bar, ns, local);
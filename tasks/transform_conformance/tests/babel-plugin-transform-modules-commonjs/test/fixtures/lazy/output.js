@@ -0,0 +1,43 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+function _foo() {
+  const data = babelHelpers.interopRequireWildcard(require("foo"));
+  _foo = function() {
+    return data;
+  };
+  return data;
+}
+function _ns() {
+  const data = babelHelpers.interopRequireWildcard(require("ns"));
+  _ns = function() {
+    return data;
+  };
+  return data;
+}
+var _local = babelHelpers.interopRequireDefault(require("./local.js"));
+require("side-effect");
+function _baz() {
+  const data = require("baz");
+  _baz = function() {
+    return data;
+  };
+  return data;
+}
+Object.defineProperty(exports, "baz", {
+  enumerable: true,
+  get: function() {
+    return _baz().baz;
+  }
+});
+var _all = require("all");
+Object.keys(_all).forEach(function(key) {
+  if (key === "default" || key === "__esModule" || key === "baz") return;
+  if (key in exports && exports[key] === _all[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _all[key];
+    }
+  });
+});
+(0, _foo().default)(_foo().bar, _ns(), _local.default);
@@ -0,0 +1,41 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.local = void 0;
+var _foo = babelHelpers.interopRequireWildcard(require("foo"));
+Object.defineProperty(exports, "a", {
+  enumerable: true,
+  get: function() {
+    return _foo.a;
+  }
+});
+Object.defineProperty(exports, "c", {
+  enumerable: true,
+  get: function() {
+    return _foo.b;
+  }
+});
+Object.defineProperty(exports, "default", {
+  enumerable: true,
+  get: function() {
+    return _foo.default;
+  }
+});
+var _bar = babelHelpers.interopRequireWildcard(require("bar"));
+Object.defineProperty(exports, "ns", {
+  enumerable: true,
+  get: function() {
+    return _bar;
+  }
+});
+var _baz = require("baz");
+Object.keys(_baz).forEach(function(key) {
+  if (key === "default" || key === "__esModule" || key === "local" || key === "a" || key === "c" || key === "ns") return;
+  if (key in exports && exports[key] === _baz[key]) return;
+  Object.defineProperty(exports, key, {
+    enumerable: true,
+    get: function() {
+      return _baz[key];
+    }
+  });
+});
+const local = exports.local = 1;
@@ -0,0 +1,5 @@
+import { createRequire } from "module";
+export const require = createRequire(import.meta.url);
+const module = require("./foo.cjs");
+function exports() {}
+export { module, exports };
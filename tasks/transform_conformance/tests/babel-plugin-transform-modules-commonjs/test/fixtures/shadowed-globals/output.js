@@ -0,0 +1,8 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.require = exports.module = void 0;
+exports.exports = _exports;
+var _module = require("module");
+const _require = exports.require = (0, _module.createRequire)(require("url").pathToFileURL(__filename).href);
+const _module2 = exports.module = _require("./foo.cjs");
+function _exports() {}
@@ -0,0 +1,3 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.a = void 0;
+const a = exports.a = 1;
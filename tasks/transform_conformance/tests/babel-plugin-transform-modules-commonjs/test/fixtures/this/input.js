@@ -0,0 +1,8 @@
+this.foo;
+function f() {
+  return this;
+}
+const g = () => this;
+class C {
+  x = this;
+}
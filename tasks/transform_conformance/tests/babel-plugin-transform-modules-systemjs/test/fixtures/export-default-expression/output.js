@@ -0,0 +1,9 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  return {
+    setters: [],
+    execute: function() {
+      _export("default", 1 + 2);
+    }
+  };
+});
@@ -0,0 +1,15 @@
+export let count = 0;
+export var a = 1, b;
+export function inc() {
+  count++;
+}
+export class C {}
+export const { x, y: [z] } = obj;
+export { count as total };
+export default function () {}
+
+let local = 1;
+{
+  var nested = 2;
+}
+b = a + local + nested;
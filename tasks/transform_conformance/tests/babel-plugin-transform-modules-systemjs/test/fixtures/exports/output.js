@@ -0,0 +1,24 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var C, count, a, b, x, z, local, nested;
+  function inc() {
+    _export("count", _export("total", ++count));
+  }
+  function _default() {}
+  _export("inc", inc);
+  _export("default", _default);
+  return {
+    setters: [],
+    execute: function() {
+      _export("count", _export("total", count = 0));
+      _export("a", a = 1);
+      _export("C", C = class {});
+      ({x: x, y: [z]} = obj), _export("x", x), _export("z", z);
+      local = 1;
+      {
+        nested = 2;
+      }
+      _export("b", b = a + local + nested);
+    }
+  };
+});
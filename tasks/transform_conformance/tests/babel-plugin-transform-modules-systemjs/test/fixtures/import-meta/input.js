@@ -0,0 +1,2 @@
+console.log(import.meta.url, import.meta);
+export const load = () => import("./foo.js");
@@ -0,0 +1,11 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var load;
+  return {
+    setters: [],
+    execute: function() {
+      console.log(_context.meta.url, _context.meta);
+      _export("load", load = () => _context.import("./foo.js"));
+    }
+  };
+});
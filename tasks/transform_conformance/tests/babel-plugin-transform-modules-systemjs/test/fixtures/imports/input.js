@@ -0,0 +1,4 @@
+import foo, { bar } from "foo";
+import * as ns from "ns";
+import "side-effect";
+foo(bar, ns);
@@ -0,0 +1,23 @@
+System.register([
+  "foo",
+  "ns",
+  "side-effect"
+], function(_export, _context) {
+  "use strict";
+  var foo, bar, ns;
+  return {
+    setters: [
+      function(_foo) {
+        foo = _foo.default;
+        bar = _foo.bar;
+      },
+      function(_ns) {
+        ns = _ns;
+      },
+      function(_sideEffect) {}
+    ],
+    execute: function() {
+      foo(bar, ns);
+    }
+  };
+});
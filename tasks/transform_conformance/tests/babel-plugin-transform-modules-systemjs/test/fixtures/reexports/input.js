@@ -0,0 +1,5 @@
+import { a } from "foo";
+export { a };
+export { b as c, d } from "foo";
+export * from "bar";
+export const local = 1;
@@ -0,0 +1,23 @@
+System.register(["foo", "bar"], function(_export, _context) {
+  "use strict";
+  var a, local;
+  return {
+    setters: [function(_foo) {
+      a = _foo.a;
+      _export({
+        a: _foo.a,
+        c: _foo.b,
+        d: _foo.d
+      });
+    }, function(_bar) {
+      var _exportObj = {};
+      for (var _key in _bar) {
+        if (_key !== "default" && _key !== "__esModule" && _key !== "local" && _key !== "a" && _key !== "c" && _key !== "d") _exportObj[_key] = _bar[_key];
+      }
+      _export(_exportObj);
+    }],
+    execute: function() {
+      _export("local", local = 1);
+    }
+  };
+});
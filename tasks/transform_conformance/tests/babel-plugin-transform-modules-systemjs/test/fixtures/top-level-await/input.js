@@ -0,0 +1 @@
+export const data = await fetch("/data");
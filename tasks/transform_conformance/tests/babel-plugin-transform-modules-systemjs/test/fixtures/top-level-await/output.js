@@ -0,0 +1,10 @@
+System.register([], function(_export, _context) {
+  "use strict";
+  var data;
+  return {
+    setters: [],
+    execute: async function() {
+      _export("data", data = await fetch("/data"));
+    }
+  };
+});
@@ -0,0 +1,7 @@
+import foo, { bar } from "foo";
+import * as baz from "./baz-qux.js";
+export let count = 0;
+export function inc() {
+  count += 1;
+}
+foo(bar, baz);
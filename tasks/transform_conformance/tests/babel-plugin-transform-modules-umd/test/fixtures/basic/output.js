@@ -0,0 +1,27 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo",
+      "./baz-qux.js"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"), require("./baz-qux.js"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.foo, global.bazQux);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _bazQux) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  _exports.count = void 0;
+  _exports.inc = inc;
+  _foo = babelHelpers.interopRequireWildcard(_foo);
+  _bazQux = babelHelpers.interopRequireWildcard(_bazQux);
+  let count = _exports.count = 0;
+  function inc() {
+    _exports.count = count += 1;
+  }
+  (0, _foo.default)(_foo.bar, _bazQux);
+});
@@ -0,0 +1 @@
+console.log(import.meta.url);
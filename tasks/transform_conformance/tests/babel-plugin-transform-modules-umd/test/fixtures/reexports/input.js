@@ -0,0 +1,2 @@
+export { a as b } from "foo";
+export * from "bar";
@@ -0,0 +1,34 @@
+(function(global, factory) {
+  if (typeof define === "function" && define.amd) {
+    define([
+      "exports",
+      "foo",
+      "bar"
+    ], factory);
+  } else if (typeof exports !== "undefined") {
+    factory(exports, require("foo"), require("bar"));
+  } else {
+    var mod = { exports: {} };
+    factory(mod.exports, global.foo, global.bar);
+    global.input = mod.exports;
+  }
+})(typeof globalThis !== "undefined" ? globalThis : typeof self !== "undefined" ? self : this, function(_exports, _foo, _bar) {
+  "use strict";
+  Object.defineProperty(_exports, "__esModule", { value: true });
+  Object.defineProperty(_exports, "b", {
+    enumerable: true,
+    get: function() {
+      return _foo.a;
+    }
+  });
+  Object.keys(_bar).forEach(function(key) {
+    if (key === "default" || key === "__esModule" || key === "b") return;
+    if (key in _exports && _exports[key] === _bar[key]) return;
+    Object.defineProperty(_exports, key, {
+      enumerable: true,
+      get: function() {
+        return _bar[key];
+      }
+    });
+  });
+});
@@ -0,0 +1,10 @@
+const f = function() {
+  var a = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : 1;
+  return a;
+};
+const g = function(a) {
+  for (var _len = arguments.length, rest = new Array(_len > 1 ? _len - 1 : 0), _key = 1; _key < _len; _key++) {
+    rest[_key - 1] = arguments[_key];
+  }
+  return rest;
+};
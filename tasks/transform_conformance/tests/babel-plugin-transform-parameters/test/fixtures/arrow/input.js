@@ -0,0 +1,2 @@
+const f = (a = 1) => a;
+const g = (a, ...rest) => rest;
@@ -0,0 +1,7 @@
+const f = (a) => {
+  if (a === void 0) {
+    a = 1;
+  }
+  return a;
+};
+const g = (a, ...rest) => rest;
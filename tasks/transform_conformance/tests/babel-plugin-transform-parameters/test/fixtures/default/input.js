@@ -0,0 +1,4 @@
+function f(a, b = 1, { c }, d) {
+  return a + b + c + d;
+}
+function g({ x }, y = x) {}
@@ -0,0 +1,10 @@
+function f(a) {
+  var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+  var { c } = arguments.length > 2 ? arguments[2] : void 0;
+  var d = arguments.length > 3 ? arguments[3] : void 0;
+  return a + b + c + d;
+}
+function g(_ref) {
+  var { x } = _ref;
+  var y = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : x;
+}
@@ -0,0 +1 @@
+function f(a, b = 1, { c } = {}) {}
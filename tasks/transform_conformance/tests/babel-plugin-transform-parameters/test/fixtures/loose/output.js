@@ -0,0 +1,6 @@
+function f(a, b, _ref) {
+  if (b === void 0) {
+    b = 1;
+  }
+  var { c } = _ref === void 0 ? {} : _ref;
+}
@@ -0,0 +1,5 @@
+function f(...args) {
+  return args;
+}
+function g(a, ...[b, c]) {}
+function h(a, b = 1, ...rest) {}
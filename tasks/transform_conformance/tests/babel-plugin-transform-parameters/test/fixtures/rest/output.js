@@ -0,0 +1,18 @@
+function f() {
+  for (var _len = arguments.length, args = new Array(_len), _key = 0; _key < _len; _key++) {
+    args[_key] = arguments[_key];
+  }
+  return args;
+}
+function g(a) {
+  for (var _len2 = arguments.length, _ref = new Array(_len2 > 1 ? _len2 - 1 : 0), _key2 = 1; _key2 < _len2; _key2++) {
+    _ref[_key2 - 1] = arguments[_key2];
+  }
+  var [b, c] = _ref;
+}
+function h(a) {
+  var b = arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : 1;
+  for (var _len3 = arguments.length, rest = new Array(_len3 > 2 ? _len3 - 2 : 0), _key3 = 2; _key3 < _len3; _key3++) {
+    rest[_key3 - 2] = arguments[_key3];
+  }
+}
@@ -0,0 +1,6 @@
+var o = {
+  set x(v = 1) {},
+};
+class C {
+  set y({ a } = {}) {}
+}
@@ -0,0 +1,10 @@
+var o = { set x(v) {
+  if (v === void 0) {
+    v = 1;
+  }
+} };
+class C {
+  set y(_ref) {
+    var { a } = _ref === void 0 ? {} : _ref;
+  }
+}
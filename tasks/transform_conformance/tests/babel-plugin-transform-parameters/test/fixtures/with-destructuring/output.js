@@ -0,0 +1,5 @@
+function f() {
+  var _ref = arguments.length > 0 && arguments[0] !== void 0 ? arguments[0] : {}, a = _ref.a, b = _ref.b;
+  var _ref2 = babelHelpers.slicedToArray(arguments.length > 1 && arguments[1] !== void 0 ? arguments[1] : [], 1), c = _ref2[0];
+  return a + b + c;
+}
@@ -1,15 +1,15 @@
 "use strict";
-import { jsx as _jsx } from "react/jsx-runtime";
 Object.defineProperty(exports, "__esModule", { value: true });
-(exports.default = App);
+exports.default = App;
 var _hooks = require("./hooks");
+var _jsxRuntime = require("react/jsx-runtime");
 var _s = $RefreshSig$();
 function App() {
   _s();
   const bar = (0, _hooks.useFancyState)();
-  return _jsx("h1", { children: bar });
+  return /* @__PURE__ */ (0, _jsxRuntime.jsx)("h1", { children: bar });
 }
-_s(App, "useFancyState{bar}", false, function() {
+_s(App, "rz4co8LypA7kpuSvM8OvWs/wIW4=", false, function() {
   return [_hooks.useFancyState];
 });
 _c = App;
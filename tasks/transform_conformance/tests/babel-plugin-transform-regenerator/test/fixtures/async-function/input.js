@@ -0,0 +1,18 @@
+async function foo(x) {
+  const a = await x;
+  try {
+    return await bar(a);
+  } catch {
+    return null;
+  }
+}
+
+const baz = async () => {
+  await foo(this);
+};
+
+class A {
+  async method() {
+    await this.foo;
+  }
+}
@@ -0,0 +1,62 @@
+var _this = this;
+function foo(_x) {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee(x) {
+    var a;
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return x;
+        case 2:
+          a = _context.sent;
+          _context.prev = 3;
+          _context.next = 6;
+          return bar(a);
+        case 6: return _context.abrupt("return", _context.sent);
+        case 9:
+          _context.prev = 9;
+          _context["catch"](3);
+          return _context.abrupt("return", null);
+        case 12: _context.prev = 12;
+        case 13:
+        case "end": return _context.stop();
+      }
+    }, _callee, null, [[3, 9]]);
+  }));
+  return _foo.apply(this, arguments);
+}
+const baz = function() {
+  var _ref = babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee2() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return foo(_this);
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, _callee2);
+  }));
+  return function baz() {
+    return _ref.apply(this, arguments);
+  };
+}();
+class A {
+  method() {
+    var _this2 = this;
+    return babelHelpers.asyncToGenerator(babelHelpers.regeneratorRuntime().mark(function _callee3() {
+      return babelHelpers.regeneratorRuntime().wrap(function _callee3$(_context3) {
+        while (1) switch (_context3.prev = _context3.next) {
+          case 0:
+            _context3.next = 2;
+            return _this2.foo;
+          case 2:
+          case "end": return _context3.stop();
+        }
+      }, _callee3);
+    }))();
+  }
+}
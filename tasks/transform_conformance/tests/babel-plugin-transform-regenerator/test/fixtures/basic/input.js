@@ -0,0 +1,14 @@
+function* gen(a) {
+  var x = a + 1;
+  const y = yield x;
+  let z = yield y * 2;
+  return x + y + z + arguments.length;
+}
+
+const expr = function* () {
+  yield this.value;
+};
+
+export default function* () {
+  yield 1;
+}
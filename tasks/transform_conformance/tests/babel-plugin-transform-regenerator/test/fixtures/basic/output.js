@@ -0,0 +1,43 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(_callee2);
+function gen(a) {
+  var x, y, z, _args = arguments;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        x = a + 1;
+        _context.next = 3;
+        return x;
+      case 3:
+        y = _context.sent;
+        _context.next = 6;
+        return y * 2;
+      case 6:
+        z = _context.sent;
+        return _context.abrupt("return", x + y + z + _args.length);
+      case 8:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+const expr = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(function _callee() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.next = 2;
+        return this.value;
+      case 2:
+      case "end": return _context2.stop();
+    }
+  }, _callee, this);
+});
+export default function _callee2() {
+  return babelHelpers.regeneratorRuntime().wrap(function _callee2$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0:
+        _context3.next = 2;
+        return 1;
+      case 2:
+      case "end": return _context3.stop();
+    }
+  }, _marked2);
+}
@@ -0,0 +1,28 @@
+function* fib() {
+  let [a, b] = [0, 1];
+  while (true) {
+    const reset = yield a;
+    if (reset) [a, b] = [0, 1];
+    else [a, b] = [b, a + b];
+  }
+}
+
+const it = fib();
+expect([it.next().value, it.next().value, it.next().value, it.next().value]).toEqual([0, 1, 1, 2]);
+expect(it.next(true).value).toBe(0);
+
+function* withFinally(log) {
+  try {
+    yield 1;
+    yield 2;
+  } finally {
+    log.push("finally");
+  }
+}
+
+const log = [];
+const gen = withFinally(log);
+expect(gen.next()).toEqual({ value: 1, done: false });
+expect(gen.return(5)).toEqual({ value: 5, done: true });
+expect(log).toEqual(["finally"]);
+expect([...withFinally([])]).toEqual([1, 2]);
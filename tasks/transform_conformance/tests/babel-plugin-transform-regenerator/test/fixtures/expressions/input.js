@@ -0,0 +1,16 @@
+function* gen(obj, fn) {
+  const a = (yield 1) || (yield 2);
+  const b = (yield 3) ? yield 4 : 5;
+  obj.method(yield 6, a);
+  fn(yield 7);
+  obj.count += yield 8;
+  const c = [yield 9, ...(yield 10)];
+  const d = { a, [yield 11]: yield 12 };
+  return a ?? b;
+}
+
+function* logicalAssignment(obj) {
+  obj.a ||= yield 1;
+  obj[yield 2] &&= 3;
+  return obj;
+}
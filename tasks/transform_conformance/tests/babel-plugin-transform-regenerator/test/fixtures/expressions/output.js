@@ -0,0 +1,110 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(logicalAssignment);
+function gen(obj, fn) {
+  var a, b, c, d;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2:
+        _context.t0 = _context.sent;
+        if (_context.t0) {
+          _context.next = 7;
+          break;
+        }
+        _context.next = 6;
+        return 2;
+      case 6: _context.t0 = _context.sent;
+      case 7:
+        a = _context.t0;
+        _context.next = 10;
+        return 3;
+      case 10:
+        if (!_context.sent) {
+          _context.next = 16;
+          break;
+        }
+        _context.next = 13;
+        return 4;
+      case 13:
+        _context.t1 = _context.sent;
+        _context.next = 17;
+        break;
+      case 16: _context.t1 = 5;
+      case 17:
+        b = _context.t1;
+        _context.t2 = obj;
+        _context.next = 21;
+        return 6;
+      case 21:
+        _context.t3 = _context.sent;
+        _context.t4 = a;
+        _context.t2.method.call(_context.t2, _context.t3, _context.t4);
+        _context.t5 = fn;
+        _context.next = 27;
+        return 7;
+      case 27:
+        _context.t6 = _context.sent;
+        (0, _context.t5)(_context.t6);
+        _context.t7 = obj.count;
+        _context.next = 32;
+        return 8;
+      case 32:
+        obj.count = _context.t7 += _context.sent;
+        _context.next = 35;
+        return 9;
+      case 35:
+        _context.t8 = _context.sent;
+        _context.next = 38;
+        return 10;
+      case 38:
+        _context.t9 = _context.sent;
+        c = [_context.t8, ..._context.t9];
+        _context.t10 = a;
+        _context.next = 43;
+        return 11;
+      case 43:
+        _context.t11 = _context.sent;
+        _context.next = 46;
+        return 12;
+      case 46:
+        _context.t12 = _context.sent;
+        d = {
+          a: _context.t10,
+          [_context.t11]: _context.t12
+        };
+        return _context.abrupt("return", a ?? b);
+      case 49:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+function logicalAssignment(obj) {
+  return babelHelpers.regeneratorRuntime().wrap(function logicalAssignment$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0:
+        _context2.t0 = obj.a;
+        if (_context2.t0) {
+          _context2.next = 5;
+          break;
+        }
+        _context2.next = 4;
+        return 1;
+      case 4: _context2.t0 = obj.a = _context2.sent;
+      case 5:
+        _context2.next = 7;
+        return 2;
+      case 7:
+        _context2.t1 = _context2.sent;
+        _context2.t2 = obj[_context2.t1];
+        if (!_context2.t2) {
+          _context2.next = 11;
+          break;
+        }
+        _context2.t2 = obj[_context2.t1] = 3;
+      case 11: return _context2.abrupt("return", obj);
+      case 12:
+      case "end": return _context2.stop();
+    }
+  }, _marked2);
+}
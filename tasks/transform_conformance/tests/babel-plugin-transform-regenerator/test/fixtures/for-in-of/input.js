@@ -0,0 +1,8 @@
+function* gen(obj, list) {
+  for (const key in obj) {
+    yield key;
+  }
+  for (const [a, b] of list) {
+    yield a + b;
+  }
+}
@@ -0,0 +1,34 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(obj, list) {
+  var key, a, b;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: _context.t0 = babelHelpers.regeneratorRuntime().keys(obj);
+      case 1:
+        if ((_context.t1 = _context.t0()).done) {
+          _context.next = 7;
+          break;
+        }
+        key = _context.t1.value;
+        _context.next = 5;
+        return key;
+      case 5:
+        _context.next = 1;
+        break;
+      case 7: _context.t2 = babelHelpers.regeneratorRuntime().values(list);
+      case 8:
+        if ((_context.t3 = _context.t2.next()).done) {
+          _context.next = 14;
+          break;
+        }
+        [a, b] = _context.t3.value;
+        _context.next = 12;
+        return a + b;
+      case 12:
+        _context.next = 8;
+        break;
+      case 14:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,14 @@
+function* gen(n) {
+  outer: for (let i = 0; i < n; i++) {
+    let j = 0;
+    while (true) {
+      j++;
+      if (j > i) continue outer;
+      if (j === 3) break outer;
+      yield [i, j];
+    }
+  }
+  do {
+    yield n--;
+  } while (n > 0);
+}
@@ -0,0 +1,51 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen(n) {
+  var i, j;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0: i = 0;
+      case 1:
+        if (!(i < n)) {
+          _context.next = 16;
+          break;
+        }
+        j = 0;
+      case 3:
+        if (!true) {
+          _context.next = 13;
+          break;
+        }
+        j++;
+        if (!(j > i)) {
+          _context.next = 7;
+          break;
+        }
+        return _context.abrupt("continue", 13);
+      case 7:
+        if (!(j === 3)) {
+          _context.next = 9;
+          break;
+        }
+        return _context.abrupt("break", 16);
+      case 9:
+        _context.next = 11;
+        return [i, j];
+      case 11:
+        _context.next = 3;
+        break;
+      case 13:
+        i++;
+        _context.next = 1;
+        break;
+      case 16:
+        _context.next = 18;
+        return n--;
+      case 18: if (n > 0) {
+        _context.next = 16;
+        break;
+      }
+      case 19:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
@@ -0,0 +1,14 @@
+class A {
+  *values() {
+    yield this.a;
+  }
+  *["computed key"]() {
+    yield 1;
+  }
+}
+
+const obj = {
+  *items() {
+    yield* this.list;
+  },
+};
@@ -0,0 +1,33 @@
+class A {
+  values() {
+    return babelHelpers.regeneratorRuntime().wrap(function values$(_context) {
+      while (1) switch (_context.prev = _context.next) {
+        case 0:
+          _context.next = 2;
+          return this.a;
+        case 2:
+        case "end": return _context.stop();
+      }
+    }, null, this);
+  }
+  ["computed key"]() {
+    return babelHelpers.regeneratorRuntime().wrap(function _callee$(_context2) {
+      while (1) switch (_context2.prev = _context2.next) {
+        case 0:
+          _context2.next = 2;
+          return 1;
+        case 2:
+        case "end": return _context2.stop();
+      }
+    }, null);
+  }
+}
+const obj = { items() {
+  return babelHelpers.regeneratorRuntime().wrap(function items$(_context3) {
+    while (1) switch (_context3.prev = _context3.next) {
+      case 0: return _context3.delegateYield(this.list, "t0", 1);
+      case 1:
+      case "end": return _context3.stop();
+    }
+  }, null, this);
+} };
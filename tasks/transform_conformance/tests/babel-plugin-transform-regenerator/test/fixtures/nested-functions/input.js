@@ -0,0 +1,10 @@
+function* gen() {
+  function helper() {
+    return arguments.length;
+  }
+  const arrow = () => arguments[0];
+  {
+    let gen = yield helper();
+    yield arrow(gen);
+  }
+}
@@ -0,0 +1,21 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var arrow, _gen, _args = arguments;
+  function helper() {
+    return arguments.length;
+  }
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        arrow = () => _args[0];
+        _context.next = 3;
+        return helper();
+      case 3:
+        _gen = _context.sent;
+        _context.next = 6;
+        return arrow(_gen);
+      case 6:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
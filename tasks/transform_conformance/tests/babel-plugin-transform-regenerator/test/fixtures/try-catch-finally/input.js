@@ -0,0 +1,16 @@
+function* gen() {
+  try {
+    yield 1;
+    throw new Error("x");
+  } catch (err) {
+    yield err.message;
+  } finally {
+    yield 3;
+    cleanup();
+  }
+  try {
+    yield 4;
+  } finally {
+    done();
+  }
+}
@@ -0,0 +1,49 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(gen);
+function gen() {
+  var err;
+  return babelHelpers.regeneratorRuntime().wrap(function gen$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.prev = 0;
+        _context.next = 3;
+        return 1;
+      case 3: throw new Error("x");
+      case 6:
+        _context.prev = 6;
+        err = _context["catch"](0);
+        _context.next = 10;
+        return err.message;
+      case 10:
+        _context.prev = 10;
+        _context.next = 13;
+        return 3;
+      case 13:
+        cleanup();
+        return _context.finish(10);
+      case 15:
+        _context.prev = 15;
+        _context.prev = 16;
+        _context.next = 19;
+        return 4;
+      case 19:
+        _context.prev = 19;
+        {
+          done();
+        }
+        return _context.finish(19);
+      case 22: _context.prev = 22;
+      case 23:
+      case "end": return _context.stop();
+    }
+  }, _marked, null, [[
+    0,
+    6,
+    10,
+    15
+  ], [
+    16,
+    ,
+    19,
+    22
+  ]]);
+}
@@ -0,0 +1,27 @@
+var _marked = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(inner), _marked2 = /* @__PURE__ */ babelHelpers.regeneratorRuntime().mark(outer);
+function inner() {
+  return babelHelpers.regeneratorRuntime().wrap(function inner$(_context) {
+    while (1) switch (_context.prev = _context.next) {
+      case 0:
+        _context.next = 2;
+        return 1;
+      case 2: return _context.abrupt("return", 2);
+      case 3:
+      case "end": return _context.stop();
+    }
+  }, _marked);
+}
+function outer() {
+  var result;
+  return babelHelpers.regeneratorRuntime().wrap(function outer$(_context2) {
+    while (1) switch (_context2.prev = _context2.next) {
+      case 0: return _context2.delegateYield(inner(), "t0", 1);
+      case 1:
+        result = _context2.t0;
+        _context2.next = 4;
+        return result;
+      case 4:
+      case "end": return _context2.stop();
+    }
+  }, _marked2);
+}
@@ -0,0 +1,7 @@
+var obj = {
+  a,
+  b() {},
+  *c() {},
+  async d() {},
+  e: 1,
+};
@@ -0,0 +1,7 @@
+var obj = {
+  a: a,
+  b: function() {},
+  c: function* () {},
+  d: async function() {},
+  e: 1
+};
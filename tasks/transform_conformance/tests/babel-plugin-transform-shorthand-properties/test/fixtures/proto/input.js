@@ -0,0 +1,2 @@
+var a = { __proto__ };
+var b = { __proto__() {} };
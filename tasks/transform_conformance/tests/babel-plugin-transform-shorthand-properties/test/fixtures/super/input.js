@@ -0,0 +1,10 @@
+var obj = {
+  a() {
+    return super.a();
+  },
+  b() {
+    return function () {
+      return 1;
+    };
+  },
+};
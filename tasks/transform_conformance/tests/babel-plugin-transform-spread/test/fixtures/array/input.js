@@ -0,0 +1,7 @@
+[...a];
+[a, ...b, c];
+[...a, ...b];
+[...[1, 2], 3];
+function f() {
+  return [...arguments];
+}
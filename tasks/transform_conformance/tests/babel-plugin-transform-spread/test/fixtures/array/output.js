@@ -0,0 +1,7 @@
+babelHelpers.toConsumableArray(a);
+[a].concat(babelHelpers.toConsumableArray(b), [c]);
+[].concat(babelHelpers.toConsumableArray(a), babelHelpers.toConsumableArray(b));
+[].concat([1, 2], [3]);
+function f() {
+  return Array.prototype.slice.call(arguments);
+}
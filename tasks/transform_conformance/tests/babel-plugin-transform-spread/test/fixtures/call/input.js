@@ -0,0 +1,8 @@
+foo(...args);
+foo(a, ...args, b);
+obj.foo(...args);
+obj.bar.baz(...args);
+obj[key](...args);
+function f() {
+  return g(...arguments);
+}
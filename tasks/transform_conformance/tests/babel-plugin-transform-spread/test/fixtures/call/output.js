@@ -0,0 +1,9 @@
+var _obj, _obj$bar, _obj2;
+foo.apply(void 0, babelHelpers.toConsumableArray(args));
+foo.apply(void 0, [a].concat(babelHelpers.toConsumableArray(args), [b]));
+(_obj = obj).foo.apply(_obj, babelHelpers.toConsumableArray(args));
+(_obj$bar = obj.bar).baz.apply(_obj$bar, babelHelpers.toConsumableArray(args));
+(_obj2 = obj)[key].apply(_obj2, babelHelpers.toConsumableArray(args));
+function f() {
+  return g.apply(void 0, arguments);
+}
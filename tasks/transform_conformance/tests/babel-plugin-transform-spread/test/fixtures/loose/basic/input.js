@@ -0,0 +1,3 @@
+[a, ...b];
+foo(...args);
+obj.foo(a, ...args);
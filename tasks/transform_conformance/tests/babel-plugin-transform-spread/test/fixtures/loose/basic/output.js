@@ -0,0 +1,4 @@
+var _obj;
+[a].concat(b);
+foo.apply(void 0, args);
+(_obj = obj).foo.apply(_obj, [a].concat(args));
@@ -0,0 +1,5 @@
+`a${b}c${d}`;
+`${a}`;
+`${a}${b}`;
+`a${1}b${"c"}${d}${e}f`;
+``;
@@ -0,0 +1,5 @@
+"a".concat(b, "c").concat(d);
+"".concat(a);
+"".concat(a).concat(b);
+"a".concat(1, "b", "c", d).concat(e, "f");
+"";
@@ -0,0 +1,3 @@
+`a${b}c${d}`;
+`${a}${b}`;
+`${a}b`;
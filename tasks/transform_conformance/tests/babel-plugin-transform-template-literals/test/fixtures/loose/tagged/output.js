@@ -0,0 +1,2 @@
+var _templateObject;
+tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteralLoose(["a", "\n"], ["a", "\\n"])), b);
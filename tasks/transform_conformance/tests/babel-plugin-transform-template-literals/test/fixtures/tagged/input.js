@@ -0,0 +1,6 @@
+function foo() {
+  return tag`a${b}\n${c}`;
+}
+tag`plain`;
+tag`\unicode and \u{55}`;
+obj.method`x`;
@@ -0,0 +1,15 @@
+var _templateObject, _templateObject2, _templateObject3, _templateObject4;
+function foo() {
+  return tag(_templateObject || (_templateObject = babelHelpers.taggedTemplateLiteral([
+    "a",
+    "\n",
+    ""
+  ], [
+    "a",
+    "\\n",
+    ""
+  ])), b, c);
+}
+tag(_templateObject2 || (_templateObject2 = babelHelpers.taggedTemplateLiteral(["plain"])));
+tag(_templateObject3 || (_templateObject3 = babelHelpers.taggedTemplateLiteral([void 0], ["\\unicode and \\u{55}"])));
+obj.method(_templateObject4 || (_templateObject4 = babelHelpers.taggedTemplateLiteral(["x"])));